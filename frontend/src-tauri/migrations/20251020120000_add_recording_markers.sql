@@ -0,0 +1,14 @@
+-- Migration: Add recording markers (bookmarks) table
+-- Markers flag important moments during a recording ("decision here", "follow up").
+--   - recording_time: Seconds from recording start, same timeline as transcripts.audio_start_time
+--   - label: Optional user-provided label
+CREATE TABLE IF NOT EXISTS recording_markers (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    recording_time REAL NOT NULL,
+    label TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_recording_markers_meeting_id ON recording_markers(meeting_id);
@@ -0,0 +1,5 @@
+-- Migration: Add word-level timings to transcripts
+-- JSON array of {"word", "start", "end", "probability"} objects, recording-relative
+-- like audio_start_time. NULL for transcripts saved before word timings existed or
+-- by engines that don't report them.
+ALTER TABLE transcripts ADD COLUMN words TEXT;
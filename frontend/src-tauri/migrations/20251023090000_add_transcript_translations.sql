@@ -0,0 +1,5 @@
+-- Migration: Add translation track to transcripts (bilingual meetings)
+-- translation holds the segment translated into translation_language (ISO 639-1);
+-- both are NULL for monolingual meetings or segments whose translation never arrived.
+ALTER TABLE transcripts ADD COLUMN translation TEXT;
+ALTER TABLE transcripts ADD COLUMN translation_language TEXT;
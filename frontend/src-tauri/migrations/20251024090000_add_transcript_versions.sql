@@ -0,0 +1,20 @@
+-- Migration: Transcript versions, speaker labels and user edits
+-- Re-transcribing a meeting with a larger model replaces its segments; the previous
+-- segments are archived as a version so the change can be undone.
+--   - speaker: Optional speaker label of a segment
+--   - user_edited: 1 when the user corrected the segment text (kept on re-transcription)
+--   - transcript_versions.segments: JSON array of the archived segments
+ALTER TABLE transcripts ADD COLUMN speaker TEXT;
+ALTER TABLE transcripts ADD COLUMN user_edited INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS transcript_versions (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    label TEXT NOT NULL,
+    segments TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_transcript_versions_meeting_id ON transcript_versions(meeting_id);
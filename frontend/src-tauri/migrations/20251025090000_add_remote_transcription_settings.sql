@@ -0,0 +1,5 @@
+-- Add OpenAI-compatible remote transcription settings
+--   - remoteEndpoint: Base URL of the /v1/audio/transcriptions server (NULL = provider default)
+--   - openaiCompatibleApiKey: Optional bearer token for self-hosted or third-party servers
+ALTER TABLE transcript_settings ADD COLUMN remoteEndpoint TEXT;
+ALTER TABLE transcript_settings ADD COLUMN openaiCompatibleApiKey TEXT;
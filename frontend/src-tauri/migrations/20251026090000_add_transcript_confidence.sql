@@ -0,0 +1,4 @@
+-- Migration: Persist per-segment recognition confidence
+-- confidence is the engine's score in [0, 1] (mean token probability for Whisper);
+-- NULL for segments saved before this migration.
+ALTER TABLE transcripts ADD COLUMN confidence REAL;
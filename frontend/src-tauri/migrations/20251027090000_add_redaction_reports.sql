@@ -0,0 +1,17 @@
+-- Migration: Per-meeting PII redaction reports
+-- One row per placeholder handed out while redacting a meeting. Original values are
+-- never stored.
+--   - stage: 'storage' (transcript redacted before saving) or 'llm' (summary prompt only)
+--   - placeholder: e.g. [EMAIL_1]; occurrences counts how often the value appeared
+CREATE TABLE IF NOT EXISTS redaction_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    stage TEXT NOT NULL,
+    category TEXT NOT NULL,
+    placeholder TEXT NOT NULL,
+    occurrences INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_redaction_reports_meeting_id ON redaction_reports(meeting_id);
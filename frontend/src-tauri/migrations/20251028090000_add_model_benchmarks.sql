@@ -0,0 +1,19 @@
+-- Migration: Local transcription model benchmarks
+-- Latest benchmark run per installed model on this machine.
+--   - engine: 'whisper' or 'parakeet'
+--   - real_time_factor: processing time / audio duration (below 1.0 keeps up with live audio)
+--   - word_error_rate: against the sample's reference text, NULL when no reference was given
+CREATE TABLE IF NOT EXISTS model_benchmarks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    engine TEXT NOT NULL,
+    model_name TEXT NOT NULL,
+    real_time_factor REAL NOT NULL,
+    load_time_ms INTEGER NOT NULL,
+    peak_memory_mb INTEGER NOT NULL,
+    word_error_rate REAL,
+    audio_duration_secs REAL NOT NULL,
+    performance_tier TEXT NOT NULL,
+    sample TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (engine, model_name)
+);
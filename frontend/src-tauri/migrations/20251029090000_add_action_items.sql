@@ -0,0 +1,25 @@
+-- Migration: Action items extracted from meeting summaries
+-- Items are re-extracted whenever a summary is generated; status and owner changes made
+-- by the user are kept for items whose text is unchanged.
+--   - status: 'open', 'done' or 'cancelled'
+--   - due_date: As written in the summary (YYYY-MM-DD for structured summaries)
+--   - transcript_time: Seconds from recording start, same timeline as transcripts.audio_start_time
+CREATE TABLE IF NOT EXISTS action_items (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    text TEXT NOT NULL,
+    owner TEXT,
+    due_date TEXT,
+    transcript_time REAL,
+    status TEXT NOT NULL DEFAULT 'open',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting_id ON action_items(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_action_items_status ON action_items(status);
+
+-- Recurring meetings share a series id; open items of earlier meetings in the series are
+-- carried into the next summary prompt
+ALTER TABLE meetings ADD COLUMN series_id TEXT;
@@ -0,0 +1,3 @@
+-- Add retry setting for remote transcription
+--   - remoteMaxRetries: Retries of a failed request before falling back to a local engine (NULL = default)
+ALTER TABLE transcript_settings ADD COLUMN remoteMaxRetries INTEGER;
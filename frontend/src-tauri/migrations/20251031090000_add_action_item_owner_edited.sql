@@ -0,0 +1,4 @@
+-- Migration: Remember which action item owners were set by the user
+-- Regenerating a summary refreshes the owner of an item from the new summary unless the
+-- user reassigned (or cleared) it; such items are also kept when the new summary omits them.
+ALTER TABLE action_items ADD COLUMN owner_edited INTEGER NOT NULL DEFAULT 0;
@@ -5,13 +5,17 @@ use tauri::{AppHandle, Runtime};
 use tauri_plugin_store::StoreExt;
 
 use crate::{
+    audio::transcription::WordTiming,
     database::{
         models::MeetingModel,
         repositories::{
-            meeting::MeetingsRepository, setting::SettingsRepository,
+            marker::MarkersRepository, meeting::MeetingsRepository,
+            redaction::RedactionReportsRepository, setting::SettingsRepository,
             transcript::TranscriptsRepository,
+            transcript_version::TranscriptVersionsRepository,
         },
     },
+    redaction::{self, RedactionStage},
     state::AppState,
 };
 
@@ -100,6 +104,12 @@ pub struct TranscriptConfig {
     pub model: String,
     #[serde(rename = "apiKey")]
     pub api_key: Option<String>,
+    // Base URL of an OpenAI-compatible transcription server
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    // Retries of a failed remote request before falling back to a local engine
+    #[serde(default, rename = "maxRetries")]
+    pub max_retries: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -122,6 +132,17 @@ pub struct MeetingDetails {
     pub created_at: String,
     pub updated_at: String,
     pub transcripts: Vec<MeetingTranscript>,
+    // Bookmarks stamped during recording, on the same timeline as audio_start_time
+    #[serde(default)]
+    pub markers: Vec<MeetingMarker>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeetingMarker {
+    pub id: String,
+    pub recording_time: f64,
+    pub label: Option<String>,
+    pub created_at: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -136,6 +157,22 @@ pub struct MeetingTranscript {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Word-level timings for playback highlighting and word-accurate exports
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
+    // Translation track for bilingual meetings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation_language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Corrected by the user; kept as-is when the meeting is re-transcribed
+    #[serde(default)]
+    pub user_edited: bool,
+    // Recognition confidence in [0, 1], when the engine reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -168,6 +205,17 @@ pub struct TranscriptSegment {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Word-level timings, recording-relative like audio_start_time
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
+    // Translation track for bilingual meetings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation_language: Option<String>,
+    // Recognition confidence in [0, 1], when the engine reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -591,6 +639,10 @@ pub async fn api_get_transcript_config<R: Runtime>(
                         provider: config.provider,
                         model: config.model,
                         api_key,
+                        endpoint: config.remote_endpoint,
+                        max_retries: config
+                            .remote_max_retries
+                            .and_then(|retries| u32::try_from(retries).ok()),
                     }))
                 }
                 Err(e) => {
@@ -609,6 +661,8 @@ pub async fn api_get_transcript_config<R: Runtime>(
                 provider: "localWhisper".to_string(),
                 model: "large-v3".to_string(),
                 api_key: None,
+                endpoint: None,
+                max_retries: None,
             }))
         }
         Err(e) => {
@@ -625,6 +679,8 @@ pub async fn api_save_transcript_config<R: Runtime>(
     provider: String,
     model: String,
     api_key: Option<String>,
+    endpoint: Option<String>,
+    max_retries: Option<u32>,
     _auth_token: Option<String>,
 ) -> Result<serde_json::Value, String> {
     log_info!(
@@ -632,8 +688,19 @@ pub async fn api_save_transcript_config<R: Runtime>(
         &provider
     );
     let pool = state.db_manager.pool();
+    let endpoint = endpoint
+        .map(|e| e.trim().trim_end_matches('/').to_string())
+        .filter(|e| !e.is_empty());
 
-    if let Err(e) = SettingsRepository::save_transcript_config(pool, &provider, &model).await {
+    if let Err(e) = SettingsRepository::save_transcript_config(
+        pool,
+        &provider,
+        &model,
+        endpoint.as_deref(),
+        max_retries,
+    )
+    .await
+    {
         log_error!("Failed to save transcript config: {}", e);
         return Err(e.to_string());
     }
@@ -810,6 +877,74 @@ pub async fn api_save_meeting_title<R: Runtime>(
     }
 }
 
+#[tauri::command]
+pub async fn api_update_transcript_segment<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    transcript_id: String,
+    text: String,
+    speaker: Option<String>,
+) -> Result<(), String> {
+    log_info!("api_update_transcript_segment called for {}", transcript_id);
+    let pool = state.db_manager.pool();
+    let speaker = speaker.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+
+    // Edited text goes through storage-time redaction like every other saved segment,
+    // continuing after the placeholders the meeting already uses
+    let mut text = text.trim().to_string();
+    let mut redacted = None;
+    if let Some(redactor) = redaction::storage_redactor() {
+        let meeting_id = TranscriptsRepository::meeting_id_of_segment(pool, &transcript_id)
+            .await
+            .map_err(|e| format!("Failed to update transcript segment: {}", e))?
+            .ok_or_else(|| format!("Transcript segment not found: {}", transcript_id))?;
+        let segments = TranscriptVersionsRepository::get_segments(pool, &meeting_id)
+            .await
+            .map_err(|e| format!("Failed to update transcript segment: {}", e))?;
+        let report = RedactionReportsRepository::get_report(pool, &meeting_id)
+            .await
+            .map_err(|e| format!("Failed to update transcript segment: {}", e))?;
+
+        let mut redactions = redaction::RedactionMap::reserving(
+            segments
+                .iter()
+                .map(|s| s.text.as_str())
+                .chain(report.iter().map(|e| e.placeholder.as_str())),
+        );
+        if redactor.redact_in_place(&mut text, &mut redactions) {
+            redacted = Some((meeting_id, redactions.report()));
+        }
+    }
+
+    match TranscriptsRepository::update_segment(pool, &transcript_id, &text, speaker.as_deref())
+        .await
+    {
+        Ok(true) => {
+            if let Some((meeting_id, items)) = redacted {
+                if let Err(e) = RedactionReportsRepository::add_to_report(
+                    pool,
+                    &meeting_id,
+                    RedactionStage::Storage,
+                    &items,
+                )
+                .await
+                {
+                    log_error!("Failed to save redaction report for {}: {}", meeting_id, e);
+                }
+            }
+            Ok(())
+        }
+        Ok(false) => {
+            log_warn!("Transcript segment not found: {}", transcript_id);
+            Err(format!("Transcript segment not found: {}", transcript_id))
+        }
+        Err(e) => {
+            log_error!("Failed to update transcript segment {}: {}", transcript_id, e);
+            Err(format!("Failed to update transcript segment: {}", e))
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn api_save_transcript<R: Runtime>(
     _app: AppHandle<R>,
@@ -836,7 +971,7 @@ pub async fn api_save_transcript<R: Runtime>(
     }
 
     // Convert serde_json::Value to TranscriptSegment
-    let transcripts_to_save: Vec<TranscriptSegment> = transcripts
+    let mut transcripts_to_save: Vec<TranscriptSegment> = transcripts
         .into_iter()
         .map(serde_json::from_value)
         .collect::<Result<Vec<_>, _>>()
@@ -854,8 +989,40 @@ pub async fn api_save_transcript<R: Runtime>(
                    first_seg.duration);
     }
 
+    // Storage-time PII redaction. Word timings of redacted segments are dropped since
+    // they would still carry the original tokens. Segments recovered from transcripts.json
+    // are already redacted, so their placeholders are reserved.
+    let redactor = redaction::storage_redactor();
+    let mut redactions = redaction::RedactionMap::reserving(
+        transcripts_to_save
+            .iter()
+            .flat_map(|s| std::iter::once(s.text.as_str()).chain(s.translation.as_deref())),
+    );
+    if let Some(redactor) = &redactor {
+        for segment in transcripts_to_save.iter_mut() {
+            if redactor.redact_in_place(&mut segment.text, &mut redactions) {
+                segment.words.clear();
+            }
+            if let Some(translation) = segment.translation.as_mut() {
+                redactor.redact_in_place(translation, &mut redactions);
+            }
+        }
+    }
+
     let pool = state.db_manager.pool();
 
+    // Markers stamped during recording live in the meeting folder's metadata.json
+    let markers = match folder_path.as_deref() {
+        Some(folder) => crate::audio::recording_saver::RecordingSaver::read_markers_from_folder(
+            std::path::Path::new(folder),
+        )
+        .unwrap_or_else(|e| {
+            log_warn!("Failed to read recording markers from {}: {}", folder, e);
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+
     // Now, call the repository with the correctly typed data.
     match TranscriptsRepository::save_transcript(
         pool,
@@ -870,6 +1037,22 @@ pub async fn api_save_transcript<R: Runtime>(
                 "Successfully saved transcript and created meeting with id: {}",
                 meeting_id
             );
+
+            if let Err(e) = MarkersRepository::save_markers(pool, &meeting_id, &markers).await {
+                log_error!("Failed to save recording markers for {}: {}", meeting_id, e);
+            }
+            if redactor.is_some() {
+                if let Err(e) = RedactionReportsRepository::replace_report(
+                    pool,
+                    &meeting_id,
+                    RedactionStage::Storage,
+                    &redactions.report(),
+                )
+                .await
+                {
+                    log_error!("Failed to save redaction report for {}: {}", meeting_id, e);
+                }
+            }
             Ok(serde_json::json!({
                 "status": "success",
                 "message": "Transcript saved successfully",
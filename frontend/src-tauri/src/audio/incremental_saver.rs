@@ -49,6 +49,21 @@ impl IncrementalAudioSaver {
         })
     }
 
+    /// Re-open the checkpoints an interrupted recording left behind so `finalize()` can
+    /// merge them into audio.mp4. Audio not yet written to a checkpoint is lost.
+    pub fn recover(meeting_folder: PathBuf, sample_rate: u32) -> Result<Self> {
+        let mut saver = Self::new(meeting_folder, sample_rate)?;
+        while saver.checkpoints_dir
+            .join(format!("audio_chunk_{:03}.mp4", saver.checkpoint_count))
+            .exists()
+        {
+            saver.checkpoint_count += 1;
+        }
+
+        info!("Recovered {} checkpoints from {}", saver.checkpoint_count, saver.checkpoints_dir.display());
+        Ok(saver)
+    }
+
     /// Add an audio chunk to the buffer
     /// Automatically saves a checkpoint when buffer reaches 30 seconds
     pub fn add_chunk(&mut self, chunk: AudioChunk) -> Result<()> {
@@ -34,6 +34,8 @@ pub mod system_detector;
 pub mod system_audio_commands;
 pub mod device_monitor;  // NEW: Device disconnect/reconnect monitoring
 pub mod playback_monitor; // NEW: Playback device detection for BT warnings
+pub mod quality_monitor; // Live audio quality warnings during recording
+pub mod pre_roll; // Rolling buffer while a meeting is detected but not yet recording
 
 // Transcription module (provider abstraction, engine management, worker pool)
 pub mod transcription;
@@ -12,6 +12,7 @@ use super::devices::AudioDevice;
 use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType};
 use super::audio_processing::{audio_to_mono, LoudnessNormalizer, NoiseSuppressionProcessor, HighPassFilter};
 use super::vad::{ContinuousVadProcessor};
+use super::transcription::partial::{self, InProgressSpeech};
 
 /// Ring buffer for synchronized audio mixing
 /// Accumulates samples from mic and system streams until we have aligned windows
@@ -396,6 +397,12 @@ impl AudioCapture {
             data.to_vec()
         };
 
+        // Feed raw levels (before resampling/enhancement) to the live quality analyzer
+        if self.state.is_active() {
+            let timestamp = self.state.get_recording_duration().unwrap_or(0.0);
+            self.state.get_quality_analyzer().observe(&self.device_type, &mono_data, timestamp);
+        }
+
         // CRITICAL FIX: Resample to 48kHz if device uses different sample rate
         // This fixes Bluetooth devices (like Sony WH-1000XM4) that report 16kHz or 44.1kHz
         // Without this, audio is sped up 3x and VAD fails
@@ -694,6 +701,8 @@ pub struct AudioPipeline {
     mixer: ProfessionalAudioMixer,
     // Recording sender for pre-mixed audio
     recording_sender_for_mixed: Option<mpsc::UnboundedSender<AudioChunk>>,
+    // Partial transcripts: when the open utterance was last published (None = nothing published)
+    last_partial_publish: Option<std::time::Instant>,
 }
 
 impl AudioPipeline {
@@ -760,6 +769,7 @@ impl AudioPipeline {
             ring_buffer,
             mixer,
             recording_sender_for_mixed: None,  // Will be set by manager
+            last_partial_publish: None,
         }
     }
 
@@ -865,6 +875,9 @@ impl AudioPipeline {
                                 }
                             }
 
+                            // STEP 3b: Publish the still-open utterance for partial transcripts
+                            self.publish_in_progress_speech();
+
                             // STEP 4: Send mixed audio for recording (WAV file)
                             if let Some(ref sender) = self.recording_sender_for_mixed {
                                 let recording_chunk = AudioChunk {
@@ -897,6 +910,32 @@ impl AudioPipeline {
         Ok(())
     }
 
+    /// Hand the open utterance to the partial transcription task every `PARTIAL_INTERVAL`
+    fn publish_in_progress_speech(&mut self) {
+        match self.vad_processor.in_progress_speech() {
+            Some((samples, start_ms)) => {
+                let long_enough = samples.len() as f64 / 16000.0 >= partial::MIN_PARTIAL_SPEECH_SECONDS;
+                let due = self
+                    .last_partial_publish
+                    .map_or(true, |last| last.elapsed() >= partial::PARTIAL_INTERVAL);
+                if long_enough && due {
+                    // The final segment of this utterance will be sent with the next chunk ID
+                    partial::publish_in_progress(InProgressSpeech {
+                        utterance_id: self.chunk_id_counter,
+                        samples: samples.to_vec(),
+                        start_time: start_ms / 1000.0,
+                    });
+                    self.last_partial_publish = Some(std::time::Instant::now());
+                }
+            }
+            None => {
+                if self.last_partial_publish.take().is_some() {
+                    partial::clear_in_progress();
+                }
+            }
+        }
+    }
+
     fn flush_remaining_audio(&mut self) -> Result<()> {
         info!("Flushing remaining audio from pipeline (processed {} chunks)", self.processed_chunks);
 
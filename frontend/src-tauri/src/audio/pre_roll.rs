@@ -0,0 +1,296 @@
+// audio/pre_roll.rs
+//
+// Rolling pre-roll capture while a meeting app is detected but recording hasn't started.
+// Auto-start only fires after detection and device startup, so the first seconds of a
+// meeting are easily lost. The pre-roll keeps the most recent N seconds of mic and system
+// audio in memory; when recording starts, the recording manager takes the buffered chunks
+// and feeds them to the pipeline ahead of the live streams so they land at the front of
+// the WAV file and the transcript.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::devices::AudioDevice;
+use super::recording_manager::resolve_default_recording_devices;
+use super::recording_state::{AudioChunk, RecordingState};
+use super::stream::AudioStreamManager;
+
+/// Lower/upper bound for the configurable pre-roll length (memory grows linearly:
+/// ~190 KB per second per device at 48 kHz mono f32)
+pub const MIN_PRE_ROLL_SECONDS: u32 = 5;
+pub const MAX_PRE_ROLL_SECONDS: u32 = 300;
+
+/// Buffered audio handed to the recording manager when recording starts
+#[derive(Debug, Clone)]
+pub struct PreRollAudio {
+    /// Raw per-device chunks (as produced by AudioCapture), timestamps rebased to 0.0
+    pub chunks: Vec<AudioChunk>,
+    /// Length of the buffered audio in seconds
+    pub duration_seconds: f64,
+    /// Devices the ring captured from, so a recording on other devices can skip it
+    pub microphone_name: Option<String>,
+    pub system_name: Option<String>,
+}
+
+impl PreRollAudio {
+    /// Whether this audio came from the devices a recording is about to open
+    pub fn matches_devices(
+        &self,
+        microphone_device: Option<&AudioDevice>,
+        system_device: Option<&AudioDevice>,
+    ) -> bool {
+        self.microphone_name.as_deref() == microphone_device.map(|d| d.name.as_str())
+            && self.system_name.as_deref() == system_device.map(|d| d.name.as_str())
+    }
+}
+
+/// Time-bounded ring of capture chunks (both devices interleaved in arrival order)
+struct PreRollRing {
+    chunks: VecDeque<AudioChunk>,
+    max_duration: f64,
+}
+
+impl PreRollRing {
+    fn new(max_duration: f64) -> Self {
+        Self {
+            chunks: VecDeque::new(),
+            max_duration,
+        }
+    }
+
+    /// Append a chunk and evict everything older than `max_duration` before it
+    fn push(&mut self, chunk: AudioChunk) {
+        let cutoff = chunk.timestamp - self.max_duration;
+        self.chunks.push_back(chunk);
+        while let Some(front) = self.chunks.front() {
+            if front.timestamp < cutoff {
+                self.chunks.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Drain the ring, rebasing timestamps so the oldest chunk starts at 0.0
+    fn into_audio(self) -> PreRollAudio {
+        let base = self
+            .chunks
+            .iter()
+            .map(|c| c.timestamp)
+            .fold(f64::INFINITY, f64::min);
+
+        let mut chunks: Vec<AudioChunk> = self.chunks.into_iter().collect();
+        let mut duration_seconds: f64 = 0.0;
+        for chunk in chunks.iter_mut() {
+            chunk.timestamp -= base;
+            if chunk.sample_rate > 0 {
+                let end = chunk.timestamp + chunk.data.len() as f64 / chunk.sample_rate as f64;
+                duration_seconds = duration_seconds.max(end);
+            }
+        }
+
+        PreRollAudio {
+            chunks,
+            duration_seconds,
+            microphone_name: None,
+            system_name: None,
+        }
+    }
+}
+
+/// Live pre-roll capture: its own streams and state, independent of any recording
+struct PreRollBuffer {
+    state: Arc<RecordingState>,
+    stream_manager: AudioStreamManager,
+    collector: JoinHandle<()>,
+    ring: Arc<Mutex<PreRollRing>>,
+    microphone_name: Option<String>,
+    system_name: Option<String>,
+}
+
+// SAFETY: AudioStreamManager is marked Send; the rest is Send + Sync
+unsafe impl Send for PreRollBuffer {}
+
+impl PreRollBuffer {
+    async fn start(max_seconds: u32) -> Result<Self> {
+        let (microphone_device, system_device) = resolve_default_recording_devices()?;
+        let microphone_name = microphone_device.as_ref().map(|d| d.name.clone());
+        let system_name = system_device.as_ref().map(|d| d.name.clone());
+
+        let state = RecordingState::new();
+        let (sender, mut receiver) = mpsc::unbounded_channel::<AudioChunk>();
+        state.set_audio_sender(sender);
+        state.start_recording()?;
+
+        let ring = Arc::new(Mutex::new(PreRollRing::new(max_seconds as f64)));
+        let ring_clone = ring.clone();
+        let collector = tokio::spawn(async move {
+            while let Some(chunk) = receiver.recv().await {
+                if let Ok(mut ring) = ring_clone.lock() {
+                    ring.push(chunk);
+                }
+            }
+        });
+
+        let mut stream_manager = AudioStreamManager::new(state.clone());
+        if let Err(e) = stream_manager
+            .start_streams(microphone_device, system_device, None)
+            .await
+        {
+            state.stop_recording();
+            collector.abort();
+            return Err(e);
+        }
+
+        Ok(Self {
+            state,
+            stream_manager,
+            collector,
+            ring,
+            microphone_name,
+            system_name,
+        })
+    }
+
+    /// Stop capture and return everything currently buffered
+    async fn finish(self) -> PreRollAudio {
+        let Self {
+            state,
+            mut stream_manager,
+            collector,
+            ring,
+            microphone_name,
+            system_name,
+        } = self;
+
+        if let Err(e) = stream_manager.stop_streams() {
+            warn!("Failed to stop pre-roll streams cleanly: {}", e);
+        }
+        // Clearing the state's sender closes the channel, letting the collector drain and exit
+        state.stop_recording();
+        if let Err(e) = collector.await {
+            warn!("Pre-roll collector task ended abnormally: {}", e);
+        }
+
+        let ring = std::mem::replace(&mut *ring.lock().unwrap(), PreRollRing::new(0.0));
+        PreRollAudio {
+            microphone_name,
+            system_name,
+            ..ring.into_audio()
+        }
+    }
+}
+
+static PRE_ROLL: Lazy<tokio::sync::Mutex<Option<PreRollBuffer>>> =
+    Lazy::new(|| tokio::sync::Mutex::new(None));
+
+/// Start buffering the default devices (no-op if the pre-roll is already running)
+pub async fn start_pre_roll(max_seconds: u32) -> Result<()> {
+    let mut guard = PRE_ROLL.lock().await;
+    if guard.is_some() {
+        return Ok(());
+    }
+
+    let max_seconds = max_seconds.clamp(MIN_PRE_ROLL_SECONDS, MAX_PRE_ROLL_SECONDS);
+    let buffer = PreRollBuffer::start(max_seconds).await?;
+    info!("⏪ Pre-roll buffer started (keeping last {}s of audio)", max_seconds);
+    *guard = Some(buffer);
+    Ok(())
+}
+
+/// Stop the pre-roll and hand over the buffered audio, if any was being captured
+pub async fn take_pre_roll() -> Option<PreRollAudio> {
+    let buffer = PRE_ROLL.lock().await.take()?;
+    let audio = buffer.finish().await;
+    info!(
+        "⏪ Pre-roll taken: {:.1}s of audio ({} chunks)",
+        audio.duration_seconds,
+        audio.chunks.len()
+    );
+    Some(audio)
+}
+
+/// Stop the pre-roll and drop the buffered audio (meeting ended without a recording)
+pub async fn discard_pre_roll() {
+    if let Some(buffer) = PRE_ROLL.lock().await.take() {
+        let audio = buffer.finish().await;
+        info!("⏪ Pre-roll discarded ({:.1}s of audio)", audio.duration_seconds);
+    }
+}
+
+/// Whether the pre-roll is currently capturing
+pub async fn is_pre_roll_active() -> bool {
+    PRE_ROLL.lock().await.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::recording_state::DeviceType;
+
+    fn chunk(timestamp: f64, device_type: DeviceType) -> AudioChunk {
+        AudioChunk {
+            data: vec![0.0; 4800], // 100ms at 48kHz
+            sample_rate: 48000,
+            timestamp,
+            chunk_id: 0,
+            device_type,
+        }
+    }
+
+    #[test]
+    fn ring_evicts_chunks_older_than_window() {
+        let mut ring = PreRollRing::new(2.0);
+        for i in 0..50 {
+            ring.push(chunk(i as f64 * 0.25, DeviceType::Microphone));
+        }
+
+        // Newest at 12.25s, window 2.0s -> oldest kept is 10.25s
+        assert_eq!(ring.chunks.front().unwrap().timestamp, 10.25);
+        assert_eq!(ring.chunks.len(), 9);
+    }
+
+    #[test]
+    fn drained_audio_is_rebased_to_zero() {
+        let mut ring = PreRollRing::new(10.0);
+        ring.push(chunk(12.0, DeviceType::Microphone));
+        ring.push(chunk(12.05, DeviceType::System));
+        ring.push(chunk(12.1, DeviceType::Microphone));
+
+        let audio = ring.into_audio();
+        assert_eq!(audio.chunks.len(), 3);
+        assert!(audio.chunks[0].timestamp.abs() < 1e-9);
+        assert!((audio.chunks[2].timestamp - 0.1).abs() < 1e-9);
+        assert!((audio.duration_seconds - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_ring_has_zero_duration() {
+        let audio = PreRollRing::new(60.0).into_audio();
+        assert!(audio.chunks.is_empty());
+        assert_eq!(audio.duration_seconds, 0.0);
+    }
+
+    #[test]
+    fn audio_matches_only_the_devices_it_captured() {
+        use crate::audio::devices::DeviceType as AudioDeviceType;
+
+        let audio = PreRollAudio {
+            microphone_name: Some("Built-in Microphone".to_string()),
+            system_name: None,
+            ..PreRollRing::new(60.0).into_audio()
+        };
+        let built_in = AudioDevice::new("Built-in Microphone".to_string(), AudioDeviceType::Input);
+        let headset = AudioDevice::new("USB Headset".to_string(), AudioDeviceType::Input);
+
+        assert!(audio.matches_devices(Some(&built_in), None));
+        assert!(!audio.matches_devices(Some(&headset), None));
+        assert!(!audio.matches_devices(None, None));
+    }
+}
@@ -0,0 +1,400 @@
+// audio/quality_monitor.rs
+//
+// Live audio quality analysis during recording.
+// Tracks the same RMS/peak levels as the level monitors, but per capture stream of the
+// running recording, and raises structured warnings for common problems
+// (muted mic, sustained clipping, very low level, silent system audio).
+
+use std::collections::HashSet;
+use std::sync::{Mutex, RwLock};
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::settings_store;
+use super::recording_state::DeviceType;
+
+/// Configurable thresholds for the quality analyzer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioQualityThresholds {
+    pub enabled: bool,
+    /// RMS below this is treated as silence
+    pub silence_rms: f32,
+    /// RMS above this on the system channel is treated as speech
+    pub speech_rms: f32,
+    /// Mic peak must exceed this at least once per `low_level_seconds`
+    pub low_level_peak: f32,
+    /// Samples with |x| at or above this count as clipped
+    pub clipping_level: f32,
+    /// Fraction of clipped samples in a chunk for the chunk to count as clipping
+    pub clipping_ratio: f32,
+    /// Mic silent for this long while system audio has speech
+    pub mic_silence_seconds: f64,
+    /// Mic non-silent but below `low_level_peak` for this long
+    pub low_level_seconds: f64,
+    /// Clipping sustained for this long
+    pub clipping_seconds: f64,
+    /// System channel exactly zero for this long
+    pub system_flat_seconds: f64,
+}
+
+impl Default for AudioQualityThresholds {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            silence_rms: 0.0005,
+            speech_rms: 0.01,
+            low_level_peak: 0.02,
+            clipping_level: 0.99,
+            clipping_ratio: 0.01,
+            mic_silence_seconds: 10.0,
+            low_level_seconds: 60.0,
+            clipping_seconds: 3.0,
+            system_flat_seconds: 15.0,
+        }
+    }
+}
+
+impl AudioQualityThresholds {
+    /// Load thresholds from disk
+    pub fn load() -> Self {
+        settings_store::load("audio_quality_thresholds.json", "audio quality thresholds")
+    }
+
+    /// Save thresholds to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "audio_quality_thresholds.json", "audio quality thresholds")
+    }
+}
+
+static QUALITY_THRESHOLDS: Lazy<RwLock<AudioQualityThresholds>> =
+    Lazy::new(|| RwLock::new(AudioQualityThresholds::load()));
+
+/// Get the current quality thresholds
+pub fn get_thresholds() -> AudioQualityThresholds {
+    QUALITY_THRESHOLDS.read().unwrap().clone()
+}
+
+/// Replace and persist the quality thresholds (applies to the next evaluation)
+pub fn set_thresholds(thresholds: AudioQualityThresholds) -> Result<(), String> {
+    info!("Updating audio quality thresholds: {:?}", thresholds);
+    thresholds.save()?;
+    *QUALITY_THRESHOLDS.write().unwrap() = thresholds;
+    Ok(())
+}
+
+/// Kind of quality problem detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioQualityIssue {
+    /// Mic is silent while the system channel carries speech (muted or wrong mic)
+    MicrophoneSilent,
+    /// Mic has a signal but it is consistently very quiet
+    MicrophoneLowLevel,
+    MicrophoneClipping,
+    SystemAudioClipping,
+    /// System channel delivers digital zero (permission or routing issue)
+    SystemAudioFlat,
+}
+
+impl AudioQualityIssue {
+    pub fn user_message(&self) -> &'static str {
+        match self {
+            AudioQualityIssue::MicrophoneSilent => {
+                "Your microphone appears to be muted or silent while others are speaking"
+            }
+            AudioQualityIssue::MicrophoneLowLevel => {
+                "Your microphone level is very low - move closer or raise the input gain"
+            }
+            AudioQualityIssue::MicrophoneClipping => {
+                "Your microphone is clipping - lower the input gain"
+            }
+            AudioQualityIssue::SystemAudioClipping => "System audio is clipping",
+            AudioQualityIssue::SystemAudioFlat => {
+                "No system audio is being captured - check permissions or output routing"
+            }
+        }
+    }
+}
+
+/// Structured quality event, emitted as `audio-quality-warning` and stored in metadata.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioQualityEvent {
+    pub issue: AudioQualityIssue,
+    pub device_type: String, // "microphone" or "system_audio"
+    pub recording_time: f64,  // Seconds from recording start when the issue was raised
+    pub duration_seconds: f64, // How long the condition had persisted
+    pub message: String,
+}
+
+/// Rolling level statistics for one capture stream
+#[derive(Debug, Default)]
+struct ChannelStats {
+    first_seen: Option<f64>,
+    last_seen: f64,
+    last_non_silent: f64,
+    last_speech: f64,
+    last_loud: f64,
+    last_non_zero: f64,
+    clipping_since: Option<f64>,
+    last_clipped: f64,
+}
+
+impl ChannelStats {
+    fn observe(&mut self, samples: &[f32], timestamp: f64, thresholds: &AudioQualityThresholds) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let rms = (samples.iter().map(|&x| x * x).sum::<f32>() / samples.len() as f32).sqrt();
+        let peak = samples.iter().map(|&x| x.abs()).fold(0.0f32, f32::max);
+        let clipped = samples.iter().filter(|&&x| x.abs() >= thresholds.clipping_level).count();
+        let clipped_ratio = clipped as f32 / samples.len() as f32;
+
+        if self.first_seen.is_none() {
+            self.first_seen = Some(timestamp);
+            self.last_non_silent = timestamp;
+            self.last_loud = timestamp;
+            self.last_non_zero = timestamp;
+        }
+        self.last_seen = timestamp;
+
+        if rms >= thresholds.silence_rms {
+            self.last_non_silent = timestamp;
+        }
+        if rms >= thresholds.speech_rms {
+            self.last_speech = timestamp;
+        }
+        if peak >= thresholds.low_level_peak {
+            self.last_loud = timestamp;
+        }
+        if peak > 0.0 {
+            self.last_non_zero = timestamp;
+        }
+
+        if clipped_ratio >= thresholds.clipping_ratio {
+            if self.clipping_since.is_none() {
+                self.clipping_since = Some(timestamp);
+            }
+            self.last_clipped = timestamp;
+        } else if timestamp - self.last_clipped > 1.0 {
+            // Allow short gaps between clipped chunks before resetting
+            self.clipping_since = None;
+        }
+    }
+
+    fn is_seen(&self) -> bool {
+        self.first_seen.is_some()
+    }
+
+    fn clipping_duration(&self) -> f64 {
+        self.clipping_since
+            .map(|since| self.last_clipped - since)
+            .unwrap_or(0.0)
+    }
+}
+
+/// Analyzer state for a single recording session
+#[derive(Debug, Default)]
+struct AnalyzerState {
+    microphone: ChannelStats,
+    system: ChannelStats,
+    active_issues: HashSet<AudioQualityIssue>,
+}
+
+/// Background quality analyzer fed from the capture streams of a recording
+#[derive(Debug, Default)]
+pub struct AudioQualityAnalyzer {
+    state: Mutex<AnalyzerState>,
+}
+
+impl AudioQualityAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed raw (pre-enhancement) mono samples from a capture stream
+    pub fn observe(&self, device_type: &DeviceType, samples: &[f32], timestamp: f64) {
+        let thresholds = get_thresholds();
+        if !thresholds.enabled {
+            return;
+        }
+
+        if let Ok(mut state) = self.state.lock() {
+            match device_type {
+                DeviceType::Microphone => state.microphone.observe(samples, timestamp, &thresholds),
+                DeviceType::System => state.system.observe(samples, timestamp, &thresholds),
+            }
+        }
+    }
+
+    /// Evaluate current conditions and return issues that were newly raised
+    ///
+    /// Each issue is raised once and re-armed only after the condition clears.
+    pub fn evaluate(&self, now: f64) -> Vec<AudioQualityEvent> {
+        let thresholds = get_thresholds();
+        if !thresholds.enabled {
+            return Vec::new();
+        }
+
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut conditions: Vec<(AudioQualityIssue, bool, f64)> = Vec::new();
+
+        let mic = &state.microphone;
+        let sys = &state.system;
+
+        if mic.is_seen() {
+            let silent_for = now - mic.last_non_silent;
+            let system_has_speech = sys.is_seen() && now - sys.last_speech < 2.0;
+            conditions.push((
+                AudioQualityIssue::MicrophoneSilent,
+                silent_for >= thresholds.mic_silence_seconds && system_has_speech,
+                silent_for,
+            ));
+
+            let quiet_for = now - mic.last_loud;
+            conditions.push((
+                AudioQualityIssue::MicrophoneLowLevel,
+                quiet_for >= thresholds.low_level_seconds && silent_for < thresholds.mic_silence_seconds,
+                quiet_for,
+            ));
+
+            let clipping_for = mic.clipping_duration();
+            conditions.push((
+                AudioQualityIssue::MicrophoneClipping,
+                clipping_for >= thresholds.clipping_seconds,
+                clipping_for,
+            ));
+        }
+
+        if sys.is_seen() {
+            let flat_for = now - sys.last_non_zero;
+            conditions.push((
+                AudioQualityIssue::SystemAudioFlat,
+                flat_for >= thresholds.system_flat_seconds,
+                flat_for,
+            ));
+
+            let clipping_for = sys.clipping_duration();
+            conditions.push((
+                AudioQualityIssue::SystemAudioClipping,
+                clipping_for >= thresholds.clipping_seconds,
+                clipping_for,
+            ));
+        }
+
+        let mut events = Vec::new();
+        for (issue, is_present, duration) in conditions {
+            if is_present {
+                if state.active_issues.insert(issue) {
+                    let device_type = match issue {
+                        AudioQualityIssue::MicrophoneSilent
+                        | AudioQualityIssue::MicrophoneLowLevel
+                        | AudioQualityIssue::MicrophoneClipping => "microphone",
+                        AudioQualityIssue::SystemAudioClipping
+                        | AudioQualityIssue::SystemAudioFlat => "system_audio",
+                    };
+                    warn!("⚠️ Audio quality issue at {:.1}s: {:?} (for {:.1}s)", now, issue, duration);
+                    events.push(AudioQualityEvent {
+                        issue,
+                        device_type: device_type.to_string(),
+                        recording_time: now,
+                        duration_seconds: duration,
+                        message: issue.user_message().to_string(),
+                    });
+                }
+            } else if state.active_issues.remove(&issue) {
+                info!("✅ Audio quality issue cleared at {:.1}s: {:?}", now, issue);
+            }
+        }
+
+        events
+    }
+
+    /// Reset all statistics (e.g. after a device switch)
+    pub fn reset_device(&self, device_type: &DeviceType) {
+        if let Ok(mut state) = self.state.lock() {
+            match device_type {
+                DeviceType::Microphone => state.microphone = ChannelStats::default(),
+                DeviceType::System => state.system = ChannelStats::default(),
+            }
+        }
+    }
+}
+
+/// Get the audio quality thresholds
+#[tauri::command]
+pub async fn get_audio_quality_thresholds() -> Result<AudioQualityThresholds, String> {
+    Ok(get_thresholds())
+}
+
+/// Set the audio quality thresholds
+#[tauri::command]
+pub async fn set_audio_quality_thresholds(thresholds: AudioQualityThresholds) -> Result<(), String> {
+    set_thresholds(thresholds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(amplitude: f32) -> Vec<f32> {
+        (0..4800).map(|i| amplitude * ((i as f32) * 0.1).sin()).collect()
+    }
+
+    fn feed(analyzer: &AudioQualityAnalyzer, mic: &[f32], sys: &[f32], from: f64, to: f64) {
+        let mut t = from;
+        while t < to {
+            analyzer.observe(&DeviceType::Microphone, mic, t);
+            analyzer.observe(&DeviceType::System, sys, t);
+            t += 0.1;
+        }
+    }
+
+    #[test]
+    fn test_silent_mic_while_system_speaks() {
+        let analyzer = AudioQualityAnalyzer::new();
+        feed(&analyzer, &vec![0.0; 4800], &tone(0.3), 0.0, 12.0);
+
+        let events = analyzer.evaluate(12.0);
+        assert!(events.iter().any(|e| e.issue == AudioQualityIssue::MicrophoneSilent));
+
+        // Raised only once while the condition persists
+        feed(&analyzer, &vec![0.0; 4800], &tone(0.3), 12.0, 13.0);
+        assert!(analyzer
+            .evaluate(13.0)
+            .iter()
+            .all(|e| e.issue != AudioQualityIssue::MicrophoneSilent));
+    }
+
+    #[test]
+    fn test_healthy_audio_raises_nothing() {
+        let analyzer = AudioQualityAnalyzer::new();
+        feed(&analyzer, &tone(0.2), &tone(0.2), 0.0, 30.0);
+        assert!(analyzer.evaluate(30.0).is_empty());
+    }
+
+    #[test]
+    fn test_flat_system_audio() {
+        let analyzer = AudioQualityAnalyzer::new();
+        feed(&analyzer, &tone(0.2), &vec![0.0; 4800], 0.0, 16.0);
+
+        let events = analyzer.evaluate(16.0);
+        assert!(events.iter().any(|e| e.issue == AudioQualityIssue::SystemAudioFlat));
+    }
+
+    #[test]
+    fn test_sustained_clipping() {
+        let analyzer = AudioQualityAnalyzer::new();
+        feed(&analyzer, &vec![1.0; 4800], &tone(0.2), 0.0, 4.0);
+
+        let events = analyzer.evaluate(4.0);
+        assert!(events.iter().any(|e| e.issue == AudioQualityIssue::MicrophoneClipping));
+    }
+}
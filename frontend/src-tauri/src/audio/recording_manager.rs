@@ -13,7 +13,8 @@ use super::devices::{default_input_device, default_output_device};
 use super::recording_state::{RecordingState, AudioChunk, DeviceType as RecordingDeviceType};
 use super::pipeline::AudioPipelineManager;
 use super::stream::AudioStreamManager;
-use super::recording_saver::RecordingSaver;
+use super::recording_saver::{RecordingSaver, DeviceSwitch, RecordingMarker};
+use super::quality_monitor::AudioQualityEvent;
 use super::device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType};
 
 /// Stream manager type enumeration
@@ -29,6 +30,8 @@ pub struct RecordingManager {
     recording_saver: RecordingSaver,
     device_monitor: Option<AudioDeviceMonitor>,
     device_event_receiver: Option<mpsc::UnboundedReceiver<DeviceEvent>>,
+    /// Prepend the pre-roll buffer (only for recordings auto-started by meeting detection)
+    use_pre_roll: bool,
 }
 
 // SAFETY: RecordingManager contains types that we've marked as Send
@@ -49,6 +52,7 @@ impl RecordingManager {
             recording_saver: RecordingSaver::new(),
             device_monitor: Some(device_monitor),
             device_event_receiver: Some(device_event_receiver),
+            use_pre_roll: false,
         }
     }
 
@@ -62,6 +66,27 @@ impl RecordingManager {
     ) -> Result<mpsc::UnboundedReceiver<AudioChunk>> {
         info!("Starting recording manager");
 
+        // Take over any pre-roll captured while the meeting was detected, or drop it when
+        // the recording was started by hand. Either way this stops the pre-roll streams so
+        // the live streams below can open the same devices.
+        // The ring captures the default devices; audio from other devices would splice a
+        // different source onto the start of this recording, so it is dropped instead.
+        let pre_roll = if self.use_pre_roll {
+            super::pre_roll::take_pre_roll().await
+                .filter(|audio| !audio.chunks.is_empty())
+                .filter(|audio| {
+                    let matches = audio.matches_devices(microphone_device.as_deref(), system_device.as_deref());
+                    if !matches {
+                        info!("⏪ Pre-roll captured other devices than this recording uses, skipping it");
+                    }
+                    matches
+                })
+        } else {
+            super::pre_roll::discard_pre_roll().await;
+            None
+        };
+        let pre_roll_seconds = pre_roll.as_ref().map(|audio| audio.duration_seconds).unwrap_or(0.0);
+
         // Set up transcription channel
         let (transcription_sender, transcription_receiver) = mpsc::unbounded_channel::<AudioChunk>();
 
@@ -69,8 +94,9 @@ impl RecordingManager {
         // Pipeline will mix mic + system audio professionally and send to this channel
         let recording_sender = self.recording_saver.start_accumulation();
 
-        // Start recording state first
-        self.state.start_recording()?;
+        // Start recording state first. With a pre-roll, the recording clock starts at the
+        // pre-roll length so live timestamps (markers, quality events) line up with the file.
+        self.state.start_recording_with_offset(pre_roll_seconds)?;
 
         // Get device information for adaptive mixing
         // The pipeline uses device kind (Bluetooth vs Wired) to apply adaptive buffering:
@@ -96,6 +122,11 @@ impl RecordingManager {
             system_device.as_ref().map(|d| d.name.clone())
         );
 
+        // Bilingual meetings keep their translation target with the recording
+        self.recording_saver.set_translation_language(
+            super::transcription::translation::current_translation_settings().target_language,
+        );
+
         // Start the audio processing pipeline with FFmpeg adaptive mixer
         // Pipeline will: 1) Mix mic+system audio with adaptive buffering, 2) Send mixed to recording_sender,
         // 3) Apply VAD and send speech segments to transcription
@@ -114,6 +145,19 @@ impl RecordingManager {
         // Give the pipeline a moment to fully initialize before starting streams
         tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
+        // Feed the pre-roll ahead of the live streams: the pipeline mixes, records and
+        // transcribes it like live audio, so it lands at the start of the WAV and transcript
+        if let Some(audio) = pre_roll {
+            info!("⏪ Prepending {:.1}s of pre-roll audio ({} chunks)", audio.duration_seconds, audio.chunks.len());
+            for chunk in audio.chunks {
+                if let Err(e) = self.state.send_audio_chunk(chunk) {
+                    warn!("Failed to send pre-roll chunk to pipeline: {}", e);
+                    break;
+                }
+            }
+            self.recording_saver.set_pre_roll_seconds(pre_roll_seconds);
+        }
+
         // Start audio streams - they send RAW unmixed chunks to pipeline for mixing
         // Pipeline handles mixing and distribution to both recording and transcription
         self.stream_manager.start_streams(microphone_device.clone(), system_device.clone(), None).await?;
@@ -158,61 +202,8 @@ impl RecordingManager {
     /// User still hears audio via Bluetooth (playback), but recording captures
     /// via stable wired path for best quality.
     pub async fn start_recording_with_defaults(&mut self) -> Result<mpsc::UnboundedReceiver<AudioChunk>> {
-        #[cfg(target_os = "macos")]
-        {
-            info!("🎙️ [macOS] Starting recording with smart device selection (Bluetooth override enabled)");
-
-            // Get safe recording devices with automatic Bluetooth fallback
-            // This function handles all the detection and override logic for macOS
-            let (microphone_device, system_device) = get_safe_recording_devices_macos()?;
-
-            // Wrap in Arc for sharing across threads
-            let microphone_device = microphone_device.map(Arc::new);
-            let system_device = system_device.map(Arc::new);
-
-            // Ensure at least microphone is available
-            if microphone_device.is_none() {
-                return Err(anyhow::anyhow!("❌ No microphone device available for recording"));
-            }
-
-            // Start recording with selected devices
-            self.start_recording(microphone_device, system_device).await
-        }
-
-        #[cfg(not(target_os = "macos"))]
-        {
-            info!("Starting recording with default devices");
-
-            // Get default devices (no Bluetooth override on Windows/Linux)
-            let microphone_device = match default_input_device() {
-                Ok(device) => {
-                    info!("Using default microphone: {}", device.name);
-                    Some(Arc::new(device))
-                }
-                Err(e) => {
-                    warn!("No default microphone available: {}", e);
-                    None
-                }
-            };
-
-            let system_device = match default_output_device() {
-                Ok(device) => {
-                    info!("Using default system audio: {}", device.name);
-                    Some(Arc::new(device))
-                }
-                Err(e) => {
-                    warn!("No default system audio available: {}", e);
-                    None
-                }
-            };
-
-            // Ensure at least microphone is available
-            if microphone_device.is_none() {
-                return Err(anyhow::anyhow!("No microphone device available"));
-            }
-
-            self.start_recording(microphone_device, system_device).await
-        }
+        let (microphone_device, system_device) = resolve_default_recording_devices()?;
+        self.start_recording(microphone_device, system_device).await
     }
 
     /// Stop recording streams without saving (for use when waiting for transcription)
@@ -413,9 +404,25 @@ impl RecordingManager {
         self.recording_saver.set_meeting_name(name);
     }
 
-    /// Add a structured transcript segment to be saved later
-    pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) {
-        self.recording_saver.add_transcript_segment(segment);
+    /// Prepend the audio buffered while the meeting was detected. Only recordings
+    /// auto-started by meeting detection use it; manual starts discard the buffer.
+    pub fn set_use_pre_roll(&mut self, use_pre_roll: bool) {
+        self.use_pre_roll = use_pre_roll;
+    }
+
+    /// Handle that keeps saving transcript segments after the manager is taken down
+    pub fn transcript_sink(&self) -> super::recording_saver::TranscriptSink {
+        self.recording_saver.transcript_sink()
+    }
+
+    /// Add a structured transcript segment to be saved later; returns whether it was saved
+    pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) -> bool {
+        self.recording_saver.add_transcript_segment(segment)
+    }
+
+    /// Attach a translation (bilingual meetings) to an already saved segment
+    pub fn set_segment_translation(&self, sequence_id: u64, text: String, target_language: String) {
+        self.recording_saver.set_segment_translation(sequence_id, text, target_language);
     }
 
     /// Add a transcript chunk to be saved later (legacy method)
@@ -569,6 +576,109 @@ impl RecordingManager {
         }
     }
 
+    /// Hot-swap the microphone or system audio device of a running recording
+    ///
+    /// Only the stream for `device_type` is restarted. The pipeline, recording saver and
+    /// transcription task keep running, so the meeting folder, recording-relative timestamps
+    /// and transcript sequence IDs continue uninterrupted. The switch is logged in metadata.json.
+    pub async fn switch_recording_device(&mut self, device: Arc<AudioDevice>, device_type: DeviceMonitorType) -> Result<()> {
+        if !self.is_recording() {
+            return Err(anyhow::anyhow!("Cannot switch devices when not recording"));
+        }
+
+        let (recording_device_type, previous_device) = match device_type {
+            DeviceMonitorType::Microphone => (RecordingDeviceType::Microphone, self.state.get_microphone_device()),
+            DeviceMonitorType::SystemAudio => (RecordingDeviceType::System, self.state.get_system_device()),
+        };
+
+        if previous_device.as_ref().map(|d| d.name == device.name).unwrap_or(false) {
+            info!("Device '{}' is already active for {:?}, nothing to switch", device.name, device_type);
+            return Ok(());
+        }
+
+        info!("🔀 Switching {:?} device: {:?} → {}",
+              device_type, previous_device.as_ref().map(|d| d.name.clone()), device.name);
+
+        if let Err(e) = self.stream_manager.replace_stream(device.clone(), recording_device_type.clone()).await {
+            error!("Failed to switch to device '{}': {}", device.name, e);
+
+            // Try to restore the previous device so the recording keeps capturing audio
+            if let Some(previous) = previous_device {
+                if let Err(restore_err) = self.stream_manager.replace_stream(previous.clone(), recording_device_type).await {
+                    error!("Failed to restore previous device '{}': {}", previous.name, restore_err);
+                } else {
+                    info!("Restored previous device '{}'", previous.name);
+                }
+            }
+            return Err(e);
+        }
+
+        // Levels from the old device must not trigger warnings for the new one
+        self.state.get_quality_analyzer().reset_device(&recording_device_type);
+
+        // A manual switch resolves any pending reconnection for the same device type
+        if let Some((_, disconnected_type)) = self.state.get_disconnected_device() {
+            if disconnected_type == recording_device_type {
+                self.state.stop_reconnecting();
+            }
+        }
+
+        // Restart device monitoring so disconnects are tracked for the new device
+        if let Some(ref mut monitor) = self.device_monitor {
+            monitor.stop_monitoring().await;
+            if let Err(e) = monitor.start_monitoring(self.state.get_microphone_device(), self.state.get_system_device()) {
+                warn!("Failed to restart device monitoring after switch: {}", e);
+            }
+        }
+
+        self.recording_saver.record_device_switch(DeviceSwitch {
+            device_type: match device_type {
+                DeviceMonitorType::Microphone => "microphone".to_string(),
+                DeviceMonitorType::SystemAudio => "system_audio".to_string(),
+            },
+            from_device: previous_device.map(|d| d.name.clone()),
+            to_device: device.name.clone(),
+            recording_time: self.state.get_recording_duration().unwrap_or(0.0),
+            switched_at: chrono::Utc::now().to_rfc3339(),
+        });
+
+        info!("✅ Switched {:?} device to '{}'", device_type, device.name);
+        Ok(())
+    }
+
+    /// Stamp a marker at the current recording-relative time
+    /// Uses the same clock as transcript timestamps so markers line up with segments
+    pub fn add_marker(&mut self, label: Option<String>) -> Result<RecordingMarker> {
+        if !self.is_recording() {
+            return Err(anyhow::anyhow!("Cannot add marker when not recording"));
+        }
+
+        let label = label
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty());
+
+        let marker = RecordingMarker {
+            id: format!("marker-{}", uuid::Uuid::new_v4()),
+            recording_time: self.state.get_recording_duration().unwrap_or(0.0),
+            label,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        self.recording_saver.add_marker(marker.clone())?;
+        info!("🔖 Marker added at {:.1}s: {:?}", marker.recording_time, marker.label);
+        Ok(marker)
+    }
+
+    /// Get markers of the current recording session
+    pub fn get_markers(&self) -> Vec<RecordingMarker> {
+        self.recording_saver.get_markers()
+    }
+
+    /// Store a live audio quality warning with the meeting metadata
+    pub fn record_quality_event(&mut self, event: AudioQualityEvent) {
+        self.recording_saver.record_quality_event(event);
+    }
+
     /// Check if currently attempting to reconnect
     pub fn is_reconnecting(&self) -> bool {
         self.state.is_reconnecting()
@@ -580,6 +690,67 @@ impl RecordingManager {
     }
 }
 
+/// Resolve the default recording devices (with automatic Bluetooth fallback on macOS)
+///
+/// Shared by `start_recording_with_defaults` and the meeting pre-roll buffer so both
+/// capture from the same devices. Fails if no microphone is available.
+pub fn resolve_default_recording_devices() -> Result<(Option<Arc<AudioDevice>>, Option<Arc<AudioDevice>>)> {
+    #[cfg(target_os = "macos")]
+    {
+        info!("🎙️ [macOS] Starting recording with smart device selection (Bluetooth override enabled)");
+
+        // Get safe recording devices with automatic Bluetooth fallback
+        // This function handles all the detection and override logic for macOS
+        let (microphone_device, system_device) = get_safe_recording_devices_macos()?;
+
+        // Wrap in Arc for sharing across threads
+        let microphone_device = microphone_device.map(Arc::new);
+        let system_device = system_device.map(Arc::new);
+
+        // Ensure at least microphone is available
+        if microphone_device.is_none() {
+            return Err(anyhow::anyhow!("❌ No microphone device available for recording"));
+        }
+
+        Ok((microphone_device, system_device))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        info!("Starting recording with default devices");
+
+        // Get default devices (no Bluetooth override on Windows/Linux)
+        let microphone_device = match default_input_device() {
+            Ok(device) => {
+                info!("Using default microphone: {}", device.name);
+                Some(Arc::new(device))
+            }
+            Err(e) => {
+                warn!("No default microphone available: {}", e);
+                None
+            }
+        };
+
+        let system_device = match default_output_device() {
+            Ok(device) => {
+                info!("Using default system audio: {}", device.name);
+                Some(Arc::new(device))
+            }
+            Err(e) => {
+                warn!("No default system audio available: {}", e);
+                None
+            }
+        };
+
+        // Ensure at least microphone is available
+        if microphone_device.is_none() {
+            return Err(anyhow::anyhow!("No microphone device available"));
+        }
+
+        Ok((microphone_device, system_device))
+    }
+}
+
 impl Default for RecordingManager {
     fn default() -> Self {
         Self::new()
@@ -591,4 +762,83 @@ impl Drop for RecordingManager {
         // Note: Can't call async cleanup in Drop, but streams have their own Drop implementations
         self.state.cleanup();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::devices::DeviceType as AudioDeviceType;
+    use crate::audio::stream::AudioStream;
+    use futures_util::future::LocalBoxFuture;
+
+    /// Opens any device except ones named "Broken ..."
+    fn open_fake_stream(
+        device: Arc<AudioDevice>,
+        _state: Arc<RecordingState>,
+        _device_type: RecordingDeviceType,
+    ) -> LocalBoxFuture<'static, Result<AudioStream>> {
+        Box::pin(async move {
+            if device.name.starts_with("Broken") {
+                Err(anyhow::anyhow!("Device '{}' could not be opened", device.name))
+            } else {
+                Ok(AudioStream::fake(device))
+            }
+        })
+    }
+
+    fn microphone(name: &str) -> Arc<AudioDevice> {
+        Arc::new(AudioDevice::new(name.to_string(), AudioDeviceType::Input))
+    }
+
+    fn recording_manager() -> RecordingManager {
+        let mut manager = RecordingManager::new();
+        manager.stream_manager = AudioStreamManager::with_opener(manager.state.clone(), open_fake_stream);
+        manager
+    }
+
+    #[tokio::test]
+    async fn switch_is_rejected_when_not_recording() {
+        let mut manager = recording_manager();
+
+        let result = manager
+            .switch_recording_device(microphone("USB Headset"), DeviceMonitorType::Microphone)
+            .await;
+
+        assert!(result.is_err());
+        assert!(!manager.stream_manager.has_active_streams());
+    }
+
+    #[tokio::test]
+    async fn switching_to_the_active_device_is_a_no_op() {
+        let mut manager = recording_manager();
+        manager.state.start_recording().unwrap();
+        manager.state.set_microphone_device(microphone("Built-in Microphone"));
+
+        manager
+            .switch_recording_device(microphone("Built-in Microphone"), DeviceMonitorType::Microphone)
+            .await
+            .unwrap();
+
+        // No stream was reopened
+        assert!(!manager.stream_manager.has_active_streams());
+    }
+
+    #[tokio::test]
+    async fn failed_switch_restores_the_previous_device() {
+        let mut manager = recording_manager();
+        manager.state.start_recording().unwrap();
+        manager
+            .stream_manager
+            .replace_stream(microphone("Built-in Microphone"), RecordingDeviceType::Microphone)
+            .await
+            .unwrap();
+
+        let result = manager
+            .switch_recording_device(microphone("Broken Headset"), DeviceMonitorType::Microphone)
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(manager.stream_manager.active_stream_count(), 1);
+        assert_eq!(manager.state.get_microphone_device().unwrap().name, "Built-in Microphone");
+    }
+}
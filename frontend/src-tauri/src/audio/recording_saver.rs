@@ -11,6 +11,9 @@ use super::recording_state::AudioChunk;
 use super::recording_preferences::load_recording_preferences;
 use super::audio_processing::create_meeting_folder;
 use super::incremental_saver::IncrementalAudioSaver;
+use super::quality_monitor::AudioQualityEvent;
+use super::transcription::WordTiming;
+use crate::redaction::RedactionMap;
 
 /// Structured transcript segment for JSON export
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +26,13 @@ pub struct TranscriptSegment {
     pub display_time: String,   // Formatted time for display like "[02:15]"
     pub confidence: f32,
     pub sequence_id: u64,
+    #[serde(default)]
+    pub words: Vec<WordTiming>, // Recording-relative word timings
+    // Parallel translation track for bilingual meetings (arrives after the segment)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation_language: Option<String>,
 }
 
 /// Meeting metadata structure
@@ -39,6 +49,18 @@ pub struct MeetingMetadata {
     pub transcript_file: String,
     pub sample_rate: u32,
     pub status: String,  // "recording", "completed", "error"
+    #[serde(default)]
+    pub device_switches: Vec<DeviceSwitch>,
+    #[serde(default)]
+    pub quality_events: Vec<AudioQualityEvent>,
+    #[serde(default)]
+    pub markers: Vec<RecordingMarker>,
+    /// Seconds of pre-roll audio (captured before recording started) at the start of the file
+    #[serde(default)]
+    pub pre_roll_seconds: f64,
+    /// Target language of the meeting's translation track (None = monolingual)
+    #[serde(default)]
+    pub translation_language: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,6 +69,175 @@ pub struct DeviceInfo {
     pub system_audio: Option<String>,
 }
 
+/// Bookmark stamped at a recording-relative time (e.g. "decision here")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingMarker {
+    pub id: String,
+    pub recording_time: f64, // Seconds from recording start
+    pub label: Option<String>,
+    pub created_at: String,  // Wall-clock time (RFC 3339)
+}
+
+/// Record of an input device being swapped during a running recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceSwitch {
+    pub device_type: String,          // "microphone" or "system_audio"
+    pub from_device: Option<String>,
+    pub to_device: String,
+    pub recording_time: f64,          // Seconds from recording start
+    pub switched_at: String,          // Wall-clock time (RFC 3339)
+}
+
+/// Writes a recording's transcript segments to its transcripts.json. Cloned out of the
+/// saver so segments that finish transcribing while the recording shuts down are still saved.
+#[derive(Clone)]
+pub struct TranscriptSink {
+    meeting_folder: Option<PathBuf>,
+    transcript_segments: Arc<Mutex<Vec<TranscriptSegment>>>,
+    /// Placeholders of storage-time redaction in transcripts.json, stable across rewrites
+    redactions: Arc<Mutex<RedactionMap>>,
+}
+
+impl TranscriptSink {
+    /// Sink writing to `meeting_folder`/transcripts.json (kept in memory only without a folder)
+    pub fn new(meeting_folder: Option<PathBuf>) -> Self {
+        Self {
+            meeting_folder,
+            transcript_segments: Arc::new(Mutex::new(Vec::new())),
+            redactions: Arc::new(Mutex::new(RedactionMap::default())),
+        }
+    }
+
+    /// Add or update a structured transcript segment (upserts based on sequence_id)
+    /// Also saves incrementally to disk; returns whether the segment was stored and written
+    pub fn add_transcript_segment(&self, segment: TranscriptSegment) -> bool {
+        if let Ok(mut segments) = self.transcript_segments.lock() {
+            // Check if segment with same sequence_id exists (update it)
+            if let Some(existing) = segments.iter_mut().find(|s| s.sequence_id == segment.sequence_id) {
+                *existing = segment.clone();
+                info!("Updated transcript segment {} (seq: {}) - total segments: {}",
+                      segment.id, segment.sequence_id, segments.len());
+            } else {
+                // New segment, add it
+                segments.push(segment.clone());
+                info!("Added new transcript segment {} (seq: {}) - total segments: {}",
+                      segment.id, segment.sequence_id, segments.len());
+            }
+        } else {
+            error!("Failed to lock transcript segments for adding segment {}", segment.id);
+            return false;
+        }
+
+        // NEW: Save incrementally to disk
+        if let Some(folder) = &self.meeting_folder {
+            if let Err(e) = self.write_transcripts_json(folder) {
+                warn!("Failed to write incremental transcript update: {}", e);
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Attach a translation to the segment with the given sequence_id
+    pub fn set_segment_translation(&self, sequence_id: u64, text: String, target_language: String) {
+        let found = if let Ok(mut segments) = self.transcript_segments.lock() {
+            match segments.iter_mut().find(|s| s.sequence_id == sequence_id) {
+                Some(segment) => {
+                    segment.translation = Some(text);
+                    segment.translation_language = Some(target_language);
+                    true
+                }
+                None => false,
+            }
+        } else {
+            error!("Failed to lock transcript segments for translation of seq {}", sequence_id);
+            false
+        };
+
+        if !found {
+            warn!("No transcript segment with seq {} for translation", sequence_id);
+            return;
+        }
+
+        if let Some(folder) = &self.meeting_folder {
+            if let Err(e) = self.write_transcripts_json(folder) {
+                warn!("Failed to write incremental translation update: {}", e);
+            }
+        }
+    }
+
+    /// Write transcripts.json to disk (atomic write with temp file and validation)
+    fn write_transcripts_json(&self, folder: &PathBuf) -> Result<()> {
+        // Clone segments to avoid holding lock during I/O
+        let mut segments_clone = if let Ok(segments) = self.transcript_segments.lock() {
+            segments.clone()
+        } else {
+            error!("Failed to lock transcript segments for writing");
+            return Err(anyhow::anyhow!("Failed to lock transcript segments"));
+        };
+
+        // The file is a stored copy of the transcript, so storage-time redaction applies
+        if let Some(redactor) = crate::redaction::storage_redactor() {
+            let mut redactions = self
+                .redactions
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Failed to lock redaction map"))?;
+            for segment in segments_clone.iter_mut() {
+                if redactor.redact_in_place(&mut segment.text, &mut redactions) {
+                    segment.words.clear();
+                }
+                if let Some(translation) = segment.translation.as_mut() {
+                    redactor.redact_in_place(translation, &mut redactions);
+                }
+            }
+        }
+
+        info!("Writing {} transcript segments to JSON", segments_clone.len());
+
+        let transcript_path = folder.join("transcripts.json");
+        let temp_path = folder.join(".transcripts.json.tmp");
+
+        // Create JSON structure
+        let json = serde_json::json!({
+            "version": "1.0",
+            "segments": segments_clone,
+            "last_updated": chrono::Utc::now().to_rfc3339(),
+            "total_segments": segments_clone.len()
+        });
+
+        // Serialize to pretty JSON string
+        let json_string = serde_json::to_string_pretty(&json)
+            .map_err(|e| {
+                error!("Failed to serialize transcripts to JSON: {}", e);
+                anyhow::anyhow!("JSON serialization failed: {}", e)
+            })?;
+
+        // Write to temp file with error handling
+        std::fs::write(&temp_path, &json_string)
+            .map_err(|e| {
+                error!("Failed to write transcript temp file to {}: {}", temp_path.display(), e);
+                anyhow::anyhow!("Failed to write temp file: {}", e)
+            })?;
+
+        // Verify temp file was written correctly
+        if !temp_path.exists() {
+            error!("Temp transcript file does not exist after write: {}", temp_path.display());
+            return Err(anyhow::anyhow!("Temp file verification failed"));
+        }
+
+        // Atomic rename
+        std::fs::rename(&temp_path, &transcript_path)
+            .map_err(|e| {
+                error!("Failed to rename transcript file from {} to {}: {}",
+                       temp_path.display(), transcript_path.display(), e);
+                anyhow::anyhow!("Failed to rename transcript file: {}", e)
+            })?;
+
+        info!("✅ Successfully wrote transcripts.json with {} segments", segments_clone.len());
+        Ok(())
+    }
+}
+
 /// New recording saver using incremental saving strategy
 pub struct RecordingSaver {
     incremental_saver: Option<Arc<AsyncMutex<IncrementalAudioSaver>>>,
@@ -54,6 +245,8 @@ pub struct RecordingSaver {
     meeting_name: Option<String>,
     metadata: Option<MeetingMetadata>,
     transcript_segments: Arc<Mutex<Vec<TranscriptSegment>>>,
+    /// Placeholders of storage-time redaction in transcripts.json, stable across rewrites
+    redactions: Arc<Mutex<RedactionMap>>,
     chunk_receiver: Option<mpsc::UnboundedReceiver<AudioChunk>>,
     is_saving: Arc<Mutex<bool>>,
 }
@@ -66,6 +259,7 @@ impl RecordingSaver {
             meeting_name: None,
             metadata: None,
             transcript_segments: Arc::new(Mutex::new(Vec::new())),
+            redactions: Arc::new(Mutex::new(RedactionMap::default())),
             chunk_receiver: None,
             is_saving: Arc::new(Mutex::new(false)),
         }
@@ -92,31 +286,142 @@ impl RecordingSaver {
         }
     }
 
-    /// Add or update a structured transcript segment (upserts based on sequence_id)
-    /// Also saves incrementally to disk
-    pub fn add_transcript_segment(&self, segment: TranscriptSegment) {
-        if let Ok(mut segments) = self.transcript_segments.lock() {
-            // Check if segment with same sequence_id exists (update it)
-            if let Some(existing) = segments.iter_mut().find(|s| s.sequence_id == segment.sequence_id) {
-                *existing = segment.clone();
-                info!("Updated transcript segment {} (seq: {}) - total segments: {}",
-                      segment.id, segment.sequence_id, segments.len());
-            } else {
-                // New segment, add it
-                segments.push(segment.clone());
-                info!("Added new transcript segment {} (seq: {}) - total segments: {}",
-                      segment.id, segment.sequence_id, segments.len());
+    /// Record a mid-recording device switch in metadata and update the active device names
+    pub fn record_device_switch(&mut self, switch: DeviceSwitch) {
+        if let Some(ref mut metadata) = self.metadata {
+            match switch.device_type.as_str() {
+                "microphone" => metadata.devices.microphone = Some(switch.to_device.clone()),
+                _ => metadata.devices.system_audio = Some(switch.to_device.clone()),
+            }
+            metadata.device_switches.push(switch);
+
+            // Write updated metadata to disk if folder exists
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with device switch: {}", e);
+                }
             }
-        } else {
-            error!("Failed to lock transcript segments for adding segment {}", segment.id);
         }
+    }
 
-        // NEW: Save incrementally to disk
+    /// Record a live audio quality warning in metadata
+    pub fn record_quality_event(&mut self, event: AudioQualityEvent) {
+        if let Some(ref mut metadata) = self.metadata {
+            metadata.quality_events.push(event);
+
+            // Write updated metadata to disk if folder exists
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with quality event: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Record how much pre-roll audio was prepended to the recording
+    pub fn set_pre_roll_seconds(&mut self, seconds: f64) {
+        if let Some(ref mut metadata) = self.metadata {
+            metadata.pre_roll_seconds = seconds;
+
+            // Write updated metadata to disk if folder exists
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with pre-roll length: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Record the meeting's translation target in metadata.json
+    pub fn set_translation_language(&mut self, target_language: Option<String>) {
+        if let Some(ref mut metadata) = self.metadata {
+            metadata.translation_language = target_language;
+
+            // Write updated metadata to disk if folder exists
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with translation language: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Add a marker to metadata.json
+    pub fn add_marker(&mut self, marker: RecordingMarker) -> Result<()> {
+        let Some(ref mut metadata) = self.metadata else {
+            return Err(anyhow::anyhow!("No metadata initialized - marker {} not persisted", marker.id));
+        };
+        metadata.markers.push(marker);
+
+        // Write updated metadata to disk if folder exists; the marker stays in memory and
+        // is written again when the recording is finalized
         if let Some(folder) = &self.meeting_folder {
-            if let Err(e) = self.write_transcripts_json(folder) {
-                warn!("Failed to write incremental transcript update: {}", e);
+            let metadata_clone = metadata.clone();
+            if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                warn!("Failed to update metadata with marker: {}", e);
             }
         }
+        Ok(())
+    }
+
+    /// Get markers of the current recording session
+    pub fn get_markers(&self) -> Vec<RecordingMarker> {
+        self.metadata
+            .as_ref()
+            .map(|m| m.markers.clone())
+            .unwrap_or_default()
+    }
+
+    /// Read markers from a saved meeting folder's metadata.json
+    pub fn read_markers_from_folder(folder: &std::path::Path) -> Result<Vec<RecordingMarker>> {
+        let metadata_path = folder.join("metadata.json");
+        if !metadata_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = std::fs::read_to_string(&metadata_path)?;
+        let metadata: MeetingMetadata = serde_json::from_str(&content)?;
+        Ok(metadata.markers)
+    }
+
+    /// Read the transcript segments saved so far in a meeting folder's transcripts.json
+    pub fn read_transcripts_from_folder(folder: &std::path::Path) -> Result<Vec<TranscriptSegment>> {
+        let transcript_path = folder.join("transcripts.json");
+        if !transcript_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = std::fs::read_to_string(&transcript_path)?;
+        let json: serde_json::Value = serde_json::from_str(&content)?;
+        let segments = match json.get("segments") {
+            Some(segments) => serde_json::from_value(segments.clone())?,
+            None => Vec::new(),
+        };
+        Ok(segments)
+    }
+
+    /// Add or update a structured transcript segment (upserts based on sequence_id)
+    /// Also saves incrementally to disk; returns whether the segment was stored and written
+    pub fn add_transcript_segment(&self, segment: TranscriptSegment) -> bool {
+        self.transcript_sink().add_transcript_segment(segment)
+    }
+
+    /// Attach a translation to the segment with the given sequence_id
+    pub fn set_segment_translation(&self, sequence_id: u64, text: String, target_language: String) {
+        self.transcript_sink().set_segment_translation(sequence_id, text, target_language)
+    }
+
+    /// Handle on this recording's transcript that stays usable after the saver is gone
+    pub fn transcript_sink(&self) -> TranscriptSink {
+        TranscriptSink {
+            meeting_folder: self.meeting_folder.clone(),
+            transcript_segments: self.transcript_segments.clone(),
+            redactions: self.redactions.clone(),
+        }
     }
 
     /// Legacy method for backward compatibility - converts text to basic segment
@@ -130,6 +435,9 @@ impl RecordingSaver {
             display_time: "[00:00]".to_string(),
             confidence: 1.0,
             sequence_id: 0,
+            words: Vec::new(),
+            translation: None,
+            translation_language: None,
         };
         self.add_transcript_segment(segment);
     }
@@ -223,6 +531,11 @@ impl RecordingSaver {
             transcript_file: "transcripts.json".to_string(),
             sample_rate: 48000,
             status: "recording".to_string(),
+            device_switches: Vec::new(),
+            quality_events: Vec::new(),
+            markers: Vec::new(),
+            pre_roll_seconds: 0.0,
+            translation_language: None,
         };
 
         // Write initial metadata.json
@@ -247,61 +560,6 @@ impl RecordingSaver {
         Ok(())
     }
 
-    /// Write transcripts.json to disk (atomic write with temp file and validation)
-    fn write_transcripts_json(&self, folder: &PathBuf) -> Result<()> {
-        // Clone segments to avoid holding lock during I/O
-        let segments_clone = if let Ok(segments) = self.transcript_segments.lock() {
-            segments.clone()
-        } else {
-            error!("Failed to lock transcript segments for writing");
-            return Err(anyhow::anyhow!("Failed to lock transcript segments"));
-        };
-
-        info!("Writing {} transcript segments to JSON", segments_clone.len());
-
-        let transcript_path = folder.join("transcripts.json");
-        let temp_path = folder.join(".transcripts.json.tmp");
-
-        // Create JSON structure
-        let json = serde_json::json!({
-            "version": "1.0",
-            "segments": segments_clone,
-            "last_updated": chrono::Utc::now().to_rfc3339(),
-            "total_segments": segments_clone.len()
-        });
-
-        // Serialize to pretty JSON string
-        let json_string = serde_json::to_string_pretty(&json)
-            .map_err(|e| {
-                error!("Failed to serialize transcripts to JSON: {}", e);
-                anyhow::anyhow!("JSON serialization failed: {}", e)
-            })?;
-
-        // Write to temp file with error handling
-        std::fs::write(&temp_path, &json_string)
-            .map_err(|e| {
-                error!("Failed to write transcript temp file to {}: {}", temp_path.display(), e);
-                anyhow::anyhow!("Failed to write temp file: {}", e)
-            })?;
-
-        // Verify temp file was written correctly
-        if !temp_path.exists() {
-            error!("Temp transcript file does not exist after write: {}", temp_path.display());
-            return Err(anyhow::anyhow!("Temp file verification failed"));
-        }
-
-        // Atomic rename
-        std::fs::rename(&temp_path, &transcript_path)
-            .map_err(|e| {
-                error!("Failed to rename transcript file from {} to {}: {}",
-                       temp_path.display(), transcript_path.display(), e);
-                anyhow::anyhow!("Failed to rename transcript file: {}", e)
-            })?;
-
-        info!("✅ Successfully wrote transcripts.json with {} segments", segments_clone.len());
-        Ok(())
-    }
-
     // in frontend/src-tauri/src/audio/recording_saver.rs
     pub fn get_stats(&self) -> (usize, u32) {
         if let Some(ref saver) = self.incremental_saver {
@@ -369,7 +627,7 @@ impl RecordingSaver {
 
         // Save final transcripts.json with validation
         if let Some(folder) = &self.meeting_folder {
-            if let Err(e) = self.write_transcripts_json(folder) {
+            if let Err(e) = self.transcript_sink().write_transcripts_json(folder) {
                 error!("❌ Failed to write final transcripts: {}", e);
                 return Err(format!("Failed to save transcripts: {}", e));
             }
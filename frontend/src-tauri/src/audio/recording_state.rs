@@ -6,6 +6,7 @@ use anyhow::Result;
 
 use super::devices::AudioDevice;
 use super::buffer_pool::AudioBufferPool;
+use super::quality_monitor::AudioQualityAnalyzer;
 
 /// Device type for audio chunks
 #[derive(Debug, Clone, PartialEq)]
@@ -110,6 +111,9 @@ pub struct RecordingState {
     // Memory optimization
     buffer_pool: AudioBufferPool,
 
+    // Live audio quality analysis
+    quality_analyzer: Arc<AudioQualityAnalyzer>,
+
     // Error handling
     error_count: AtomicU32,
     recoverable_error_count: AtomicU32,
@@ -137,6 +141,7 @@ impl RecordingState {
             disconnected_device: Mutex::new(None),
             audio_sender: Mutex::new(None),
             buffer_pool: AudioBufferPool::new(16, 48000), // Pool of 16 buffers with 48kHz samples capacity
+            quality_analyzer: Arc::new(AudioQualityAnalyzer::new()),
             error_count: AtomicU32::new(0),
             recoverable_error_count: AtomicU32::new(0),
             last_error: Mutex::new(None),
@@ -150,8 +155,18 @@ impl RecordingState {
 
     // Recording control
     pub fn start_recording(&self) -> Result<()> {
+        self.start_recording_with_offset(0.0)
+    }
+
+    /// Start recording with the clock already advanced by `offset_seconds`
+    /// (used when pre-roll audio is prepended to the recording)
+    pub fn start_recording_with_offset(&self, offset_seconds: f64) -> Result<()> {
+        let now = Instant::now();
+        let start = now
+            .checked_sub(std::time::Duration::from_secs_f64(offset_seconds.max(0.0)))
+            .unwrap_or(now);
         self.is_recording.store(true, Ordering::SeqCst);
-        *self.recording_start.lock().unwrap() = Some(Instant::now());
+        *self.recording_start.lock().unwrap() = Some(start);
         self.error_count.store(0, Ordering::SeqCst);
         self.recoverable_error_count.store(0, Ordering::SeqCst);
         *self.last_error.lock().unwrap() = None;
@@ -386,6 +401,11 @@ impl RecordingState {
         self.buffer_pool.clone()
     }
 
+    // Audio quality analysis
+    pub fn get_quality_analyzer(&self) -> Arc<AudioQualityAnalyzer> {
+        self.quality_analyzer.clone()
+    }
+
     // Cleanup
     pub fn cleanup(&self) {
         self.stop_recording();
@@ -419,6 +439,7 @@ impl Default for RecordingState {
             disconnected_device: Mutex::new(None),
             audio_sender: Mutex::new(None),
             buffer_pool: AudioBufferPool::new(16, 48000), // Pool of 16 buffers with 48kHz samples capacity
+            quality_analyzer: Arc::new(AudioQualityAnalyzer::new()),
             error_count: AtomicU32::new(0),
             recoverable_error_count: AtomicU32::new(0),
             last_error: Mutex::new(None),
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use anyhow::Result;
 use cpal::traits::{DeviceTrait, StreamTrait};
 use cpal::{Device, Stream, SupportedStreamConfig};
+use futures_util::future::LocalBoxFuture;
 use log::{error, info, warn};
 use tokio::sync::mpsc;
 
@@ -22,6 +23,9 @@ pub enum StreamBackend {
     CoreAudio {
         task: Option<tokio::task::JoinHandle<()>>,
     },
+    /// Stand-in used by tests, which have no audio hardware to open
+    #[cfg(test)]
+    Test,
 }
 
 // SAFETY: While Stream doesn't implement Send, we ensure it's only accessed
@@ -311,6 +315,15 @@ impl AudioStream {
         Ok(stream)
     }
 
+    /// A stream with no device behind it, for tests
+    #[cfg(test)]
+    pub(crate) fn fake(device: Arc<AudioDevice>) -> Self {
+        Self {
+            device,
+            backend: StreamBackend::Test,
+        }
+    }
+
     /// Get device info
     pub fn device(&self) -> &AudioDevice {
         &self.device
@@ -331,6 +344,8 @@ impl AudioStream {
                     task_handle.abort();
                 }
             }
+            #[cfg(test)]
+            StreamBackend::Test => {}
         }
 
         info!("Audio stream stopped for device: {}", self.device.name);
@@ -338,11 +353,24 @@ impl AudioStream {
     }
 }
 
+/// Opens the stream for a hot-swapped device (swapped out by tests)
+type StreamOpener =
+    fn(Arc<AudioDevice>, Arc<RecordingState>, DeviceType) -> LocalBoxFuture<'static, Result<AudioStream>>;
+
+fn open_audio_stream(
+    device: Arc<AudioDevice>,
+    state: Arc<RecordingState>,
+    device_type: DeviceType,
+) -> LocalBoxFuture<'static, Result<AudioStream>> {
+    Box::pin(AudioStream::create(device, state, device_type, None))
+}
+
 /// Audio stream manager for handling multiple streams
 pub struct AudioStreamManager {
     microphone_stream: Option<AudioStream>,
     system_stream: Option<AudioStream>,
     state: Arc<RecordingState>,
+    open_stream: StreamOpener,
 }
 
 // SAFETY: AudioStreamManager contains AudioStream which we've marked as Send
@@ -354,6 +382,17 @@ impl AudioStreamManager {
             microphone_stream: None,
             system_stream: None,
             state,
+            open_stream: open_audio_stream,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_opener(state: Arc<RecordingState>, open_stream: StreamOpener) -> Self {
+        Self {
+            microphone_stream: None,
+            system_stream: None,
+            state,
+            open_stream,
         }
     }
 
@@ -442,6 +481,45 @@ impl AudioStreamManager {
         }
     }
 
+    /// Replace the stream for one device type while leaving the other stream running
+    /// Used for hot-swapping devices mid-recording (e.g. headset plugged in during a call)
+    pub async fn replace_stream(
+        &mut self,
+        device: Arc<AudioDevice>,
+        device_type: DeviceType,
+    ) -> Result<()> {
+        info!("🔀 Replacing {:?} stream with device: {}", device_type, device.name);
+
+        // Stop only the stream being replaced
+        let previous_stream = match device_type {
+            DeviceType::Microphone => self.microphone_stream.take(),
+            DeviceType::System => self.system_stream.take(),
+        };
+        if let Some(stream) = previous_stream {
+            if let Err(e) = stream.stop() {
+                warn!("Failed to stop previous {:?} stream cleanly: {}", device_type, e);
+            }
+        }
+
+        // Give the OS a moment to release the old device before opening the new one
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        let stream = (self.open_stream)(device.clone(), self.state.clone(), device_type.clone()).await?;
+        match device_type {
+            DeviceType::Microphone => {
+                self.state.set_microphone_device(device);
+                self.microphone_stream = Some(stream);
+            }
+            DeviceType::System => {
+                self.state.set_system_device(device);
+                self.system_stream = Some(stream);
+            }
+        }
+
+        info!("✅ {:?} stream replaced successfully", device_type);
+        Ok(())
+    }
+
     /// Get stream count
     pub fn active_stream_count(&self) -> usize {
         let mut count = 0;
@@ -466,4 +544,61 @@ impl Drop for AudioStreamManager {
             error!("Error stopping streams during drop: {}", e);
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::devices::DeviceType as AudioDeviceType;
+
+    /// Opens any device except ones named "Broken ..."
+    fn open_fake_stream(
+        device: Arc<AudioDevice>,
+        _state: Arc<RecordingState>,
+        _device_type: DeviceType,
+    ) -> LocalBoxFuture<'static, Result<AudioStream>> {
+        Box::pin(async move {
+            if device.name.starts_with("Broken") {
+                Err(anyhow::anyhow!("Device '{}' could not be opened", device.name))
+            } else {
+                Ok(AudioStream::fake(device))
+            }
+        })
+    }
+
+    fn microphone(name: &str) -> Arc<AudioDevice> {
+        Arc::new(AudioDevice::new(name.to_string(), AudioDeviceType::Input))
+    }
+
+    #[tokio::test]
+    async fn replace_stream_swaps_only_the_given_device_type() {
+        let state = RecordingState::new();
+        let mut manager = AudioStreamManager::with_opener(state.clone(), open_fake_stream);
+        manager.system_stream = Some(AudioStream::fake(microphone("Speakers")));
+
+        manager
+            .replace_stream(microphone("USB Headset"), DeviceType::Microphone)
+            .await
+            .unwrap();
+
+        assert_eq!(manager.active_stream_count(), 2);
+        assert_eq!(manager.microphone_stream.as_ref().unwrap().device().name, "USB Headset");
+        assert_eq!(state.get_microphone_device().unwrap().name, "USB Headset");
+    }
+
+    #[tokio::test]
+    async fn failed_replace_leaves_the_device_unchanged() {
+        let state = RecordingState::new();
+        let mut manager = AudioStreamManager::with_opener(state.clone(), open_fake_stream);
+        manager
+            .replace_stream(microphone("Built-in Microphone"), DeviceType::Microphone)
+            .await
+            .unwrap();
+
+        assert!(manager
+            .replace_stream(microphone("Broken Headset"), DeviceType::Microphone)
+            .await
+            .is_err());
+        assert_eq!(state.get_microphone_device().unwrap().name, "Built-in Microphone");
+    }
+}
@@ -0,0 +1,614 @@
+// audio/transcription/backlog.rs
+//
+// Backlog monitoring and graceful degradation. Every chunk's end-to-end lag (handed to the
+// workers → emitted in order) is tracked, and a monitor checks the age of the oldest chunk
+// still in flight. Above a threshold a `transcription-lag` event tells the UI transcripts
+// are running late. With the degradation policy enabled the monitor steps down one level
+// at a time while the lag persists (narrower Whisper beam, then a faster or quantized
+// model, then deferring new chunks until the backlog clears or the recording ends) and
+// steps back up once the backlog has cleared.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Runtime};
+
+use super::engine::TranscriptionEngine;
+use super::worker::{current_metrics, update_metrics};
+use crate::settings_store;
+
+const MONITOR_INTERVAL: Duration = Duration::from_secs(2);
+/// Minimum time at a level before degrading further, so a change can take effect
+const ESCALATE_AFTER: Duration = Duration::from_secs(15);
+/// Minimum time below the recovery threshold before stepping back up
+const RECOVER_AFTER: Duration = Duration::from_secs(30);
+/// Beam size while degraded (greedy-like decoding)
+const REDUCED_BEAM_SIZE: usize = 1;
+/// Longest a worker waits for a model switch before giving up on its chunk
+const MODEL_SWITCH_WAIT: Duration = Duration::from_secs(60);
+
+/// Whisper models from slowest to fastest
+const WHISPER_SPEED_ORDER: &[&str] = &[
+    "large-v3",
+    "medium",
+    "large-v3-turbo",
+    "small",
+    "base",
+    "tiny",
+];
+
+fn default_lag_threshold() -> f64 {
+    20.0
+}
+
+fn default_recover_below() -> f64 {
+    5.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacklogSettings {
+    /// Lag (seconds) above which transcription counts as falling behind
+    #[serde(default = "default_lag_threshold")]
+    pub lag_threshold_secs: f64,
+    /// Lag (seconds) below which the backlog counts as cleared
+    #[serde(default = "default_recover_below")]
+    pub recover_below_secs: f64,
+    /// Degrade automatically while lagging (off: only report the lag)
+    #[serde(default)]
+    pub degrade: bool,
+    #[serde(default = "default_true")]
+    pub reduce_beam: bool,
+    #[serde(default = "default_true")]
+    pub switch_model: bool,
+    #[serde(default = "default_true")]
+    pub defer_chunks: bool,
+    /// Whisper model to fall back to; picked from the installed models when unset
+    #[serde(default)]
+    pub fallback_whisper_model: Option<String>,
+}
+
+impl Default for BacklogSettings {
+    fn default() -> Self {
+        Self {
+            lag_threshold_secs: default_lag_threshold(),
+            recover_below_secs: default_recover_below(),
+            degrade: false,
+            reduce_beam: true,
+            switch_model: true,
+            defer_chunks: true,
+            fallback_whisper_model: None,
+        }
+    }
+}
+
+impl BacklogSettings {
+    /// Load the settings from disk
+    pub fn load() -> Self {
+        settings_store::load("transcription_backlog.json", "backlog settings")
+    }
+
+    /// Save the settings to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "transcription_backlog.json", "backlog settings")
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.lag_threshold_secs <= 0.0 {
+            return Err("Lag threshold must be positive".to_string());
+        }
+        if self.recover_below_secs < 0.0 || self.recover_below_secs >= self.lag_threshold_secs {
+            return Err("Recovery level must be below the lag threshold".to_string());
+        }
+        Ok(())
+    }
+
+    fn allows(&self, level: DegradationLevel) -> bool {
+        match level {
+            DegradationLevel::Normal => true,
+            DegradationLevel::ReducedBeam => self.reduce_beam,
+            DegradationLevel::FasterModel => self.switch_model,
+            DegradationLevel::Deferring => self.defer_chunks,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DegradationLevel {
+    #[default]
+    Normal,
+    ReducedBeam,
+    FasterModel,
+    Deferring,
+}
+
+const LEVELS: [DegradationLevel; 4] = [
+    DegradationLevel::Normal,
+    DegradationLevel::ReducedBeam,
+    DegradationLevel::FasterModel,
+    DegradationLevel::Deferring,
+];
+
+/// What changed after an observation
+#[derive(Debug, Default, PartialEq)]
+pub struct PolicyChange {
+    pub lagging: Option<bool>,
+    pub level: Option<(DegradationLevel, DegradationLevel)>,
+}
+
+impl PolicyChange {
+    fn is_empty(&self) -> bool {
+        self.lagging.is_none() && self.level.is_none()
+    }
+}
+
+/// Lag state machine: reports crossing the threshold and moves between degradation levels
+#[derive(Debug, Default)]
+pub struct BacklogPolicy {
+    lagging: bool,
+    level: DegradationLevel,
+    last_change: Option<Instant>,
+    below_since: Option<Instant>,
+}
+
+impl BacklogPolicy {
+    pub fn observe(&mut self, settings: &BacklogSettings, lag: f64, now: Instant) -> PolicyChange {
+        let mut change = PolicyChange::default();
+        let settled = |after: Duration, last: Option<Instant>| {
+            last.map_or(true, |t| now.duration_since(t) >= after)
+        };
+
+        if lag > settings.lag_threshold_secs {
+            self.below_since = None;
+            if !self.lagging {
+                self.lagging = true;
+                change.lagging = Some(true);
+            }
+            if settings.degrade && settled(ESCALATE_AFTER, self.last_change) {
+                let next = LEVELS
+                    .iter()
+                    .copied()
+                    .find(|&l| l > self.level && settings.allows(l));
+                if let Some(next) = next {
+                    change.level = Some((self.level, next));
+                    self.level = next;
+                    self.last_change = Some(now);
+                }
+            }
+        } else if lag < settings.recover_below_secs {
+            let below_since = *self.below_since.get_or_insert(now);
+            if self.lagging {
+                self.lagging = false;
+                change.lagging = Some(false);
+            }
+            if self.level > DegradationLevel::Normal
+                && settled(RECOVER_AFTER, Some(below_since))
+                && settled(RECOVER_AFTER, self.last_change)
+            {
+                let previous = LEVELS
+                    .iter()
+                    .rev()
+                    .copied()
+                    .find(|&l| l < self.level && settings.allows(l))
+                    .unwrap_or(DegradationLevel::Normal);
+                change.level = Some((self.level, previous));
+                self.level = previous;
+                self.last_change = Some(now);
+            }
+        } else {
+            self.below_since = None;
+        }
+        change
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LagEvent {
+    pub lagging: bool,
+    pub lag_seconds: f64,
+    pub queue_depth: u64,
+    pub deferred_chunks: usize,
+    pub threshold_seconds: f64,
+    pub degradation_level: DegradationLevel,
+}
+
+static SETTINGS: Lazy<RwLock<BacklogSettings>> = Lazy::new(|| RwLock::new(BacklogSettings::load()));
+static POLICY: Lazy<Mutex<BacklogPolicy>> = Lazy::new(|| Mutex::new(BacklogPolicy::default()));
+/// Dispatch time of every chunk handed to the workers and not yet emitted, by sequence
+static IN_FLIGHT: Lazy<Mutex<BTreeMap<u64, Instant>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+/// Model configured before switching to a faster one, restored on recovery
+static SWITCHED_FROM: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+/// Engines unload the old model before loading the new one; workers wait meanwhile
+static SWITCHING_MODEL: AtomicBool = AtomicBool::new(false);
+
+fn settings() -> BacklogSettings {
+    SETTINGS.read().map(|s| s.clone()).unwrap_or_default()
+}
+
+fn current_level() -> DegradationLevel {
+    POLICY.lock().map(|p| p.level).unwrap_or_default()
+}
+
+/// Start a recording session at full quality
+pub fn reset_session() {
+    if let Ok(mut policy) = POLICY.lock() {
+        *policy = BacklogPolicy::default();
+    }
+    if let Ok(mut in_flight) = IN_FLIGHT.lock() {
+        in_flight.clear();
+    }
+}
+
+pub fn chunk_dispatched(sequence: u64) {
+    if let Ok(mut in_flight) = IN_FLIGHT.lock() {
+        in_flight.insert(sequence, Instant::now());
+    }
+}
+
+/// End-to-end lag of a chunk, in seconds, when it is emitted
+pub fn chunk_emitted(sequence: u64) -> Option<f64> {
+    IN_FLIGHT
+        .lock()
+        .ok()?
+        .remove(&sequence)
+        .map(|dispatched| dispatched.elapsed().as_secs_f64())
+}
+
+/// Age of the oldest chunk still waiting or being transcribed
+fn oldest_in_flight_secs() -> f64 {
+    IN_FLIGHT
+        .lock()
+        .ok()
+        .and_then(|in_flight| in_flight.values().min().map(|t| t.elapsed().as_secs_f64()))
+        .unwrap_or(0.0)
+}
+
+/// New chunks are parked until the backlog clears or the recording ends
+pub fn is_deferring() -> bool {
+    current_level() >= DegradationLevel::Deferring
+}
+
+/// Optional extra work (e.g. low-confidence re-decodes) is skipped while degraded
+pub fn is_degraded() -> bool {
+    current_level() > DegradationLevel::Normal
+}
+
+/// Whisper beam size for live decoding
+pub fn beam_size(configured: usize) -> usize {
+    if current_level() >= DegradationLevel::ReducedBeam && settings().reduce_beam {
+        configured.min(REDUCED_BEAM_SIZE)
+    } else {
+        configured
+    }
+}
+
+/// Hold a worker while the live engine swaps models, so its chunk is not skipped
+pub async fn wait_for_model_switch() {
+    let started = Instant::now();
+    while SWITCHING_MODEL.load(Ordering::SeqCst) && started.elapsed() < MODEL_SWITCH_WAIT {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// Next faster installed Whisper model: the quantized variant first, then smaller sizes
+fn next_faster_whisper(current: &str, installed: &[String]) -> Option<String> {
+    let is_installed = |name: &str| installed.iter().any(|m| m == name);
+    let base = current.trim_end_matches("-q5_0");
+    let position = WHISPER_SPEED_ORDER.iter().position(|&m| m == base)?;
+
+    if !current.ends_with("-q5_0") && is_installed(&format!("{}-q5_0", base)) {
+        return Some(format!("{}-q5_0", base));
+    }
+    WHISPER_SPEED_ORDER[position + 1..]
+        .iter()
+        .flat_map(|m| [format!("{}-q5_0", m), m.to_string()])
+        .find(|m| is_installed(m))
+}
+
+async fn load_while_workers_wait<F>(load: F) -> anyhow::Result<()>
+where
+    F: std::future::Future<Output = anyhow::Result<()>>,
+{
+    SWITCHING_MODEL.store(true, Ordering::SeqCst);
+    let result = load.await;
+    SWITCHING_MODEL.store(false, Ordering::SeqCst);
+    result
+}
+
+/// Load a faster model into the live engine, remembering the configured one
+async fn switch_to_faster_model(engine: &TranscriptionEngine, fallback: Option<String>) {
+    let switched = match engine {
+        TranscriptionEngine::Whisper(whisper) => {
+            let Some(current) = whisper.get_current_model().await else {
+                return;
+            };
+            let installed: Vec<String> = match whisper.discover_models().await {
+                Ok(models) => models
+                    .into_iter()
+                    .filter(|m| matches!(m.status, crate::whisper_engine::ModelStatus::Available))
+                    .map(|m| m.name)
+                    .collect(),
+                Err(e) => {
+                    warn!("⏱️ Could not list Whisper models: {}", e);
+                    return;
+                }
+            };
+            let target = fallback
+                .filter(|m| *m != current && installed.contains(m))
+                .or_else(|| next_faster_whisper(&current, &installed));
+            match target {
+                Some(target) => match load_while_workers_wait(whisper.load_model(&target)).await {
+                    Ok(()) => Some((current, target)),
+                    Err(e) => {
+                        warn!("⏱️ Failed to load faster model {}: {}", target, e);
+                        None
+                    }
+                },
+                None => None,
+            }
+        }
+        TranscriptionEngine::Parakeet(parakeet) => {
+            let Some(current) = parakeet.get_current_model().await else {
+                return;
+            };
+            let models = parakeet.discover_models().await.unwrap_or_default();
+            let current_is_int8 = models.iter().any(|m| {
+                m.name == current
+                    && m.quantization == crate::parakeet_engine::QuantizationType::Int8
+            });
+            let target = models
+                .into_iter()
+                .find(|m| {
+                    !current_is_int8
+                        && m.quantization == crate::parakeet_engine::QuantizationType::Int8
+                        && matches!(m.status, crate::parakeet_engine::ModelStatus::Available)
+                })
+                .map(|m| m.name);
+            match target {
+                Some(target) => match load_while_workers_wait(parakeet.load_model(&target)).await {
+                    Ok(()) => Some((current, target)),
+                    Err(e) => {
+                        warn!("⏱️ Failed to load quantized model {}: {}", target, e);
+                        None
+                    }
+                },
+                None => None,
+            }
+        }
+        TranscriptionEngine::Provider(_) => None,
+    };
+
+    match switched {
+        Some((from, to)) => {
+            info!(
+                "⏱️ Switched transcription model {} → {} to catch up",
+                from, to
+            );
+            if let Ok(mut switched_from) = SWITCHED_FROM.lock() {
+                switched_from.get_or_insert(from);
+            }
+        }
+        None => info!(
+            "⏱️ No faster model available for {}, keeping the current one",
+            engine.provider_name()
+        ),
+    }
+}
+
+/// Reload the model that was configured before degrading
+pub async fn restore_model(engine: &TranscriptionEngine) {
+    let Some(original) = SWITCHED_FROM.lock().ok().and_then(|mut m| m.take()) else {
+        return;
+    };
+    let result = match engine {
+        TranscriptionEngine::Whisper(whisper) => {
+            load_while_workers_wait(whisper.load_model(&original)).await
+        }
+        TranscriptionEngine::Parakeet(parakeet) => {
+            load_while_workers_wait(parakeet.load_model(&original)).await
+        }
+        TranscriptionEngine::Provider(_) => Ok(()),
+    };
+    match result {
+        Ok(()) => info!("⏱️ Restored transcription model {}", original),
+        Err(e) => warn!(
+            "⏱️ Failed to restore transcription model {}: {}",
+            original, e
+        ),
+    }
+}
+
+/// Check the backlog periodically, report lag and apply the degradation policy
+pub fn spawn_backlog_monitor<R: Runtime>(
+    app: AppHandle<R>,
+    engine: TranscriptionEngine,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(MONITOR_INTERVAL);
+        loop {
+            interval.tick().await;
+            let settings = settings();
+            let lag = oldest_in_flight_secs();
+            let (change, lagging) = match POLICY.lock() {
+                Ok(mut policy) => (
+                    policy.observe(&settings, lag, Instant::now()),
+                    policy.lagging,
+                ),
+                Err(_) => continue,
+            };
+            if change.is_empty() {
+                continue;
+            }
+
+            if let Some((from, to)) = change.level {
+                info!(
+                    "⏱️ Transcription degradation {:?} → {:?} (lag {:.1}s)",
+                    from, to, lag
+                );
+                if to >= DegradationLevel::FasterModel && from < DegradationLevel::FasterModel {
+                    switch_to_faster_model(&engine, settings.fallback_whisper_model.clone()).await;
+                } else if to < DegradationLevel::FasterModel
+                    && from >= DegradationLevel::FasterModel
+                {
+                    restore_model(&engine).await;
+                }
+                update_metrics(|m| m.degradation_level = to);
+            }
+            match change.lagging {
+                Some(true) => warn!(
+                    "⏱️ Transcription is {:.1}s behind (threshold {:.0}s)",
+                    lag, settings.lag_threshold_secs
+                ),
+                Some(false) => info!("⏱️ Transcription backlog cleared"),
+                None => {}
+            }
+
+            let metrics = current_metrics();
+            let _ = app.emit(
+                "transcription-lag",
+                LagEvent {
+                    lagging,
+                    lag_seconds: lag,
+                    queue_depth: metrics.queue_depth,
+                    deferred_chunks: metrics.deferred_chunks,
+                    threshold_seconds: settings.lag_threshold_secs,
+                    degradation_level: metrics.degradation_level,
+                },
+            );
+        }
+    })
+}
+
+#[tauri::command]
+pub async fn get_backlog_settings() -> Result<BacklogSettings, String> {
+    Ok(settings())
+}
+
+#[tauri::command]
+pub async fn set_backlog_settings(settings: BacklogSettings) -> Result<(), String> {
+    settings.validate()?;
+    settings.save()?;
+    *SETTINGS
+        .write()
+        .map_err(|e| format!("Failed to update backlog settings: {}", e))? = settings;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degrading() -> BacklogSettings {
+        BacklogSettings {
+            degrade: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reports_lag_without_degrading_by_default() {
+        let settings = BacklogSettings::default();
+        let mut policy = BacklogPolicy::default();
+        let now = Instant::now();
+
+        assert_eq!(
+            policy.observe(&settings, 25.0, now),
+            PolicyChange {
+                lagging: Some(true),
+                level: None
+            }
+        );
+        assert_eq!(
+            policy.observe(&settings, 30.0, now + ESCALATE_AFTER),
+            PolicyChange::default()
+        );
+        assert_eq!(
+            policy
+                .observe(&settings, 2.0, now + ESCALATE_AFTER * 2)
+                .lagging,
+            Some(false)
+        );
+        assert_eq!(policy.level, DegradationLevel::Normal);
+    }
+
+    #[test]
+    fn degrades_one_level_at_a_time_and_recovers() {
+        let settings = degrading();
+        let mut policy = BacklogPolicy::default();
+        let start = Instant::now();
+
+        let change = policy.observe(&settings, 25.0, start);
+        assert_eq!(
+            change.level,
+            Some((DegradationLevel::Normal, DegradationLevel::ReducedBeam))
+        );
+        // Too soon to degrade further
+        assert_eq!(
+            policy
+                .observe(&settings, 25.0, start + Duration::from_secs(5))
+                .level,
+            None
+        );
+        let later = start + ESCALATE_AFTER;
+        assert_eq!(
+            policy.observe(&settings, 40.0, later).level,
+            Some((DegradationLevel::ReducedBeam, DegradationLevel::FasterModel))
+        );
+
+        // Backlog clears: step back only after staying below the recovery level
+        let cleared = later + Duration::from_secs(10);
+        assert_eq!(policy.observe(&settings, 1.0, cleared).lagging, Some(false));
+        assert_eq!(
+            policy
+                .observe(&settings, 1.0, cleared + Duration::from_secs(10))
+                .level,
+            None
+        );
+        assert_eq!(
+            policy
+                .observe(&settings, 1.0, cleared + RECOVER_AFTER)
+                .level,
+            Some((DegradationLevel::FasterModel, DegradationLevel::ReducedBeam))
+        );
+    }
+
+    #[test]
+    fn skips_disabled_actions() {
+        let settings = BacklogSettings {
+            reduce_beam: false,
+            switch_model: false,
+            ..degrading()
+        };
+        let mut policy = BacklogPolicy::default();
+        assert_eq!(
+            policy.observe(&settings, 25.0, Instant::now()).level,
+            Some((DegradationLevel::Normal, DegradationLevel::Deferring))
+        );
+    }
+
+    #[test]
+    fn picks_quantized_then_smaller_whisper_models() {
+        let installed: Vec<String> = ["large-v3", "large-v3-q5_0", "small", "base-q5_0"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            next_faster_whisper("large-v3", &installed).as_deref(),
+            Some("large-v3-q5_0")
+        );
+        assert_eq!(
+            next_faster_whisper("large-v3-q5_0", &installed).as_deref(),
+            Some("small")
+        );
+        assert_eq!(next_faster_whisper("base-q5_0", &installed), None);
+        assert_eq!(next_faster_whisper("my-custom-model", &installed), None);
+    }
+}
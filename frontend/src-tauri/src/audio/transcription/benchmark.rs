@@ -0,0 +1,714 @@
+// audio/transcription/benchmark.rs
+//
+// Local model benchmark. `HardwareProfile` guesses a performance tier from core count and
+// RAM; this measures instead. Every installed Whisper and Parakeet model transcribes a
+// speech sample (bundled under resources/benchmark or fetched from the model mirror, or
+// one the user picks) in live-sized chunks on a dedicated engine, so the live engines are
+// left alone. Real-time factor, load time, memory growth and word error rate against the
+// reference text are stored per model, and the recommendation is the most accurate model
+// below a target real-time factor, optionally saved as the transcription model.
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessesToUpdate, System};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use super::retranscription::{decode_recording, split_at_pauses};
+use crate::custom_models;
+use crate::database::models::ModelBenchmark;
+use crate::database::repositories::model_benchmark::ModelBenchmarksRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::model_download::{self, ModelArtifact};
+
+const SAMPLE_RATE: u32 = 16000;
+/// Same chunking as re-transcription, close to what live transcription feeds the engine
+const CHUNK_TARGET_SECS: f64 = 20.0;
+const PAUSE_SEARCH_SECS: f64 = 4.0;
+/// Leaves headroom for VAD, diarization and the rest of the app while recording
+pub const DEFAULT_TARGET_RTF: f64 = 0.5;
+const MEMORY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Bundled sample under resources/benchmark, also looked up on the model mirror
+const SAMPLE_FILE: &str = "sample.wav";
+const SAMPLE_MIRROR_DIR: &str = "benchmark";
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BenchmarkEngine {
+    Whisper,
+    Parakeet,
+}
+
+impl BenchmarkEngine {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BenchmarkEngine::Whisper => "whisper",
+            BenchmarkEngine::Parakeet => "parakeet",
+        }
+    }
+
+    /// Provider name in the transcript config
+    fn provider(&self) -> &'static str {
+        match self {
+            BenchmarkEngine::Whisper => "localWhisper",
+            BenchmarkEngine::Parakeet => "parakeet",
+        }
+    }
+
+    fn parse(engine: &str) -> Option<Self> {
+        match engine {
+            "whisper" => Some(BenchmarkEngine::Whisper),
+            "parakeet" => Some(BenchmarkEngine::Parakeet),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkProgress {
+    pub engine: BenchmarkEngine,
+    pub model_name: String,
+    pub index: usize,
+    pub total: usize,
+    pub stage: String, // "loading", "transcribing", "done" or "failed"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRecommendation {
+    pub engine: BenchmarkEngine,
+    pub provider: String,
+    pub model_name: String,
+    pub real_time_factor: f64,
+    pub word_error_rate: Option<f64>,
+    /// False when no model stays below the target and the fastest one is suggested instead
+    pub meets_target: bool,
+    pub target_rtf: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub results: Vec<ModelBenchmark>,
+    pub failures: Vec<String>,
+    pub recommendation: Option<ModelRecommendation>,
+    /// True when the recommendation was saved as the transcription model
+    pub applied: bool,
+}
+
+/// Speech sample and the text spoken in it
+struct BenchmarkSample {
+    audio: Vec<f32>,
+    reference: Option<String>,
+    label: String,
+}
+
+/// Where the sample fetched from the model mirror is kept
+fn downloaded_sample_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join("com.meetily.ai").join("benchmark").join(SAMPLE_FILE))
+}
+
+/// The bundled sample or, for builds that ship without one, a copy fetched from the model
+/// mirror (`<mirror>/benchmark/sample.wav`) and verified like a model file
+async fn resolve_bundled_sample(resource_dir: &Path) -> Result<PathBuf> {
+    let bundled = resource_dir.join(SAMPLE_FILE);
+    if bundled.exists() {
+        return Ok(bundled);
+    }
+    let downloaded = downloaded_sample_path()
+        .ok_or_else(|| anyhow!("Could not determine the app data directory"))?;
+
+    let settings = model_download::current_settings();
+    let Some(mirror) = settings.mirror() else {
+        if downloaded.exists() {
+            return Ok(downloaded);
+        }
+        return Err(anyhow!(
+            "This build does not include the benchmark sample. Choose a speech recording of \
+             your own, or set a model mirror that serves benchmark/{}",
+            SAMPLE_FILE
+        ));
+    };
+
+    info!("🏁 Fetching the benchmark sample from {}", mirror);
+    if let Some(parent) = downloaded.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let artifact = ModelArtifact::new(SAMPLE_MIRROR_DIR, SAMPLE_FILE);
+    let client = reqwest::Client::new();
+    let manifest = model_download::load_manifest(&client, &settings).await;
+    let fetched = custom_models::download_verified_file(
+        &artifact.url(&settings),
+        &downloaded,
+        manifest.get(&artifact),
+        settings.require_checksum,
+    )
+    .await;
+    match fetched {
+        Ok(()) => Ok(downloaded),
+        Err(e) if downloaded.exists() => {
+            warn!("🏁 Using the previously downloaded benchmark sample: {}", e);
+            Ok(downloaded)
+        }
+        Err(e) => Err(anyhow!(
+            "The benchmark sample could not be downloaded from the model mirror ({}). \
+             Choose a speech recording of your own instead.",
+            e
+        )),
+    }
+}
+
+async fn load_sample<R: Runtime>(
+    app: &AppHandle<R>,
+    sample_path: Option<String>,
+    reference_text: Option<String>,
+) -> Result<BenchmarkSample> {
+    let reference_text = reference_text.filter(|t| !t.trim().is_empty());
+    let (path, reference, label) = match sample_path {
+        Some(path) => {
+            let path = PathBuf::from(path);
+            let label = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "custom sample".to_string());
+            (path, reference_text, label)
+        }
+        None => {
+            let dir = app
+                .path()
+                .resource_dir()
+                .map_err(|e| anyhow!("Failed to resolve resource directory: {}", e))?
+                .join("benchmark");
+            let reference =
+                reference_text.or_else(|| std::fs::read_to_string(dir.join("reference.txt")).ok());
+            let path = resolve_bundled_sample(&dir).await?;
+            (path, reference, "bundled".to_string())
+        }
+    };
+
+    if !path.exists() {
+        return Err(anyhow!("Benchmark sample not found at {}", path.display()));
+    }
+    let audio = decode_recording(&path)?;
+    if audio.is_empty() {
+        return Err(anyhow!(
+            "Benchmark sample {} contains no audio",
+            path.display()
+        ));
+    }
+    Ok(BenchmarkSample {
+        audio,
+        reference,
+        label,
+    })
+}
+
+/// Installed (downloaded and not corrupted) local models
+async fn installed_models() -> Result<Vec<(BenchmarkEngine, String)>> {
+    let mut models = Vec::new();
+
+    let whisper = crate::whisper_engine::WhisperEngine::new_with_models_dir(
+        crate::whisper_engine::commands::get_models_directory(),
+    )?;
+    for model in whisper.discover_models().await? {
+        if matches!(model.status, crate::whisper_engine::ModelStatus::Available) {
+            models.push((BenchmarkEngine::Whisper, model.name));
+        }
+    }
+
+    let parakeet = crate::parakeet_engine::ParakeetEngine::new_with_models_dir(
+        crate::parakeet_engine::commands::get_models_directory(),
+    )?;
+    for model in parakeet.discover_models().await? {
+        if matches!(model.status, crate::parakeet_engine::ModelStatus::Available) {
+            models.push((BenchmarkEngine::Parakeet, model.name));
+        }
+    }
+
+    Ok(models)
+}
+
+/// Resident memory of this process in MB
+fn process_memory_mb(system: &mut System) -> u64 {
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return 0;
+    };
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system
+        .process(pid)
+        .map(|p| p.memory() / 1024 / 1024)
+        .unwrap_or(0)
+}
+
+/// Samples process memory in the background until dropped, keeping the peak
+struct MemorySampler {
+    peak_mb: Arc<AtomicU64>,
+    stop: Arc<AtomicBool>,
+}
+
+impl MemorySampler {
+    fn start() -> Self {
+        let peak_mb = Arc::new(AtomicU64::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (peak, stopped) = (peak_mb.clone(), stop.clone());
+        tokio::spawn(async move {
+            let mut system = System::new();
+            while !stopped.load(Ordering::SeqCst) {
+                peak.fetch_max(process_memory_mb(&mut system), Ordering::SeqCst);
+                tokio::time::sleep(MEMORY_POLL_INTERVAL).await;
+            }
+        });
+        Self { peak_mb, stop }
+    }
+
+    fn peak_mb(&self) -> u64 {
+        self.peak_mb.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for MemorySampler {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Load a model on a dedicated engine and transcribe the sample chunk by chunk.
+/// Returns (load time, processing time, transcript).
+async fn run_model(
+    engine: BenchmarkEngine,
+    model_name: &str,
+    chunks: &[Vec<f32>],
+    on_loaded: impl FnOnce(),
+) -> Result<(Duration, Duration, String)> {
+    let mut texts = Vec::with_capacity(chunks.len());
+    match engine {
+        BenchmarkEngine::Whisper => {
+            let whisper = crate::whisper_engine::WhisperEngine::new_with_models_dir(
+                crate::whisper_engine::commands::get_models_directory(),
+            )?;
+            whisper.discover_models().await?;
+            let load_start = Instant::now();
+            whisper.load_model(model_name).await?;
+            let load_time = load_start.elapsed();
+            on_loaded();
+
+            let start = Instant::now();
+            for chunk in chunks {
+                if CANCELLED.load(Ordering::SeqCst) {
+                    return Err(anyhow!("Benchmark cancelled"));
+                }
+                texts.push(
+                    whisper
+                        .transcribe_audio(chunk.clone(), Some("en".to_string()))
+                        .await?,
+                );
+            }
+            let elapsed = start.elapsed();
+            whisper.unload_model().await;
+            Ok((load_time, elapsed, texts.join(" ")))
+        }
+        BenchmarkEngine::Parakeet => {
+            let parakeet = crate::parakeet_engine::ParakeetEngine::new_with_models_dir(
+                crate::parakeet_engine::commands::get_models_directory(),
+            )?;
+            parakeet.discover_models().await?;
+            let load_start = Instant::now();
+            parakeet.load_model(model_name).await?;
+            let load_time = load_start.elapsed();
+            on_loaded();
+
+            let start = Instant::now();
+            for chunk in chunks {
+                if CANCELLED.load(Ordering::SeqCst) {
+                    return Err(anyhow!("Benchmark cancelled"));
+                }
+                texts.push(parakeet.transcribe_audio(chunk.clone()).await?);
+            }
+            let elapsed = start.elapsed();
+            parakeet.unload_model().await;
+            Ok((load_time, elapsed, texts.join(" ")))
+        }
+    }
+}
+
+/// Word error rate: word-level edit distance over the reference length, ignoring case
+/// and punctuation
+pub fn word_error_rate(reference: &str, hypothesis: &str) -> Option<f64> {
+    let words = |text: &str| -> Vec<String> {
+        text.split_whitespace()
+            .map(|w| {
+                w.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .flat_map(|c| c.to_lowercase())
+                    .collect::<String>()
+            })
+            .filter(|w| !w.is_empty())
+            .collect()
+    };
+    let reference = words(reference);
+    let hypothesis = words(hypothesis);
+    if reference.is_empty() {
+        return None;
+    }
+
+    let mut previous: Vec<usize> = (0..=hypothesis.len()).collect();
+    for (i, r) in reference.iter().enumerate() {
+        let mut current = vec![i + 1; hypothesis.len() + 1];
+        for (j, h) in hypothesis.iter().enumerate() {
+            let substitution = previous[j] + usize::from(r != h);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    Some(previous[hypothesis.len()] as f64 / reference.len() as f64)
+}
+
+/// Most accurate model that stays below `target_rtf`. Without accuracy data the slowest
+/// model still within the target is taken, which is usually the largest. When nothing
+/// meets the target the fastest model is suggested.
+pub fn recommend(results: &[ModelBenchmark], target_rtf: f64) -> Option<ModelRecommendation> {
+    let within_target = results
+        .iter()
+        .filter(|r| r.real_time_factor <= target_rtf)
+        .min_by(|a, b| {
+            let wer = |r: &ModelBenchmark| r.word_error_rate.unwrap_or(f64::INFINITY);
+            wer(a)
+                .total_cmp(&wer(b))
+                .then(b.real_time_factor.total_cmp(&a.real_time_factor))
+        });
+
+    let (best, meets_target) = match within_target {
+        Some(best) => (best, true),
+        None => (
+            results
+                .iter()
+                .min_by(|a, b| a.real_time_factor.total_cmp(&b.real_time_factor))?,
+            false,
+        ),
+    };
+    let engine = BenchmarkEngine::parse(&best.engine)?;
+    Some(ModelRecommendation {
+        engine,
+        provider: engine.provider().to_string(),
+        model_name: best.model_name.clone(),
+        real_time_factor: best.real_time_factor,
+        word_error_rate: best.word_error_rate,
+        meets_target,
+        target_rtf,
+    })
+}
+
+/// Stored runs of models that are still installed
+async fn current_results(pool: &sqlx::SqlitePool) -> Result<Vec<ModelBenchmark>, String> {
+    let installed = installed_models()
+        .await
+        .map_err(|e| format!("Failed to list installed models: {}", e))?;
+    let results = ModelBenchmarksRepository::get_all(pool)
+        .await
+        .map_err(|e| format!("Failed to load benchmark results: {}", e))?;
+    Ok(results
+        .into_iter()
+        .filter(|r| {
+            installed
+                .iter()
+                .any(|(engine, name)| engine.as_str() == r.engine && *name == r.model_name)
+        })
+        .collect())
+}
+
+async fn apply_recommendation(
+    pool: &sqlx::SqlitePool,
+    recommendation: &ModelRecommendation,
+) -> Result<(), String> {
+    SettingsRepository::save_transcript_config(
+        pool,
+        &recommendation.provider,
+        &recommendation.model_name,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| format!("Failed to save transcript config: {}", e))?;
+    info!(
+        "🏁 Selected {} model {} (RTF {:.2}) as the transcription model",
+        recommendation.engine.as_str(),
+        recommendation.model_name,
+        recommendation.real_time_factor
+    );
+    Ok(())
+}
+
+/// Benchmark every installed local model. `sample_path` defaults to the bundled sample;
+/// `reference_text` defaults to its transcript.
+#[tauri::command]
+pub async fn run_model_benchmark<R: Runtime>(
+    app: AppHandle<R>,
+    sample_path: Option<String>,
+    reference_text: Option<String>,
+    target_rtf: Option<f64>,
+    apply: bool,
+) -> Result<BenchmarkReport, String> {
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Stop the recording before running the benchmark".to_string());
+    }
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("A benchmark is already running".to_string());
+    }
+    CANCELLED.store(false, Ordering::SeqCst);
+
+    let result = run_benchmark(&app, sample_path, reference_text, target_rtf, apply).await;
+    RUNNING.store(false, Ordering::SeqCst);
+    result
+}
+
+async fn run_benchmark<R: Runtime>(
+    app: &AppHandle<R>,
+    sample_path: Option<String>,
+    reference_text: Option<String>,
+    target_rtf: Option<f64>,
+    apply: bool,
+) -> Result<BenchmarkReport, String> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+    let pool = state.db_manager.pool();
+    let target_rtf = target_rtf.unwrap_or(DEFAULT_TARGET_RTF);
+
+    let sample = load_sample(app, sample_path, reference_text)
+        .await
+        .map_err(|e| e.to_string())?;
+    let audio_secs = sample.audio.len() as f64 / SAMPLE_RATE as f64;
+    let chunks: Vec<Vec<f32>> = split_at_pauses(
+        &sample.audio,
+        SAMPLE_RATE,
+        CHUNK_TARGET_SECS,
+        PAUSE_SEARCH_SECS,
+    )
+    .into_iter()
+    .map(|(start, end)| sample.audio[start..end].to_vec())
+    .collect();
+
+    let models = installed_models()
+        .await
+        .map_err(|e| format!("Failed to list installed models: {}", e))?;
+    if models.is_empty() {
+        return Err("No local models are installed".to_string());
+    }
+    info!(
+        "🏁 Benchmarking {} models on {:.1}s of audio ({})",
+        models.len(),
+        audio_secs,
+        sample.label
+    );
+
+    let tier = format!(
+        "{:?}",
+        crate::audio::HardwareProfile::detect().performance_tier
+    );
+    let total = models.len();
+    let mut failures = Vec::new();
+    for (index, (engine, model_name)) in models.into_iter().enumerate() {
+        if CANCELLED.load(Ordering::SeqCst) {
+            return Err("Benchmark cancelled".to_string());
+        }
+        let progress = |stage: &str| {
+            let _ = app.emit(
+                "model-benchmark-progress",
+                BenchmarkProgress {
+                    engine,
+                    model_name: model_name.clone(),
+                    index,
+                    total,
+                    stage: stage.to_string(),
+                },
+            );
+        };
+        progress("loading");
+
+        let mut system = System::new();
+        let baseline_mb = process_memory_mb(&mut system);
+        let sampler = MemorySampler::start();
+        let run = run_model(engine, &model_name, &chunks, || progress("transcribing")).await;
+        let peak_mb = sampler.peak_mb().max(process_memory_mb(&mut system));
+        drop(sampler);
+
+        let (load_time, elapsed, transcript) = match run {
+            Ok(run) => run,
+            Err(e) if CANCELLED.load(Ordering::SeqCst) => return Err(e.to_string()),
+            Err(e) => {
+                warn!("🏁 Benchmark of {} failed: {}", model_name, e);
+                failures.push(format!("{}: {}", model_name, e));
+                progress("failed");
+                continue;
+            }
+        };
+
+        let benchmark = ModelBenchmark {
+            engine: engine.as_str().to_string(),
+            model_name: model_name.clone(),
+            real_time_factor: elapsed.as_secs_f64() / audio_secs,
+            load_time_ms: load_time.as_millis() as i64,
+            peak_memory_mb: peak_mb.saturating_sub(baseline_mb) as i64,
+            word_error_rate: sample
+                .reference
+                .as_deref()
+                .and_then(|reference| word_error_rate(reference, &transcript)),
+            audio_duration_secs: audio_secs,
+            performance_tier: tier.clone(),
+            sample: sample.label.clone(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+        info!(
+            "🏁 {} {}: RTF {:.2}, load {}ms, +{}MB, WER {}",
+            benchmark.engine,
+            benchmark.model_name,
+            benchmark.real_time_factor,
+            benchmark.load_time_ms,
+            benchmark.peak_memory_mb,
+            benchmark
+                .word_error_rate
+                .map_or("n/a".to_string(), |w| format!("{:.1}%", w * 100.0))
+        );
+        ModelBenchmarksRepository::save(pool, &benchmark)
+            .await
+            .map_err(|e| format!("Failed to save benchmark result: {}", e))?;
+        progress("done");
+    }
+
+    let results = current_results(pool).await?;
+    let recommendation = recommend(&results, target_rtf);
+    let applied = match (&recommendation, apply) {
+        (Some(recommendation), true) if recommendation.meets_target => {
+            apply_recommendation(pool, recommendation).await?;
+            true
+        }
+        _ => false,
+    };
+
+    Ok(BenchmarkReport {
+        results,
+        failures,
+        recommendation,
+        applied,
+    })
+}
+
+#[tauri::command]
+pub async fn cancel_model_benchmark() -> Result<(), String> {
+    if RUNNING.load(Ordering::SeqCst) {
+        CANCELLED.store(true, Ordering::SeqCst);
+        info!("🏁 Benchmark cancellation requested");
+    }
+    Ok(())
+}
+
+/// Stored results of installed models, fastest first
+#[tauri::command]
+pub async fn get_model_benchmarks<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<ModelBenchmark>, String> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+    current_results(state.db_manager.pool()).await
+}
+
+/// Recommendation from stored results; `apply` saves it as the transcription model. A
+/// fallback that misses the target is only saved with `confirm_below_target`, after the
+/// user has agreed to a model that may not keep up with live audio.
+#[tauri::command]
+pub async fn get_model_recommendation<R: Runtime>(
+    app: AppHandle<R>,
+    target_rtf: Option<f64>,
+    apply: bool,
+    confirm_below_target: Option<bool>,
+) -> Result<Option<ModelRecommendation>, String> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+    let pool = state.db_manager.pool();
+    let results = current_results(pool).await?;
+    let recommendation = recommend(&results, target_rtf.unwrap_or(DEFAULT_TARGET_RTF));
+    if let (Some(recommendation), true) = (&recommendation, apply) {
+        if !recommendation.meets_target && !confirm_below_target.unwrap_or(false) {
+            return Err(format!(
+                "No installed model transcribes fast enough (best real-time factor {:.2}, \
+                 target {:.2}); confirm to use {} anyway",
+                recommendation.real_time_factor,
+                recommendation.target_rtf,
+                recommendation.model_name
+            ));
+        }
+        apply_recommendation(pool, recommendation).await?;
+    }
+    Ok(recommendation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(engine: &str, model: &str, rtf: f64, wer: Option<f64>) -> ModelBenchmark {
+        ModelBenchmark {
+            engine: engine.to_string(),
+            model_name: model.to_string(),
+            real_time_factor: rtf,
+            load_time_ms: 500,
+            peak_memory_mb: 400,
+            word_error_rate: wer,
+            audio_duration_secs: 60.0,
+            performance_tier: "Medium".to_string(),
+            sample: "bundled".to_string(),
+            created_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn word_error_rate_counts_edits() {
+        assert_eq!(word_error_rate("The cat sat.", "the cat sat"), Some(0.0));
+        // One substitution, one deletion over four reference words
+        assert_eq!(word_error_rate("a b c d", "a x c"), Some(0.5));
+        assert_eq!(word_error_rate("", "anything"), None);
+    }
+
+    #[test]
+    fn recommends_most_accurate_model_within_target() {
+        let results = vec![
+            result("whisper", "tiny", 0.05, Some(0.20)),
+            result("whisper", "small", 0.3, Some(0.09)),
+            result("parakeet", "parakeet-tdt-0.6b-v3-int8", 0.1, Some(0.08)),
+            result("whisper", "large-v3", 1.4, Some(0.05)),
+        ];
+        let recommendation = recommend(&results, 0.5).unwrap();
+        assert_eq!(recommendation.model_name, "parakeet-tdt-0.6b-v3-int8");
+        assert_eq!(recommendation.provider, "parakeet");
+        assert!(recommendation.meets_target);
+    }
+
+    #[test]
+    fn without_accuracy_prefers_slowest_within_target() {
+        let results = vec![
+            result("whisper", "tiny", 0.05, None),
+            result("whisper", "small", 0.4, None),
+            result("whisper", "medium", 0.9, None),
+        ];
+        assert_eq!(recommend(&results, 0.5).unwrap().model_name, "small");
+    }
+
+    #[test]
+    fn falls_back_to_fastest_when_nothing_meets_target() {
+        let results = vec![
+            result("whisper", "medium", 0.9, Some(0.07)),
+            result("whisper", "large-v3", 1.6, Some(0.05)),
+        ];
+        let recommendation = recommend(&results, 0.5).unwrap();
+        assert_eq!(recommendation.model_name, "medium");
+        assert!(!recommendation.meets_target);
+        assert!(recommend(&[], 0.5).is_none());
+    }
+}
@@ -0,0 +1,299 @@
+// audio/transcription/confidence.rs
+//
+// Low-confidence handling. Whisper chunks whose mean token probability falls below the
+// configured threshold are decoded a second time with a wider beam and temperature
+// fallback (the better of the two results is kept), and saved meetings can be queried
+// for the passages that remain below the threshold so users can review them.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::provider::TranscriptResult;
+use crate::api::MeetingTranscript;
+use crate::database::repositories::transcript_version::TranscriptVersionsRepository;
+use crate::settings_store;
+use crate::whisper_engine::WhisperEngine;
+
+fn default_threshold() -> f32 {
+    0.6
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Persisted low-confidence configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceSettings {
+    /// Segments below this confidence (0-1) are re-decoded and listed for review
+    #[serde(default = "default_threshold")]
+    pub low_confidence_threshold: f32,
+    /// Re-decode low-confidence Whisper chunks with a wider beam during recording
+    #[serde(default = "default_true")]
+    pub redecode_low_confidence: bool,
+}
+
+impl Default for ConfidenceSettings {
+    fn default() -> Self {
+        Self {
+            low_confidence_threshold: default_threshold(),
+            redecode_low_confidence: true,
+        }
+    }
+}
+
+impl ConfidenceSettings {
+    /// Load settings from disk
+    pub fn load() -> Self {
+        settings_store::load("confidence_settings.json", "confidence settings")
+    }
+
+    /// Save settings to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "confidence_settings.json", "confidence settings")
+    }
+}
+
+static CONFIDENCE_SETTINGS: Lazy<RwLock<ConfidenceSettings>> =
+    Lazy::new(|| RwLock::new(ConfidenceSettings::load()));
+
+/// Current settings (cheap copy for the transcription hot path)
+pub fn current_settings() -> ConfidenceSettings {
+    CONFIDENCE_SETTINGS
+        .read()
+        .map(|s| s.clone())
+        .unwrap_or_default()
+}
+
+/// Whether `result` should be decoded again with the high-accuracy settings
+fn needs_redecode(result: &TranscriptResult, threshold: f32) -> bool {
+    !result.text.trim().is_empty() && result.confidence.is_some_and(|c| c < threshold)
+}
+
+/// Re-decode a low-confidence Whisper chunk with a wider beam and temperature fallback.
+/// Keeps whichever decode is more confident; the original result is returned unchanged
+/// when it is confident enough or the second pass fails.
+pub(super) async fn redecode_if_low_confidence(
+    whisper: &Arc<WhisperEngine>,
+    audio: Vec<f32>,
+    language: Option<String>,
+    result: TranscriptResult,
+    chunk_id: u64,
+) -> TranscriptResult {
+    let settings = current_settings();
+    if !settings.redecode_low_confidence
+        || !needs_redecode(&result, settings.low_confidence_threshold)
+    {
+        return result;
+    }
+
+    let original_confidence = result.confidence.unwrap_or(0.0);
+    info!(
+        "🔁 Chunk {} below confidence threshold ({:.2} < {:.2}), re-decoding with wider beam",
+        chunk_id, original_confidence, settings.low_confidence_threshold
+    );
+
+    match whisper
+        .transcribe_audio_high_accuracy(audio, language)
+        .await
+    {
+        Ok((text, confidence, is_partial, words))
+            if !text.trim().is_empty() && confidence > original_confidence =>
+        {
+            info!(
+                "✅ Re-decode improved chunk {}: {:.2} → {:.2}",
+                chunk_id, original_confidence, confidence
+            );
+            TranscriptResult {
+                text: text.trim().to_string(),
+                confidence: Some(confidence),
+                is_partial,
+                words,
+            }
+        }
+        Ok((_, confidence, _, _)) => {
+            info!(
+                "Re-decode of chunk {} did not improve confidence ({:.2} vs {:.2}), keeping original",
+                chunk_id, confidence, original_confidence
+            );
+            result
+        }
+        Err(e) => {
+            warn!("⚠️ Re-decode of chunk {} failed: {}", chunk_id, e);
+            result
+        }
+    }
+}
+
+/// Run of consecutive low-confidence segments in a saved meeting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LowConfidencePassage {
+    pub transcript_ids: Vec<String>,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub min_confidence: f32,
+    pub avg_confidence: f32,
+}
+
+/// Group segments below `threshold` into passages. Consecutive low segments are merged;
+/// segments without a confidence or corrected by the user break a passage and are skipped.
+pub fn group_low_confidence_passages(
+    segments: &[MeetingTranscript],
+    threshold: f32,
+) -> Vec<LowConfidencePassage> {
+    let mut passages = Vec::new();
+    let mut current: Vec<(&MeetingTranscript, f32)> = Vec::new();
+
+    let mut flush = |current: &mut Vec<(&MeetingTranscript, f32)>| {
+        if current.is_empty() {
+            return;
+        }
+        let confidences: Vec<f32> = current.iter().map(|(_, c)| *c).collect();
+        passages.push(LowConfidencePassage {
+            transcript_ids: current.iter().map(|(s, _)| s.id.clone()).collect(),
+            text: current
+                .iter()
+                .map(|(s, _)| s.text.trim())
+                .collect::<Vec<_>>()
+                .join(" "),
+            audio_start_time: current.first().and_then(|(s, _)| s.audio_start_time),
+            audio_end_time: current.last().and_then(|(s, _)| s.audio_end_time),
+            min_confidence: confidences.iter().copied().fold(f32::MAX, f32::min),
+            avg_confidence: confidences.iter().sum::<f32>() / confidences.len() as f32,
+        });
+        current.clear();
+    };
+
+    for segment in segments {
+        match segment.confidence {
+            Some(confidence) if !segment.user_edited && confidence < threshold => {
+                current.push((segment, confidence));
+            }
+            _ => flush(&mut current),
+        }
+    }
+    flush(&mut current);
+
+    passages
+}
+
+#[tauri::command]
+pub async fn get_confidence_settings() -> Result<ConfidenceSettings, String> {
+    CONFIDENCE_SETTINGS
+        .read()
+        .map(|s| s.clone())
+        .map_err(|e| format!("Failed to read confidence settings: {}", e))
+}
+
+#[tauri::command]
+pub async fn set_confidence_settings(settings: ConfidenceSettings) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&settings.low_confidence_threshold) {
+        return Err("Confidence threshold must be between 0 and 1".to_string());
+    }
+
+    settings.save()?;
+    let mut current = CONFIDENCE_SETTINGS
+        .write()
+        .map_err(|e| format!("Failed to update confidence settings: {}", e))?;
+    *current = settings;
+    info!(
+        "🎯 Confidence settings updated (threshold {:.2}, re-decode {})",
+        current.low_confidence_threshold, current.redecode_low_confidence
+    );
+    Ok(())
+}
+
+/// List the low-confidence passages of a saved meeting, using the configured threshold
+/// unless `threshold` is given
+#[tauri::command]
+pub async fn get_low_confidence_passages<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    threshold: Option<f32>,
+) -> Result<Vec<LowConfidencePassage>, String> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "App state not available".to_string())?;
+    let segments = TranscriptVersionsRepository::get_segments(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?;
+
+    let threshold = threshold.unwrap_or_else(|| current_settings().low_confidence_threshold);
+    Ok(group_low_confidence_passages(&segments, threshold))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(id: &str, text: &str, start: f64, confidence: Option<f32>) -> MeetingTranscript {
+        MeetingTranscript {
+            id: id.to_string(),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(start + 2.0),
+            duration: Some(2.0),
+            words: Vec::new(),
+            translation: None,
+            translation_language: None,
+            speaker: None,
+            user_edited: false,
+            confidence,
+        }
+    }
+
+    #[test]
+    fn test_groups_consecutive_low_segments() {
+        let segments = vec![
+            segment("a", "clear start", 0.0, Some(0.9)),
+            segment("b", "mumbled", 2.0, Some(0.4)),
+            segment("c", "words", 4.0, Some(0.5)),
+            segment("d", "clear again", 6.0, Some(0.8)),
+            segment("e", "noise", 8.0, Some(0.2)),
+        ];
+
+        let passages = group_low_confidence_passages(&segments, 0.6);
+        assert_eq!(passages.len(), 2);
+        assert_eq!(passages[0].transcript_ids, vec!["b", "c"]);
+        assert_eq!(passages[0].text, "mumbled words");
+        assert_eq!(passages[0].audio_start_time, Some(2.0));
+        assert_eq!(passages[0].audio_end_time, Some(6.0));
+        assert!((passages[0].min_confidence - 0.4).abs() < 1e-6);
+        assert!((passages[0].avg_confidence - 0.45).abs() < 1e-6);
+        assert_eq!(passages[1].transcript_ids, vec!["e"]);
+    }
+
+    #[test]
+    fn test_skips_unknown_and_edited_segments() {
+        let mut edited = segment("b", "fixed by hand", 2.0, Some(0.1));
+        edited.user_edited = true;
+        let segments = vec![
+            segment("a", "legacy", 0.0, None),
+            edited,
+            segment("c", "low", 4.0, Some(0.3)),
+        ];
+
+        let passages = group_low_confidence_passages(&segments, 0.6);
+        assert_eq!(passages.len(), 1);
+        assert_eq!(passages[0].transcript_ids, vec!["c"]);
+    }
+
+    #[test]
+    fn test_needs_redecode() {
+        let result = |text: &str, confidence| TranscriptResult {
+            text: text.to_string(),
+            confidence,
+            is_partial: false,
+            words: Vec::new(),
+        };
+        assert!(needs_redecode(&result("hello", Some(0.4)), 0.6));
+        assert!(!needs_redecode(&result("hello", Some(0.7)), 0.6));
+        assert!(!needs_redecode(&result("hello", None), 0.6));
+        assert!(!needs_redecode(&result("  ", Some(0.1)), 0.6));
+    }
+}
@@ -0,0 +1,815 @@
+// audio/transcription/durable_queue.rs
+//
+// Durable record of the chunks handed to the transcription task. Every chunk the dispatcher
+// receives is journaled (meeting folder, recording-relative time range, checkpoint file)
+// and removed once its result has been emitted and saved to transcripts.json. If the app
+// quits or crashes while a backlog is still queued, the journal survives and on the next
+// start the remaining passages are cut from the saved recording (merging the leftover
+// checkpoints first), transcribed, and added to the meeting; a meeting that never made it
+// into the database is created from its transcripts.json plus the resumed passages.
+
+use anyhow::{anyhow, Result};
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use uuid::Uuid;
+
+use super::engine::TranscriptionEngine;
+use super::glossary;
+use super::hallucination::{self, Verdict};
+use super::provider::{TranscriptResult, TranscriptionError};
+use super::retranscription::{decode_recording, recording_audio_path};
+use super::translation::TranslationSettings;
+use super::word_timing::offset_words;
+use super::worker::transcribe_chunk_with_provider;
+use crate::api::TranscriptSegment;
+use crate::audio::incremental_saver::IncrementalAudioSaver;
+use crate::audio::recording_saver::{self, RecordingSaver};
+use crate::audio::{AudioChunk, RecordingDeviceType};
+use crate::database::repositories::redaction::RedactionReportsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::database::repositories::transcript_version::TranscriptVersionsRepository;
+use crate::redaction::{self, RedactionMap, RedactionStage};
+
+/// Decoding rate of the saved recording (what the engines expect)
+const SAMPLE_RATE: u32 = 16000;
+/// Length of one audio checkpoint written by the incremental saver
+const CHECKPOINT_SECS: f64 = 30.0;
+/// Sample rate of the checkpoints written while recording
+const CHECKPOINT_SAMPLE_RATE: u32 = 48000;
+/// Let the engines and the database finish starting up before resuming
+const STARTUP_RESUME_DELAY: Duration = Duration::from_secs(10);
+/// A pending chunk whose start matches a saved segment within this tolerance was already saved
+const SAVED_TOLERANCE_SECS: f64 = 0.05;
+/// Same threshold the live workers apply to Whisper and remote providers
+const MIN_CONFIDENCE: f32 = 0.3;
+/// Failed resumes before a passage is given up on
+const MAX_RESUME_ATTEMPTS: u32 = 3;
+
+/// A chunk handed to the transcription task but not yet transcribed and saved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingChunk {
+    pub meeting_folder: String,
+    pub meeting_name: Option<String>,
+    pub chunk_id: u64,
+    /// Recording-relative time range of the chunk, in seconds
+    pub audio_start_time: f64,
+    pub audio_end_time: f64,
+    /// Checkpoint in `.checkpoints/` the chunk's audio starts in
+    pub checkpoint_file: String,
+    /// Wall-clock time the chunk was queued (RFC 3339)
+    pub queued_at: String,
+    /// Resumes that failed so far
+    #[serde(default)]
+    pub attempts: u32,
+}
+
+/// Pending work of one meeting, as listed in the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingMeeting {
+    pub meeting_folder: String,
+    pub meeting_name: Option<String>,
+    pub chunks: usize,
+    pub pending_audio_secs: f64,
+    pub oldest_queued_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeProgress {
+    pub meeting_folder: String,
+    pub meeting_name: Option<String>,
+    pub completed: usize,
+    pub total: usize,
+}
+
+/// Result of resuming one meeting, emitted as `transcription-resumed`
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeOutcome {
+    pub meeting_folder: String,
+    pub meeting_name: Option<String>,
+    /// Meeting the passages were added to (None if it could not be saved)
+    pub meeting_id: Option<String>,
+    /// Whether the meeting was created because it never reached the database
+    pub created_meeting: bool,
+    pub recovered_segments: usize,
+    /// Chunks whose audio was never written to disk
+    pub unrecoverable_chunks: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Journal {
+    chunks: Vec<PendingChunk>,
+}
+
+impl Journal {
+    fn journal_path() -> Option<PathBuf> {
+        // Tests never touch the user's queue
+        if cfg!(test) {
+            return Some(std::env::temp_dir().join(format!(
+                "meetily_transcription_queue_{}.json",
+                std::process::id()
+            )));
+        }
+        dirs::data_dir().map(|p| p.join("com.meetily.ai").join("transcription_queue.json"))
+    }
+
+    fn load() -> Self {
+        let Some(path) = Self::journal_path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => match serde_json::from_str::<Journal>(&contents) {
+                Ok(journal) => {
+                    if !journal.chunks.is_empty() {
+                        info!(
+                            "📼 {} chunks left untranscribed by an earlier session",
+                            journal.chunks.len()
+                        );
+                    }
+                    journal
+                }
+                Err(e) => {
+                    warn!("Failed to parse transcription queue journal: {}", e);
+                    Self::default()
+                }
+            },
+            Err(e) => {
+                warn!("Failed to read transcription queue journal: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write atomically so a crash mid-write never loses the whole journal
+    fn write(contents: &str) -> Result<()> {
+        let path = Self::journal_path()
+            .ok_or_else(|| anyhow!("Could not determine transcription queue path"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, contents)?;
+        std::fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+
+    fn remove(&mut self, meeting_folder: &str, chunk_ids: &[u64]) -> bool {
+        let before = self.chunks.len();
+        self.chunks
+            .retain(|c| c.meeting_folder != meeting_folder || !chunk_ids.contains(&c.chunk_id));
+        self.chunks.len() != before
+    }
+
+    /// Pending chunks grouped by meeting folder, in recording order
+    fn by_meeting(&self, skip_folder: Option<&str>) -> BTreeMap<String, Vec<PendingChunk>> {
+        let mut meetings: BTreeMap<String, Vec<PendingChunk>> = BTreeMap::new();
+        for chunk in &self.chunks {
+            if skip_folder == Some(chunk.meeting_folder.as_str()) {
+                continue;
+            }
+            meetings
+                .entry(chunk.meeting_folder.clone())
+                .or_default()
+                .push(chunk.clone());
+        }
+        for chunks in meetings.values_mut() {
+            chunks.sort_by(|a, b| a.audio_start_time.total_cmp(&b.audio_start_time));
+        }
+        meetings
+    }
+}
+
+/// Meeting the running transcription task belongs to
+struct Session {
+    meeting_folder: String,
+    meeting_name: Option<String>,
+}
+
+static JOURNAL: Lazy<Mutex<Journal>> = Lazy::new(|| Mutex::new(Journal::load()));
+static SESSION: Lazy<RwLock<Option<Session>>> = Lazy::new(|| RwLock::new(None));
+static RESUMING: AtomicBool = AtomicBool::new(false);
+/// Set while a journal write is scheduled; changes made before it runs share that write
+static SAVE_SCHEDULED: AtomicBool = AtomicBool::new(false);
+/// Serializes journal writes so an older snapshot never overwrites a newer one
+static SAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Apply a change to the in-memory journal and persist it off the calling thread.
+/// Called once or twice per chunk from the dispatcher and the transcript listener,
+/// so bursts of changes are coalesced into a single write.
+fn update_journal(change: impl FnOnce(&mut Journal) -> bool) {
+    let changed = match JOURNAL.lock() {
+        Ok(mut journal) => change(&mut journal),
+        Err(_) => {
+            error!("Transcription queue journal lock poisoned");
+            return;
+        }
+    };
+    if changed && !SAVE_SCHEDULED.swap(true, Ordering::SeqCst) {
+        tauri::async_runtime::spawn_blocking(save_journal);
+    }
+}
+
+fn save_journal() {
+    let Ok(_writing) = SAVE_LOCK.lock() else {
+        error!("Transcription queue journal save lock poisoned");
+        return;
+    };
+    // Changes from here on schedule another write, which waits for this one
+    SAVE_SCHEDULED.store(false, Ordering::SeqCst);
+    let contents = match JOURNAL.lock() {
+        Ok(journal) => serde_json::to_string_pretty(&*journal),
+        Err(_) => {
+            error!("Transcription queue journal lock poisoned");
+            return;
+        }
+    };
+    let result = contents
+        .map_err(anyhow::Error::from)
+        .and_then(|contents| Journal::write(&contents));
+    if let Err(e) = result {
+        warn!("Failed to save transcription queue journal: {}", e);
+    }
+}
+
+fn current_session_folder() -> Option<String> {
+    SESSION
+        .read()
+        .ok()
+        .and_then(|s| s.as_ref().map(|s| s.meeting_folder.clone()))
+}
+
+/// Journal the chunks of a new recording against its meeting folder
+pub fn begin_session(meeting_folder: Option<&Path>, meeting_name: Option<String>) {
+    let session = meeting_folder.map(|folder| Session {
+        meeting_folder: folder.to_string_lossy().to_string(),
+        meeting_name,
+    });
+    if session.is_none() {
+        warn!("📼 No meeting folder for this recording, queued chunks will not survive a restart");
+    }
+    if let Ok(mut current) = SESSION.write() {
+        *current = session;
+    }
+}
+
+/// Stop journaling; chunks still pending are left for the next start
+pub fn end_session() {
+    if let Ok(mut current) = SESSION.write() {
+        *current = None;
+    }
+}
+
+/// Checkpoint file the audio at `audio_start_time` was written to
+fn checkpoint_file_for(audio_start_time: f64) -> String {
+    let index = (audio_start_time.max(0.0) / CHECKPOINT_SECS) as u32;
+    format!("audio_chunk_{:03}.mp4", index)
+}
+
+/// Record a chunk received by the transcription task
+pub fn chunk_received(chunk: &AudioChunk) {
+    let Some((meeting_folder, meeting_name)) = SESSION.read().ok().and_then(|s| {
+        s.as_ref()
+            .map(|s| (s.meeting_folder.clone(), s.meeting_name.clone()))
+    }) else {
+        return;
+    };
+
+    let audio_end_time =
+        chunk.timestamp + chunk.data.len() as f64 / chunk.sample_rate.max(1) as f64;
+    let pending = PendingChunk {
+        meeting_folder,
+        meeting_name,
+        chunk_id: chunk.chunk_id,
+        audio_start_time: chunk.timestamp,
+        audio_end_time,
+        checkpoint_file: checkpoint_file_for(chunk.timestamp),
+        queued_at: chrono::Utc::now().to_rfc3339(),
+        attempts: 0,
+    };
+    update_journal(|journal| {
+        journal.chunks.push(pending);
+        true
+    });
+}
+
+/// Forget a chunk of the running session once its result has been emitted
+pub fn chunk_finished(chunk_id: u64) {
+    let Some(meeting_folder) = current_session_folder() else {
+        return;
+    };
+    update_journal(|journal| journal.remove(&meeting_folder, &[chunk_id]));
+}
+
+/// Sample range of a chunk in the decoded recording, clipped to the audio that was saved
+fn sample_range(audio_len: usize, start_secs: f64, end_secs: f64) -> Option<(usize, usize)> {
+    let start = (start_secs.max(0.0) * SAMPLE_RATE as f64).round() as usize;
+    let end = ((end_secs * SAMPLE_RATE as f64).round() as usize).min(audio_len);
+    (start < end).then_some((start, end))
+}
+
+fn already_saved(saved_starts: &[f64], audio_start_time: f64) -> bool {
+    saved_starts
+        .iter()
+        .any(|start| (start - audio_start_time).abs() < SAVED_TOLERANCE_SECS)
+}
+
+/// List meetings with chunks left untranscribed by an earlier session
+#[tauri::command]
+pub async fn get_pending_transcriptions() -> Result<Vec<PendingMeeting>, String> {
+    let journal = JOURNAL.lock().map_err(|e| e.to_string())?;
+    let session_folder = current_session_folder();
+    Ok(journal
+        .by_meeting(session_folder.as_deref())
+        .into_iter()
+        .map(|(meeting_folder, chunks)| PendingMeeting {
+            meeting_name: chunks.iter().find_map(|c| c.meeting_name.clone()),
+            pending_audio_secs: chunks
+                .iter()
+                .map(|c| c.audio_end_time - c.audio_start_time)
+                .sum(),
+            oldest_queued_at: chunks
+                .iter()
+                .map(|c| c.queued_at.clone())
+                .min()
+                .unwrap_or_default(),
+            chunks: chunks.len(),
+            meeting_folder,
+        })
+        .collect())
+}
+
+/// Transcribe the chunks left by an earlier session now
+#[tauri::command]
+pub async fn resume_pending_transcriptions<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<ResumeOutcome>, String> {
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Stop the recording before resuming pending transcriptions".to_string());
+    }
+    resume_pending(&app).await.map_err(|e| e.to_string())
+}
+
+/// Resume pending chunks in the background shortly after startup
+pub fn spawn_startup_resume<R: Runtime>(app: AppHandle<R>) {
+    let has_pending = JOURNAL
+        .lock()
+        .map(|j| !j.chunks.is_empty())
+        .unwrap_or(false);
+    if !has_pending {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(STARTUP_RESUME_DELAY).await;
+        if crate::audio::recording_commands::is_recording().await {
+            info!("📼 Recording in progress, pending transcriptions stay queued");
+            return;
+        }
+        if let Err(e) = resume_pending(&app).await {
+            warn!("📼 Failed to resume pending transcriptions: {}", e);
+        }
+    });
+}
+
+async fn resume_pending<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<ResumeOutcome>> {
+    if RESUMING.swap(true, Ordering::SeqCst) {
+        return Err(anyhow!("Pending transcriptions are already being resumed"));
+    }
+    let result = resume_all(app).await;
+    RESUMING.store(false, Ordering::SeqCst);
+    result
+}
+
+async fn resume_all<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<ResumeOutcome>> {
+    let pending = {
+        let journal = JOURNAL
+            .lock()
+            .map_err(|_| anyhow!("Transcription queue journal lock poisoned"))?;
+        journal.by_meeting(current_session_folder().as_deref())
+    };
+    if pending.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| anyhow!("Database not initialized"))?;
+    let pool = state.db_manager.pool().clone();
+    // Loads the configured model, as starting a recording does
+    super::engine::validate_transcription_model_ready(app)
+        .await
+        .map_err(|e| anyhow!(e))?;
+    let engine = super::engine::get_or_init_transcription_engine(app)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+    info!(
+        "📼 Resuming {} untranscribed chunks of {} meetings",
+        pending.values().map(Vec::len).sum::<usize>(),
+        pending.len()
+    );
+
+    let mut outcomes = Vec::new();
+    for (meeting_folder, chunks) in pending {
+        let outcome = match resume_meeting(app, &pool, &engine, &meeting_folder, &chunks).await {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                error!(
+                    "📼 Failed to resume transcription of {}: {}",
+                    meeting_folder, e
+                );
+                record_failed_attempt(&meeting_folder, &chunks);
+                ResumeOutcome {
+                    meeting_name: chunks.iter().find_map(|c| c.meeting_name.clone()),
+                    meeting_folder,
+                    meeting_id: None,
+                    created_meeting: false,
+                    recovered_segments: 0,
+                    unrecoverable_chunks: 0,
+                    error: Some(e.to_string()),
+                }
+            }
+        };
+        if let Err(e) = app.emit("transcription-resumed", &outcome) {
+            warn!("Failed to emit transcription-resumed event: {}", e);
+        }
+        outcomes.push(outcome);
+    }
+    Ok(outcomes)
+}
+
+/// Count a failed resume, giving up on chunks that keep failing
+fn record_failed_attempt(meeting_folder: &str, chunks: &[PendingChunk]) {
+    let chunk_ids: Vec<u64> = chunks.iter().map(|c| c.chunk_id).collect();
+    update_journal(|journal| {
+        for chunk in journal.chunks.iter_mut() {
+            if chunk.meeting_folder == meeting_folder && chunk_ids.contains(&chunk.chunk_id) {
+                chunk.attempts += 1;
+            }
+        }
+        let before = journal.chunks.len();
+        journal.chunks.retain(|c| c.attempts < MAX_RESUME_ATTEMPTS);
+        if journal.chunks.len() != before {
+            warn!(
+                "📼 Giving up on {} chunks after {} failed resumes",
+                before - journal.chunks.len(),
+                MAX_RESUME_ATTEMPTS
+            );
+        }
+        true
+    });
+}
+
+/// Merge the checkpoints of a recording that was never finalized into audio.mp4
+async fn finalize_checkpoints(folder: &Path) -> Result<PathBuf> {
+    let mut saver = IncrementalAudioSaver::recover(folder.to_path_buf(), CHECKPOINT_SAMPLE_RATE)?;
+    saver.finalize().await
+}
+
+fn meeting_name_for(folder: &Path, chunks: &[PendingChunk]) -> String {
+    std::fs::read_to_string(folder.join("metadata.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<recording_saver::MeetingMetadata>(&json).ok())
+        .and_then(|metadata| metadata.meeting_name)
+        .or_else(|| chunks.iter().find_map(|c| c.meeting_name.clone()))
+        .or_else(|| folder.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Recovered meeting".to_string())
+}
+
+async fn resume_meeting<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    engine: &TranscriptionEngine,
+    meeting_folder: &str,
+    chunks: &[PendingChunk],
+) -> Result<ResumeOutcome> {
+    let folder = PathBuf::from(meeting_folder);
+    let meeting_name = meeting_name_for(&folder, chunks);
+    let mut outcome = ResumeOutcome {
+        meeting_folder: meeting_folder.to_string(),
+        meeting_name: Some(meeting_name.clone()),
+        meeting_id: None,
+        created_meeting: false,
+        recovered_segments: 0,
+        unrecoverable_chunks: 0,
+        error: None,
+    };
+    let all_ids: Vec<u64> = chunks.iter().map(|c| c.chunk_id).collect();
+
+    if !folder.exists() {
+        warn!(
+            "📼 Recording folder {} no longer exists, dropping its pending chunks",
+            meeting_folder
+        );
+        update_journal(|journal| journal.remove(meeting_folder, &all_ids));
+        outcome.unrecoverable_chunks = chunks.len();
+        outcome.error = Some("The recording folder no longer exists".to_string());
+        return Ok(outcome);
+    }
+
+    // 1. The saved audio (merging the checkpoints of an interrupted recording)
+    let audio_path = match recording_audio_path(&folder) {
+        Some(path) => path,
+        None => finalize_checkpoints(&folder).await?,
+    };
+    info!(
+        "📼 Decoding {} to resume {} chunks",
+        audio_path.display(),
+        chunks.len()
+    );
+    let audio = tokio::task::spawn_blocking(move || decode_recording(&audio_path)).await??;
+
+    // 2. What already made it to the database or transcripts.json
+    let meeting_id: Option<String> =
+        sqlx::query_scalar("SELECT id FROM meetings WHERE folder_path = ?")
+            .bind(meeting_folder)
+            .fetch_optional(pool)
+            .await?;
+    let folder_segments = if meeting_id.is_none() {
+        RecordingSaver::read_transcripts_from_folder(&folder)?
+    } else {
+        Vec::new()
+    };
+    let saved_segments = match &meeting_id {
+        Some(id) => TranscriptVersionsRepository::get_segments(pool, id).await?,
+        None => Vec::new(),
+    };
+    let saved_starts: Vec<f64> = match &meeting_id {
+        Some(_) => saved_segments
+            .iter()
+            .filter_map(|s| s.audio_start_time)
+            .collect(),
+        None => folder_segments.iter().map(|s| s.audio_start_time).collect(),
+    };
+
+    // 3. Transcribe the passages that are still missing
+    let mut recovered = Vec::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let _ = app.emit(
+            "transcription-resume-progress",
+            ResumeProgress {
+                meeting_folder: meeting_folder.to_string(),
+                meeting_name: Some(meeting_name.clone()),
+                completed: index,
+                total: chunks.len(),
+            },
+        );
+
+        if already_saved(&saved_starts, chunk.audio_start_time) {
+            continue;
+        }
+        let Some((start, end)) =
+            sample_range(audio.len(), chunk.audio_start_time, chunk.audio_end_time)
+        else {
+            warn!(
+                "📼 Chunk {} ({:.1}s) was never written to {}",
+                chunk.chunk_id, chunk.audio_start_time, chunk.checkpoint_file
+            );
+            outcome.unrecoverable_chunks += 1;
+            continue;
+        };
+        if let Some(segment) =
+            transcribe_pending(app, engine, chunk, audio[start..end].to_vec()).await?
+        {
+            recovered.push(segment);
+        }
+    }
+
+    // 4. Complete the meeting's transcript
+    outcome.recovered_segments = recovered.len();
+    match meeting_id {
+        Some(id) => {
+            if !recovered.is_empty() {
+                // Continue after the placeholders the saved transcript already uses
+                let redactor = redaction::storage_redactor();
+                let mut redactions =
+                    RedactionMap::reserving(saved_segments.iter().map(|s| s.text.as_str()));
+                if let Some(redactor) = &redactor {
+                    for segment in recovered.iter_mut() {
+                        if redactor.redact_in_place(&mut segment.text, &mut redactions) {
+                            segment.words.clear();
+                        }
+                    }
+                }
+                TranscriptsRepository::append_segments(pool, &id, &recovered).await?;
+                if redactor.is_some() {
+                    RedactionReportsRepository::add_to_report(
+                        pool,
+                        &id,
+                        RedactionStage::Storage,
+                        &redactions.report(),
+                    )
+                    .await?;
+                }
+            }
+            outcome.meeting_id = Some(id);
+        }
+        None => {
+            // The meeting was never saved: create it from transcripts.json plus the resumed passages
+            let mut segments: Vec<TranscriptSegment> = folder_segments
+                .into_iter()
+                .map(|s| TranscriptSegment {
+                    id: s.id,
+                    text: s.text,
+                    timestamp: s.display_time,
+                    audio_start_time: Some(s.audio_start_time),
+                    audio_end_time: Some(s.audio_end_time),
+                    duration: Some(s.duration),
+                    words: s.words,
+                    translation: s.translation,
+                    translation_language: s.translation_language,
+                    confidence: Some(s.confidence),
+                })
+                .chain(recovered)
+                .collect();
+            if !segments.is_empty() {
+                segments.sort_by(|a, b| {
+                    a.audio_start_time
+                        .unwrap_or_default()
+                        .total_cmp(&b.audio_start_time.unwrap_or_default())
+                });
+                let transcripts = segments
+                    .iter()
+                    .map(serde_json::to_value)
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                let saved = crate::api::api::api_save_transcript(
+                    app.clone(),
+                    app.state(),
+                    meeting_name.clone(),
+                    transcripts,
+                    Some(meeting_folder.to_string()),
+                    None,
+                )
+                .await
+                .map_err(|e| anyhow!(e))?;
+                outcome.meeting_id = saved
+                    .get("meeting_id")
+                    .and_then(|id| id.as_str())
+                    .map(str::to_string);
+                outcome.created_meeting = true;
+            }
+        }
+    }
+
+    update_journal(|journal| journal.remove(meeting_folder, &all_ids));
+    info!(
+        "📼 Resumed {}: {} passages transcribed, {} lost with the unsaved audio",
+        meeting_name, outcome.recovered_segments, outcome.unrecoverable_chunks
+    );
+    Ok(outcome)
+}
+
+/// Transcribe one pending chunk with the same clean-up the live workers apply.
+/// Engine failures abort the meeting so its chunks stay queued for another attempt.
+async fn transcribe_pending<R: Runtime>(
+    app: &AppHandle<R>,
+    engine: &TranscriptionEngine,
+    chunk: &PendingChunk,
+    data: Vec<f32>,
+) -> Result<Option<TranscriptSegment>> {
+    let speech_energy = hallucination::peak_frame_rms(&data, SAMPLE_RATE);
+    let audio_chunk = AudioChunk {
+        data,
+        sample_rate: SAMPLE_RATE,
+        timestamp: chunk.audio_start_time,
+        chunk_id: chunk.chunk_id,
+        device_type: RecordingDeviceType::Microphone,
+    };
+
+    let result = match transcribe_chunk_with_provider(
+        engine,
+        audio_chunk,
+        app,
+        &TranslationSettings::default(),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(TranscriptionError::AudioTooShort { .. }) => return Ok(None),
+        Err(e) => return Err(anyhow!("{}", e)),
+    };
+
+    let TranscriptResult {
+        text,
+        confidence,
+        mut words,
+        ..
+    } = glossary::correct_result(result);
+    let text = match hallucination::filter_segment(
+        &text,
+        speech_energy,
+        confidence,
+        chunk.audio_start_time,
+    ) {
+        Verdict::Keep => text,
+        Verdict::Rewrite { text, .. } => {
+            words.clear();
+            text
+        }
+        Verdict::Drop(_) => return Ok(None),
+    };
+    if text.trim().is_empty() || confidence.is_some_and(|c| c < MIN_CONFIDENCE) {
+        return Ok(None);
+    }
+
+    let timestamp = chrono::DateTime::parse_from_rfc3339(&chunk.queued_at)
+        .map(|t| t.format("%H:%M:%S").to_string())
+        .unwrap_or_default();
+    Ok(Some(TranscriptSegment {
+        id: format!("transcript-{}", Uuid::new_v4()),
+        text: text.trim().to_string(),
+        timestamp,
+        audio_start_time: Some(chunk.audio_start_time),
+        audio_end_time: Some(chunk.audio_end_time),
+        duration: Some(chunk.audio_end_time - chunk.audio_start_time),
+        words: offset_words(words, chunk.audio_start_time),
+        translation: None,
+        translation_language: None,
+        confidence,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(folder: &str, chunk_id: u64, start: f64) -> PendingChunk {
+        PendingChunk {
+            meeting_folder: folder.to_string(),
+            meeting_name: None,
+            chunk_id,
+            audio_start_time: start,
+            audio_end_time: start + 2.0,
+            checkpoint_file: checkpoint_file_for(start),
+            queued_at: "2025-10-28T09:00:00Z".to_string(),
+            attempts: 0,
+        }
+    }
+
+    #[test]
+    fn groups_pending_chunks_by_meeting_in_recording_order() {
+        let journal = Journal {
+            chunks: vec![
+                pending("/b", 3, 40.0),
+                pending("/a", 2, 12.0),
+                pending("/b", 1, 5.0),
+                pending("/live", 7, 1.0),
+            ],
+        };
+
+        let meetings = journal.by_meeting(Some("/live"));
+        assert_eq!(meetings.len(), 2);
+        let starts: Vec<f64> = meetings["/b"].iter().map(|c| c.audio_start_time).collect();
+        assert_eq!(starts, vec![5.0, 40.0]);
+    }
+
+    #[test]
+    fn removes_only_the_chunks_of_the_given_meeting() {
+        let mut journal = Journal {
+            chunks: vec![
+                pending("/a", 1, 0.0),
+                pending("/b", 1, 0.0),
+                pending("/a", 2, 3.0),
+            ],
+        };
+
+        assert!(journal.remove("/a", &[1]));
+        assert!(!journal.remove("/a", &[9]));
+        let left: Vec<(&str, u64)> = journal
+            .chunks
+            .iter()
+            .map(|c| (c.meeting_folder.as_str(), c.chunk_id))
+            .collect();
+        assert_eq!(left, vec![("/b", 1), ("/a", 2)]);
+    }
+
+    #[test]
+    fn maps_chunks_to_checkpoints_and_saved_samples() {
+        assert_eq!(checkpoint_file_for(0.0), "audio_chunk_000.mp4");
+        assert_eq!(checkpoint_file_for(95.0), "audio_chunk_003.mp4");
+
+        let saved = 60 * SAMPLE_RATE as usize;
+        assert_eq!(sample_range(saved, 1.0, 2.0), Some((16000, 32000)));
+        // Audio still in memory when the app quit is clipped or missing
+        assert_eq!(sample_range(saved, 59.0, 62.0), Some((59 * 16000, saved)));
+        assert_eq!(sample_range(saved, 61.0, 63.0), None);
+    }
+
+    #[test]
+    fn skips_chunks_already_saved() {
+        let saved = [0.0, 12.48];
+        assert!(already_saved(&saved, 12.48));
+        assert!(!already_saved(&saved, 14.0));
+    }
+}
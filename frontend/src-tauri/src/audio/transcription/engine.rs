@@ -74,6 +74,8 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 provider: "localWhisper".to_string(),
                 model: "large-v3".to_string(),
                 api_key: None,
+                endpoint: None,
+                max_retries: None,
             }
         }
         Err(e) => {
@@ -82,6 +84,8 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 provider: "localWhisper".to_string(),
                 model: "large-v3".to_string(),
                 api_key: None,
+                endpoint: None,
+                max_retries: None,
             }
         }
     };
@@ -135,10 +139,47 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 }
             }
         }
+        "onnxAsr" => {
+            info!("🔍 Validating ONNX ASR model...");
+            if let Err(init_error) = crate::onnx_asr::commands::onnx_asr_init().await {
+                warn!("❌ Failed to initialize ONNX ASR engine: {}", init_error);
+                return Err(format!(
+                    "Failed to initialize ONNX speech recognition: {}",
+                    init_error
+                ));
+            }
+
+            match crate::onnx_asr::commands::onnx_asr_validate_model_ready_with_config(app).await {
+                Ok(model_name) => {
+                    info!("✅ ONNX ASR model validation successful: {} is ready", model_name);
+                    Ok(())
+                }
+                Err(e) => {
+                    warn!("❌ ONNX ASR model validation failed: {}", e);
+                    Err(e)
+                }
+            }
+        }
+        "openaiCompatible" | "openai" | "groq" => {
+            // Reachability is checked per chunk; unreachable servers fall back to a local engine
+            match super::remote_provider::RemoteTranscriptionConfig::from_transcript_config(&config) {
+                Ok(remote) => {
+                    info!(
+                        "✅ Remote transcription configured: {} ({})",
+                        remote.base_url, remote.model
+                    );
+                    Ok(())
+                }
+                Err(e) => {
+                    warn!("❌ Remote transcription config invalid: {}", e);
+                    Err(e)
+                }
+            }
+        }
         other => {
             warn!("❌ Unsupported transcription provider for local recording: {}", other);
             Err(format!(
-                "Provider '{}' is not supported for local transcription. Please select 'localWhisper' or 'parakeet'.",
+                "Provider '{}' is not supported for local transcription. Please select 'localWhisper', 'parakeet', 'onnxAsr' or 'openaiCompatible'.",
                 other
             ))
         }
@@ -170,6 +211,8 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 provider: "localWhisper".to_string(),
                 model: "large-v3".to_string(),
                 api_key: None,
+                endpoint: None,
+                max_retries: None,
             }
         }
         Err(e) => {
@@ -178,6 +221,8 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 provider: "localWhisper".to_string(),
                 model: "large-v3".to_string(),
                 api_key: None,
+                endpoint: None,
+                max_retries: None,
             }
         }
     };
@@ -212,6 +257,41 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 }
             }
         }
+        "onnxAsr" => {
+            info!("🧩 Initializing ONNX ASR transcription engine");
+            let engine = {
+                let guard = crate::onnx_asr::commands::ONNX_ASR_ENGINE.lock().unwrap();
+                guard.as_ref().cloned()
+            };
+
+            match engine {
+                Some(engine) if engine.is_model_loaded().await => {
+                    let model_name = engine.get_current_model().await
+                        .unwrap_or_else(|| "unknown".to_string());
+                    info!("✅ ONNX ASR model '{}' already loaded", model_name);
+                    Ok(TranscriptionEngine::Provider(Arc::new(
+                        super::onnx_asr_provider::OnnxAsrProvider::new(engine),
+                    )))
+                }
+                Some(_) => {
+                    Err("ONNX ASR engine initialized but no model loaded. This should not happen after validation.".to_string())
+                }
+                None => {
+                    Err("ONNX ASR engine not initialized. This should not happen after validation.".to_string())
+                }
+            }
+        }
+        "openaiCompatible" | "openai" | "groq" => {
+            let remote =
+                super::remote_provider::RemoteTranscriptionConfig::from_transcript_config(&config)?;
+            info!(
+                "☁️ Initializing remote transcription provider: {} ({})",
+                remote.base_url, remote.model
+            );
+            Ok(TranscriptionEngine::Provider(Arc::new(
+                super::remote_provider::RemoteProvider::new(remote),
+            )))
+        }
         "localWhisper" | _ => {
             info!("🎤 Initializing Whisper transcription engine");
             let whisper_engine = get_or_init_whisper(app).await?;
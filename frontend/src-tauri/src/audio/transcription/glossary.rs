@@ -0,0 +1,811 @@
+// audio/transcription/glossary.rs
+//
+// Custom vocabulary for transcription. Terms (global plus the active template's list)
+// bias Whisper through its initial prompt, and every engine's output goes through a
+// correction pass before segments are emitted and saved: an explicit replacement
+// dictionary first, then fuzzy/phonetic matching that snaps near-misses of glossary
+// terms ("Meetly", "meet ilee") to their canonical spelling ("Meetily").
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use super::provider::TranscriptResult;
+use super::word_timing::WordTiming;
+use crate::settings_store;
+
+/// Whisper's prompt window is ~224 tokens; leave room and keep the prompt short
+const MAX_INITIAL_PROMPT_CHARS: usize = 600;
+
+/// Minimum normalized length for fuzzy matching (short words match too much by accident)
+const MIN_FUZZY_TERM_LEN: usize = 4;
+
+/// Terms shorter than this are only fuzzy-matched when the transcript split them over
+/// several words ("no shun"); a single word that close to a short term is usually a real
+/// word ("motion" is one edit from "Notion")
+const SHORT_TERM_LEN: usize = 7;
+
+/// Edit-distance similarity needed for a fuzzy match, and the lower bar when the
+/// phonetic codes also agree
+const FUZZY_SIMILARITY: f32 = 0.8;
+const PHONETIC_SIMILARITY: f32 = 0.6;
+
+fn default_true() -> bool {
+    true
+}
+
+/// Explicit "heard as" → "should be" correction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlossaryReplacement {
+    pub from: String,
+    pub to: String,
+}
+
+/// Persisted glossary configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlossarySettings {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Terms used in every meeting
+    #[serde(default)]
+    pub global_terms: Vec<String>,
+    /// Extra terms per summary template id (e.g. customer names for "sales_call")
+    #[serde(default)]
+    pub template_terms: HashMap<String, Vec<String>>,
+    /// Template whose terms are active for recordings
+    #[serde(default)]
+    pub active_template: Option<String>,
+    #[serde(default)]
+    pub replacements: Vec<GlossaryReplacement>,
+    /// Snap near-misses of glossary terms using edit distance and phonetic codes
+    #[serde(default = "default_true")]
+    pub fuzzy_matching: bool,
+}
+
+impl Default for GlossarySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            global_terms: Vec::new(),
+            template_terms: HashMap::new(),
+            active_template: None,
+            replacements: Vec::new(),
+            fuzzy_matching: true,
+        }
+    }
+}
+
+impl GlossarySettings {
+    /// Load the glossary from disk
+    pub fn load() -> Self {
+        settings_store::load("glossary.json", "glossary")
+    }
+
+    /// Save the glossary to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "glossary.json", "glossary")
+    }
+
+    /// Terms in effect for recordings: global terms plus the active template's list
+    pub fn active_terms(&self) -> Vec<String> {
+        let template_terms = self
+            .active_template
+            .as_ref()
+            .and_then(|id| self.template_terms.get(id))
+            .into_iter()
+            .flatten();
+
+        let mut terms: Vec<String> = Vec::new();
+        for term in self.global_terms.iter().chain(template_terms) {
+            let term = term.trim();
+            if !term.is_empty() && !terms.iter().any(|t| t.eq_ignore_ascii_case(term)) {
+                terms.push(term.to_string());
+            }
+        }
+        terms
+    }
+}
+
+/// Glossary term prepared for matching
+struct Term {
+    canonical: String,
+    normalized: String,
+    phonetic: Option<String>,
+    word_count: usize,
+}
+
+/// Glossary with compiled replacement patterns and prepared terms
+pub struct CompiledGlossary {
+    settings: GlossarySettings,
+    replacements: Vec<(Regex, String)>,
+    terms: Vec<Term>,
+}
+
+impl CompiledGlossary {
+    pub fn new(settings: GlossarySettings) -> Self {
+        let replacements = settings
+            .replacements
+            .iter()
+            .filter(|r| !r.from.trim().is_empty())
+            .filter_map(|r| {
+                let pattern = format!(r"\b{}\b", regex::escape(r.from.trim()));
+                match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+                    Ok(regex) => Some((regex, r.to.trim().to_string())),
+                    Err(e) => {
+                        warn!("Skipping invalid glossary replacement '{}': {}", r.from, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let terms = settings
+            .active_terms()
+            .into_iter()
+            .map(|canonical| {
+                let normalized = normalize(&canonical);
+                Term {
+                    phonetic: soundex(&normalized),
+                    word_count: canonical.split_whitespace().count().max(1),
+                    normalized,
+                    canonical,
+                }
+            })
+            .filter(|t| !t.normalized.is_empty())
+            .collect();
+
+        Self {
+            settings,
+            replacements,
+            terms,
+        }
+    }
+
+    /// Initial prompt biasing Whisper towards the glossary spellings
+    pub fn initial_prompt(&self) -> Option<String> {
+        if !self.settings.enabled {
+            return None;
+        }
+
+        let mut vocabulary: Vec<&str> = self.terms.iter().map(|t| t.canonical.as_str()).collect();
+        for (_, to) in &self.replacements {
+            if !to.is_empty() && !vocabulary.iter().any(|v| v.eq_ignore_ascii_case(to)) {
+                vocabulary.push(to);
+            }
+        }
+        if vocabulary.is_empty() {
+            return None;
+        }
+
+        let mut prompt = String::from("Glossary:");
+        for (i, term) in vocabulary.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            if prompt.len() + separator.len() + term.len() + 1 > MAX_INITIAL_PROMPT_CHARS {
+                break;
+            }
+            prompt.push_str(separator);
+            prompt.push_str(term);
+        }
+        prompt.push('.');
+        Some(prompt)
+    }
+
+    /// Apply replacements and term matching to a transcript text
+    pub fn correct(&self, text: &str) -> String {
+        if !self.settings.enabled || text.trim().is_empty() {
+            return text.to_string();
+        }
+
+        let mut corrected = text.to_string();
+        for (regex, to) in &self.replacements {
+            corrected = regex.replace_all(&corrected, to.as_str()).into_owned();
+        }
+
+        if self.terms.is_empty() {
+            return corrected;
+        }
+        self.match_terms(&corrected)
+    }
+
+    /// Apply the glossary to word timings. The words are corrected as one text and
+    /// aligned back, so multi-word corrections ("post gres" → "Postgres") merge the
+    /// timings of the words they replace and match the corrected segment text.
+    pub fn correct_words(&self, words: Vec<WordTiming>) -> Vec<WordTiming> {
+        if !self.settings.enabled || words.is_empty() {
+            return words;
+        }
+
+        let text = words
+            .iter()
+            .map(|w| w.word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let corrected = self.correct(&text);
+        if corrected == text {
+            return words;
+        }
+        let corrected: Vec<&str> = corrected.split_whitespace().collect();
+        align_words(&words, &corrected)
+    }
+
+    /// Snap word windows that (nearly) match a glossary term to its canonical spelling,
+    /// keeping the text between them (including whitespace) as it was
+    fn match_terms(&self, text: &str) -> String {
+        let tokens = tokenize(text);
+        let mut output = String::with_capacity(text.len());
+        let mut copied = 0;
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let Some((term, len)) = self.find_term_at(&tokens, i) else {
+                i += 1;
+                continue;
+            };
+            let window = &tokens[i..i + len];
+            let surface: Vec<&str> = window.iter().map(|t| t.core).collect();
+            if surface.join(" ") != term.canonical {
+                let (first, last) = (&window[0], &window[len - 1]);
+                output.push_str(&text[copied..first.start]);
+                output.push_str(first.leading);
+                output.push_str(&term.canonical);
+                output.push_str(last.trailing);
+                copied = last.end;
+            }
+            i += len;
+        }
+
+        output.push_str(&text[copied..]);
+        output
+    }
+
+    /// Best term matching the window starting at `start`: exact matches win over fuzzy
+    /// ones, the longest exact match first ("Acme Cloud" over "Acme"), then the most
+    /// similar fuzzy match. Windows one word longer than a term let "post gres" become
+    /// "Postgres".
+    fn find_term_at(&self, tokens: &[Token], start: usize) -> Option<(&Term, usize)> {
+        let mut best: Option<(&Term, usize, bool, f32)> = None;
+
+        for term in &self.terms {
+            for len in [term.word_count, term.word_count + 1] {
+                if start + len > tokens.len() {
+                    continue;
+                }
+                let window = &tokens[start..start + len];
+                let surface: Vec<&str> = window.iter().map(|t| t.core).collect();
+                let normalized = normalize(&surface.join(""));
+                if normalized.is_empty() {
+                    continue;
+                }
+
+                let (exact, similarity) = if normalized == term.normalized {
+                    (true, 1.0)
+                } else if self.settings.fuzzy_matching {
+                    match fuzzy_similarity(&normalized, term, len) {
+                        Some(similarity) => (false, similarity),
+                        None => continue,
+                    }
+                } else {
+                    continue;
+                };
+
+                let better = match best {
+                    None => true,
+                    Some((_, best_len, best_exact, best_similarity)) => match (exact, best_exact) {
+                        (true, false) => true,
+                        (false, true) => false,
+                        (true, true) => len > best_len,
+                        (false, false) => similarity > best_similarity,
+                    },
+                };
+                if better {
+                    best = Some((term, len, exact, similarity));
+                }
+            }
+        }
+
+        best.map(|(term, len, _, _)| (term, len))
+    }
+}
+
+/// Whitespace-separated token split into punctuation and word core, with its byte
+/// range in the text
+struct Token<'a> {
+    start: usize,
+    end: usize,
+    leading: &'a str,
+    core: &'a str,
+    trailing: &'a str,
+}
+
+impl<'a> Token<'a> {
+    fn parse(text: &'a str, start: usize, end: usize) -> Self {
+        let raw = &text[start..end];
+        let core_start = raw.find(|c: char| c.is_alphanumeric()).unwrap_or(raw.len());
+        let core_end = raw
+            .rfind(|c: char| c.is_alphanumeric())
+            .map(|i| i + raw[i..].chars().next().map_or(1, |c| c.len_utf8()))
+            .unwrap_or(core_start)
+            .max(core_start);
+        Self {
+            start,
+            end,
+            leading: &raw[..core_start],
+            core: &raw[core_start..core_end],
+            trailing: &raw[core_end..],
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), token_start) {
+            (true, Some(start)) => {
+                tokens.push(Token::parse(text, start, i));
+                token_start = None;
+            }
+            (false, None) => token_start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(start) = token_start {
+        tokens.push(Token::parse(text, start, text.len()));
+    }
+    tokens
+}
+
+/// Similarity of a `window_len`-word window to a term, if close enough to count as a match
+fn fuzzy_similarity(normalized: &str, term: &Term, window_len: usize) -> Option<f32> {
+    let term_len = term.normalized.chars().count();
+    if term_len < MIN_FUZZY_TERM_LEN || (term_len < SHORT_TERM_LEN && window_len == 1) {
+        return None;
+    }
+
+    let len = normalized.chars().count().max(term_len);
+    let similarity = 1.0 - levenshtein(normalized, &term.normalized) as f32 / len as f32;
+    if similarity >= FUZZY_SIMILARITY {
+        return Some(similarity);
+    }
+
+    let same_sound = match (&term.phonetic, soundex(normalized)) {
+        (Some(a), Some(b)) => *a == b,
+        _ => false,
+    };
+    (same_sound && similarity >= PHONETIC_SIMILARITY).then_some(similarity)
+}
+
+/// Map corrected words back onto the original timings. Words left unchanged keep their
+/// timing; a run of changed words takes the span of the originals it replaces (one to
+/// one when the counts agree, merged into a single word otherwise).
+fn align_words(words: &[WordTiming], corrected: &[&str]) -> Vec<WordTiming> {
+    let (n, m) = (words.len(), corrected.len());
+    // lcs[i][j]: longest common subsequence of words[i..] and corrected[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if words[i].word == corrected[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut aligned = Vec::with_capacity(m);
+    let (mut i, mut j) = (0, 0);
+    let (mut gap_i, mut gap_j) = (0, 0);
+    while i < n && j < m {
+        if words[i].word == corrected[j] {
+            push_changed(
+                &mut aligned,
+                &words[gap_i..i],
+                &corrected[gap_j..j],
+                words[i].start,
+            );
+            aligned.push(words[i].clone());
+            i += 1;
+            j += 1;
+            (gap_i, gap_j) = (i, j);
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    let end = words.last().map_or(0.0, |w| w.end);
+    push_changed(&mut aligned, &words[gap_i..], &corrected[gap_j..], end);
+    aligned
+}
+
+/// Timings for `replacement`, the corrected form of `original`. Words inserted without
+/// any original get a zero-length timing at `at`.
+fn push_changed(
+    aligned: &mut Vec<WordTiming>,
+    original: &[WordTiming],
+    replacement: &[&str],
+    at: f64,
+) {
+    if replacement.is_empty() {
+        return;
+    }
+    match original {
+        [] => aligned.extend(replacement.iter().map(|word| WordTiming {
+            word: word.to_string(),
+            start: at,
+            end: at,
+            probability: None,
+        })),
+        _ if original.len() == replacement.len() => aligned.extend(
+            original
+                .iter()
+                .zip(replacement)
+                .map(|(word, text)| WordTiming {
+                    word: text.to_string(),
+                    ..word.clone()
+                }),
+        ),
+        _ => aligned.push(WordTiming {
+            word: replacement.join(" "),
+            start: original[0].start,
+            end: original[original.len() - 1].end,
+            probability: original
+                .iter()
+                .filter_map(|w| w.probability)
+                .reduce(f32::min),
+        }),
+    }
+}
+
+/// Lowercase alphanumerics only ("Post-gres" → "postgres")
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Character-level edit distance
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b_chars.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current[j + 1] = (previous[j + 1] + 1)
+                .min(current[j] + 1)
+                .min(previous[j] + cost);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b_chars.len()]
+}
+
+/// American Soundex code (ASCII letters only; None for other scripts)
+fn soundex(normalized: &str) -> Option<String> {
+    fn code(c: char) -> Option<char> {
+        match c {
+            'b' | 'f' | 'p' | 'v' => Some('1'),
+            'c' | 'g' | 'j' | 'k' | 'q' | 's' | 'x' | 'z' => Some('2'),
+            'd' | 't' => Some('3'),
+            'l' => Some('4'),
+            'm' | 'n' => Some('5'),
+            'r' => Some('6'),
+            _ => None,
+        }
+    }
+
+    let letters: Vec<char> = normalized
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .collect();
+    let first = *letters.first()?;
+    let mut result = first.to_ascii_uppercase().to_string();
+    let mut last_code = code(first);
+
+    for &c in &letters[1..] {
+        let current = code(c);
+        if let Some(digit) = current.filter(|_| current != last_code) {
+            result.push(digit);
+            if result.len() == 4 {
+                break;
+            }
+        }
+        // 'h' and 'w' don't separate equal codes; vowels do
+        if c != 'h' && c != 'w' {
+            last_code = current;
+        }
+    }
+
+    while result.len() < 4 {
+        result.push('0');
+    }
+    Some(result)
+}
+
+static GLOSSARY: Lazy<RwLock<CompiledGlossary>> =
+    Lazy::new(|| RwLock::new(CompiledGlossary::new(GlossarySettings::load())));
+
+/// Whisper initial prompt for the current glossary, if any terms are configured
+pub fn whisper_initial_prompt() -> Option<String> {
+    GLOSSARY.read().ok()?.initial_prompt()
+}
+
+/// Apply the glossary to a transcript text
+pub fn correct_text(text: &str) -> String {
+    match GLOSSARY.read() {
+        Ok(glossary) => glossary.correct(text),
+        Err(_) => text.to_string(),
+    }
+}
+
+/// Apply the glossary to a provider result (text and word-level timings)
+pub fn correct_result(result: TranscriptResult) -> TranscriptResult {
+    let Ok(glossary) = GLOSSARY.read() else {
+        return result;
+    };
+
+    let text = glossary.correct(&result.text);
+    let words = glossary.correct_words(result.words);
+
+    TranscriptResult {
+        text,
+        words,
+        ..result
+    }
+}
+
+fn update_glossary<F: FnOnce(&mut GlossarySettings)>(f: F) -> Result<GlossarySettings, String> {
+    let mut glossary = GLOSSARY
+        .write()
+        .map_err(|e| format!("Failed to update glossary: {}", e))?;
+    let mut settings = glossary.settings.clone();
+    f(&mut settings);
+    settings.save()?;
+    *glossary = CompiledGlossary::new(settings.clone());
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_glossary() -> Result<GlossarySettings, String> {
+    GLOSSARY
+        .read()
+        .map(|g| g.settings.clone())
+        .map_err(|e| format!("Failed to read glossary: {}", e))
+}
+
+#[tauri::command]
+pub async fn set_glossary(settings: GlossarySettings) -> Result<(), String> {
+    update_glossary(|current| *current = settings)?;
+    info!("📖 Glossary updated");
+    Ok(())
+}
+
+/// Add a term to the global list, or to a template's list when `template_id` is given
+#[tauri::command]
+pub async fn add_glossary_term(
+    term: String,
+    template_id: Option<String>,
+) -> Result<GlossarySettings, String> {
+    let term = term.trim().to_string();
+    if term.is_empty() {
+        return Err("Term cannot be empty".to_string());
+    }
+
+    update_glossary(|settings| {
+        let list = match template_id {
+            Some(id) => settings.template_terms.entry(id).or_default(),
+            None => &mut settings.global_terms,
+        };
+        if !list.iter().any(|t| t.eq_ignore_ascii_case(&term)) {
+            list.push(term);
+        }
+    })
+}
+
+#[tauri::command]
+pub async fn remove_glossary_term(
+    term: String,
+    template_id: Option<String>,
+) -> Result<GlossarySettings, String> {
+    update_glossary(|settings| {
+        let list = match &template_id {
+            Some(id) => settings.template_terms.get_mut(id),
+            None => Some(&mut settings.global_terms),
+        };
+        if let Some(list) = list {
+            list.retain(|t| !t.eq_ignore_ascii_case(term.trim()));
+        }
+        settings.template_terms.retain(|_, terms| !terms.is_empty());
+    })
+}
+
+#[tauri::command]
+pub async fn add_glossary_replacement(
+    from: String,
+    to: String,
+) -> Result<GlossarySettings, String> {
+    let (from, to) = (from.trim().to_string(), to.trim().to_string());
+    if from.is_empty() {
+        return Err("Replacement source cannot be empty".to_string());
+    }
+
+    update_glossary(|settings| {
+        settings
+            .replacements
+            .retain(|r| !r.from.eq_ignore_ascii_case(&from));
+        settings.replacements.push(GlossaryReplacement { from, to });
+    })
+}
+
+#[tauri::command]
+pub async fn remove_glossary_replacement(from: String) -> Result<GlossarySettings, String> {
+    update_glossary(|settings| {
+        settings
+            .replacements
+            .retain(|r| !r.from.eq_ignore_ascii_case(from.trim()));
+    })
+}
+
+/// Run the correction pass on sample text (for trying out glossary entries)
+#[tauri::command]
+pub async fn preview_glossary(text: String) -> Result<String, String> {
+    Ok(correct_text(&text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glossary(terms: &[&str], replacements: &[(&str, &str)]) -> CompiledGlossary {
+        CompiledGlossary::new(GlossarySettings {
+            global_terms: terms.iter().map(|t| t.to_string()).collect(),
+            replacements: replacements
+                .iter()
+                .map(|(from, to)| GlossaryReplacement {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn replacement_dictionary_is_case_insensitive_and_word_bounded() {
+        let g = glossary(&[], &[("cooper netties", "Kubernetes")]);
+        assert_eq!(
+            g.correct("We deploy on Cooper Netties, not cooper nettiesque."),
+            "We deploy on Kubernetes, not cooper nettiesque."
+        );
+    }
+
+    #[test]
+    fn fuzzy_and_phonetic_matches_snap_to_canonical_term() {
+        let g = glossary(&["Meetily", "Postgres"], &[]);
+        assert_eq!(
+            g.correct("I opened meetly today."),
+            "I opened Meetily today."
+        );
+        assert_eq!(g.correct("The post gres database"), "The Postgres database");
+        // Casing is fixed even for exact matches
+        assert_eq!(g.correct("postgres"), "Postgres");
+    }
+
+    #[test]
+    fn unrelated_words_are_left_alone() {
+        let g = glossary(&["Meetily"], &[]);
+        assert_eq!(
+            g.correct("The meeting is at noon."),
+            "The meeting is at noon."
+        );
+    }
+
+    #[test]
+    fn short_terms_do_not_rewrite_similar_words() {
+        let g = glossary(&["Notion"], &[]);
+        assert_eq!(
+            g.correct("I second the motion, the nation agrees."),
+            "I second the motion, the nation agrees."
+        );
+        // Split over two words it is still recognised
+        assert_eq!(g.correct("notes live in no tion"), "notes live in Notion");
+    }
+
+    #[test]
+    fn corrections_keep_original_whitespace() {
+        let g = glossary(&["Meetily"], &[("cooper netties", "Kubernetes")]);
+        assert_eq!(
+            g.correct("Open  meetly\nthen\tcooper netties."),
+            "Open  Meetily\nthen\tKubernetes."
+        );
+    }
+
+    #[test]
+    fn longest_exact_match_wins() {
+        let g = glossary(&["Acme", "Acme Cloud"], &[]);
+        assert_eq!(g.correct("acme cloud is down"), "Acme Cloud is down");
+
+        // An exact match beats a fuzzy one that swallows the next word
+        let g = glossary(&["Postgres"], &[]);
+        assert_eq!(g.correct("postgres is fast"), "Postgres is fast");
+    }
+
+    #[test]
+    fn multi_word_corrections_merge_word_timings() {
+        let word = |text: &str, start: f64, end: f64| WordTiming {
+            word: text.to_string(),
+            start,
+            end,
+            probability: Some(0.9),
+        };
+        let g = glossary(&["Postgres", "Meetily"], &[]);
+        let words = g.correct_words(vec![
+            word("we", 0.0, 0.2),
+            word("use", 0.2, 0.4),
+            word("post", 0.4, 0.6),
+            word("gres", 0.6, 0.9),
+            word("and", 0.9, 1.0),
+            word("meetly.", 1.0, 1.5),
+        ]);
+
+        let text: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(text.join(" "), g.correct("we use post gres and meetly."));
+        assert_eq!(
+            words[2],
+            WordTiming {
+                word: "Postgres".to_string(),
+                start: 0.4,
+                end: 0.9,
+                probability: Some(0.9),
+            }
+        );
+        assert_eq!(words[4].word, "Meetily.");
+        assert_eq!((words[4].start, words[4].end), (1.0, 1.5));
+    }
+
+    #[test]
+    fn template_terms_are_active_only_for_their_template() {
+        let mut settings = GlossarySettings {
+            global_terms: vec!["Meetily".to_string()],
+            ..Default::default()
+        };
+        settings
+            .template_terms
+            .insert("sales_call".to_string(), vec!["Contoso".to_string()]);
+        assert_eq!(settings.active_terms(), vec!["Meetily".to_string()]);
+
+        settings.active_template = Some("sales_call".to_string());
+        assert_eq!(
+            settings.active_terms(),
+            vec!["Meetily".to_string(), "Contoso".to_string()]
+        );
+    }
+
+    #[test]
+    fn initial_prompt_lists_terms_within_budget() {
+        let g = glossary(&["Meetily", "Parakeet"], &[("whisper cpp", "whisper.cpp")]);
+        assert_eq!(
+            g.initial_prompt().as_deref(),
+            Some("Glossary: Meetily, Parakeet, whisper.cpp.")
+        );
+
+        let many: Vec<String> = (0..200).map(|i| format!("Term{}", i)).collect();
+        let refs: Vec<&str> = many.iter().map(|s| s.as_str()).collect();
+        assert!(glossary(&refs, &[]).initial_prompt().unwrap().len() <= MAX_INITIAL_PROMPT_CHARS);
+        assert_eq!(glossary(&[], &[]).initial_prompt(), None);
+    }
+
+    #[test]
+    fn soundex_and_levenshtein_basics() {
+        assert_eq!(soundex("robert").as_deref(), Some("R163"));
+        assert_eq!(soundex("rupert").as_deref(), Some("R163"));
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,851 @@
+// audio/transcription/hallucination.rs
+//
+// Post-decode hallucination filter. On silence and music Whisper produces phantom text:
+// sign-off phrases from its subtitle-heavy training data ("Thank you for watching"), a
+// phrase looping until the chunk ends, or a repeat of the previous segment. Workers check
+// each final segment against the per-segment rules (speech energy, known phrases, loops);
+// the ordered emitter compares it with the previous segment. Dropped text is logged and
+// kept in a short history, and phrases/patterns can be extended in the settings.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+
+use crate::settings_store;
+
+/// Longest phrase (in words) considered when collapsing repetition loops
+const MAX_LOOP_PHRASE_WORDS: usize = 8;
+
+/// Frame length used to find the loudest part of a chunk
+const ENERGY_FRAME_SECS: f32 = 0.03;
+
+/// Near-duplicate checks need a few words; short replies ("Yes.", "Okay.") repeat for real
+const MIN_DUPLICATE_WORDS: usize = 3;
+
+/// Words a segment must share with the end of the previous one before the overlap is trimmed
+const MIN_OVERLAP_WORDS: usize = 3;
+
+const MAX_RECENT_DROPS: usize = 50;
+
+/// Phrases Whisper emits on silence or music, per language. Matched against whole
+/// sentences after normalization (lowercase, no punctuation).
+const BUILTIN_PHRASES: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "thank you for watching",
+            "thanks for watching",
+            "thank you so much for watching",
+            "thank you very much for watching",
+            "thank you for listening",
+            "please subscribe",
+            "please subscribe to my channel",
+            "like and subscribe",
+            "dont forget to like and subscribe",
+            "see you in the next video",
+            "subtitles by the amaraorg community",
+            "transcribed by otterai",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "vielen dank fürs zuschauen",
+            "danke fürs zuschauen",
+            "untertitel im auftrag des zdf",
+            "untertitel der amaraorg community",
+            "untertitel im auftrag des zdf 2017",
+            "bis zum nächsten mal",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "merci davoir regardé",
+            "merci davoir regardé cette vidéo",
+            "sous titres réalisés par la communauté damaraorg",
+            "sous titrage st 501",
+            "abonnez vous",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "gracias por ver",
+            "gracias por ver el video",
+            "subtítulos realizados por la comunidad de amaraorg",
+            "suscríbete",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "obrigado por assistir",
+            "legendas pela comunidade amaraorg",
+            "inscreva se no canal",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "grazie per la visione",
+            "sottotitoli creati dalla comunità amaraorg",
+            "iscriviti al canale",
+        ],
+    ),
+    (
+        "ru",
+        &[
+            "продолжение следует",
+            "спасибо за просмотр",
+            "субтитры сделал dimatorzok",
+            "субтитры создавал dimatorzok",
+        ],
+    ),
+    (
+        "ja",
+        &["ご視聴ありがとうございました", "チャンネル登録お願いします"],
+    ),
+    (
+        "zh",
+        &[
+            "谢谢观看",
+            "请不吝点赞 订阅 转发 打赏支持明镜与点点栏目",
+            "字幕由amaraorg社区提供",
+        ],
+    ),
+    (
+        "ko",
+        &["시청해주셔서 감사합니다", "구독과 좋아요 부탁드립니다"],
+    ),
+];
+
+/// Phrases Whisper emits on silence that are also ordinary speech. They only count as
+/// hallucinations when they are the whole segment and the engine was unsure about it.
+const WHOLE_SEGMENT_PHRASES: &[(&str, &[&str])] = &[("en", &["you", "music"])];
+
+/// Confidence below which a whole-segment phrase is dropped
+const WHOLE_SEGMENT_MAX_CONFIDENCE: f32 = 0.6;
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_min_loop_repeats() -> usize {
+    4
+}
+
+fn default_silence_rms() -> f32 {
+    0.002
+}
+
+fn default_duplicate_similarity() -> f32 {
+    0.9
+}
+
+/// Persisted filter configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HallucinationFilterSettings {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Collapse phrases repeated back to back; drop segments that are mostly a loop
+    #[serde(default = "default_true")]
+    pub repetition_loops: bool,
+    /// Back-to-back repeats of a phrase that count as a loop
+    #[serde(default = "default_min_loop_repeats")]
+    pub min_loop_repeats: usize,
+    #[serde(default = "default_true")]
+    pub known_phrases: bool,
+    /// Extra phrases per language code ("en", "de", ...; "*" applies to every language)
+    #[serde(default)]
+    pub extra_phrases: HashMap<String, Vec<String>>,
+    /// Extra regular expressions; sentences they match entirely are dropped
+    #[serde(default)]
+    pub extra_patterns: Vec<String>,
+    /// Drop text decoded from chunks without any speech energy
+    #[serde(default = "default_true")]
+    pub silent_segments: bool,
+    /// RMS of the loudest 30ms frame below which a chunk counts as silent
+    #[serde(default = "default_silence_rms")]
+    pub silence_rms: f32,
+    #[serde(default = "default_true")]
+    pub near_duplicates: bool,
+    /// Share of a segment's words found in the previous segment that makes it a duplicate
+    #[serde(default = "default_duplicate_similarity")]
+    pub duplicate_similarity: f32,
+}
+
+impl Default for HallucinationFilterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            repetition_loops: true,
+            min_loop_repeats: default_min_loop_repeats(),
+            known_phrases: true,
+            extra_phrases: HashMap::new(),
+            extra_patterns: Vec::new(),
+            silent_segments: true,
+            silence_rms: default_silence_rms(),
+            near_duplicates: true,
+            duplicate_similarity: default_duplicate_similarity(),
+        }
+    }
+}
+
+impl HallucinationFilterSettings {
+    /// Load the settings from disk
+    pub fn load() -> Self {
+        settings_store::load("hallucination_filter.json", "hallucination filter settings")
+    }
+
+    /// Save the settings to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(
+            self,
+            "hallucination_filter.json",
+            "hallucination filter settings",
+        )
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.min_loop_repeats < 2 {
+            return Err("A loop needs at least 2 repeats".to_string());
+        }
+        if !(0.5..=1.0).contains(&self.duplicate_similarity) {
+            return Err("Duplicate similarity must be between 0.5 and 1".to_string());
+        }
+        if !(0.0..=0.1).contains(&self.silence_rms) {
+            return Err("Silence level must be between 0 and 0.1".to_string());
+        }
+        for pattern in &self.extra_patterns {
+            let regex =
+                Regex::new(pattern).map_err(|e| format!("Invalid pattern '{}': {}", pattern, e))?;
+            if regex.is_match("") {
+                return Err(format!("Pattern '{}' matches empty text", pattern));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What the filter decided for a segment
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Keep,
+    /// Keep a cleaned-up text (a loop collapsed, a phantom sentence or overlap removed)
+    Rewrite {
+        text: String,
+        reason: String,
+    },
+    Drop(String),
+}
+
+/// A dropped segment, for the settings page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DroppedSegment {
+    pub text: String,
+    pub reason: String,
+    pub audio_start_time: f64,
+    pub dropped_at: String,
+}
+
+/// Settings with phrases normalized and patterns compiled
+pub struct HallucinationFilter {
+    settings: HallucinationFilterSettings,
+    phrases: HashMap<String, Vec<String>>,
+    whole_segment_phrases: HashMap<String, Vec<String>>,
+    patterns: Vec<Regex>,
+}
+
+impl HallucinationFilter {
+    pub fn new(settings: HallucinationFilterSettings) -> Self {
+        let mut phrases: HashMap<String, Vec<String>> = HashMap::new();
+        let builtin = BUILTIN_PHRASES
+            .iter()
+            .flat_map(|(lang, list)| list.iter().map(move |p| (lang.to_string(), p.to_string())));
+        let extra = settings
+            .extra_phrases
+            .iter()
+            .flat_map(|(lang, list)| list.iter().map(move |p| (lang.to_lowercase(), p.clone())));
+        for (lang, phrase) in builtin.chain(extra) {
+            let normalized = normalize(&phrase);
+            if !normalized.is_empty() {
+                phrases.entry(lang).or_default().push(normalized);
+            }
+        }
+
+        let whole_segment_phrases = WHOLE_SEGMENT_PHRASES
+            .iter()
+            .map(|(lang, list)| {
+                (
+                    lang.to_string(),
+                    list.iter().map(|p| normalize(p)).collect(),
+                )
+            })
+            .collect();
+
+        let patterns = settings
+            .extra_patterns
+            .iter()
+            .filter_map(|p| match Regex::new(p) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    warn!("Skipping invalid hallucination pattern '{}': {}", p, e);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            settings,
+            phrases,
+            whole_segment_phrases,
+            patterns,
+        }
+    }
+
+    /// Per-segment rules: speech energy, known phrases and repetition loops.
+    /// `speech_energy` is the RMS of the chunk's loudest frame (see `peak_frame_rms`).
+    pub fn check_segment(
+        &self,
+        text: &str,
+        speech_energy: f32,
+        confidence: Option<f32>,
+        language: Option<&str>,
+    ) -> Verdict {
+        let s = &self.settings;
+        if !s.enabled || text.trim().is_empty() {
+            return Verdict::Keep;
+        }
+
+        if s.silent_segments && speech_energy < s.silence_rms {
+            return Verdict::Drop(format!(
+                "no speech energy (loudest frame RMS {:.4})",
+                speech_energy
+            ));
+        }
+
+        let mut current = text.trim().to_string();
+        let mut reasons = Vec::new();
+
+        if s.known_phrases {
+            let unsure = confidence.is_some_and(|c| c < WHOLE_SEGMENT_MAX_CONFIDENCE);
+            if unsure && self.is_whole_segment_phrase(&current, language) {
+                return Verdict::Drop("low-confidence filler phrase".to_string());
+            }
+
+            let sentences = split_sentences(&current);
+            let kept: Vec<&str> = sentences
+                .iter()
+                .copied()
+                .filter(|sentence| !self.is_known_phrase(sentence, language))
+                .collect();
+            if kept.is_empty() {
+                return Verdict::Drop("known hallucination phrase".to_string());
+            }
+            if kept.len() < sentences.len() {
+                current = kept.join(" ");
+                reasons.push("removed known hallucination phrase");
+            }
+        }
+
+        if s.repetition_loops {
+            let words: Vec<&str> = current.split_whitespace().collect();
+            let collapsed = collapse_loops(&words, s.min_loop_repeats);
+            if collapsed.len() < words.len() {
+                // Mostly loop: nothing in it is trustworthy
+                if collapsed.len() * 3 <= words.len() {
+                    return Verdict::Drop(format!(
+                        "repetition loop ({} words collapse to {})",
+                        words.len(),
+                        collapsed.len()
+                    ));
+                }
+                current = collapsed.join(" ");
+                reasons.push("collapsed repetition loop");
+            }
+        }
+
+        if reasons.is_empty() {
+            Verdict::Keep
+        } else {
+            Verdict::Rewrite {
+                text: current,
+                reason: reasons.join(", "),
+            }
+        }
+    }
+
+    fn is_known_phrase(&self, sentence: &str, language: Option<&str>) -> bool {
+        let normalized = normalize(sentence);
+        if normalized.is_empty() {
+            // Punctuation-only leftovers ("...") go with the phrase they followed
+            return true;
+        }
+
+        in_phrase_lists(&self.phrases, &normalized, language)
+            || self.patterns.iter().any(|regex| {
+                regex
+                    .find(sentence.trim())
+                    .is_some_and(|m| m.len() == sentence.trim().len())
+            })
+    }
+
+    fn is_whole_segment_phrase(&self, text: &str, language: Option<&str>) -> bool {
+        in_phrase_lists(&self.whole_segment_phrases, &normalize(text), language)
+    }
+}
+
+/// Whether a normalized sentence is in the lists for `language` (all lists when unknown)
+fn in_phrase_lists(
+    lists: &HashMap<String, Vec<String>>,
+    normalized: &str,
+    language: Option<&str>,
+) -> bool {
+    let language = language
+        .map(|l| l.split(['-', '_']).next().unwrap_or(l).to_lowercase())
+        .filter(|l| !l.starts_with("auto"));
+    lists
+        .iter()
+        .filter(|(lang, _)| match &language {
+            Some(language) => *lang == language || lang.as_str() == "*",
+            None => true,
+        })
+        .any(|(_, list)| list.iter().any(|p| p == normalized))
+}
+
+/// Compares each segment with the previous one, in emission order
+#[derive(Debug, Default)]
+pub struct DuplicateTracker {
+    previous: Option<String>,
+}
+
+impl DuplicateTracker {
+    pub fn check(&mut self, filter: &HallucinationFilter, text: &str) -> Verdict {
+        let s = &filter.settings;
+        let verdict = match &self.previous {
+            Some(previous) if s.enabled && s.near_duplicates => {
+                near_duplicate_verdict(previous, text, s.duplicate_similarity)
+            }
+            _ => Verdict::Keep,
+        };
+
+        match &verdict {
+            Verdict::Keep => self.previous = Some(text.to_string()),
+            Verdict::Rewrite { text, .. } => self.previous = Some(text.clone()),
+            // Keep comparing against the last real segment
+            Verdict::Drop(_) => {}
+        }
+        verdict
+    }
+
+    /// Check against the current settings, logging anything dropped or trimmed
+    pub fn filter(&mut self, text: &str, audio_start_time: f64) -> Verdict {
+        let verdict = match FILTER.read() {
+            Ok(filter) => self.check(&filter, text),
+            Err(_) => Verdict::Keep,
+        };
+        log_verdict(&verdict, text, audio_start_time);
+        verdict
+    }
+}
+
+fn near_duplicate_verdict(previous: &str, text: &str, similarity: f32) -> Verdict {
+    let current_words: Vec<&str> = text.split_whitespace().collect();
+    if current_words.len() < MIN_DUPLICATE_WORDS {
+        return Verdict::Keep;
+    }
+    let Some((prev_start, cur_start, len)) = longest_common_word_run(previous, text) else {
+        return Verdict::Keep;
+    };
+
+    if len as f32 / current_words.len() as f32 >= similarity {
+        return Verdict::Drop(format!(
+            "near-duplicate of the previous segment ({} of {} words)",
+            len,
+            current_words.len()
+        ));
+    }
+
+    // The segment starts with the tail of the previous one: trim the overlap
+    let previous_len = previous.split_whitespace().count();
+    if cur_start == 0 && prev_start + len == previous_len && len >= MIN_OVERLAP_WORDS {
+        return Verdict::Rewrite {
+            text: current_words[len..].join(" "),
+            reason: format!("trimmed {} words repeated from the previous segment", len),
+        };
+    }
+    Verdict::Keep
+}
+
+/// Longest run of equal words (ignoring case and punctuation) in `a` and `b`, as
+/// (start in a, start in b, length). Same dynamic programme as
+/// `longest_common_word_substring` in audio/stt.rs, plus the run length.
+fn longest_common_word_run(a: &str, b: &str) -> Option<(usize, usize, usize)> {
+    let a_words: Vec<String> = a.split_whitespace().map(normalize).collect();
+    let b_words: Vec<String> = b.split_whitespace().map(normalize).collect();
+
+    let mut dp = vec![vec![0usize; b_words.len() + 1]; a_words.len() + 1];
+    let mut best: Option<(usize, usize, usize)> = None;
+
+    for i in 1..=a_words.len() {
+        for j in 1..=b_words.len() {
+            if !a_words[i - 1].is_empty() && a_words[i - 1] == b_words[j - 1] {
+                dp[i][j] = dp[i - 1][j - 1] + 1;
+                if best.map_or(true, |(_, _, len)| dp[i][j] > len) {
+                    best = Some((i - dp[i][j], j - dp[i][j], dp[i][j]));
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Collapse phrases of up to `MAX_LOOP_PHRASE_WORDS` words repeated at least
+/// `min_repeats` times back to back into a single occurrence
+fn collapse_loops<'a>(words: &[&'a str], min_repeats: usize) -> Vec<&'a str> {
+    let same = |a: &[&str], b: &[&str]| a.iter().zip(b).all(|(x, y)| normalize(x) == normalize(y));
+
+    let mut current = words.to_vec();
+    for n in 1..=MAX_LOOP_PHRASE_WORDS {
+        let mut collapsed = Vec::with_capacity(current.len());
+        let mut i = 0;
+        while i < current.len() {
+            let mut repeats = 1;
+            while i + (repeats + 1) * n <= current.len()
+                && same(
+                    &current[i..i + n],
+                    &current[i + repeats * n..i + (repeats + 1) * n],
+                )
+            {
+                repeats += 1;
+            }
+
+            if repeats >= min_repeats {
+                collapsed.extend_from_slice(&current[i..i + n]);
+                i += repeats * n;
+            } else {
+                collapsed.push(current[i]);
+                i += 1;
+            }
+        }
+        current = collapsed;
+    }
+    current
+}
+
+/// Split after sentence punctuation that is followed by whitespace or the end
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let is_terminator = matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？');
+        let at_boundary = chars.peek().map_or(true, |(_, next)| next.is_whitespace());
+        if is_terminator && at_boundary {
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+/// Lowercase words without punctuation ("Thank you for watching!" → "thank you for watching")
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(|c| c.to_lowercase())
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// RMS of the loudest ~30ms frame of a chunk
+pub fn peak_frame_rms(samples: &[f32], sample_rate: u32) -> f32 {
+    let frame_len = ((sample_rate as f32 * ENERGY_FRAME_SECS) as usize).max(1);
+    samples
+        .chunks(frame_len)
+        .map(|frame| (frame.iter().map(|&x| x * x).sum::<f32>() / frame.len() as f32).sqrt())
+        .fold(0.0, f32::max)
+}
+
+static FILTER: Lazy<RwLock<HallucinationFilter>> =
+    Lazy::new(|| RwLock::new(HallucinationFilter::new(HallucinationFilterSettings::load())));
+
+static RECENT_DROPS: Lazy<RwLock<VecDeque<DroppedSegment>>> =
+    Lazy::new(|| RwLock::new(VecDeque::new()));
+
+fn log_verdict(verdict: &Verdict, text: &str, audio_start_time: f64) {
+    match verdict {
+        Verdict::Keep => {}
+        Verdict::Rewrite {
+            text: cleaned,
+            reason,
+        } => {
+            info!(
+                "🧹 Cleaned segment at {:.1}s ({}): '{}' → '{}'",
+                audio_start_time, reason, text, cleaned
+            );
+        }
+        Verdict::Drop(reason) => {
+            info!(
+                "🚫 Dropped hallucinated segment at {:.1}s ({}): '{}'",
+                audio_start_time, reason, text
+            );
+            if let Ok(mut drops) = RECENT_DROPS.write() {
+                if drops.len() == MAX_RECENT_DROPS {
+                    drops.pop_front();
+                }
+                drops.push_back(DroppedSegment {
+                    text: text.to_string(),
+                    reason: reason.clone(),
+                    audio_start_time,
+                    dropped_at: chrono::Local::now().to_rfc3339(),
+                });
+            }
+        }
+    }
+}
+
+fn check_with_current_settings(text: &str, speech_energy: f32, confidence: Option<f32>) -> Verdict {
+    let language = crate::get_language_preference_internal();
+    match FILTER.read() {
+        Ok(filter) => filter.check_segment(text, speech_energy, confidence, language.as_deref()),
+        Err(_) => Verdict::Keep,
+    }
+}
+
+/// Run the per-segment rules with the current settings and recording language, logging
+/// anything dropped or cleaned up
+pub fn filter_segment(
+    text: &str,
+    speech_energy: f32,
+    confidence: Option<f32>,
+    audio_start_time: f64,
+) -> Verdict {
+    let verdict = check_with_current_settings(text, speech_energy, confidence);
+    log_verdict(&verdict, text, audio_start_time);
+    verdict
+}
+
+/// `filter_segment` for live partials. Nothing is logged or added to the history: the
+/// final result of the utterance is filtered (and reported) again.
+pub fn filter_partial(text: &str, speech_energy: f32, confidence: Option<f32>) -> Verdict {
+    check_with_current_settings(text, speech_energy, confidence)
+}
+
+#[tauri::command]
+pub async fn get_hallucination_filter_settings() -> Result<HallucinationFilterSettings, String> {
+    FILTER
+        .read()
+        .map(|f| f.settings.clone())
+        .map_err(|e| format!("Failed to read hallucination filter settings: {}", e))
+}
+
+#[tauri::command]
+pub async fn set_hallucination_filter_settings(
+    settings: HallucinationFilterSettings,
+) -> Result<(), String> {
+    settings.validate()?;
+    settings.save()?;
+
+    let mut filter = FILTER
+        .write()
+        .map_err(|e| format!("Failed to update hallucination filter: {}", e))?;
+    *filter = HallucinationFilter::new(settings);
+    info!("🧹 Hallucination filter updated");
+    Ok(())
+}
+
+/// Segments dropped recently (newest last)
+#[tauri::command]
+pub async fn get_dropped_segments() -> Result<Vec<DroppedSegment>, String> {
+    RECENT_DROPS
+        .read()
+        .map(|drops| drops.iter().cloned().collect())
+        .map_err(|e| format!("Failed to read dropped segments: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOUD: f32 = 0.1;
+
+    fn filter() -> HallucinationFilter {
+        HallucinationFilter::new(HallucinationFilterSettings::default())
+    }
+
+    #[test]
+    fn known_phrases_are_dropped_or_stripped() {
+        let f = filter();
+        assert_eq!(
+            f.check_segment("Thank you for watching!", LOUD, None, Some("en")),
+            Verdict::Drop("known hallucination phrase".to_string())
+        );
+        assert_eq!(
+            f.check_segment(
+                "We ship on Friday. Thanks for watching.",
+                LOUD,
+                None,
+                Some("en")
+            ),
+            Verdict::Rewrite {
+                text: "We ship on Friday.".to_string(),
+                reason: "removed known hallucination phrase".to_string(),
+            }
+        );
+        // Other languages' phrases only apply when the language is unknown
+        assert_eq!(
+            f.check_segment("Продолжение следует...", LOUD, None, Some("en")),
+            Verdict::Keep
+        );
+        assert!(matches!(
+            f.check_segment("Продолжение следует...", LOUD, None, Some("auto")),
+            Verdict::Drop(_)
+        ));
+        assert_eq!(
+            f.check_segment("Thank you, that helps.", LOUD, None, Some("en")),
+            Verdict::Keep
+        );
+    }
+
+    #[test]
+    fn filler_words_need_low_confidence() {
+        let f = filter();
+        assert_eq!(
+            f.check_segment("You.", LOUD, Some(0.9), Some("en")),
+            Verdict::Keep
+        );
+        assert_eq!(
+            f.check_segment("[Music]", LOUD, None, Some("en")),
+            Verdict::Keep
+        );
+        assert!(matches!(
+            f.check_segment("you", LOUD, Some(0.2), Some("en")),
+            Verdict::Drop(_)
+        ));
+        assert!(matches!(
+            f.check_segment("[MUSIC]", LOUD, Some(0.4), None),
+            Verdict::Drop(_)
+        ));
+        // Never stripped out of a longer segment
+        assert_eq!(
+            f.check_segment("Thank you. You.", LOUD, Some(0.2), Some("en")),
+            Verdict::Keep
+        );
+    }
+
+    #[test]
+    fn repetition_loops_are_collapsed_or_dropped() {
+        let f = filter();
+        assert!(matches!(
+            f.check_segment(
+                "I think so. I think so. I think so. I think so. I think so.",
+                LOUD,
+                None,
+                Some("en")
+            ),
+            Verdict::Drop(_)
+        ));
+        assert_eq!(
+            f.check_segment(
+                "The budget is approved and the launch moves to May, no no no no doubt about it",
+                LOUD,
+                None,
+                Some("en")
+            ),
+            Verdict::Rewrite {
+                text: "The budget is approved and the launch moves to May, no doubt about it"
+                    .to_string(),
+                reason: "collapsed repetition loop".to_string(),
+            }
+        );
+        assert_eq!(
+            f.check_segment("very very very good", LOUD, None, Some("en")),
+            Verdict::Keep
+        );
+    }
+
+    #[test]
+    fn silent_chunks_are_dropped() {
+        let f = filter();
+        let silence = vec![0.0005f32; 16000];
+        let energy = peak_frame_rms(&silence, 16000);
+        assert!(matches!(
+            f.check_segment("Okay, let's start.", energy, None, Some("en")),
+            Verdict::Drop(_)
+        ));
+
+        let mut speech = silence.clone();
+        speech[8000..8480].iter_mut().for_each(|s| *s = 0.2);
+        let energy = peak_frame_rms(&speech, 16000);
+        assert_eq!(
+            f.check_segment("Okay, let's start.", energy, None, Some("en")),
+            Verdict::Keep
+        );
+    }
+
+    #[test]
+    fn near_duplicates_are_dropped_and_overlaps_trimmed() {
+        let f = filter();
+        let mut tracker = DuplicateTracker::default();
+
+        assert_eq!(
+            tracker.check(&f, "Let's review the quarterly numbers now."),
+            Verdict::Keep
+        );
+        assert!(matches!(
+            tracker.check(&f, "let's review the quarterly numbers now"),
+            Verdict::Drop(_)
+        ));
+        assert_eq!(
+            tracker.check(&f, "the quarterly numbers now. Revenue is up ten percent."),
+            Verdict::Rewrite {
+                text: "Revenue is up ten percent.".to_string(),
+                reason: "trimmed 4 words repeated from the previous segment".to_string(),
+            }
+        );
+        // Short replies may repeat
+        assert_eq!(tracker.check(&f, "Yes."), Verdict::Keep);
+        assert_eq!(tracker.check(&f, "Yes."), Verdict::Keep);
+    }
+
+    #[test]
+    fn rules_can_be_extended() {
+        let mut settings = HallucinationFilterSettings::default();
+        settings
+            .extra_phrases
+            .insert("*".to_string(), vec!["Captions by ACME".to_string()]);
+        settings.extra_patterns.push(r"(?i)^www\.\S+$".to_string());
+        assert!(settings.validate().is_ok());
+        let f = HallucinationFilter::new(settings.clone());
+
+        assert!(matches!(
+            f.check_segment("Captions by ACME.", LOUD, None, Some("de")),
+            Verdict::Drop(_)
+        ));
+        assert!(matches!(
+            f.check_segment("www.example.com", LOUD, None, Some("en")),
+            Verdict::Drop(_)
+        ));
+
+        settings.extra_patterns.push(".*".to_string());
+        assert!(settings.validate().is_err());
+    }
+}
@@ -5,13 +5,30 @@
 pub mod provider;
 pub mod whisper_provider;
 pub mod parakeet_provider;
+pub mod onnx_asr_provider;
 pub mod engine;
 pub mod worker;
+pub mod reorder;
+pub mod word_timing;
+pub mod translation;
+pub mod glossary;
+pub mod retranscription;
+pub mod remote_provider;
+pub mod partial;
+pub mod confidence;
+pub mod hallucination;
+pub mod benchmark;
+pub mod backlog;
+pub mod durable_queue;
 
 // Re-export commonly used types
 pub use provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+pub use word_timing::WordTiming;
+pub use translation::{TranslationSettings, TranslationUpdate};
 pub use whisper_provider::WhisperProvider;
 pub use parakeet_provider::ParakeetProvider;
+pub use onnx_asr_provider::OnnxAsrProvider;
+pub use remote_provider::{RemoteProvider, RemoteTranscriptionConfig};
 pub use engine::{
     TranscriptionEngine,
     validate_transcription_model_ready,
@@ -21,5 +38,7 @@ pub use engine::{
 pub use worker::{
     start_transcription_task,
     reset_speech_detected_flag,
-    TranscriptUpdate
+    current_metrics,
+    TranscriptUpdate,
+    TranscriptionMetrics
 };
@@ -0,0 +1,72 @@
+// audio/transcription/onnx_asr_provider.rs
+//
+// Transcription provider for manifest-driven ONNX ASR models (Moonshine, SenseVoice, Canary, ...).
+
+use super::provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
+use super::word_timing::{tokens_from_start_times, words_from_tokens};
+use async_trait::async_trait;
+use log::warn;
+use std::sync::Arc;
+
+/// ONNX ASR transcription provider (wraps OnnxAsrEngine)
+pub struct OnnxAsrProvider {
+    engine: Arc<crate::onnx_asr::OnnxAsrEngine>,
+}
+
+impl OnnxAsrProvider {
+    pub fn new(engine: Arc<crate::onnx_asr::OnnxAsrEngine>) -> Self {
+        Self { engine }
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for OnnxAsrProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        // Like Parakeet, fall back to the model's default language rather than failing every chunk
+        let language = match language {
+            Some(lang) if !self.engine.supports_language(&lang).await => {
+                warn!(
+                    "ONNX ASR model doesn't support language '{}' - transcribing in its default language",
+                    lang
+                );
+                None
+            }
+            other => other,
+        };
+
+        let audio_duration = audio.len() as f64 / 16000.0;
+        match self
+            .engine
+            .transcribe_audio_with_timestamps(audio, language.as_deref())
+            .await
+        {
+            Ok(result) => Ok(TranscriptResult {
+                text: result.text.trim().to_string(),
+                confidence: None,
+                is_partial: false,
+                words: words_from_tokens(tokens_from_start_times(
+                    &result.tokens,
+                    &result.timestamps,
+                    audio_duration,
+                )),
+            }),
+            Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
+        }
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        self.engine.is_model_loaded().await
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        self.engine.get_current_model().await
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "ONNX ASR"
+    }
+}
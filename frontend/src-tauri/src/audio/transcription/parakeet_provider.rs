@@ -3,6 +3,7 @@
 // Parakeet transcription provider implementation.
 
 use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+use super::word_timing::{tokens_from_start_times, words_from_tokens};
 use async_trait::async_trait;
 use log::warn;
 use std::sync::Arc;
@@ -33,11 +34,17 @@ impl TranscriptionProvider for ParakeetProvider {
             );
         }
 
-        match self.engine.transcribe_audio(audio).await {
-            Ok(text) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
+        let audio_duration = audio.len() as f64 / 16000.0;
+        match self.engine.transcribe_audio_with_timestamps(audio).await {
+            Ok(result) => Ok(TranscriptResult {
+                text: result.text.trim().to_string(),
                 confidence: None, // Parakeet doesn't provide confidence scores
                 is_partial: false, // Parakeet doesn't provide partial results
+                words: words_from_tokens(tokens_from_start_times(
+                    &result.tokens,
+                    &result.timestamps,
+                    audio_duration,
+                )),
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
@@ -0,0 +1,247 @@
+// audio/transcription/partial.rs
+//
+// Partial hypotheses for long utterances. While VAD keeps a speech segment open the
+// pipeline publishes the in-progress audio here; a dedicated task decodes it whenever the
+// worker pool is idle and emits `transcript-partial` events. Partials share the utterance
+// ID of the final segment (its chunk ID), so the UI replaces them instead of appending,
+// and no partial is emitted once its utterance has been finalized.
+
+use super::engine::TranscriptionEngine;
+use super::glossary;
+use super::hallucination::{self, Verdict};
+use super::translation::TranslationSettings;
+use super::word_timing::offset_words;
+use super::worker::{self, TranscriptUpdate};
+use crate::audio::recording_state::{AudioChunk, DeviceType};
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::watch;
+
+/// How often the pipeline publishes the in-progress utterance
+pub const PARTIAL_INTERVAL: Duration = Duration::from_millis(1500);
+/// Utterances shorter than this are left to the final decode
+pub const MIN_PARTIAL_SPEECH_SECONDS: f64 = 1.0;
+/// Only the most recent audio of very long utterances is decoded for partials
+const MAX_PARTIAL_WINDOW_SECONDS: f64 = 30.0;
+const SAMPLE_RATE: u32 = 16000;
+
+/// Speech of the utterance VAD currently keeps open (16kHz mono)
+#[derive(Debug, Clone)]
+pub struct InProgressSpeech {
+    /// Chunk ID the final segment of this utterance will be dispatched with
+    pub utterance_id: u64,
+    pub samples: Vec<f32>,
+    /// Seconds from recording start
+    pub start_time: f64,
+}
+
+/// Latest snapshot only: a slow decoder skips straight to the newest audio
+static IN_PROGRESS: Lazy<watch::Sender<Option<InProgressSpeech>>> =
+    Lazy::new(|| watch::channel(None).0);
+
+#[derive(Debug, Default)]
+struct PartialState {
+    /// Highest utterance whose final result has been emitted (or dropped)
+    finalized_through: Option<u64>,
+    /// Utterance of the partial currently shown in the UI
+    shown: Option<u64>,
+}
+
+impl PartialState {
+    fn is_finalized(&self, utterance_id: u64) -> bool {
+        self.finalized_through.is_some_and(|id| utterance_id <= id)
+    }
+}
+
+static PARTIAL_STATE: Lazy<Mutex<PartialState>> = Lazy::new(|| Mutex::new(PartialState::default()));
+
+/// Publish the in-progress utterance (called by the pipeline every `PARTIAL_INTERVAL`)
+pub fn publish_in_progress(speech: InProgressSpeech) {
+    IN_PROGRESS.send_replace(Some(speech));
+}
+
+/// The utterance was closed by VAD; its final segment is on the way
+pub fn clear_in_progress() {
+    IN_PROGRESS.send_replace(None);
+}
+
+/// Reset partial tracking for a new recording session
+pub fn reset_partial_session() {
+    IN_PROGRESS.send_replace(None);
+    *PARTIAL_STATE.lock().unwrap() = PartialState::default();
+}
+
+/// Mark an utterance as final. Called by the ordered emitter for every chunk, before its
+/// result is emitted; returns true if a partial of this utterance is still shown.
+pub(super) fn finalize_utterance(utterance_id: u64) -> bool {
+    let mut state = PARTIAL_STATE.lock().unwrap();
+    state.finalized_through = Some(
+        state
+            .finalized_through
+            .map_or(utterance_id, |id| id.max(utterance_id)),
+    );
+    if state.shown.is_some_and(|id| id <= utterance_id) {
+        state.shown = None;
+        true
+    } else {
+        false
+    }
+}
+
+/// Emit a partial unless its utterance was finalized meanwhile. The state lock is held
+/// while emitting so a partial can never arrive after the final that supersedes it.
+fn emit_partial<R: Runtime>(app: &AppHandle<R>, update: &TranscriptUpdate) -> bool {
+    let mut state = PARTIAL_STATE.lock().unwrap();
+    if state.is_finalized(update.utterance_id) {
+        return false;
+    }
+    state.shown = Some(update.utterance_id);
+    app.emit("transcript-partial", update).is_ok()
+}
+
+fn is_finalized(utterance_id: u64) -> bool {
+    PARTIAL_STATE.lock().unwrap().is_finalized(utterance_id)
+}
+
+/// Whether partial decoding is worth it for this engine. Remote providers are skipped:
+/// re-posting the growing utterance every interval would multiply API usage.
+pub(super) fn engine_supports_partials(engine: &TranscriptionEngine) -> bool {
+    match engine {
+        TranscriptionEngine::Whisper(_) | TranscriptionEngine::Parakeet(_) => true,
+        TranscriptionEngine::Provider(p) => p.provider_name() != "OpenAI-compatible",
+    }
+}
+
+/// Decode published in-progress speech until aborted by the transcription task
+pub(super) fn spawn_partial_task<R: Runtime>(
+    app: AppHandle<R>,
+    engine: TranscriptionEngine,
+    translation_settings: TranslationSettings,
+) -> tokio::task::JoinHandle<()> {
+    let mut receiver = IN_PROGRESS.subscribe();
+
+    tokio::spawn(async move {
+        info!(
+            "✏️ Partial transcription task started ({})",
+            engine.provider_name()
+        );
+
+        while receiver.changed().await.is_ok() {
+            let Some(speech) = receiver.borrow_and_update().clone() else {
+                continue;
+            };
+
+            // Finals take priority: only decode partials while the workers are idle
+            if is_finalized(speech.utterance_id) || worker::current_metrics().queue_depth > 0 {
+                continue;
+            }
+
+            let (samples, start_time) = partial_window(speech.samples, speech.start_time);
+            let duration = samples.len() as f64 / SAMPLE_RATE as f64;
+            let speech_energy = hallucination::peak_frame_rms(&samples, SAMPLE_RATE);
+            let chunk = AudioChunk {
+                data: samples,
+                sample_rate: SAMPLE_RATE,
+                timestamp: start_time,
+                chunk_id: speech.utterance_id,
+                device_type: DeviceType::Microphone,
+            };
+
+            // Failures are only logged: the final transcription of the utterance reports
+            // errors, and a failed preview must not look like a failed recording
+            let mut result =
+                match worker::transcribe_chunk(&engine, chunk, &translation_settings).await {
+                    Ok(result) => glossary::correct_result(result),
+                    Err(e) => {
+                        warn!(
+                            "✏️ Partial decode of utterance {} failed: {}",
+                            speech.utterance_id, e
+                        );
+                        continue;
+                    }
+                };
+
+            // Same phantom-text rules as the finals, so a partial never shows text the
+            // final will drop
+            match hallucination::filter_partial(&result.text, speech_energy, result.confidence) {
+                Verdict::Keep => {}
+                Verdict::Rewrite { text, .. } => {
+                    result.text = text;
+                    result.words.clear();
+                }
+                Verdict::Drop(_) => continue,
+            }
+            if result.text.trim().is_empty() {
+                continue;
+            }
+
+            let update = TranscriptUpdate {
+                text: result.text,
+                timestamp: worker::format_current_timestamp(),
+                source: "Audio".to_string(),
+                sequence_id: 0, // Partials are not part of the ordered sequence
+                chunk_start_time: start_time,
+                is_partial: true,
+                confidence: result.confidence.unwrap_or(0.85),
+                audio_start_time: start_time,
+                audio_end_time: start_time + duration,
+                duration,
+                words: offset_words(result.words, start_time),
+                utterance_id: speech.utterance_id,
+            };
+
+            if emit_partial(&app, &update) {
+                debug!(
+                    "✏️ Partial for utterance {} ({:.1}s): '{}'",
+                    update.utterance_id, duration, update.text
+                );
+            }
+        }
+    })
+}
+
+/// Limit very long utterances to their most recent audio
+fn partial_window(mut samples: Vec<f32>, start_time: f64) -> (Vec<f32>, f64) {
+    let max_samples = (MAX_PARTIAL_WINDOW_SECONDS * SAMPLE_RATE as f64) as usize;
+    if samples.len() <= max_samples {
+        return (samples, start_time);
+    }
+
+    let skipped = samples.len() - max_samples;
+    samples.drain(..skipped);
+    (samples, start_time + skipped as f64 / SAMPLE_RATE as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_window_keeps_recent_audio() {
+        let samples = vec![0.0; (SAMPLE_RATE as f64 * 40.0) as usize];
+        let (window, start) = partial_window(samples, 5.0);
+        assert_eq!(
+            window.len(),
+            (MAX_PARTIAL_WINDOW_SECONDS * SAMPLE_RATE as f64) as usize
+        );
+        assert!((start - 15.0).abs() < 1e-9);
+
+        let (window, start) = partial_window(vec![0.0; 100], 2.0);
+        assert_eq!(window.len(), 100);
+        assert_eq!(start, 2.0);
+    }
+
+    #[test]
+    fn test_finalized_utterances() {
+        let mut state = PartialState::default();
+        assert!(!state.is_finalized(0));
+
+        state.finalized_through = Some(3);
+        assert!(state.is_finalized(2));
+        assert!(state.is_finalized(3));
+        assert!(!state.is_finalized(4));
+    }
+}
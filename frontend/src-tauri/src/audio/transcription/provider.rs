@@ -5,6 +5,8 @@
 
 use async_trait::async_trait;
 
+use super::word_timing::WordTiming;
+
 // ============================================================================
 // TRANSCRIPTION PROVIDER TRAIT & ERROR TYPES
 // ============================================================================
@@ -43,6 +45,7 @@ pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    pub words: Vec<WordTiming>,  // Word timings relative to the start of the audio (empty if unsupported)
 }
 
 /// Trait for transcription providers (Whisper, Parakeet, future providers)
@@ -55,7 +58,7 @@ pub trait TranscriptionProvider: Send + Sync {
     /// * `language` - Optional language hint (e.g., "en", "es", "fr")
     ///
     /// # Returns
-    /// * `TranscriptResult` with text, optional confidence, and partial flag
+    /// * `TranscriptResult` with text, optional confidence, partial flag and word timings
     async fn transcribe(
         &self,
         audio: Vec<f32>,
@@ -0,0 +1,692 @@
+// audio/transcription/remote_provider.rs
+//
+// Remote transcription provider for any server exposing the OpenAI-compatible
+// `/v1/audio/transcriptions` endpoint (OpenAI, Groq, faster-whisper-server,
+// whisper.cpp server, ...). Chunks are posted as 16-bit WAV; transient failures are
+// retried with backoff, and when the server stays unreachable the chunk is
+// transcribed by a local engine instead so a recording never loses speech.
+
+use super::glossary;
+use super::provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
+use super::word_timing::WordTiming;
+use super::{ParakeetProvider, WhisperProvider};
+use async_trait::async_trait;
+use log::{info, warn};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+pub const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+pub const DEFAULT_GROQ_BASE_URL: &str = "https://api.groq.com/openai/v1";
+
+const SAMPLE_RATE: u32 = 16000;
+/// Retries of a transient failure before falling back, unless configured otherwise
+pub const DEFAULT_MAX_RETRIES: u32 = 2;
+/// Upper bound for the configured retries (backoff doubles per attempt)
+const MAX_RETRIES_LIMIT: u32 = 5;
+/// After the server failed, chunks go straight to the local engine for this long
+const REMOTE_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Connection settings for an OpenAI-compatible transcription server
+#[derive(Debug, Clone)]
+pub struct RemoteTranscriptionConfig {
+    pub base_url: String,
+    pub model: String,
+    pub api_key: Option<String>,
+    pub max_retries: u32,
+    pub retry_delay: Duration,
+    pub timeout: Duration,
+}
+
+impl RemoteTranscriptionConfig {
+    pub fn new(base_url: &str, model: &str, api_key: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim().trim_end_matches('/').to_string(),
+            model: model.trim().to_string(),
+            api_key: api_key.filter(|key| !key.trim().is_empty()),
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: Duration::from_millis(500),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Build the config from the saved transcript settings.
+    ///
+    /// `openai` and `groq` always use their public endpoints; `openaiCompatible`
+    /// requires a base URL.
+    pub fn from_transcript_config(
+        config: &crate::api::api::TranscriptConfig,
+    ) -> Result<Self, String> {
+        let base_url = match config.provider.as_str() {
+            "openai" => DEFAULT_OPENAI_BASE_URL,
+            "groq" => DEFAULT_GROQ_BASE_URL,
+            _ => config
+                .endpoint
+                .as_deref()
+                .map(str::trim)
+                .filter(|e| !e.is_empty())
+                .ok_or_else(|| {
+                    "No server URL configured for the OpenAI-compatible transcription provider"
+                        .to_string()
+                })?,
+        };
+
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            return Err(format!(
+                "Invalid transcription server URL '{}': expected http:// or https://",
+                base_url
+            ));
+        }
+        if config.model.trim().is_empty() {
+            return Err("No model configured for the remote transcription provider".to_string());
+        }
+
+        Ok(Self {
+            max_retries: config
+                .max_retries
+                .unwrap_or(DEFAULT_MAX_RETRIES)
+                .min(MAX_RETRIES_LIMIT),
+            ..Self::new(base_url, &config.model, config.api_key.clone())
+        })
+    }
+
+    /// Full URL of the transcription (or translation) endpoint
+    fn endpoint_url(&self, translate: bool) -> String {
+        let path = if translate {
+            "audio/translations"
+        } else {
+            "audio/transcriptions"
+        };
+        if self.base_url.ends_with("/v1") {
+            format!("{}/{}", self.base_url, path)
+        } else {
+            format!("{}/v1/{}", self.base_url, path)
+        }
+    }
+}
+
+/// Encode 16kHz mono f32 samples as a 16-bit PCM WAV file
+pub fn encode_wav_pcm16(samples: &[f32]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes()); // byte rate
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        wav.extend_from_slice(&value.to_le_bytes());
+    }
+    wav
+}
+
+#[derive(Debug, Deserialize)]
+struct VerboseResponse {
+    text: String,
+    #[serde(default)]
+    words: Vec<ResponseWord>,
+    #[serde(default)]
+    segments: Vec<ResponseSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseWord {
+    word: String,
+    start: f64,
+    end: f64,
+    #[serde(default)]
+    probability: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseSegment {
+    #[serde(default)]
+    avg_logprob: Option<f64>,
+}
+
+/// Parse a transcription response body.
+///
+/// Accepts `verbose_json` (text, words, segments) and plain `json` (text only).
+/// Anything else (an HTML login or error page from a proxy, say) is an error rather
+/// than transcript text. Confidence is the mean segment probability when the server
+/// reports `avg_logprob`.
+fn parse_transcription_response(body: &str) -> Result<TranscriptResult, String> {
+    match serde_json::from_str::<VerboseResponse>(body) {
+        Ok(response) => {
+            let words = response
+                .words
+                .into_iter()
+                .filter(|w| !w.word.trim().is_empty())
+                .map(|w| WordTiming {
+                    word: w.word.trim().to_string(),
+                    start: w.start,
+                    end: w.end,
+                    probability: w.probability,
+                })
+                .collect();
+
+            let logprobs: Vec<f64> = response
+                .segments
+                .iter()
+                .filter_map(|s| s.avg_logprob)
+                .collect();
+            let confidence = if logprobs.is_empty() {
+                None
+            } else {
+                let mean = logprobs.iter().map(|lp| lp.exp()).sum::<f64>() / logprobs.len() as f64;
+                Some(mean.clamp(0.0, 1.0) as f32)
+            };
+
+            Ok(TranscriptResult {
+                text: response.text.trim().to_string(),
+                confidence,
+                is_partial: false,
+                words,
+            })
+        }
+        Err(e) => {
+            let preview: String = body.trim().chars().take(100).collect();
+            Err(format!(
+                "Unexpected response from transcription server ({}): {}",
+                e, preview
+            ))
+        }
+    }
+}
+
+/// Outcome of a single request attempt
+enum AttemptError {
+    /// Connection failure, timeout, rate limit or server error - worth retrying
+    Transient(String),
+    /// Rejected request (bad key, unknown model, ...) - retrying won't help
+    Fatal(String),
+}
+
+/// Transcription provider for OpenAI-compatible servers with local fallback
+pub struct RemoteProvider {
+    config: RemoteTranscriptionConfig,
+    client: reqwest::Client,
+    unavailable_until: Mutex<Option<Instant>>,
+    fallback: Mutex<Option<Arc<dyn TranscriptionProvider>>>,
+}
+
+impl RemoteProvider {
+    pub fn new(config: RemoteTranscriptionConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        Self {
+            config,
+            client,
+            unavailable_until: Mutex::new(None),
+            fallback: Mutex::new(None),
+        }
+    }
+
+    /// Use the given provider when the server is unreachable instead of loading a local engine
+    pub fn with_fallback(self, fallback: Arc<dyn TranscriptionProvider>) -> Self {
+        Self {
+            fallback: Mutex::new(Some(fallback)),
+            ..self
+        }
+    }
+
+    async fn send_once(
+        &self,
+        wav: &[u8],
+        language: Option<&str>,
+        translate: bool,
+    ) -> Result<TranscriptResult, AttemptError> {
+        let file = reqwest::multipart::Part::bytes(wav.to_vec())
+            .file_name("audio.wav")
+            .mime_str("audio/wav")
+            .map_err(|e| AttemptError::Fatal(e.to_string()))?;
+
+        let mut form = reqwest::multipart::Form::new()
+            .part("file", file)
+            .text("model", self.config.model.clone())
+            .text("response_format", "verbose_json")
+            .text("temperature", "0")
+            .text("timestamp_granularities[]", "word")
+            .text("timestamp_granularities[]", "segment");
+        if let Some(language) = language {
+            form = form.text("language", language.to_string());
+        }
+        if let Some(prompt) = glossary::whisper_initial_prompt() {
+            form = form.text("prompt", prompt);
+        }
+
+        let mut request = self
+            .client
+            .post(self.config.endpoint_url(translate))
+            .multipart(form);
+        if let Some(api_key) = &self.config.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AttemptError::Transient(e.to_string()))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AttemptError::Transient(e.to_string()))?;
+
+        if status.is_success() {
+            // A success status with an unreadable body means the URL points at the wrong
+            // server; retrying won't change that
+            parse_transcription_response(&body).map_err(AttemptError::Fatal)
+        } else if status.as_u16() == 429 || status.is_server_error() {
+            Err(AttemptError::Transient(format!(
+                "HTTP {}: {}",
+                status,
+                body.trim()
+            )))
+        } else {
+            Err(AttemptError::Fatal(format!(
+                "HTTP {}: {}",
+                status,
+                body.trim()
+            )))
+        }
+    }
+
+    /// Post the chunk, retrying transient failures with exponential backoff
+    async fn transcribe_remote(
+        &self,
+        audio: &[f32],
+        language: Option<&str>,
+    ) -> Result<TranscriptResult, AttemptError> {
+        let wav = encode_wav_pcm16(audio);
+        let translate = language == Some("auto-translate");
+        let language = language.filter(|l| *l != "auto" && *l != "auto-translate");
+
+        let mut attempt = 0;
+        loop {
+            match self.send_once(&wav, language, translate).await {
+                Ok(result) => return Ok(result),
+                Err(AttemptError::Transient(e)) if attempt < self.config.max_retries => {
+                    let delay = self.config.retry_delay * 2u32.pow(attempt);
+                    attempt += 1;
+                    warn!(
+                        "⚠️ Remote transcription attempt {} failed: {} (retrying in {:?})",
+                        attempt, e, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Local provider used while the server is unreachable, loaded on first use
+    async fn local_fallback(&self) -> Result<Arc<dyn TranscriptionProvider>, TranscriptionError> {
+        let mut fallback = self.fallback.lock().await;
+        if let Some(provider) = fallback.as_ref() {
+            return Ok(provider.clone());
+        }
+
+        let provider = load_local_provider().await?;
+        info!(
+            "🔁 Using local {} engine as fallback for remote transcription",
+            provider.provider_name()
+        );
+        *fallback = Some(provider.clone());
+        Ok(provider)
+    }
+}
+
+/// Pick an already loaded local engine, or load the smallest downloaded Whisper model
+async fn load_local_provider() -> Result<Arc<dyn TranscriptionProvider>, TranscriptionError> {
+    let parakeet = crate::parakeet_engine::commands::PARAKEET_ENGINE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .cloned();
+    if let Some(engine) = parakeet {
+        if engine.is_model_loaded().await {
+            return Ok(Arc::new(ParakeetProvider::new(engine)));
+        }
+    }
+
+    crate::whisper_engine::commands::whisper_init()
+        .await
+        .map_err(TranscriptionError::EngineFailed)?;
+    let whisper = crate::whisper_engine::commands::WHISPER_ENGINE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .cloned()
+        .ok_or(TranscriptionError::ModelNotLoaded)?;
+
+    if !whisper.is_model_loaded().await {
+        let models = whisper
+            .discover_models()
+            .await
+            .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?;
+        let smallest = models
+            .into_iter()
+            .filter(|m| matches!(m.status, crate::whisper_engine::ModelStatus::Available))
+            .min_by_key(|m| m.size_mb)
+            .ok_or(TranscriptionError::ModelNotLoaded)?;
+
+        info!(
+            "📥 Loading local Whisper model '{}' for fallback",
+            smallest.name
+        );
+        whisper
+            .load_model(&smallest.name)
+            .await
+            .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?;
+    }
+
+    Ok(Arc::new(WhisperProvider::new(whisper)))
+}
+
+#[async_trait]
+impl TranscriptionProvider for RemoteProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let cooling_down = self
+            .unavailable_until
+            .lock()
+            .await
+            .is_some_and(|until| Instant::now() < until);
+
+        if !cooling_down {
+            match self.transcribe_remote(&audio, language.as_deref()).await {
+                Ok(result) => {
+                    *self.unavailable_until.lock().await = None;
+                    return Ok(result);
+                }
+                Err(AttemptError::Fatal(e)) => {
+                    // The server answered but rejected the request: surface the error
+                    // rather than silently switching engines
+                    return Err(TranscriptionError::EngineFailed(e));
+                }
+                Err(AttemptError::Transient(e)) => {
+                    warn!(
+                        "❌ Transcription server {} unreachable: {} - falling back to local engine for {:?}",
+                        self.config.base_url, e, REMOTE_COOLDOWN
+                    );
+                    *self.unavailable_until.lock().await = Some(Instant::now() + REMOTE_COOLDOWN);
+                }
+            }
+        }
+
+        let fallback = self.local_fallback().await?;
+        fallback.transcribe(audio, language).await
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        // Nothing to load locally; availability is checked per request
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.config.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "OpenAI-compatible"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    struct StubProvider;
+
+    #[async_trait]
+    impl TranscriptionProvider for StubProvider {
+        async fn transcribe(
+            &self,
+            _audio: Vec<f32>,
+            _language: Option<String>,
+        ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+            Ok(TranscriptResult {
+                text: "local".to_string(),
+                confidence: None,
+                is_partial: false,
+                words: Vec::new(),
+            })
+        }
+
+        async fn is_model_loaded(&self) -> bool {
+            true
+        }
+
+        async fn get_current_model(&self) -> Option<String> {
+            None
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "Stub"
+        }
+    }
+
+    /// Read one HTTP request (headers plus Content-Length body) and return its head
+    async fn read_request(stream: &mut TcpStream) -> String {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let n = stream.read(&mut chunk).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            let text = String::from_utf8_lossy(&buf);
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let head = text[..header_end].to_lowercase();
+                let body = buf.len() - header_end - 4;
+                let content_length = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("content-length:"))
+                    .and_then(|v| v.trim().parse::<usize>().ok());
+                let done = match content_length {
+                    Some(len) => body >= len,
+                    None => text.ends_with("0\r\n\r\n"),
+                };
+                if done {
+                    return head;
+                }
+            }
+        }
+        String::from_utf8_lossy(&buf).to_lowercase()
+    }
+
+    /// Serve the given (status, body) responses in order, one per connection
+    async fn spawn_stub_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let head = read_request(&mut stream).await;
+                assert!(head.starts_with("post /v1/audio/transcriptions"));
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.ok();
+            }
+        });
+
+        (url, hits)
+    }
+
+    fn test_config(base_url: &str) -> RemoteTranscriptionConfig {
+        RemoteTranscriptionConfig {
+            retry_delay: Duration::from_millis(10),
+            timeout: Duration::from_secs(5),
+            ..RemoteTranscriptionConfig::new(base_url, "whisper-1", Some("secret".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_endpoint_url() {
+        let config = RemoteTranscriptionConfig::new("http://localhost:8000/", "m", None);
+        assert_eq!(
+            config.endpoint_url(false),
+            "http://localhost:8000/v1/audio/transcriptions"
+        );
+
+        let config = RemoteTranscriptionConfig::new(DEFAULT_GROQ_BASE_URL, "m", None);
+        assert_eq!(
+            config.endpoint_url(true),
+            "https://api.groq.com/openai/v1/audio/translations"
+        );
+    }
+
+    #[test]
+    fn test_from_transcript_config() {
+        let mut config = crate::api::api::TranscriptConfig {
+            provider: "groq".to_string(),
+            model: "whisper-large-v3".to_string(),
+            api_key: Some(" ".to_string()),
+            endpoint: None,
+            max_retries: None,
+        };
+        let remote = RemoteTranscriptionConfig::from_transcript_config(&config).unwrap();
+        assert_eq!(remote.base_url, DEFAULT_GROQ_BASE_URL);
+        assert_eq!(remote.api_key, None);
+        assert_eq!(remote.max_retries, DEFAULT_MAX_RETRIES);
+
+        // A URL left over from the custom provider doesn't redirect groq/openai
+        config.endpoint = Some("http://localhost:8000/v1".to_string());
+        config.max_retries = Some(0);
+        let remote = RemoteTranscriptionConfig::from_transcript_config(&config).unwrap();
+        assert_eq!(remote.base_url, DEFAULT_GROQ_BASE_URL);
+        assert_eq!(remote.max_retries, 0);
+
+        config.provider = "openaiCompatible".to_string();
+        config.max_retries = Some(100);
+        let remote = RemoteTranscriptionConfig::from_transcript_config(&config).unwrap();
+        assert_eq!(remote.base_url, "http://localhost:8000/v1");
+        assert_eq!(remote.max_retries, MAX_RETRIES_LIMIT);
+
+        config.endpoint = None;
+        assert!(RemoteTranscriptionConfig::from_transcript_config(&config).is_err());
+
+        config.endpoint = Some("localhost:8000".to_string());
+        assert!(RemoteTranscriptionConfig::from_transcript_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_parse_verbose_response() {
+        let body = r#"{"text":" Hello world. ","words":[{"word":" Hello","start":0.0,"end":0.4},{"word":"world.","start":0.5,"end":0.9}],"segments":[{"avg_logprob":-0.1},{"avg_logprob":-0.3}]}"#;
+        let result = parse_transcription_response(body).unwrap();
+        assert_eq!(result.text, "Hello world.");
+        assert_eq!(result.words.len(), 2);
+        assert_eq!(result.words[0].word, "Hello");
+        let expected = ((-0.1f64).exp() + (-0.3f64).exp()) / 2.0;
+        assert!((result.confidence.unwrap() as f64 - expected).abs() < 1e-5);
+
+        let plain = parse_transcription_response(r#"{"text":"just text"}"#).unwrap();
+        assert_eq!(plain.text, "just text");
+        assert!(plain.confidence.is_none());
+
+        assert!(parse_transcription_response("<html><body>Sign in</body></html>").is_err());
+    }
+
+    #[test]
+    fn test_encode_wav_header() {
+        let wav = encode_wav_pcm16(&[0.0, 1.0, -1.0]);
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(i16::from_le_bytes([wav[46], wav[47]]), i16::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_errors() {
+        let (url, hits) = spawn_stub_server(vec![
+            (503, r#"{"error":"busy"}"#),
+            (200, r#"{"text":"from server"}"#),
+        ])
+        .await;
+
+        let provider = RemoteProvider::new(test_config(&url)).with_fallback(Arc::new(StubProvider));
+        let result = provider
+            .transcribe(vec![0.0; 16000], Some("en".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.text, "from server");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_request_is_not_retried() {
+        let (url, hits) = spawn_stub_server(vec![(401, r#"{"error":"bad key"}"#)]).await;
+
+        let provider = RemoteProvider::new(test_config(&url)).with_fallback(Arc::new(StubProvider));
+        let result = provider.transcribe(vec![0.0; 16000], None).await;
+
+        assert!(matches!(result, Err(TranscriptionError::EngineFailed(_))));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_non_json_response_is_an_error() {
+        let (url, hits) =
+            spawn_stub_server(vec![(200, "<html><body>Please sign in</body></html>")]).await;
+
+        let provider = RemoteProvider::new(test_config(&url)).with_fallback(Arc::new(StubProvider));
+        let result = provider.transcribe(vec![0.0; 16000], None).await;
+
+        assert!(matches!(result, Err(TranscriptionError::EngineFailed(_))));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_when_unreachable() {
+        // Bind and drop a listener to get a port nothing listens on
+        let port = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let provider = RemoteProvider::new(test_config(&format!("http://127.0.0.1:{}", port)))
+            .with_fallback(Arc::new(StubProvider));
+        let result = provider.transcribe(vec![0.0; 16000], None).await.unwrap();
+        assert_eq!(result.text, "local");
+
+        // Within the cooldown the server is not contacted again
+        let result = provider.transcribe(vec![0.0; 16000], None).await.unwrap();
+        assert_eq!(result.text, "local");
+    }
+}
@@ -0,0 +1,118 @@
+// audio/transcription/reorder.rs
+//
+// Reorder buffer for the parallel worker pool. Chunks are numbered in dispatch order;
+// workers may finish them in any order, and results are released strictly by number so
+// transcript updates are always emitted chronologically.
+
+use std::collections::BTreeMap;
+
+use log::warn;
+
+pub struct ReorderBuffer<T> {
+    next_sequence: u64,
+    pending: BTreeMap<u64, Option<T>>,
+}
+
+impl<T> ReorderBuffer<T> {
+    pub fn new() -> Self {
+        Self {
+            next_sequence: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Store the outcome of chunk `sequence` (`None` = chunk produced no transcript)
+    /// and return every result that is now releasable, in dispatch order
+    pub fn insert(&mut self, sequence: u64, item: Option<T>) -> Vec<T> {
+        if sequence < self.next_sequence || self.pending.contains_key(&sequence) {
+            warn!("Reorder buffer: ignoring duplicate result for chunk sequence {}", sequence);
+            return Vec::new();
+        }
+        self.pending.insert(sequence, item);
+
+        let mut ready = Vec::new();
+        while let Some(item) = self.pending.remove(&self.next_sequence) {
+            self.next_sequence += 1;
+            if let Some(item) = item {
+                ready.push(item);
+            }
+        }
+        ready
+    }
+
+    /// Sequence number the buffer is waiting for next
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Number of finished results held back waiting for an earlier chunk
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Release everything still held, in order, skipping over gaps
+    /// (only used at shutdown, when a missing chunk will never arrive)
+    pub fn drain_all(&mut self) -> Vec<T> {
+        if let Some((&last, _)) = self.pending.iter().next_back() {
+            self.next_sequence = last + 1;
+        }
+        std::mem::take(&mut self.pending)
+            .into_values()
+            .flatten()
+            .collect()
+    }
+}
+
+impl<T> Default for ReorderBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_order_results_are_released_immediately() {
+        let mut buffer = ReorderBuffer::new();
+        assert_eq!(buffer.insert(0, Some("a")), vec!["a"]);
+        assert_eq!(buffer.insert(1, Some("b")), vec!["b"]);
+        assert_eq!(buffer.pending_len(), 0);
+        assert_eq!(buffer.next_sequence(), 2);
+    }
+
+    #[test]
+    fn out_of_order_results_wait_for_earlier_chunks() {
+        let mut buffer = ReorderBuffer::new();
+        assert!(buffer.insert(2, Some("c")).is_empty());
+        assert!(buffer.insert(1, Some("b")).is_empty());
+        assert_eq!(buffer.pending_len(), 2);
+        assert_eq!(buffer.insert(0, Some("a")), vec!["a", "b", "c"]);
+        assert_eq!(buffer.pending_len(), 0);
+    }
+
+    #[test]
+    fn empty_outcomes_advance_without_emitting() {
+        let mut buffer = ReorderBuffer::new();
+        assert!(buffer.insert(1, Some("b")).is_empty());
+        assert_eq!(buffer.insert(0, None), vec!["b"]);
+        assert_eq!(buffer.next_sequence(), 2);
+    }
+
+    #[test]
+    fn duplicates_are_ignored() {
+        let mut buffer = ReorderBuffer::new();
+        assert_eq!(buffer.insert(0, Some("a")), vec!["a"]);
+        assert!(buffer.insert(0, Some("again")).is_empty());
+    }
+
+    #[test]
+    fn drain_all_skips_gaps_in_order() {
+        let mut buffer = ReorderBuffer::new();
+        buffer.insert(3, Some("d"));
+        buffer.insert(1, Some("b"));
+        assert_eq!(buffer.drain_all(), vec!["b", "d"]);
+        assert_eq!(buffer.next_sequence(), 4);
+    }
+}
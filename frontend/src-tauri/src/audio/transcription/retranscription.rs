@@ -0,0 +1,1151 @@
+// audio/transcription/retranscription.rs
+//
+// Second-pass transcription of saved meetings. Live transcription favours a small, fast
+// model; afterwards the whole recording can be re-run through a larger Whisper or Parakeet
+// model in the background. Whisper runs on the resource-aware ParallelProcessor, Parakeet
+// (which serializes inference) runs overlapping windows one at a time and waits while
+// SystemMonitor reports the machine as constrained. The result replaces the meeting's
+// segments; the previous segments are archived as a transcript version, and segments the
+// user edited keep their text while speaker labels carry over to the overlapping new
+// segments. If any part of the recording fails to transcribe, the job fails and the
+// existing transcript is left untouched.
+
+use anyhow::{anyhow, Result};
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use uuid::Uuid;
+
+use super::glossary;
+use super::hallucination::{self, DuplicateTracker, Verdict};
+use super::provider::TranscriptResult;
+use super::word_timing::{offset_words, tokens_from_start_times, words_from_tokens, WordTiming};
+use crate::api::MeetingTranscript;
+use crate::database::models::{MeetingModel, TranscriptVersion};
+use crate::database::repositories::redaction::RedactionReportsRepository;
+use crate::database::repositories::transcript_version::TranscriptVersionsRepository;
+use crate::parakeet_engine::long_audio::{self, WindowMerger};
+use crate::redaction::{self, RedactionMap, RedactionStage};
+use crate::whisper_engine::{
+    AudioChunk, ParallelConfig, ParallelProcessor, ProcessingEvent, SystemMonitor,
+};
+
+const SAMPLE_RATE: u32 = 16000;
+/// Whisper's context window is 30s; leave room to cut at a pause
+const CHUNK_TARGET_SECS: f64 = 20.0;
+/// How far back from the target length to look for the quietest point
+const PAUSE_SEARCH_SECS: f64 = 4.0;
+/// Chunks quieter than this RMS are skipped (Whisper hallucinates on silence)
+const SILENCE_RMS: f32 = 0.001;
+const RESOURCE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RetranscriptionEngine {
+    Whisper,
+    Parakeet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetranscriptionStage {
+    Decoding,
+    LoadingModel,
+    Transcribing,
+    Paused,
+    Saving,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// Payload of the `retranscription-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetranscriptionProgress {
+    pub meeting_id: String,
+    pub engine: RetranscriptionEngine,
+    pub model_name: String,
+    pub stage: RetranscriptionStage,
+    pub completed_chunks: usize,
+    pub total_chunks: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+struct ActiveJob {
+    meeting_id: String,
+    cancel: Arc<AtomicBool>,
+}
+
+// One re-transcription at a time: a large model already uses the CPU budget on its own
+static ACTIVE_JOB: Lazy<RwLock<Option<ActiveJob>>> = Lazy::new(|| RwLock::new(None));
+
+// Last progress per meeting, so a reopened meeting page can pick up the state
+static LAST_PROGRESS: Lazy<RwLock<HashMap<String, RetranscriptionProgress>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Progress reporter for one job
+struct JobReporter<R: Runtime> {
+    app: AppHandle<R>,
+    progress: RetranscriptionProgress,
+    cancel: Arc<AtomicBool>,
+}
+
+impl<R: Runtime> JobReporter<R> {
+    fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::SeqCst)
+    }
+
+    fn report(&mut self, stage: RetranscriptionStage, message: Option<String>) {
+        self.progress.stage = stage;
+        self.progress.message = message;
+        self.emit();
+    }
+
+    fn set_chunks(&mut self, completed: usize, total: usize) {
+        self.progress.completed_chunks = completed;
+        self.progress.total_chunks = total;
+        self.emit();
+    }
+
+    fn emit(&self) {
+        if let Ok(mut last) = LAST_PROGRESS.write() {
+            last.insert(self.progress.meeting_id.clone(), self.progress.clone());
+        }
+        if let Err(e) = self.app.emit("retranscription-progress", &self.progress) {
+            warn!("Failed to emit re-transcription progress: {}", e);
+        }
+    }
+}
+
+/// A transcribed chunk on the recording timeline
+struct ChunkTranscript {
+    start: f64,
+    end: f64,
+    result: TranscriptResult,
+}
+
+#[tauri::command]
+pub async fn start_retranscription<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    engine: RetranscriptionEngine,
+    model_name: String,
+) -> Result<(), String> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let mut active = ACTIVE_JOB
+            .write()
+            .map_err(|e| format!("Failed to start re-transcription: {}", e))?;
+        if let Some(job) = active.as_ref() {
+            return Err(format!(
+                "A re-transcription is already running for meeting {}",
+                job.meeting_id
+            ));
+        }
+        *active = Some(ActiveJob {
+            meeting_id: meeting_id.clone(),
+            cancel: cancel.clone(),
+        });
+    }
+
+    info!(
+        "🔁 Starting re-transcription of meeting {} with {:?} model '{}'",
+        meeting_id, engine, model_name
+    );
+
+    let mut reporter = JobReporter {
+        app: app.clone(),
+        progress: RetranscriptionProgress {
+            meeting_id: meeting_id.clone(),
+            engine,
+            model_name: model_name.clone(),
+            stage: RetranscriptionStage::Decoding,
+            completed_chunks: 0,
+            total_chunks: 0,
+            message: None,
+        },
+        cancel,
+    };
+    reporter.emit();
+
+    tokio::spawn(async move {
+        let outcome =
+            run_retranscription(&app, &meeting_id, engine, &model_name, &mut reporter).await;
+
+        // Release the slot before the final event so the UI can start another job right away
+        if let Ok(mut active) = ACTIVE_JOB.write() {
+            *active = None;
+        }
+
+        match outcome {
+            Ok(true) => {
+                info!("✅ Re-transcription of meeting {} completed", meeting_id);
+                reporter.report(RetranscriptionStage::Completed, None);
+            }
+            Ok(false) => {
+                info!("🛑 Re-transcription of meeting {} cancelled", meeting_id);
+                reporter.report(RetranscriptionStage::Cancelled, None);
+            }
+            Err(e) => {
+                error!(
+                    "❌ Re-transcription of meeting {} failed: {}",
+                    meeting_id, e
+                );
+                reporter.report(RetranscriptionStage::Failed, Some(e.to_string()));
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn cancel_retranscription(meeting_id: String) -> Result<bool, String> {
+    let active = ACTIVE_JOB
+        .read()
+        .map_err(|e| format!("Failed to cancel re-transcription: {}", e))?;
+    match active.as_ref() {
+        Some(job) if job.meeting_id == meeting_id => {
+            info!("🛑 Cancelling re-transcription of meeting {}", meeting_id);
+            job.cancel.store(true, Ordering::SeqCst);
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+#[tauri::command]
+pub async fn get_retranscription_status(
+    meeting_id: String,
+) -> Result<Option<RetranscriptionProgress>, String> {
+    let last = LAST_PROGRESS
+        .read()
+        .map_err(|e| format!("Failed to read re-transcription status: {}", e))?;
+    Ok(last.get(&meeting_id).cloned())
+}
+
+#[tauri::command]
+pub async fn list_transcript_versions<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<TranscriptVersion>, String> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+    TranscriptVersionsRepository::list_versions(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to list transcript versions: {}", e))
+}
+
+#[tauri::command]
+pub async fn restore_transcript_version<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    version_id: String,
+) -> Result<(), String> {
+    if is_running_for(&meeting_id) {
+        return Err(
+            "Cannot restore a version while the meeting is being re-transcribed".to_string(),
+        );
+    }
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+    match TranscriptVersionsRepository::restore_version(
+        state.db_manager.pool(),
+        &meeting_id,
+        &version_id,
+    )
+    .await
+    {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(format!("Transcript version not found: {}", version_id)),
+        Err(e) => Err(format!("Failed to restore transcript version: {}", e)),
+    }
+}
+
+fn is_running_for(meeting_id: &str) -> bool {
+    ACTIVE_JOB
+        .read()
+        .map(|active| {
+            active
+                .as_ref()
+                .is_some_and(|job| job.meeting_id == meeting_id)
+        })
+        .unwrap_or(false)
+}
+
+/// Runs the whole job. Returns Ok(false) when it was cancelled before saving.
+async fn run_retranscription<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    engine: RetranscriptionEngine,
+    model_name: &str,
+    reporter: &mut JobReporter<R>,
+) -> Result<bool> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| anyhow!("Database not initialized"))?;
+    let pool = state.db_manager.pool().clone();
+
+    let meeting: Option<MeetingModel> = sqlx::query_as(
+        "SELECT id, title, created_at, updated_at, folder_path FROM meetings WHERE id = ?",
+    )
+    .bind(meeting_id)
+    .fetch_optional(&pool)
+    .await?;
+    let meeting = meeting.ok_or_else(|| anyhow!("Meeting not found: {}", meeting_id))?;
+    let folder = meeting
+        .folder_path
+        .ok_or_else(|| anyhow!("Meeting '{}' has no recording folder", meeting.title))?;
+    let audio_path = recording_audio_path(Path::new(&folder))
+        .ok_or_else(|| anyhow!("No saved recording found in {}", folder))?;
+
+    // 1. Decode the recording
+    info!("🔁 Decoding {}", audio_path.display());
+    let audio = tokio::task::spawn_blocking(move || decode_recording(&audio_path)).await??;
+    let ranges: Vec<(usize, usize)> =
+        split_at_pauses(&audio, SAMPLE_RATE, CHUNK_TARGET_SECS, PAUSE_SEARCH_SECS)
+            .into_iter()
+            .filter(|&(start, end)| rms(&audio[start..end]) >= SILENCE_RMS)
+            .collect();
+    info!(
+        "🔁 Recording decoded: {:.1}s of audio, {} chunks with speech",
+        audio.len() as f64 / SAMPLE_RATE as f64,
+        ranges.len()
+    );
+    if reporter.is_cancelled() {
+        return Ok(false);
+    }
+    if ranges.is_empty() {
+        return Err(anyhow!("The recording contains no audible speech"));
+    }
+
+    // 2. Transcribe
+    reporter.report(RetranscriptionStage::LoadingModel, None);
+    let transcribed = match engine {
+        RetranscriptionEngine::Whisper => {
+            transcribe_with_whisper(&audio, &ranges, model_name, reporter).await?
+        }
+        RetranscriptionEngine::Parakeet => {
+            transcribe_with_parakeet(&audio, &ranges, model_name, reporter).await?
+        }
+    };
+    let Some(chunks) = transcribed else {
+        return Ok(false);
+    };
+    if chunks.is_empty() {
+        return Err(anyhow!(
+            "The model produced no transcript for this recording"
+        ));
+    }
+
+    // 3. Merge with the current transcript and save, archiving the old segments
+    reporter.report(RetranscriptionStage::Saving, None);
+    // Re-read the segments so edits made while the job was running are kept. Their
+    // placeholders (and those of the archived versions) are never handed out again.
+    let current = TranscriptVersionsRepository::get_segments(&pool, meeting_id).await?;
+    let redactor = redaction::storage_redactor();
+    let mut redactions = RedactionMap::reserving(current.iter().map(|s| s.text.as_str()));
+    // Same phantom-text rules as live transcription, in order so near-duplicates are caught
+    let mut duplicates = DuplicateTracker::default();
+    let new_segments = chunks
+        .into_iter()
+        .filter_map(|chunk| {
+            let mut result = glossary::correct_result(chunk.result);
+            let start = (chunk.start * SAMPLE_RATE as f64) as usize;
+            let end = ((chunk.end * SAMPLE_RATE as f64) as usize).min(audio.len());
+            let speech_energy =
+                hallucination::peak_frame_rms(&audio[start.min(end)..end], SAMPLE_RATE);
+            let verdict = hallucination::filter_segment(
+                &result.text,
+                speech_energy,
+                result.confidence,
+                chunk.start,
+            );
+            if !apply_verdict(verdict, &mut result) {
+                return None;
+            }
+            let verdict = duplicates.filter(&result.text, chunk.start);
+            if !apply_verdict(verdict, &mut result) {
+                return None;
+            }
+            let mut text = result.text.trim().to_string();
+            if text.is_empty() {
+                return None;
+            }
+            if let Some(redactor) = &redactor {
+                if redactor.redact_in_place(&mut text, &mut redactions) {
+                    result.words.clear();
+                }
+            }
+            Some(MeetingTranscript {
+                id: format!("transcript-{}", Uuid::new_v4()),
+                text,
+                timestamp: String::new(),
+                audio_start_time: Some(chunk.start),
+                audio_end_time: Some(chunk.end),
+                duration: Some(chunk.end - chunk.start),
+                words: offset_words(result.words, chunk.start),
+                translation: None,
+                translation_language: None,
+                speaker: None,
+                user_edited: false,
+                confidence: result.confidence,
+            })
+        })
+        .collect();
+
+    let merged = merge_segments(new_segments, &current);
+    let label = format!("Before re-transcription with {}", model_name);
+    let version =
+        TranscriptVersionsRepository::replace_segments(&pool, meeting_id, &merged, &label).await?;
+    if redactor.is_some() {
+        RedactionReportsRepository::add_to_report(
+            &pool,
+            meeting_id,
+            RedactionStage::Storage,
+            &redactions.report(),
+        )
+        .await?;
+    }
+    info!(
+        "🔁 Meeting {} now has {} segments (previous transcript archived as version {})",
+        meeting_id,
+        merged.len(),
+        version
+    );
+    Ok(true)
+}
+
+async fn transcribe_with_whisper<R: Runtime>(
+    audio: &[f32],
+    ranges: &[(usize, usize)],
+    model_name: &str,
+    reporter: &mut JobReporter<R>,
+) -> Result<Option<Vec<ChunkTranscript>>> {
+    let probe = crate::whisper_engine::WhisperEngine::new_with_models_dir(
+        crate::whisper_engine::commands::get_models_directory(),
+    )?;
+    let available = probe.discover_models().await?.into_iter().any(|m| {
+        m.name == model_name && matches!(m.status, crate::whisper_engine::ModelStatus::Available)
+    });
+    if !available {
+        return Err(anyhow!("Whisper model '{}' is not downloaded", model_name));
+    }
+
+    let chunks: Vec<AudioChunk> = ranges
+        .iter()
+        .enumerate()
+        .map(|(id, &(start, end))| AudioChunk {
+            id: id as u32,
+            data: audio[start..end].to_vec(),
+            sample_rate: SAMPLE_RATE,
+            start_time_ms: samples_to_secs(start) * 1000.0,
+            duration_ms: samples_to_secs(end - start) * 1000.0,
+        })
+        .collect();
+    let total = chunks.len();
+
+    let (mut processor, mut events) =
+        ParallelProcessor::new(ParallelConfig::default(), Arc::new(SystemMonitor::new()))?;
+    processor
+        .start_processing(chunks, model_name.to_string())
+        .await?;
+    reporter.report(RetranscriptionStage::Transcribing, None);
+    reporter.set_chunks(0, total);
+
+    let mut completed: HashMap<u32, ChunkTranscript> = HashMap::new();
+    let mut failed = 0;
+    let mut constraint: Option<String> = None;
+    while completed.len() + failed < total {
+        if reporter.is_cancelled() {
+            processor.stop_processing().await;
+            return Ok(None);
+        }
+
+        let event = match tokio::time::timeout(Duration::from_millis(500), events.recv()).await {
+            Ok(Some(event)) => event,
+            Ok(None) => break,
+            Err(_) => continue, // Periodic wake-up to check for cancellation
+        };
+        match event {
+            ProcessingEvent::ChunkCompleted(result) => {
+                let (start, end) = ranges[result.chunk_id as usize];
+                completed.insert(
+                    result.chunk_id,
+                    ChunkTranscript {
+                        start: samples_to_secs(start),
+                        end: samples_to_secs(end),
+                        result: TranscriptResult {
+                            text: result.text,
+                            confidence: result.confidence_score,
+                            is_partial: false,
+                            words: result.words,
+                        },
+                    },
+                );
+                reporter.set_chunks(completed.len() + failed, total);
+            }
+            ProcessingEvent::ChunkFailed(failure) if !failure.is_recoverable => {
+                warn!(
+                    "🔁 Chunk {} could not be re-transcribed: {}",
+                    failure.chunk_id, failure.error_message
+                );
+                failed += 1;
+                reporter.set_chunks(completed.len() + failed, total);
+            }
+            ProcessingEvent::ResourceConstraint(message) => constraint = Some(message),
+            ProcessingEvent::ProcessingPaused => {
+                reporter.report(RetranscriptionStage::Paused, constraint.take());
+            }
+            ProcessingEvent::ProcessingResumed => {
+                reporter.report(RetranscriptionStage::Transcribing, None);
+            }
+            _ => {}
+        }
+    }
+    processor.stop_processing().await;
+    ensure_complete(completed.len(), total, "chunks")?;
+
+    let mut chunks: Vec<ChunkTranscript> = completed.into_values().collect();
+    chunks.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(Some(chunks))
+}
+
+async fn transcribe_with_parakeet<R: Runtime>(
+    audio: &[f32],
+    ranges: &[(usize, usize)],
+    model_name: &str,
+    reporter: &mut JobReporter<R>,
+) -> Result<Option<Vec<ChunkTranscript>>> {
+    // Reuse the live engine when it already holds this model, otherwise load a dedicated one
+    let live_engine = crate::parakeet_engine::commands::PARAKEET_ENGINE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .cloned();
+    let engine = match live_engine {
+        Some(engine) if engine.get_current_model().await.as_deref() == Some(model_name) => engine,
+        _ => {
+            let engine = crate::parakeet_engine::ParakeetEngine::new_with_models_dir(
+                crate::parakeet_engine::commands::get_models_directory(),
+            )?;
+            let available = engine.discover_models().await?.into_iter().any(|m| {
+                m.name == model_name
+                    && matches!(m.status, crate::parakeet_engine::ModelStatus::Available)
+            });
+            if !available {
+                return Err(anyhow!("Parakeet model '{}' is not downloaded", model_name));
+            }
+            engine.load_model(model_name).await?;
+            Arc::new(engine)
+        }
+    };
+
+    // Contiguous speech is transcribed as one stream of overlapping windows so no word is
+    // cut at a chunk edge; the merged words are then handed back to the pause-based ranges
+    let spans = contiguous_spans(ranges);
+    let windows: Vec<(usize, usize, usize)> = spans
+        .iter()
+        .enumerate()
+        .flat_map(|(span_index, &(span_start, span_end))| {
+            long_audio::plan_windows_16k(span_end - span_start)
+                .into_iter()
+                .map(move |(start, end)| (span_index, span_start + start, span_start + end))
+        })
+        .collect();
+
+    let monitor = SystemMonitor::new();
+    let total = windows.len();
+    let mut mergers: Vec<WindowMerger> = spans.iter().map(|_| WindowMerger::new()).collect();
+    let mut failed = 0;
+    reporter.report(RetranscriptionStage::Transcribing, None);
+    reporter.set_chunks(0, total);
+
+    for (index, &(span_index, start, end)) in windows.iter().enumerate() {
+        // Wait for headroom before each window
+        loop {
+            if reporter.is_cancelled() {
+                return Ok(None);
+            }
+            match monitor.check_resource_constraints().await {
+                Ok(status) if !status.can_proceed => {
+                    if reporter.progress.stage != RetranscriptionStage::Paused {
+                        reporter.report(
+                            RetranscriptionStage::Paused,
+                            status.get_primary_constraint(),
+                        );
+                    }
+                    tokio::time::sleep(RESOURCE_POLL_INTERVAL).await;
+                }
+                _ => break,
+            }
+        }
+        if reporter.progress.stage == RetranscriptionStage::Paused {
+            reporter.report(RetranscriptionStage::Transcribing, None);
+        }
+
+        let samples = audio[start..end].to_vec();
+        match engine.transcribe_audio_with_timestamps(samples).await {
+            Ok(result) => mergers[span_index].push(
+                samples_to_secs(start) as f32,
+                samples_to_secs(end) as f32,
+                result,
+            ),
+            Err(e) => {
+                warn!("🔁 Window {} could not be re-transcribed: {}", index, e);
+                failed += 1;
+            }
+        }
+        reporter.set_chunks(index + 1, total);
+    }
+    ensure_complete(total - failed, total, "windows")?;
+
+    let mut chunks = Vec::with_capacity(ranges.len());
+    for (merger, &(span_start, span_end)) in mergers.into_iter().zip(&spans) {
+        let merged = merger.finish();
+        let words = words_from_tokens(tokens_from_start_times(
+            &merged.tokens,
+            &merged.timestamps,
+            samples_to_secs(span_end),
+        ));
+        let span_ranges: Vec<(usize, usize)> = ranges
+            .iter()
+            .copied()
+            .filter(|&(start, end)| start >= span_start && end <= span_end)
+            .collect();
+        chunks.extend(words_into_ranges(words, &span_ranges));
+    }
+
+    Ok(Some(chunks))
+}
+
+/// Apply a hallucination filter verdict; false when the segment is dropped
+fn apply_verdict(verdict: Verdict, result: &mut TranscriptResult) -> bool {
+    match verdict {
+        Verdict::Keep => true,
+        Verdict::Rewrite { text, .. } => {
+            result.text = text;
+            result.words.clear();
+            true
+        }
+        Verdict::Drop(_) => false,
+    }
+}
+
+/// Fail the job when part of the recording has no transcript: saving would replace the
+/// meeting's segments with a transcript that has gaps
+fn ensure_complete(transcribed: usize, total: usize, unit: &str) -> Result<()> {
+    if transcribed < total {
+        return Err(anyhow!(
+            "{} of {} {} could not be re-transcribed; the existing transcript was kept",
+            total - transcribed,
+            total,
+            unit
+        ));
+    }
+    Ok(())
+}
+
+/// Join ranges that follow each other without a gap (silent chunks were dropped between
+/// the others)
+fn contiguous_spans(ranges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    for &(start, end) in ranges {
+        match spans.last_mut() {
+            Some(last) if last.1 == start => last.1 = end,
+            _ => spans.push((start, end)),
+        }
+    }
+    spans
+}
+
+/// Give each word (recording timeline) to the range it starts in, producing one chunk per
+/// range that received words. Chunk word timings are relative to the chunk start.
+fn words_into_ranges(words: Vec<WordTiming>, ranges: &[(usize, usize)]) -> Vec<ChunkTranscript> {
+    let mut grouped: Vec<Vec<WordTiming>> = vec![Vec::new(); ranges.len()];
+    for word in words {
+        let index = ranges
+            .iter()
+            .rposition(|&(start, _)| samples_to_secs(start) <= word.start)
+            .unwrap_or(0);
+        if let Some(group) = grouped.get_mut(index) {
+            group.push(word);
+        }
+    }
+
+    ranges
+        .iter()
+        .zip(grouped)
+        .filter(|(_, words)| !words.is_empty())
+        .map(|(&(start, end), words)| {
+            let start = samples_to_secs(start);
+            let text = words
+                .iter()
+                .map(|w| w.word.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            ChunkTranscript {
+                start,
+                end: samples_to_secs(end),
+                result: TranscriptResult {
+                    text,
+                    confidence: None,
+                    is_partial: false,
+                    words: offset_words(words, -start),
+                },
+            }
+        })
+        .collect()
+}
+
+fn samples_to_secs(samples: usize) -> f64 {
+    samples as f64 / SAMPLE_RATE as f64
+}
+
+/// Locate the saved recording of a meeting folder
+pub(crate) fn recording_audio_path(folder: &Path) -> Option<PathBuf> {
+    let from_metadata = std::fs::read_to_string(folder.join("metadata.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|metadata| metadata.get("audio_file")?.as_str().map(PathBuf::from))
+        .map(|path| {
+            if path.is_absolute() {
+                path
+            } else {
+                folder.join(path)
+            }
+        });
+
+    from_metadata
+        .into_iter()
+        .chain(std::iter::once(folder.join("audio.mp4")))
+        .find(|path| path.exists())
+}
+
+/// Decode a recording to 16 kHz mono f32 samples with FFmpeg
+pub(crate) fn decode_recording(path: &Path) -> Result<Vec<f32>> {
+    use std::process::{Command, Stdio};
+
+    let ffmpeg_path = crate::audio::ffmpeg::find_ffmpeg_path().ok_or_else(|| {
+        anyhow!("FFmpeg not found. Please install FFmpeg to re-transcribe recordings.")
+    })?;
+
+    let mut command = Command::new(ffmpeg_path);
+    command
+        .arg("-i")
+        .arg(path)
+        .args([
+            "-f",
+            "f32le",
+            "-ac",
+            "1",
+            "-ar",
+            &SAMPLE_RATE.to_string(),
+            "pipe:1",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // Hide console window on Windows
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!(
+            "FFmpeg failed to decode {}: {}",
+            path.display(),
+            stderr.lines().last().unwrap_or("unknown error")
+        ));
+    }
+
+    Ok(output
+        .stdout
+        .chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect())
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Split audio into chunks of at most `target_secs`, cutting each at the quietest 20ms
+/// frame within the last `search_secs` so words are not split across chunks.
+pub fn split_at_pauses(
+    audio: &[f32],
+    sample_rate: u32,
+    target_secs: f64,
+    search_secs: f64,
+) -> Vec<(usize, usize)> {
+    let target = (target_secs * sample_rate as f64) as usize;
+    let search = ((search_secs * sample_rate as f64) as usize).min(target);
+    let frame = (sample_rate as usize / 50).max(1);
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < audio.len() {
+        if target == 0 || audio.len() - start <= target {
+            ranges.push((start, audio.len()));
+            break;
+        }
+
+        let window_end = start + target;
+        let mut cut = window_end;
+        let mut quietest = f32::MAX;
+        let mut pos = window_end - search;
+        while pos + frame <= window_end {
+            let energy = rms(&audio[pos..pos + frame]);
+            if energy < quietest {
+                quietest = energy;
+                cut = pos + frame / 2;
+            }
+            pos += frame;
+        }
+
+        ranges.push((start, cut));
+        start = cut;
+    }
+    ranges
+}
+
+fn segment_span(segment: &MeetingTranscript) -> Option<(f64, f64)> {
+    let start = segment.audio_start_time?;
+    let end = segment
+        .audio_end_time
+        .or_else(|| segment.duration.map(|d| start + d))
+        .unwrap_or(start);
+    Some((start, end.max(start)))
+}
+
+/// Largest share of a new segment that may overlap user-edited segments before it is
+/// trimmed (or dropped when it has no word timings to trim by)
+const MAX_EDITED_OVERLAP_SHARE: f64 = 0.25;
+
+/// Keep the parts of a new segment outside user-edited spans. Segments overlapping them by
+/// at most `MAX_EDITED_OVERLAP_SHARE` are kept whole; others are cut at their words, one
+/// segment per run of words between edits.
+fn trim_around_edits(
+    segment: MeetingTranscript,
+    edited_spans: &[(f64, f64)],
+) -> Vec<MeetingTranscript> {
+    let Some((start, end)) = segment_span(&segment) else {
+        return vec![segment];
+    };
+    let inside_edit = |t: f64| edited_spans.iter().any(|&(s, e)| t >= s && t <= e);
+    let overlap: f64 = edited_spans
+        .iter()
+        .map(|&(s, e)| (end.min(e) - start.max(s)).max(0.0))
+        .sum();
+    let within_share = if end > start {
+        overlap <= (end - start) * MAX_EDITED_OVERLAP_SHARE
+    } else {
+        !inside_edit(start)
+    };
+    if within_share {
+        return vec![segment];
+    }
+
+    let mut runs: Vec<Vec<WordTiming>> = Vec::new();
+    let mut in_run = false;
+    for word in &segment.words {
+        if inside_edit((word.start + word.end) / 2.0) {
+            in_run = false;
+        } else {
+            if !in_run {
+                runs.push(Vec::new());
+                in_run = true;
+            }
+            if let Some(run) = runs.last_mut() {
+                run.push(word.clone());
+            }
+        }
+    }
+
+    runs.into_iter()
+        .enumerate()
+        .filter_map(|(i, words)| {
+            let (first, last) = (words.first()?, words.last()?);
+            let (run_start, run_end) = (first.start, last.end.max(first.start));
+            Some(MeetingTranscript {
+                id: if i == 0 {
+                    segment.id.clone()
+                } else {
+                    format!("transcript-{}", Uuid::new_v4())
+                },
+                text: words.iter().map(|w| w.word.as_str()).collect::<Vec<_>>().join(" "),
+                audio_start_time: Some(run_start),
+                audio_end_time: Some(run_end),
+                duration: Some(run_end - run_start),
+                words,
+                ..segment.clone()
+            })
+        })
+        .collect()
+}
+
+/// Translation for a new segment spanning `start..end`, joined from the current segments
+/// that lie mostly inside it (in the language of the first one)
+fn carried_translation(
+    start: f64,
+    end: f64,
+    current: &[MeetingTranscript],
+) -> Option<(String, Option<String>)> {
+    let mut sources: Vec<(f64, &MeetingTranscript)> = current
+        .iter()
+        .filter(|c| c.translation.as_deref().is_some_and(|t| !t.trim().is_empty()))
+        .filter_map(|c| {
+            let (s, e) = segment_span(c)?;
+            let inside = if e > s {
+                end.min(e) - start.max(s) > (e - s) / 2.0
+            } else {
+                s >= start && s <= end
+            };
+            inside.then_some((s, c))
+        })
+        .collect();
+    sources.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let language = sources.first()?.1.translation_language.clone();
+    let text = sources
+        .iter()
+        .filter(|(_, c)| c.translation_language == language)
+        .filter_map(|(_, c)| c.translation.as_deref())
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some((text, language))
+}
+
+/// Merge re-transcribed segments with the current transcript.
+///
+/// Segments the user edited are kept verbatim; new segments overlapping them are trimmed to
+/// the words outside the edits, or dropped. Kept new segments take the speaker label and wall-clock timestamp of the current
+/// segment they overlap most (or the nearest one), and the translations of the current
+/// segments lying mostly inside them.
+pub fn merge_segments(
+    new_segments: Vec<MeetingTranscript>,
+    current: &[MeetingTranscript],
+) -> Vec<MeetingTranscript> {
+    let edited: Vec<&MeetingTranscript> = current.iter().filter(|s| s.user_edited).collect();
+    let edited_spans: Vec<(f64, f64)> = edited.iter().filter_map(|s| segment_span(s)).collect();
+
+    let mut merged: Vec<MeetingTranscript> = new_segments
+        .into_iter()
+        .flat_map(|segment| trim_around_edits(segment, &edited_spans))
+        .map(|mut segment| {
+            if let Some((start, end)) = segment_span(&segment) {
+                let best = current
+                    .iter()
+                    .filter_map(|c| segment_span(c).map(|span| (c, span)))
+                    .map(|(c, (s, e))| {
+                        let overlap = end.min(e) - start.max(s);
+                        let distance = (start - s).abs();
+                        (c, overlap, distance)
+                    })
+                    .max_by(|a, b| a.1.total_cmp(&b.1).then(b.2.total_cmp(&a.2)));
+                if let Some((closest, overlap, _)) = best {
+                    if overlap > 0.0 && segment.speaker.is_none() {
+                        segment.speaker = closest.speaker.clone();
+                    }
+                    segment.timestamp = closest.timestamp.clone();
+                }
+                if let Some((translation, language)) = carried_translation(start, end, current) {
+                    segment.translation = Some(translation);
+                    segment.translation_language = language;
+                }
+            }
+            segment
+        })
+        .collect();
+
+    merged.extend(edited.into_iter().cloned());
+    // Untimed (legacy) segments sort first
+    merged.sort_by(|a, b| {
+        a.audio_start_time
+            .unwrap_or(f64::MIN)
+            .total_cmp(&b.audio_start_time.unwrap_or(f64::MIN))
+    });
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: f64, end: f64) -> MeetingTranscript {
+        MeetingTranscript {
+            id: format!("transcript-{}", text),
+            text: text.to_string(),
+            timestamp: format!("t{}", start),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            words: Vec::new(),
+            translation: None,
+            translation_language: None,
+            speaker: None,
+            user_edited: false,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn splits_at_the_quietest_point_before_the_target() {
+        let sample_rate = 100;
+        // 30s of "speech" with a silent gap at 17s
+        let mut audio = vec![0.5f32; 30 * sample_rate as usize];
+        for sample in &mut audio[1700..1720] {
+            *sample = 0.0;
+        }
+
+        let ranges = split_at_pauses(&audio, sample_rate, 20.0, 4.0);
+        assert_eq!(ranges.len(), 2);
+        assert!(ranges[0].1 >= 1700 && ranges[0].1 <= 1720);
+        assert_eq!(ranges[1], (ranges[0].1, audio.len()));
+    }
+
+    #[test]
+    fn hands_merged_words_back_to_the_ranges_they_start_in() {
+        let word = |w: &str, start: f64| WordTiming {
+            word: w.to_string(),
+            start,
+            end: start + 0.5,
+            probability: None,
+        };
+        // Two pause-based ranges of 16k samples (0-10s, 10-20s) with a gap before a third
+        let ranges = [(0, 160_000), (160_000, 320_000), (400_000, 480_000)];
+        assert_eq!(contiguous_spans(&ranges), vec![(0, 320_000), (400_000, 480_000)]);
+
+        let chunks = words_into_ranges(
+            vec![word("across", 9.75), word("the", 10.5), word("edge", 11.0)],
+            &ranges[..2],
+        );
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].result.text, "across");
+        assert_eq!(chunks[1].start, 10.0);
+        assert_eq!(chunks[1].result.text, "the edge");
+        assert_eq!(chunks[1].result.words[0].start, 0.5);
+    }
+
+    #[test]
+    fn fails_when_any_chunk_is_missing() {
+        assert!(ensure_complete(20, 20, "chunks").is_ok());
+
+        let error = ensure_complete(19, 20, "chunks").unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "1 of 20 chunks could not be re-transcribed; the existing transcript was kept"
+        );
+    }
+
+    #[test]
+    fn keeps_user_edits_and_replaces_overlapping_new_segments() {
+        let mut edited = segment("edited by hand", 10.0, 20.0);
+        edited.user_edited = true;
+        let current = vec![segment("old one", 0.0, 10.0), edited];
+
+        let merged = merge_segments(
+            vec![segment("new one", 0.0, 9.5), segment("new two", 11.0, 19.0)],
+            &current,
+        );
+
+        let texts: Vec<&str> = merged.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["new one", "edited by hand"]);
+        assert_eq!(merged[0].timestamp, "t0");
+    }
+
+    #[test]
+    fn trims_new_segments_partly_covered_by_edits() {
+        let word = |word: &str, start: f64, end: f64| WordTiming {
+            word: word.to_string(),
+            start,
+            end,
+            probability: None,
+        };
+        let mut edited = segment("edited by hand", 10.0, 20.0);
+        edited.user_edited = true;
+
+        let mut straddling = segment("so anyway edited words", 6.0, 16.0);
+        straddling.words = vec![
+            word("so", 6.0, 7.0),
+            word("anyway", 7.0, 9.0),
+            word("edited", 10.5, 13.0),
+            word("words", 13.0, 16.0),
+        ];
+        let untimed_overlap = segment("no word timings", 17.0, 26.0);
+        let grazing = segment("mostly after", 19.0, 30.0);
+
+        let merged = merge_segments(vec![straddling, untimed_overlap, grazing], &[edited]);
+
+        let texts: Vec<&str> = merged.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["so anyway", "edited by hand", "mostly after"]);
+        assert_eq!(merged[0].audio_end_time, Some(9.0));
+        assert_eq!(merged[0].words.len(), 2);
+    }
+
+    #[test]
+    fn carries_speaker_labels_to_overlapping_segments() {
+        let mut alice = segment("hello", 0.0, 6.0);
+        alice.speaker = Some("Alice".to_string());
+        let mut bob = segment("hi there", 6.0, 12.0);
+        bob.speaker = Some("Bob".to_string());
+
+        let merged = merge_segments(
+            vec![
+                segment("hello everyone", 0.0, 5.0),
+                segment("hi there how are you", 5.5, 12.0),
+            ],
+            &[alice, bob],
+        );
+
+        assert_eq!(merged[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!(merged[1].speaker.as_deref(), Some("Bob"));
+    }
+
+    #[test]
+    fn carries_translations_of_segments_inside_new_ones() {
+        let translated = |text: &str, start: f64, end: f64, translation: &str| {
+            let mut s = segment(text, start, end);
+            s.translation = Some(translation.to_string());
+            s.translation_language = Some("de".to_string());
+            s
+        };
+        let current = vec![
+            translated("hello", 0.0, 3.0, "hallo"),
+            translated("everyone", 3.0, 6.0, "zusammen"),
+            translated("see you", 20.0, 30.0, "bis dann"),
+        ];
+
+        let merged = merge_segments(
+            vec![
+                segment("hello everyone", 0.0, 6.5),
+                segment("right", 12.0, 22.0),
+            ],
+            &current,
+        );
+
+        assert_eq!(merged[0].translation.as_deref(), Some("hallo zusammen"));
+        assert_eq!(merged[0].translation_language.as_deref(), Some("de"));
+        // Only a fifth of "see you" falls inside the second segment
+        assert_eq!(merged[1].translation, None);
+    }
+}
@@ -0,0 +1,385 @@
+// audio/transcription/translation.rs
+//
+// Bilingual transcripts: a per-meeting translation track kept alongside the original.
+// Translating into English uses Whisper's built-in translate task on the same audio chunk;
+// any other target is translated from the segment text by a local LLM (Ollama). Results
+// are emitted as `transcript-translation` events keyed by the segment's sequence_id.
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::settings_store;
+use crate::summary::llm_client::{generate_summary, LLMProvider};
+
+/// Target handled by Whisper's translate task (Whisper can only translate into English)
+pub const WHISPER_TRANSLATION_TARGET: &str = "en";
+
+/// Translation mode for the next/current meeting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranslationSettings {
+    /// ISO 639-1 target language; `None` keeps the transcript monolingual
+    #[serde(default)]
+    pub target_language: Option<String>,
+    /// Ollama model for non-English targets; falls back to the summary model
+    /// when Ollama is the configured summary provider
+    #[serde(default)]
+    pub llm_model: Option<String>,
+}
+
+impl TranslationSettings {
+    /// Load settings from disk
+    pub fn load() -> Self {
+        settings_store::load("translation_settings.json", "translation settings")
+    }
+
+    /// Save settings to disk
+    pub fn save(&self) -> std::result::Result<(), String> {
+        settings_store::save(self, "translation_settings.json", "translation settings")
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.target_language.is_some()
+    }
+
+    /// Whether this meeting's translation can be produced by Whisper from the audio
+    pub fn uses_whisper(&self, engine_is_whisper: bool) -> bool {
+        engine_is_whisper && self.target_language.as_deref() == Some(WHISPER_TRANSLATION_TARGET)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslationMethod {
+    Whisper,
+    Llm,
+}
+
+/// Payload of the `transcript-translation` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslationUpdate {
+    pub sequence_id: u64,
+    pub text: String,
+    pub target_language: String,
+    pub method: TranslationMethod,
+}
+
+static TRANSLATION_SETTINGS: Lazy<RwLock<TranslationSettings>> =
+    Lazy::new(|| RwLock::new(TranslationSettings::load()));
+
+// LLM translations still running; drained when the recording stops so late results are
+// saved with the transcript
+static PENDING_LLM_TRANSLATIONS: Lazy<Mutex<Vec<JoinHandle<Option<TranslationUpdate>>>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+// Local LLMs handle one request at a time well; queueing keeps segments roughly in order
+static LLM_TRANSLATION_PERMITS: Lazy<Semaphore> = Lazy::new(|| Semaphore::new(1));
+
+// Only warn the user once per recording when no translation model is available
+static LLM_UNAVAILABLE_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Snapshot of the translation settings (taken once per recording by the transcription task)
+pub fn current_translation_settings() -> TranslationSettings {
+    TRANSLATION_SETTINGS.read().unwrap().clone()
+}
+
+/// Reset per-recording translation state when a new transcription session starts
+pub fn reset_translation_session() {
+    LLM_UNAVAILABLE_WARNED.store(false, Ordering::SeqCst);
+}
+
+#[tauri::command]
+pub async fn get_translation_settings() -> Result<TranslationSettings, String> {
+    Ok(current_translation_settings())
+}
+
+/// Set the translation mode. Takes effect for the next recording; a meeting keeps the
+/// mode it was started with.
+#[tauri::command]
+pub async fn set_translation_settings(settings: TranslationSettings) -> Result<(), String> {
+    let normalized = TranslationSettings {
+        target_language: settings
+            .target_language
+            .map(|code| code.trim().to_lowercase())
+            .filter(|code| !code.is_empty()),
+        llm_model: settings
+            .llm_model
+            .map(|model| model.trim().to_string())
+            .filter(|model| !model.is_empty()),
+    };
+
+    info!(
+        "🌐 Translation mode set to {:?} (LLM model: {:?})",
+        normalized.target_language, normalized.llm_model
+    );
+    normalized.save()?;
+    *TRANSLATION_SETTINGS
+        .write()
+        .map_err(|e| format!("Failed to set translation settings: {}", e))? = normalized;
+    Ok(())
+}
+
+/// Language to transcribe the original track in. "auto-translate" would replace the
+/// original with English, so bilingual meetings fall back to auto-detection instead.
+pub fn original_track_language(
+    language: Option<String>,
+    settings: &TranslationSettings,
+) -> Option<String> {
+    match language.as_deref() {
+        Some("auto-translate") if settings.is_enabled() => Some("auto".to_string()),
+        _ => language,
+    }
+}
+
+/// Human-readable name for prompts (falls back to the ISO code)
+fn language_name(code: &str) -> &str {
+    match code {
+        "en" => "English",
+        "de" => "German",
+        "es" => "Spanish",
+        "fr" => "French",
+        "it" => "Italian",
+        "pt" => "Portuguese",
+        "nl" => "Dutch",
+        "pl" => "Polish",
+        "ru" => "Russian",
+        "uk" => "Ukrainian",
+        "tr" => "Turkish",
+        "zh" => "Chinese",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "ar" => "Arabic",
+        "hi" => "Hindi",
+        other => other,
+    }
+}
+
+/// Build the (system, user) prompt pair for translating one transcript segment
+pub fn build_translation_prompt(text: &str, target_language: &str) -> (String, String) {
+    let target = language_name(target_language);
+    let system = format!(
+        "You are a professional meeting interpreter. Translate the user's transcript segment into {}. \
+         Preserve meaning, names and numbers. Reply with the translation only, without quotes, notes or explanations. \
+         If the segment is already in {}, repeat it unchanged.",
+        target, target
+    );
+    (system, text.to_string())
+}
+
+/// Strip wrapping quotes and "Translation:" style preambles some local models add
+pub fn clean_translation_output(output: &str) -> String {
+    let mut text = output.trim();
+    if let Some((prefix, rest)) = text.split_once(':') {
+        if prefix.trim().eq_ignore_ascii_case("translation") {
+            text = rest.trim();
+        }
+    }
+    text.trim_matches(|c| c == '"' || c == '“' || c == '”')
+        .trim()
+        .to_string()
+}
+
+/// Translate a 16 kHz speech chunk into English with Whisper's translate task
+pub async fn translate_audio_with_whisper(
+    engine: &crate::whisper_engine::WhisperEngine,
+    audio_16k: Vec<f32>,
+) -> Result<String> {
+    let (text, _confidence, _is_partial, _words) = engine
+        .transcribe_audio_with_confidence(audio_16k, Some("auto-translate".to_string()))
+        .await?;
+    Ok(text.trim().to_string())
+}
+
+/// Resolve the Ollama model and endpoint used for LLM translation
+async fn resolve_llm_model<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &TranslationSettings,
+) -> Result<(String, Option<String>)> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| anyhow!("Database not initialized"))?;
+    let config = SettingsRepository::get_model_config(state.db_manager.pool()).await?;
+    let endpoint = config.as_ref().and_then(|c| c.ollama_endpoint.clone());
+
+    if let Some(model) = &settings.llm_model {
+        return Ok((model.clone(), endpoint));
+    }
+    match config {
+        Some(c) if c.provider.eq_ignore_ascii_case("ollama") && !c.model.is_empty() => {
+            Ok((c.model, endpoint))
+        }
+        _ => Err(anyhow!(
+            "No local translation model configured. Choose an Ollama model in Language Settings."
+        )),
+    }
+}
+
+/// Wait up to `timeout` for the LLM translations still running and return their results.
+/// Translations not done by then are aborted.
+pub async fn drain_pending_translations(timeout: Duration) -> Vec<TranslationUpdate> {
+    let handles: Vec<_> = match PENDING_LLM_TRANSLATIONS.lock() {
+        Ok(mut pending) => pending.drain(..).collect(),
+        Err(_) => return Vec::new(),
+    };
+    if handles.is_empty() {
+        return Vec::new();
+    }
+
+    info!("🌐 Waiting for {} pending translations", handles.len());
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut updates = Vec::new();
+    for mut handle in handles {
+        match tokio::time::timeout_at(deadline, &mut handle).await {
+            Ok(Ok(Some(update))) => updates.push(update),
+            Ok(_) => {}
+            Err(_) => {
+                warn!("🌐 Translation still running at shutdown, dropping it");
+                handle.abort();
+            }
+        }
+    }
+    updates
+}
+
+/// Translate a segment's text with the local LLM in the background and emit the result
+pub fn spawn_llm_translation<R: Runtime>(
+    app: AppHandle<R>,
+    sequence_id: u64,
+    text: String,
+    settings: TranslationSettings,
+) {
+    let Some(target_language) = settings.target_language.clone() else {
+        return;
+    };
+
+    let handle = tokio::spawn(async move {
+        let Ok(_permit) = LLM_TRANSLATION_PERMITS.acquire().await else {
+            return None;
+        };
+
+        let (model, endpoint) = match resolve_llm_model(&app, &settings).await {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                if !LLM_UNAVAILABLE_WARNED.swap(true, Ordering::SeqCst) {
+                    warn!("🌐 Translation unavailable: {}", e);
+                    let _ = app.emit(
+                        "transcription-warning",
+                        format!("Translation unavailable: {}", e),
+                    );
+                }
+                return None;
+            }
+        };
+
+        let (system_prompt, user_prompt) = build_translation_prompt(&text, &target_language);
+        let client = reqwest::Client::new();
+        match generate_summary(
+            &client,
+            &LLMProvider::Ollama,
+            &model,
+            "",
+            &system_prompt,
+            &user_prompt,
+            endpoint.as_deref(),
+        )
+        .await
+        {
+            Ok(output) => {
+                let translated = clean_translation_output(&output);
+                if translated.is_empty() {
+                    return None;
+                }
+                let update = TranslationUpdate {
+                    sequence_id,
+                    text: translated,
+                    target_language,
+                    method: TranslationMethod::Llm,
+                };
+                if let Err(e) = app.emit("transcript-translation", &update) {
+                    warn!(
+                        "Failed to emit translation for segment {}: {}",
+                        sequence_id, e
+                    );
+                }
+                Some(update)
+            }
+            Err(e) => {
+                warn!(
+                    "🌐 LLM translation failed for segment {}: {}",
+                    sequence_id, e
+                );
+                None
+            }
+        }
+    });
+
+    if let Ok(mut pending) = PENDING_LLM_TRANSLATIONS.lock() {
+        pending.retain(|handle| !handle.is_finished());
+        pending.push(handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_target_uses_whisper_only_with_whisper_engine() {
+        let settings = TranslationSettings {
+            target_language: Some("en".to_string()),
+            llm_model: None,
+        };
+        assert!(settings.uses_whisper(true));
+        assert!(!settings.uses_whisper(false));
+
+        let german = TranslationSettings {
+            target_language: Some("de".to_string()),
+            llm_model: None,
+        };
+        assert!(!german.uses_whisper(true));
+    }
+
+    #[test]
+    fn bilingual_mode_keeps_original_language() {
+        let enabled = TranslationSettings {
+            target_language: Some("es".to_string()),
+            llm_model: None,
+        };
+        assert_eq!(
+            original_track_language(Some("auto-translate".to_string()), &enabled),
+            Some("auto".to_string())
+        );
+        assert_eq!(
+            original_track_language(Some("de".to_string()), &enabled),
+            Some("de".to_string())
+        );
+        assert_eq!(
+            original_track_language(
+                Some("auto-translate".to_string()),
+                &TranslationSettings::default()
+            ),
+            Some("auto-translate".to_string())
+        );
+    }
+
+    #[test]
+    fn cleans_llm_preambles_and_quotes() {
+        assert_eq!(
+            clean_translation_output("Translation: \"Hola a todos\""),
+            "Hola a todos"
+        );
+        assert_eq!(clean_translation_output("  Guten Morgen  "), "Guten Morgen");
+        assert_eq!(
+            clean_translation_output("Note: keep this"),
+            "Note: keep this"
+        );
+    }
+}
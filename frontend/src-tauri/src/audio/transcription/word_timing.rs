@@ -0,0 +1,160 @@
+// audio/transcription/word_timing.rs
+//
+// Word-level timing shared by all providers. Engines report sub-word tokens with their
+// own timings; these helpers merge them into words and shift them onto the recording
+// timeline so the UI can highlight words during playback.
+
+use serde::{Deserialize, Serialize};
+
+/// A single word with its timing (seconds) and optional recognition probability
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTiming {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probability: Option<f32>,
+}
+
+/// A sub-word token as reported by an engine (times relative to the chunk start)
+#[derive(Debug, Clone)]
+pub struct TokenTiming {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+    pub probability: Option<f32>,
+}
+
+/// Merge sub-word tokens into words.
+///
+/// A token starting with whitespace begins a new word; other tokens (word pieces,
+/// trailing punctuation) extend the current word. Word probability is the mean of
+/// its token probabilities when the engine provides them.
+pub fn words_from_tokens<I: IntoIterator<Item = TokenTiming>>(tokens: I) -> Vec<WordTiming> {
+    let mut words: Vec<WordTiming> = Vec::new();
+    let mut probability_sums: Vec<(f32, u32)> = Vec::new();
+
+    for token in tokens {
+        if token.text.trim().is_empty() {
+            continue;
+        }
+
+        let starts_word = token.text.starts_with(char::is_whitespace) || words.is_empty();
+        if starts_word {
+            words.push(WordTiming {
+                word: token.text.trim().to_string(),
+                start: token.start,
+                end: token.end.max(token.start),
+                probability: None,
+            });
+            probability_sums.push((0.0, 0));
+        } else if let Some(word) = words.last_mut() {
+            word.word.push_str(token.text.trim_end());
+            word.end = word.end.max(token.end);
+        }
+
+        if let (Some(p), Some(sum)) = (token.probability, probability_sums.last_mut()) {
+            sum.0 += p;
+            sum.1 += 1;
+        }
+    }
+
+    for (word, (sum, count)) in words.iter_mut().zip(probability_sums) {
+        if count > 0 {
+            word.probability = Some(sum / count as f32);
+        }
+    }
+
+    words
+}
+
+/// Build token timings from per-token start times only (e.g. Parakeet frame offsets):
+/// each token ends where the next one starts, the last one at `audio_duration`
+pub fn tokens_from_start_times(tokens: &[String], starts: &[f32], audio_duration: f64) -> Vec<TokenTiming> {
+    let count = tokens.len().min(starts.len());
+    (0..count)
+        .map(|i| {
+            let start = starts[i] as f64;
+            let end = if i + 1 < count {
+                starts[i + 1] as f64
+            } else {
+                audio_duration.max(start)
+            };
+            TokenTiming {
+                text: tokens[i].clone(),
+                start,
+                end,
+                probability: None,
+            }
+        })
+        .collect()
+}
+
+/// Shift chunk-relative word timings onto the recording timeline
+pub fn offset_words(words: Vec<WordTiming>, offset_seconds: f64) -> Vec<WordTiming> {
+    words
+        .into_iter()
+        .map(|mut word| {
+            word.start += offset_seconds;
+            word.end += offset_seconds;
+            word
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(text: &str, start: f64, end: f64, probability: Option<f32>) -> TokenTiming {
+        TokenTiming {
+            text: text.to_string(),
+            start,
+            end,
+            probability,
+        }
+    }
+
+    #[test]
+    fn merges_word_pieces_and_punctuation() {
+        let words = words_from_tokens(vec![
+            token(" Hel", 0.0, 0.2, Some(0.8)),
+            token("lo", 0.2, 0.4, Some(0.6)),
+            token(",", 0.4, 0.45, Some(1.0)),
+            token(" world", 0.5, 0.9, Some(0.9)),
+        ]);
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "Hello,");
+        assert_eq!(words[0].start, 0.0);
+        assert_eq!(words[0].end, 0.45);
+        assert!((words[0].probability.unwrap() - 0.8).abs() < 1e-6);
+        assert_eq!(words[1].word, "world");
+    }
+
+    #[test]
+    fn first_token_without_space_starts_a_word() {
+        let words = words_from_tokens(vec![token("Yes", 0.0, 0.3, None), token(" sir", 0.3, 0.6, None)]);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "Yes");
+        assert_eq!(words[0].probability, None);
+    }
+
+    #[test]
+    fn start_times_become_token_spans() {
+        let tokens = vec![" good".to_string(), " morning".to_string()];
+        let spans = tokens_from_start_times(&tokens, &[0.16, 0.48], 1.0);
+        assert_eq!(spans[0].end, 0.48);
+        assert_eq!(spans[1].end, 1.0);
+    }
+
+    #[test]
+    fn offsets_onto_recording_timeline() {
+        let words = offset_words(
+            vec![WordTiming { word: "hi".to_string(), start: 0.5, end: 0.75, probability: None }],
+            120.0,
+        );
+        assert_eq!(words[0].start, 120.5);
+        assert_eq!(words[0].end, 120.75);
+    }
+}
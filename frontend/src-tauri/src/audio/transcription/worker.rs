@@ -2,15 +2,33 @@
 //
 // Parallel transcription worker pool and chunk processing logic.
 
+use super::backlog::{self, DegradationLevel};
 use super::engine::TranscriptionEngine;
-use super::provider::TranscriptionError;
+use super::provider::{TranscriptResult, TranscriptionError};
+use super::word_timing::{offset_words, tokens_from_start_times, words_from_tokens, WordTiming};
+use super::confidence;
+use super::durable_queue;
+use super::glossary;
+use super::hallucination::{self, DuplicateTracker, Verdict};
+use super::partial;
+use super::reorder::ReorderBuffer;
+use super::translation::{
+    self, TranslationMethod, TranslationSettings, TranslationUpdate,
+};
+use crate::audio::hardware_detector::PerformanceTier;
 use crate::audio::AudioChunk;
 use log::{error, info, warn};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, Runtime};
 
+/// How often deferred chunks are released to the workers once the backlog has cleared
+const DEFERRED_RELEASE_INTERVAL: Duration = Duration::from_millis(500);
+
 // Sequence counter for transcript updates
 static SEQUENCE_COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -36,12 +54,111 @@ pub struct TranscriptUpdate {
     pub audio_start_time: f64, // Seconds from recording start (e.g., 125.3)
     pub audio_end_time: f64,   // Seconds from recording start (e.g., 128.6)
     pub duration: f64,          // Segment duration in seconds (e.g., 3.3)
+    // Word-level timings, recording-relative like audio_start_time (empty if the engine has none)
+    #[serde(default)]
+    pub words: Vec<WordTiming>,
+    // Stable per utterance (the chunk ID): a final result replaces the partials sharing its ID
+    #[serde(default)]
+    pub utterance_id: u64,
+}
+
+/// Live metrics of the transcription worker pool (queue depth and lag behind the recording)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TranscriptionMetrics {
+    pub is_running: bool,
+    pub worker_count: usize,
+    pub chunks_queued: u64,
+    pub chunks_completed: u64,
+    /// Chunks waiting for a worker or currently being transcribed
+    pub queue_depth: u64,
+    /// Finished chunks held back until earlier chunks complete (ordered emission)
+    pub reorder_buffer_depth: usize,
+    /// Recording-relative end time of the newest chunk handed to the workers
+    pub queued_audio_time: f64,
+    /// Recording-relative end time of the newest chunk emitted in order
+    pub emitted_audio_time: f64,
+    /// Seconds of recorded speech audio not yet emitted as transcript
+    pub lag_seconds: f64,
+    /// End-to-end lag of the last emitted chunk (handed to the workers → emitted)
+    pub chunk_lag_seconds: f64,
+    pub max_chunk_lag_seconds: f64,
+    /// Chunks parked while degraded, transcribed once the backlog clears
+    pub deferred_chunks: usize,
+    pub degradation_level: DegradationLevel,
+}
+
+static TRANSCRIPTION_METRICS: Lazy<RwLock<TranscriptionMetrics>> =
+    Lazy::new(|| RwLock::new(TranscriptionMetrics::default()));
+
+/// Snapshot of the current transcription metrics
+pub fn current_metrics() -> TranscriptionMetrics {
+    TRANSCRIPTION_METRICS.read().unwrap().clone()
+}
+
+pub(super) fn update_metrics<F: FnOnce(&mut TranscriptionMetrics)>(f: F) {
+    let mut metrics = TRANSCRIPTION_METRICS.write().unwrap();
+    f(&mut metrics);
+    metrics.queue_depth = metrics.chunks_queued.saturating_sub(metrics.chunks_completed);
+    metrics.lag_seconds = (metrics.queued_audio_time - metrics.emitted_audio_time).max(0.0);
+}
+
+#[tauri::command]
+pub async fn get_transcription_metrics() -> Result<TranscriptionMetrics, String> {
+    Ok(current_metrics())
+}
+
+/// Outcome of one chunk, passed from a worker to the ordered emitter
+struct ChunkResult {
+    sequence: u64,
+    utterance_id: u64,
+    audio_end_time: f64,
+    update: Option<TranscriptUpdate>,
+    /// Whisper translation of the same audio (bilingual meetings with an English target)
+    translation: Option<String>,
+    /// False if the engine could not process the chunk; it then stays in the durable queue
+    transcribed: bool,
+}
+
+/// Size the worker pool from available resources and the hardware tier
+async fn determine_worker_count(engine: &TranscriptionEngine) -> usize {
+    // Parakeet and ONNX ASR serialize inference behind a write lock, extra workers would only queue up
+    let is_serialized = match engine {
+        TranscriptionEngine::Parakeet(_) => true,
+        TranscriptionEngine::Provider(p) => matches!(p.provider_name(), "Parakeet" | "ONNX ASR"),
+        TranscriptionEngine::Whisper(_) => false,
+    };
+    if is_serialized {
+        return 1;
+    }
+
+    let profile = crate::audio::hardware_detector::HardwareProfile::detect();
+    let tier_cap = match profile.performance_tier {
+        PerformanceTier::Low => 1,
+        PerformanceTier::Medium => 2,
+        PerformanceTier::High => 3,
+        PerformanceTier::Ultra => 4,
+    };
+
+    let monitor = crate::whisper_engine::SystemMonitor::new();
+    let safe_count = match monitor.calculate_safe_worker_count().await {
+        Ok(count) => count,
+        Err(e) => {
+            warn!("Failed to calculate safe worker count, using 1 worker: {}", e);
+            1
+        }
+    };
+
+    safe_count.min(tier_cap).max(1)
 }
 
 // NOTE: get_transcript_history and get_recording_meeting_name functions
 // have been moved to recording_commands.rs where they have access to RECORDING_MANAGER
 
 /// Optimized parallel transcription task ensuring ZERO chunk loss
+///
+/// Chunks are transcribed by a pool of workers; results go through a reorder buffer
+/// keyed by dispatch sequence so `transcript-update` events are still emitted strictly
+/// in chronological order.
 pub fn start_transcription_task<R: Runtime>(
     app: AppHandle<R>,
     transcription_receiver: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
@@ -63,21 +180,95 @@ pub fn start_transcription_task<R: Runtime>(
             }
         };
 
+        // Translation mode is fixed for the whole meeting
+        let translation_settings = translation::current_translation_settings();
+        translation::reset_translation_session();
+        let whisper_translates =
+            translation_settings.uses_whisper(matches!(transcription_engine, TranscriptionEngine::Whisper(_)));
+        if let Some(target) = &translation_settings.target_language {
+            let method = if whisper_translates { "Whisper translate" } else { "local LLM" };
+            info!("🌐 Bilingual transcript enabled: translating to '{}' via {}", target, method);
+        }
+
+        // Partial hypotheses for long utterances, decoded while the workers are idle
+        partial::reset_partial_session();
+        let partial_handle = if partial::engine_supports_partials(&transcription_engine) {
+            let partial_engine = match &transcription_engine {
+                TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
+                TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
+                TranscriptionEngine::Provider(p) => TranscriptionEngine::Provider(p.clone()),
+            };
+            Some(partial::spawn_partial_task(
+                app.clone(),
+                partial_engine,
+                translation_settings.clone(),
+            ))
+        } else {
+            info!("✏️ Partial transcripts disabled for {}", transcription_engine.provider_name());
+            None
+        };
+
+        // Lag reporting and the optional degradation policy
+        backlog::reset_session();
+        let backlog_handle = backlog::spawn_backlog_monitor(
+            app.clone(),
+            match &transcription_engine {
+                TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
+                TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
+                TranscriptionEngine::Provider(p) => TranscriptionEngine::Provider(p.clone()),
+            },
+        );
+
         // Create parallel workers for faster processing while preserving ALL chunks
-        const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
-        let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
+        let num_workers = determine_worker_count(&transcription_engine).await;
+        let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<(u64, AudioChunk)>();
         let work_receiver = Arc::new(tokio::sync::Mutex::new(work_receiver));
+        let (result_sender, mut result_receiver) = tokio::sync::mpsc::unbounded_channel::<(u64, ChunkResult)>();
 
         // Track completion: AtomicU64 for chunks queued, AtomicU64 for chunks completed
         let chunks_queued = Arc::new(AtomicU64::new(0));
         let chunks_completed = Arc::new(AtomicU64::new(0));
         let input_finished = Arc::new(AtomicBool::new(false));
 
-        info!("📊 Starting {} transcription worker{} (serial mode for ordered emission)", NUM_WORKERS, if NUM_WORKERS == 1 { "" } else { "s" });
+        update_metrics(|m| {
+            *m = TranscriptionMetrics {
+                is_running: true,
+                worker_count: num_workers,
+                ..Default::default()
+            };
+        });
+
+        info!("📊 Starting {} transcription worker{} (ordered emission via reorder buffer)", num_workers, if num_workers == 1 { "" } else { "s" });
+
+        // Ordered emitter: releases worker results strictly in dispatch order
+        let emitter_app = app.clone();
+        let emitter_translation = translation_settings.clone();
+        let emitter_handle = tokio::spawn(async move {
+            let mut reorder_buffer: ReorderBuffer<ChunkResult> = ReorderBuffer::new();
+            let mut duplicates = DuplicateTracker::default();
+
+            while let Some((sequence, result)) = result_receiver.recv().await {
+                for ready in reorder_buffer.insert(sequence, Some(result)) {
+                    emit_in_order(&emitter_app, ready, &emitter_translation, whisper_translates, &mut duplicates);
+                }
+                let held_back = reorder_buffer.pending_len();
+                update_metrics(|m| m.reorder_buffer_depth = held_back);
+            }
+
+            // All workers are done; anything still held is behind a chunk that never reported
+            let leftovers = reorder_buffer.drain_all();
+            if !leftovers.is_empty() {
+                warn!("⚠️ Emitting {} transcript results held behind missing chunks", leftovers.len());
+            }
+            for ready in leftovers {
+                emit_in_order(&emitter_app, ready, &emitter_translation, whisper_translates, &mut duplicates);
+            }
+            update_metrics(|m| m.reorder_buffer_depth = 0);
+        });
 
         // Spawn worker tasks
         let mut worker_handles = Vec::new();
-        for worker_id in 0..NUM_WORKERS {
+        for worker_id in 0..num_workers {
             let engine_clone = match &transcription_engine {
                 TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
                 TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
@@ -85,9 +276,11 @@ pub fn start_transcription_task<R: Runtime>(
             };
             let app_clone = app.clone();
             let work_receiver_clone = work_receiver.clone();
+            let result_sender_clone = result_sender.clone();
             let chunks_completed_clone = chunks_completed.clone();
             let input_finished_clone = input_finished.clone();
             let chunks_queued_clone = chunks_queued.clone();
+            let translation_clone = translation_settings.clone();
 
             let worker_handle = tokio::spawn(async move {
                 info!("👷 Worker {} started", worker_id);
@@ -112,13 +305,13 @@ pub fn start_transcription_task<R: Runtime>(
 
                 loop {
                     // Try to get a chunk to process
-                    let chunk = {
+                    let work = {
                         let mut receiver = work_receiver_clone.lock().await;
                         receiver.recv().await
                     };
 
-                    match chunk {
-                        Some(chunk) => {
+                    match work {
+                        Some((sequence, chunk)) => {
                             // PERFORMANCE OPTIMIZATION: Reduce logging in hot path
                             // Only log every 10th chunk per worker to reduce I/O overhead
                             let should_log_this_chunk = chunk.chunk_id % 10 == 0;
@@ -132,135 +325,180 @@ pub fn start_transcription_task<R: Runtime>(
                                 );
                             }
 
-                            // Check if model is still loaded before processing
-                            if !engine_clone.is_model_loaded().await {
-                                warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
-                                // Still count as completed even if we can't process
-                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                continue;
-                            }
-
+                            let utterance_id = chunk.chunk_id;
                             let chunk_timestamp = chunk.timestamp;
                             let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
+                            let audio_end_time = chunk_timestamp + chunk_duration;
+                            let speech_energy = hallucination::peak_frame_rms(&chunk.data, chunk.sample_rate);
 
-                            // Transcribe with provider-agnostic approach
-                            match transcribe_chunk_with_provider(
-                                &engine_clone,
-                                chunk,
-                                &app_clone,
-                            )
-                            .await
-                            {
-                                Ok((transcript, confidence_opt, is_partial)) => {
-                                    // Provider-aware confidence threshold
-                                    let confidence_threshold = match &engine_clone {
-                                        TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
-                                        TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
-                                    };
+                            // Keep the audio for Whisper's translate pass (English bilingual track)
+                            let translation_audio = match &engine_clone {
+                                TranscriptionEngine::Whisper(whisper) if translation_clone.uses_whisper(true) => {
+                                    Some((whisper.clone(), chunk.data.clone(), chunk.sample_rate))
+                                }
+                                _ => None,
+                            };
 
-                                    let confidence_str = match confidence_opt {
-                                        Some(c) => format!("{:.2}", c),
-                                        None => "N/A".to_string(),
-                                    };
+                            // Keep the audio for a wider-beam re-decode if Whisper comes back unsure
+                            let redecode_audio = match &engine_clone {
+                                TranscriptionEngine::Whisper(whisper)
+                                    if confidence::current_settings().redecode_low_confidence && !backlog::is_degraded() =>
+                                {
+                                    Some((whisper.clone(), chunk.data.clone(), chunk.sample_rate))
+                                }
+                                _ => None,
+                            };
+
+                            // Check if model is still loaded before processing
+                            backlog::wait_for_model_switch().await;
+                            let mut transcribed = true;
+                            let update = if !engine_clone.is_model_loaded().await {
+                                warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
+                                transcribed = false;
+                                None
+                            } else {
+                                // Transcribe with provider-agnostic approach
+                                match transcribe_chunk_with_provider(
+                                    &engine_clone,
+                                    chunk,
+                                    &app_clone,
+                                    &translation_clone,
+                                )
+                                .await
+                                {
+                                    Ok(result) => {
+                                        let result = match redecode_audio {
+                                            Some((whisper, data, sample_rate)) => {
+                                                let audio = if sample_rate != 16000 {
+                                                    crate::audio::audio_processing::resample_audio(&data, sample_rate, 16000)
+                                                } else {
+                                                    data
+                                                };
+                                                let language = translation::original_track_language(
+                                                    crate::get_language_preference_internal(),
+                                                    &translation_clone,
+                                                );
+                                                confidence::redecode_if_low_confidence(&whisper, audio, language, result, utterance_id).await
+                                            }
+                                            None => result,
+                                        };
 
-                                    info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
-                                          worker_id, transcript, confidence_str, is_partial, confidence_threshold);
-
-                                    // Check confidence threshold (or accept if no confidence provided)
-                                    let meets_threshold = confidence_opt.map_or(true, |c| c >= confidence_threshold);
-
-                                    if !transcript.trim().is_empty() && meets_threshold {
-                                        // PERFORMANCE: Only log transcription results, not every processing step
-                                        info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
-                                              worker_id, transcript, confidence_str, is_partial);
-
-                                        // Emit speech-detected event for frontend UX (only on first detection per session)
-                                        // This is lightweight and provides better user feedback
-                                        let current_flag = SPEECH_DETECTED_EMITTED.load(Ordering::SeqCst);
-                                        info!("🔍 Checking speech-detected flag: current={}, will_emit={}", current_flag, !current_flag);
-
-                                        if !current_flag {
-                                            SPEECH_DETECTED_EMITTED.store(true, Ordering::SeqCst);
-                                            match app_clone.emit("speech-detected", serde_json::json!({
-                                                "message": "Speech activity detected"
-                                            })) {
-                                                Ok(_) => info!("🎤 ✅ First speech detected - successfully emitted speech-detected event"),
-                                                Err(e) => error!("🎤 ❌ Failed to emit speech-detected event: {}", e),
+                                        // Custom vocabulary corrections before the segment is emitted and saved
+                                        let TranscriptResult { text: transcript, confidence: confidence_opt, is_partial, mut words } =
+                                            glossary::correct_result(result);
+
+                                        // Drop phantom text (silence, sign-off phrases, loops) before it is emitted
+                                        let transcript = match hallucination::filter_segment(&transcript, speech_energy, confidence_opt, chunk_timestamp) {
+                                            Verdict::Keep => transcript,
+                                            Verdict::Rewrite { text, .. } => {
+                                                words.clear();
+                                                text
                                             }
-                                        } else {
-                                            info!("🔍 Speech already detected in this session, not re-emitting");
-                                        }
+                                            Verdict::Drop(_) => String::new(),
+                                        };
 
-                                        // Generate sequence ID and calculate timestamps FIRST
-                                        let sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
-                                        let audio_start_time = chunk_timestamp; // Already in seconds from recording start
-                                        let audio_end_time = chunk_timestamp + chunk_duration;
-
-                                        // Save structured transcript segment to recording manager (only final results)
-                                        // Save ALL segments (partial and final) to ensure complete JSON
-                                        // Create structured segment with full timestamp data
-                                        // NOTE: This is now handled via the transcript-update event emission below
-                                        // The recording_commands module listens to these events and saves them
-                                        // This decouples the transcription worker from direct RECORDING_MANAGER access
-
-                                        // Emit transcript update with NEW recording-relative timestamps
-
-                                        let update = TranscriptUpdate {
-                                            text: transcript,
-                                            timestamp: format_current_timestamp(), // Wall-clock for reference
-                                            source: "Audio".to_string(),
-                                            sequence_id,
-                                            chunk_start_time: chunk_timestamp, // Legacy compatibility
-                                            is_partial,
-                                            confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
-                                            // NEW: Recording-relative timestamps for sync
-                                            audio_start_time,
-                                            audio_end_time,
-                                            duration: chunk_duration,
+                                        // Provider-aware confidence threshold
+                                        let confidence_threshold = match &engine_clone {
+                                            TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
+                                            TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
                                         };
 
-                                        if let Err(e) = app_clone.emit("transcript-update", &update)
-                                        {
-                                            error!(
-                                                "Worker {}: Failed to emit transcript update: {}",
-                                                worker_id, e
-                                            );
+                                        let confidence_str = match confidence_opt {
+                                            Some(c) => format!("{:.2}", c),
+                                            None => "N/A".to_string(),
+                                        };
+
+                                        info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
+                                              worker_id, transcript, confidence_str, is_partial, confidence_threshold);
+
+                                        // Check confidence threshold (or accept if no confidence provided)
+                                        let meets_threshold = confidence_opt.map_or(true, |c| c >= confidence_threshold);
+
+                                        if !transcript.trim().is_empty() && meets_threshold {
+                                            // PERFORMANCE: Only log transcription results, not every processing step
+                                            info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
+                                                  worker_id, transcript, confidence_str, is_partial);
+
+                                            // Sequence ID is assigned by the ordered emitter
+                                            Some(TranscriptUpdate {
+                                                text: transcript,
+                                                timestamp: format_current_timestamp(), // Wall-clock for reference
+                                                source: "Audio".to_string(),
+                                                sequence_id: 0,
+                                                chunk_start_time: chunk_timestamp, // Legacy compatibility
+                                                is_partial,
+                                                confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
+                                                // NEW: Recording-relative timestamps for sync
+                                                audio_start_time: chunk_timestamp, // Already in seconds from recording start
+                                                audio_end_time,
+                                                duration: chunk_duration,
+                                                words: offset_words(words, chunk_timestamp),
+                                                utterance_id,
+                                            })
+                                        } else {
+                                            if !transcript.trim().is_empty() && should_log_this_chunk {
+                                                // PERFORMANCE: Only log low-confidence results occasionally
+                                                if let Some(c) = confidence_opt {
+                                                    info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
+                                                }
+                                            }
+                                            None
                                         }
-                                        // PERFORMANCE: Removed verbose logging of every emission
-                                    } else if !transcript.trim().is_empty() && should_log_this_chunk
-                                    {
-                                        // PERFORMANCE: Only log low-confidence results occasionally
-                                        if let Some(c) = confidence_opt {
-                                            info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
+                                    }
+                                    Err(e) => {
+                                        // Improved error handling with specific cases
+                                        match e {
+                                            TranscriptionError::AudioTooShort { .. } => {
+                                                // Skip silently, this is expected for very short chunks
+                                                info!("Worker {}: {}", worker_id, e);
+                                            }
+                                            TranscriptionError::ModelNotLoaded => {
+                                                warn!("Worker {}: Model unloaded during transcription", worker_id);
+                                                transcribed = false;
+                                            }
+                                            _ => {
+                                                // Reported to the UI by transcribe_chunk_with_provider
+                                                warn!("Worker {}: Transcription failed: {}", worker_id, e);
+                                                transcribed = false;
+                                            }
                                         }
+                                        None
                                     }
                                 }
-                                Err(e) => {
-                                    // Improved error handling with specific cases
-                                    match e {
-                                        TranscriptionError::AudioTooShort { .. } => {
-                                            // Skip silently, this is expected for very short chunks
-                                            info!("Worker {}: {}", worker_id, e);
-                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                            continue;
-                                        }
-                                        TranscriptionError::ModelNotLoaded => {
-                                            warn!("Worker {}: Model unloaded during transcription", worker_id);
-                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                            continue;
-                                        }
-                                        _ => {
-                                            warn!("Worker {}: Transcription failed: {}", worker_id, e);
-                                            let _ = app_clone.emit("transcription-warning", e.to_string());
+                            };
+
+                            let translation = match (&update, translation_audio) {
+                                (Some(_), Some((whisper, data, sample_rate))) => {
+                                    let audio_16k = if sample_rate != 16000 {
+                                        crate::audio::audio_processing::resample_audio(&data, sample_rate, 16000)
+                                    } else {
+                                        data
+                                    };
+                                    match translation::translate_audio_with_whisper(&whisper, audio_16k).await {
+                                        Ok(text) if !text.is_empty() => Some(text),
+                                        Ok(_) => None,
+                                        Err(e) => {
+                                            warn!("Worker {}: Whisper translation failed: {}", worker_id, e);
+                                            None
                                         }
                                     }
                                 }
+                                _ => None,
+                            };
+
+                            // Every chunk reports back (even without text) so the reorder buffer can advance
+                            if result_sender_clone
+                                .send((sequence, ChunkResult { sequence, utterance_id, audio_end_time, update, translation, transcribed }))
+                                .is_err()
+                            {
+                                error!("❌ Worker {}: ordered emitter closed, dropping result for sequence {}", worker_id, sequence);
                             }
 
                             // Mark chunk as completed
                             let completed =
                                 chunks_completed_clone.fetch_add(1, Ordering::SeqCst) + 1;
                             let queued = chunks_queued_clone.load(Ordering::SeqCst);
+                            update_metrics(|m| m.chunks_completed = completed);
 
                             // PERFORMANCE: Only log progress every 5th chunk to reduce I/O overhead
                             if completed % 5 == 0 || should_log_this_chunk {
@@ -279,12 +517,15 @@ pub fn start_transcription_task<R: Runtime>(
                             } else {
                                 100
                             };
+                            let metrics = current_metrics();
 
                             let _ = app_clone.emit("transcription-progress", serde_json::json!({
                                 "worker_id": worker_id,
                                 "chunks_completed": completed,
                                 "chunks_queued": queued,
                                 "progress_percentage": progress_percentage,
+                                "queue_depth": metrics.queue_depth,
+                                "lag_seconds": metrics.lag_seconds,
                                 "message": format!("Worker {} processing... ({}/{})", worker_id, completed, queued)
                             }));
                         }
@@ -320,28 +561,113 @@ pub fn start_transcription_task<R: Runtime>(
             worker_handles.push(worker_handle);
         }
 
+        // Only workers hold result senders now, so the emitter ends once they all finish
+        drop(result_sender);
+
         // Main dispatcher: receive chunks and distribute to workers
         let mut receiver = transcription_receiver;
-        while let Some(chunk) = receiver.recv().await {
+        let mut dispatch_sequence: u64 = 0;
+        let mut dispatch = |chunk: AudioChunk| -> bool {
             let queued = chunks_queued.fetch_add(1, Ordering::SeqCst) + 1;
             info!(
-                "📥 Dispatching chunk {} to workers (total queued: {})",
-                chunk.chunk_id, queued
+                "📥 Dispatching chunk {} to workers (sequence {}, total queued: {})",
+                chunk.chunk_id, dispatch_sequence, queued
             );
 
-            if let Err(_) = work_sender.send(chunk) {
+            let chunk_end_time = chunk.timestamp + chunk.data.len() as f64 / chunk.sample_rate.max(1) as f64;
+            update_metrics(|m| {
+                m.chunks_queued = queued;
+                m.queued_audio_time = m.queued_audio_time.max(chunk_end_time);
+            });
+
+            backlog::chunk_dispatched(dispatch_sequence);
+            if work_sender.send((dispatch_sequence, chunk)).is_err() {
                 error!("❌ Failed to send chunk to workers - this should not happen!");
-                break;
+                return false;
+            }
+            dispatch_sequence += 1;
+            true
+        };
+
+        // Chunks parked by the degradation policy, oldest first. Once the policy stops
+        // deferring they are released on a timer, no more than the workers have room for,
+        // so they drain even when no new chunk arrives and never flood the queue again.
+        let mut deferred: VecDeque<AudioChunk> = VecDeque::new();
+        let mut releasing = false;
+        let mut release_timer = tokio::time::interval(DEFERRED_RELEASE_INTERVAL);
+        release_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        'receive: loop {
+            tokio::select! {
+                received = receiver.recv() => {
+                    let Some(chunk) = received else {
+                        break 'receive;
+                    };
+                    // Journaled until emitted, so a restart can still transcribe it from the saved audio
+                    durable_queue::chunk_received(&chunk);
+
+                    // New chunks wait behind the deferred ones to keep the dispatch order
+                    if backlog::is_deferring() || !deferred.is_empty() {
+                        deferred.push_back(chunk);
+                        let count = deferred.len();
+                        update_metrics(|m| m.deferred_chunks = count);
+                        continue;
+                    }
+                    if !dispatch(chunk) {
+                        break 'receive;
+                    }
+                }
+                _ = release_timer.tick(), if !deferred.is_empty() => {
+                    if backlog::is_deferring() {
+                        releasing = false;
+                        continue;
+                    }
+                    if !releasing {
+                        info!("⏱️ Backlog cleared, transcribing {} deferred chunks", deferred.len());
+                        releasing = true;
+                    }
+
+                    let in_flight = chunks_queued
+                        .load(Ordering::SeqCst)
+                        .saturating_sub(chunks_completed.load(Ordering::SeqCst));
+                    let room = (num_workers as u64).saturating_sub(in_flight);
+                    for _ in 0..room {
+                        let Some(chunk) = deferred.pop_front() else {
+                            break;
+                        };
+                        if !dispatch(chunk) {
+                            break 'receive;
+                        }
+                    }
+                    let count = deferred.len();
+                    update_metrics(|m| m.deferred_chunks = count);
+                }
             }
         }
 
+        // Post-meeting processing of whatever was still deferred when the recording ended
+        if !deferred.is_empty() {
+            info!("⏱️ Recording ended, transcribing {} deferred chunks", deferred.len());
+            for chunk in deferred.drain(..) {
+                if !dispatch(chunk) {
+                    break;
+                }
+            }
+            update_metrics(|m| m.deferred_chunks = 0);
+        }
+
         // Signal that input is finished
         input_finished.store(true, Ordering::SeqCst);
+
+        // No utterance is in progress anymore; the remaining finals supersede any partial
+        if let Some(handle) = partial_handle {
+            handle.abort();
+        }
+        partial::clear_in_progress();
         drop(work_sender); // Close the channel to signal workers
 
         let total_chunks_queued = chunks_queued.load(Ordering::SeqCst);
         info!("📭 Input finished with {} total chunks queued. Waiting for all {} workers to complete...",
-              total_chunks_queued, NUM_WORKERS);
+              total_chunks_queued, num_workers);
 
         // Emit final chunk count to frontend
         let _ = app.emit("transcription-queue-complete", serde_json::json!({
@@ -358,6 +684,11 @@ pub fn start_transcription_task<R: Runtime>(
             }
         }
 
+        // Wait for the ordered emitter to flush the remaining transcripts
+        if let Err(e) = emitter_handle.await {
+            error!("❌ Ordered transcript emitter panicked: {:?}", e);
+        }
+
         // Final verification with retry logic to catch any stragglers
         let mut verification_attempts = 0;
         const MAX_VERIFICATION_ATTEMPTS: u32 = 10;
@@ -399,17 +730,153 @@ pub fn start_transcription_task<R: Runtime>(
             }
         }
 
+        // Leave the engine with the configured model for the next recording
+        backlog_handle.abort();
+        backlog::restore_model(&transcription_engine).await;
+        backlog::reset_session();
+        durable_queue::end_session();
+
+        update_metrics(|m| {
+            m.is_running = false;
+            m.degradation_level = DegradationLevel::Normal;
+        });
         info!("✅ Parallel transcription task completed - all workers finished, ready for model unload");
     })
 }
 
-/// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
-/// Returns: (text, confidence Option, is_partial)
-async fn transcribe_chunk_with_provider<R: Runtime>(
+/// Emit one chunk result released by the reorder buffer (called in chronological order)
+fn emit_in_order<R: Runtime>(
+    app: &AppHandle<R>,
+    result: ChunkResult,
+    translation_settings: &TranslationSettings,
+    whisper_translates: bool,
+    duplicates: &mut DuplicateTracker,
+) {
+    let chunk_lag = backlog::chunk_emitted(result.sequence);
+    update_metrics(|m| {
+        m.emitted_audio_time = m.emitted_audio_time.max(result.audio_end_time);
+        if let Some(lag) = chunk_lag {
+            m.chunk_lag_seconds = lag;
+            m.max_chunk_lag_seconds = m.max_chunk_lag_seconds.max(lag);
+        }
+    });
+
+    // From here on no partial of this utterance is emitted
+    let partial_shown = partial::finalize_utterance(result.utterance_id);
+
+    // Near-duplicates of the previous segment can only be judged in emission order
+    let update = result.update.and_then(|mut update| {
+        match duplicates.filter(&update.text, update.audio_start_time) {
+            Verdict::Keep => {}
+            Verdict::Rewrite { text, .. } => {
+                update.text = text;
+                update.words.clear();
+            }
+            Verdict::Drop(_) => return None,
+        }
+        Some(update)
+    });
+
+    let Some(mut update) = update else {
+        if result.transcribed {
+            durable_queue::chunk_finished(result.utterance_id);
+        }
+        // The final was empty, rejected or a duplicate: drop the partial the UI still shows
+        if partial_shown {
+            let _ = app.emit(
+                "transcript-partial-discarded",
+                serde_json::json!({ "utterance_id": result.utterance_id }),
+            );
+        }
+        return;
+    };
+
+    // Emit speech-detected event for frontend UX (only on first detection per session)
+    // This is lightweight and provides better user feedback
+    let current_flag = SPEECH_DETECTED_EMITTED.load(Ordering::SeqCst);
+    info!("🔍 Checking speech-detected flag: current={}, will_emit={}", current_flag, !current_flag);
+
+    if !current_flag {
+        SPEECH_DETECTED_EMITTED.store(true, Ordering::SeqCst);
+        match app.emit("speech-detected", serde_json::json!({
+            "message": "Speech activity detected"
+        })) {
+            Ok(_) => info!("🎤 ✅ First speech detected - successfully emitted speech-detected event"),
+            Err(e) => error!("🎤 ❌ Failed to emit speech-detected event: {}", e),
+        }
+    } else {
+        info!("🔍 Speech already detected in this session, not re-emitting");
+    }
+
+    // Sequence IDs are assigned at emission time so they follow chronological order
+    update.sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
+
+    // Saving the structured segment is handled by the transcript-update listener in
+    // recording_commands, which decouples the workers from RECORDING_MANAGER
+    // and removes the chunk from the durable queue once it is written to transcripts.json
+    if let Err(e) = app.emit("transcript-update", &update) {
+        error!("Failed to emit transcript update {}: {}", update.sequence_id, e);
+    }
+
+    // Bilingual meetings: the translation track follows its segment by sequence_id
+    let Some(target_language) = translation_settings.target_language.clone() else {
+        return;
+    };
+    match result.translation {
+        Some(text) => {
+            let translation_update = TranslationUpdate {
+                sequence_id: update.sequence_id,
+                text,
+                target_language,
+                method: TranslationMethod::Whisper,
+            };
+            if let Err(e) = app.emit("transcript-translation", &translation_update) {
+                error!("Failed to emit translation {}: {}", update.sequence_id, e);
+            }
+        }
+        None if !whisper_translates => {
+            translation::spawn_llm_translation(
+                app.clone(),
+                update.sequence_id,
+                update.text,
+                translation_settings.clone(),
+            );
+        }
+        // Whisper target but no translation produced (failure already logged)
+        None => {}
+    }
+}
+
+/// Transcribe a chunk for the transcript, reporting engine failures to the UI as
+/// `transcription-warning`: the chunk is skipped but the pipeline keeps running, so
+/// `transcription-error` stays reserved for failures that stop it
+pub(super) async fn transcribe_chunk_with_provider<R: Runtime>(
     engine: &TranscriptionEngine,
     chunk: AudioChunk,
     app: &AppHandle<R>,
-) -> std::result::Result<(String, Option<f32>, bool), TranscriptionError> {
+    translation_settings: &TranslationSettings,
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
+    let result = transcribe_chunk(engine, chunk, translation_settings).await;
+    match &result {
+        // Expected for very short chunks, skipped silently by the callers
+        Err(TranscriptionError::AudioTooShort { .. }) | Ok(_) => {}
+        Err(e) => {
+            let _ = app.emit(
+                "transcription-warning",
+                format!("Transcription failed: {}", e),
+            );
+        }
+    }
+    result
+}
+
+/// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
+/// Returns the provider result with word timings relative to the chunk start
+pub(super) async fn transcribe_chunk(
+    engine: &TranscriptionEngine,
+    chunk: AudioChunk,
+    translation_settings: &TranslationSettings,
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
     // Convert to 16kHz mono for transcription
     let transcription_data = if chunk.sample_rate != 16000 {
         crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
@@ -445,52 +912,60 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     // Transcribe using the appropriate engine (with improved error handling)
     match engine {
         TranscriptionEngine::Whisper(whisper_engine) => {
-            // Get language preference from global state
-            let language = crate::get_language_preference_internal();
+            // Get language preference from global state (bilingual meetings keep the original language)
+            let language = translation::original_track_language(
+                crate::get_language_preference_internal(),
+                translation_settings,
+            );
 
             match whisper_engine
                 .transcribe_audio_with_confidence(speech_samples, language)
                 .await
             {
-                Ok((text, confidence, is_partial)) => {
+                Ok((text, confidence, is_partial, words)) => {
                     let cleaned_text = text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), Some(confidence), is_partial));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            confidence: Some(confidence),
+                            is_partial,
+                            words: Vec::new(),
+                        });
                     }
 
                     info!(
-                        "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {})",
-                        chunk.chunk_id, cleaned_text, confidence, is_partial
+                        "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {}, {} words timed)",
+                        chunk.chunk_id, cleaned_text, confidence, is_partial, words.len()
                     );
 
-                    Ok((cleaned_text, Some(confidence), is_partial))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: Some(confidence),
+                        is_partial,
+                        words,
+                    })
                 }
                 Err(e) => {
                     error!(
                         "Whisper transcription failed for chunk {}: {}",
                         chunk.chunk_id, e
                     );
-
-                    let transcription_error = TranscriptionError::EngineFailed(e.to_string());
-                    let _ = app.emit(
-                        "transcription-error",
-                        &serde_json::json!({
-                            "error": transcription_error.to_string(),
-                            "userMessage": format!("Transcription failed: {}", transcription_error),
-                            "actionable": false
-                        }),
-                    );
-
-                    Err(transcription_error)
+                    Err(TranscriptionError::EngineFailed(e.to_string()))
                 }
             }
         }
         TranscriptionEngine::Parakeet(parakeet_engine) => {
-            match parakeet_engine.transcribe_audio(speech_samples).await {
-                Ok(text) => {
-                    let cleaned_text = text.trim().to_string();
+            let audio_duration = speech_samples.len() as f64 / 16000.0;
+            match parakeet_engine.transcribe_audio_with_timestamps(speech_samples).await {
+                Ok(result) => {
+                    let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), None, false));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            confidence: None,
+                            is_partial: false,
+                            words: Vec::new(),
+                        });
                     }
 
                     info!(
@@ -498,38 +973,43 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         chunk.chunk_id, cleaned_text
                     );
 
-                    // Parakeet doesn't provide confidence or partial results
-                    Ok((cleaned_text, None, false))
+                    // Parakeet doesn't provide confidence or partial results, only token start times
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: None,
+                        is_partial: false,
+                        words: words_from_tokens(tokens_from_start_times(
+                            &result.tokens,
+                            &result.timestamps,
+                            audio_duration,
+                        )),
+                    })
                 }
                 Err(e) => {
                     error!(
                         "Parakeet transcription failed for chunk {}: {}",
                         chunk.chunk_id, e
                     );
-
-                    let transcription_error = TranscriptionError::EngineFailed(e.to_string());
-                    let _ = app.emit(
-                        "transcription-error",
-                        &serde_json::json!({
-                            "error": transcription_error.to_string(),
-                            "userMessage": format!("Transcription failed: {}", transcription_error),
-                            "actionable": false
-                        }),
-                    );
-
-                    Err(transcription_error)
+                    Err(TranscriptionError::EngineFailed(e.to_string()))
                 }
             }
         }
         TranscriptionEngine::Provider(provider) => {
             // NEW: Trait-based provider (clean, unified interface)
-            let language = crate::get_language_preference_internal();
+            let language = translation::original_track_language(
+                crate::get_language_preference_internal(),
+                translation_settings,
+            );
 
             match provider.transcribe(speech_samples, language).await {
                 Ok(result) => {
                     let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), result.confidence, result.is_partial));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            words: Vec::new(),
+                            ..result
+                        });
                     }
 
                     let confidence_str = match result.confidence {
@@ -546,7 +1026,10 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         result.is_partial
                     );
 
-                    Ok((cleaned_text, result.confidence, result.is_partial))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        ..result
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -555,16 +1038,6 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         chunk.chunk_id,
                         e
                     );
-
-                    let _ = app.emit(
-                        "transcription-error",
-                        &serde_json::json!({
-                            "error": e.to_string(),
-                            "userMessage": format!("Transcription failed: {}", e),
-                            "actionable": false
-                        }),
-                    );
-
                     Err(e)
                 }
             }
@@ -573,7 +1046,7 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
 }
 
 /// Format current timestamp (wall-clock time)
-fn format_current_timestamp() -> String {
+pub(super) fn format_current_timestamp() -> String {
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default();
@@ -24,6 +24,8 @@ pub struct ContinuousVadProcessor {
     in_speech: bool,
     processed_samples: usize,
     speech_start_sample: usize,
+    // Start of the open utterance as reported by Silero (ms from session start)
+    speech_start_ms: f64,
     // State tracking for smart logging
     last_logged_state: bool,
 }
@@ -77,6 +79,7 @@ impl ContinuousVadProcessor {
             in_speech: false,
             processed_samples: 0,
             speech_start_sample: 0,
+            speech_start_ms: 0.0,
             // Initialize state tracking
             last_logged_state: false,
         })
@@ -109,6 +112,16 @@ impl ContinuousVadProcessor {
         Ok(completed_segments)
     }
 
+    /// Speech of the utterance that is still open (16kHz) and its start in ms, if any
+    /// Used for partial transcripts before VAD closes the segment
+    pub fn in_progress_speech(&self) -> Option<(&[f32], f64)> {
+        if self.in_speech && !self.current_speech.is_empty() {
+            Some((&self.current_speech, self.speech_start_ms))
+        } else {
+            None
+        }
+    }
+
     /// Improved resampling from input sample rate to 16kHz with anti-aliasing
     /// Uses linear interpolation and basic low-pass filtering for better quality
     fn resample_to_16k(&self, samples: &[f32]) -> Result<Vec<f32>> {
@@ -217,6 +230,7 @@ impl ContinuousVadProcessor {
                     }
                     self.in_speech = true;
                     self.speech_start_sample = self.processed_samples + (timestamp_ms * self.sample_rate as usize / 1000);
+                    self.speech_start_ms = timestamp_ms as f64;
                     self.current_speech.clear();
                 }
                 VadTransition::SpeechEnd { start_timestamp_ms, end_timestamp_ms, samples } => {
@@ -0,0 +1,409 @@
+// custom_models.rs
+//
+// User-imported models, such as fine-tuned ggml Whisper files or Parakeet ONNX exports.
+// Each engine keeps a `custom_models.json` registry in its models directory. Discovery
+// lists these entries next to the built-in models so they can be selected the same way.
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::model_download::{self, ManifestEntry, ModelManifest, ResumableDownload};
+
+const REGISTRY_FILE: &str = "custom_models.json";
+const SOURCE_MANIFEST_FILE: &str = "manifest.json";
+const MAX_NAME_LEN: usize = 64;
+
+/// A model imported by the user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomModelEntry {
+    pub name: String,
+    /// File (Whisper) or directory (Parakeet) inside the engine's models directory
+    pub path: String,
+    #[serde(default)]
+    pub description: String,
+    /// Path or URL the model was imported from
+    pub source: String,
+    pub imported_at: String,
+    /// Parakeet only: int8-quantized export
+    #[serde(default)]
+    pub quantized: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomModelRegistry {
+    #[serde(default)]
+    pub models: Vec<CustomModelEntry>,
+}
+
+impl CustomModelRegistry {
+    /// Load the registry of a models directory (empty if there is none yet)
+    pub fn load(models_dir: &Path) -> Self {
+        let path = models_dir.join(REGISTRY_FILE);
+        if !path.exists() {
+            return Self::default();
+        }
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|c| serde_json::from_str(&c).map_err(|e| e.to_string()))
+        {
+            Ok(registry) => registry,
+            Err(e) => {
+                warn!("Failed to read custom model registry {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self, models_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(models_dir)?;
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(models_dir.join(REGISTRY_FILE), contents)
+            .map_err(|e| anyhow!("Failed to save custom model registry: {}", e))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CustomModelEntry> {
+        self.models.iter().find(|m| m.name == name)
+    }
+
+    pub fn insert(&mut self, entry: CustomModelEntry) {
+        self.models.retain(|m| m.name != entry.name);
+        self.models.push(entry);
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<CustomModelEntry> {
+        let index = self.models.iter().position(|m| m.name == name)?;
+        Some(self.models.remove(index))
+    }
+}
+
+/// Check a user-provided model name. Names become file names and are used as model IDs
+/// in settings, so only letters, digits, '-', '_' and '.' are allowed.
+pub fn validate_model_name(name: &str, taken: &[String]) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        return Err(anyhow!(
+            "Model name must be 1 to {} characters long",
+            MAX_NAME_LEN
+        ));
+    }
+    if name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(anyhow!(
+            "Model name may only contain letters, digits, '-', '_' and '.'"
+        ));
+    }
+    if taken.iter().any(|t| t.eq_ignore_ascii_case(name)) {
+        return Err(anyhow!("A model named '{}' already exists", name));
+    }
+    Ok(name.to_string())
+}
+
+/// Where an imported model comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportSource {
+    Path(PathBuf),
+    Url(String),
+}
+
+impl ImportSource {
+    pub fn parse(source: &str) -> Result<Self> {
+        let source = source.trim();
+        if source.starts_with("http://") || source.starts_with("https://") {
+            return Ok(Self::Url(source.trim_end_matches('/').to_string()));
+        }
+        let path = PathBuf::from(source);
+        if !path.exists() {
+            return Err(anyhow!("'{}' does not exist", source));
+        }
+        Ok(Self::Path(path))
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Path(path) => path.display().to_string(),
+            Self::Url(url) => url.clone(),
+        }
+    }
+}
+
+/// Copy a local file into place through a `.part` file, so a failed copy leaves nothing
+/// that discovery could mistake for a model
+pub async fn copy_file(src: &Path, dest: &Path) -> Result<()> {
+    copy_verified_file(src, dest, None, false).await
+}
+
+/// `copy_file`, checking the copy against `expected` before it is moved into place
+pub async fn copy_verified_file(
+    src: &Path,
+    dest: &Path,
+    expected: Option<&ManifestEntry>,
+    require_checksum: bool,
+) -> Result<()> {
+    let part = model_download::partial_path(dest);
+    fs::copy(src, &part)
+        .await
+        .map_err(|e| anyhow!("Failed to copy {}: {}", src.display(), e))?;
+
+    let file_name = dest
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if let Err(e) = model_download::verify_file(&part, &file_name, expected, require_checksum).await
+    {
+        let _ = fs::remove_file(&part).await;
+        return Err(e.into());
+    }
+
+    fs::rename(&part, dest).await?;
+    info!("Copied {} to {}", src.display(), dest.display());
+    Ok(())
+}
+
+/// Download a file with the shared resumable downloader, verifying `sha256` when given
+pub async fn download_file(url: &str, dest: &Path, sha256: Option<&str>) -> Result<()> {
+    let expected = sha256
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|sha| ManifestEntry {
+            sha256: sha.to_lowercase(),
+            size: None,
+        });
+    download_verified_file(url, dest, expected.as_ref(), false).await
+}
+
+/// Download a file, verifying it against `expected` like a regular model download
+pub async fn download_verified_file(
+    url: &str,
+    dest: &Path,
+    expected: Option<&ManifestEntry>,
+    require_checksum: bool,
+) -> Result<()> {
+    let client = reqwest::Client::new();
+    let mut download = ResumableDownload::start(&client, url, dest, expected).await?;
+    while download.next_chunk().await?.is_some() {}
+    download.finish(require_checksum).await?;
+    info!("Downloaded {} to {}", url, dest.display());
+    Ok(())
+}
+
+/// Checksums for the files of a multi-file import, keyed by file name. They come from a
+/// `manifest.json` next to the files (same format as the model manifest, keyed by file
+/// name); entries of the configured model manifest keyed by `<source>/<file>` win, the
+/// same way the local override wins for regular downloads.
+pub async fn load_import_manifest(source: &ImportSource) -> HashMap<String, ManifestEntry> {
+    let client = reqwest::Client::new();
+    let mut files = match source {
+        ImportSource::Path(dir) => read_source_manifest(&dir.join(SOURCE_MANIFEST_FILE)),
+        ImportSource::Url(url) => fetch_source_manifest(&client, url).await,
+    }
+    .map(|m| m.files)
+    .unwrap_or_default();
+
+    let prefix = format!("{}/", source.describe().trim_end_matches('/'));
+    let settings = model_download::current_settings();
+    let pinned = model_download::load_manifest(&client, &settings).await;
+    files.extend(pinned.files.into_iter().filter_map(|(key, entry)| {
+        key.strip_prefix(&prefix)
+            .map(|file| (file.to_string(), entry.clone()))
+    }));
+    files
+}
+
+fn read_source_manifest(path: &Path) -> Option<ModelManifest> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&contents) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!("Ignoring unreadable import manifest {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+async fn fetch_source_manifest(client: &reqwest::Client, base_url: &str) -> Option<ModelManifest> {
+    let url = format!("{}/{}", base_url, SOURCE_MANIFEST_FILE);
+    let response = client.get(&url).send().await.ok()?;
+    if !response.status().is_success() {
+        info!(
+            "No manifest next to the imported files ({}): {}",
+            url,
+            response.status()
+        );
+        return None;
+    }
+    match response.json::<ModelManifest>().await {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!("Ignoring unreadable import manifest {}: {}", url, e);
+            None
+        }
+    }
+}
+
+/// Registry entry for a model that was just imported and validated
+pub fn new_entry(
+    name: &str,
+    path: &Path,
+    description: Option<String>,
+    source: &ImportSource,
+    quantized: bool,
+) -> CustomModelEntry {
+    CustomModelEntry {
+        name: name.to_string(),
+        path: path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        description: description
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty())
+            .unwrap_or_else(|| "Imported model".to_string()),
+        source: source.describe(),
+        imported_at: Utc::now().to_rfc3339(),
+        quantized,
+    }
+}
+
+/// Total size in MB of a file, or of the files directly inside a directory
+pub fn size_on_disk_mb(path: &Path) -> u32 {
+    let bytes = if path.is_dir() {
+        std::fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|e| e.metadata().ok())
+                    .filter(|m| m.is_file())
+                    .map(|m| m.len())
+                    .sum()
+            })
+            .unwrap_or(0)
+    } else {
+        std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    };
+    (bytes / (1024 * 1024)) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_model_name() {
+        let taken = vec!["base".to_string(), "medical-v1".to_string()];
+        assert_eq!(
+            validate_model_name("  legal-small.v2 ", &taken).unwrap(),
+            "legal-small.v2"
+        );
+        assert!(validate_model_name("Base", &taken).is_err());
+        assert!(validate_model_name("medical-v1", &taken).is_err());
+        assert!(validate_model_name("../escape", &taken).is_err());
+        assert!(validate_model_name("with space", &taken).is_err());
+        assert!(validate_model_name("", &taken).is_err());
+        assert!(validate_model_name(&"x".repeat(65), &taken).is_err());
+    }
+
+    #[test]
+    fn test_import_source() {
+        assert_eq!(
+            ImportSource::parse("https://example.com/models/").unwrap(),
+            ImportSource::Url("https://example.com/models".to_string())
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            ImportSource::parse(dir.path().to_str().unwrap()).unwrap(),
+            ImportSource::Path(dir.path().to_path_buf())
+        );
+        assert!(ImportSource::parse("/definitely/not/here.bin").is_err());
+    }
+
+    #[test]
+    fn test_registry_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = ImportSource::Url("https://example.com/ggml-legal.bin".to_string());
+
+        let mut registry = CustomModelRegistry::load(dir.path());
+        assert!(registry.models.is_empty());
+        registry.insert(new_entry(
+            "legal",
+            &dir.path().join("ggml-legal.bin"),
+            Some(" Fine-tuned on contracts ".to_string()),
+            &source,
+            false,
+        ));
+        registry.save(dir.path()).unwrap();
+
+        let mut loaded = CustomModelRegistry::load(dir.path());
+        let entry = loaded.get("legal").unwrap();
+        assert_eq!(entry.path, "ggml-legal.bin");
+        assert_eq!(entry.description, "Fine-tuned on contracts");
+        assert_eq!(entry.source, "https://example.com/ggml-legal.bin");
+
+        assert!(loaded.remove("legal").is_some());
+        assert!(loaded.get("legal").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_copy_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("source.bin");
+        let dest = dir.path().join("ggml-custom.bin");
+        std::fs::write(&src, b"ggml model bytes").unwrap();
+
+        copy_file(&src, &dest).await.unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"ggml model bytes");
+        assert!(!model_download::partial_path(&dest).exists());
+    }
+
+    #[tokio::test]
+    async fn test_copy_verified_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("vocab.txt");
+        let dest = dir.path().join("imported-vocab.txt");
+        std::fs::write(&src, b"hello").unwrap();
+        let good = ManifestEntry {
+            sha256: "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824".to_string(),
+            size: Some(5),
+        };
+        let bad = ManifestEntry {
+            sha256: "0".repeat(64),
+            size: None,
+        };
+
+        assert!(copy_verified_file(&src, &dest, Some(&bad), false)
+            .await
+            .is_err());
+        assert!(copy_verified_file(&src, &dest, None, true).await.is_err());
+        assert!(!dest.exists());
+        assert!(!model_download::partial_path(&dest).exists());
+
+        copy_verified_file(&src, &dest, Some(&good), true)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_load_import_manifest_from_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("manifest.json"),
+            r#"{"files": {"vocab.txt": {"sha256": "abc", "size": 5}}}"#,
+        )
+        .unwrap();
+
+        let files = load_import_manifest(&ImportSource::Path(dir.path().to_path_buf())).await;
+        assert_eq!(
+            files.get("vocab.txt").map(|e| e.sha256.as_str()),
+            Some("abc")
+        );
+    }
+}
@@ -35,6 +35,77 @@ pub struct Transcript {
     pub audio_start_time: Option<f64>,
     pub audio_end_time: Option<f64>,
     pub duration: Option<f64>,
+    // Word-level timings as a JSON array (see audio::transcription::WordTiming)
+    pub words: Option<String>,
+    // Translation track for bilingual meetings
+    pub translation: Option<String>,
+    pub translation_language: Option<String>,
+    // Speaker label and whether the user corrected the text
+    pub speaker: Option<String>,
+    pub user_edited: bool,
+    // Recognition confidence in [0, 1]; NULL when the engine reports none
+    pub confidence: Option<f32>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RecordingMarker {
+    pub id: String,
+    pub meeting_id: String,
+    pub recording_time: f64, // Seconds from recording start
+    pub label: Option<String>,
+    pub created_at: String,
+}
+
+/// One placeholder in a meeting's redaction report. The redacted value itself is
+/// never stored.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RedactionReportEntry {
+    pub meeting_id: String,
+    pub stage: String, // "storage" or "llm"
+    pub category: String,
+    pub placeholder: String,
+    pub occurrences: i64,
+    pub created_at: String,
+}
+
+/// Action item extracted from a meeting summary
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub text: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub transcript_time: Option<f64>, // Seconds from recording start
+    pub status: String,               // "open", "done" or "cancelled"
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Latest benchmark run of a local transcription model on this machine
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ModelBenchmark {
+    pub engine: String, // "whisper" or "parakeet"
+    pub model_name: String,
+    pub real_time_factor: f64,
+    pub load_time_ms: i64,
+    pub peak_memory_mb: i64,
+    pub word_error_rate: Option<f64>,
+    pub audio_duration_secs: f64,
+    pub performance_tier: String,
+    pub sample: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TranscriptVersion {
+    pub id: String,
+    pub meeting_id: String,
+    pub version: i64,
+    pub label: String,
+    pub segments: String, // JSON array of api::MeetingTranscript
+    pub created_at: String,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -112,4 +183,13 @@ pub struct TranscriptSetting {
     #[sqlx(rename = "openaiApiKey")]
     #[serde(rename = "openaiApiKey")]
     pub openai_api_key: Option<String>,
+    #[sqlx(rename = "remoteEndpoint")]
+    #[serde(rename = "remoteEndpoint")]
+    pub remote_endpoint: Option<String>,
+    #[sqlx(rename = "openaiCompatibleApiKey")]
+    #[serde(rename = "openaiCompatibleApiKey")]
+    pub openai_compatible_api_key: Option<String>,
+    #[sqlx(rename = "remoteMaxRetries")]
+    #[serde(rename = "remoteMaxRetries")]
+    pub remote_max_retries: Option<i64>,
 }
@@ -0,0 +1,201 @@
+use crate::database::models::ActionItem;
+use crate::summary::action_items::{self, ExtractedActionItem};
+use chrono::Utc;
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+const SELECT_ACTION_ITEMS: &str =
+    "SELECT a.id, a.meeting_id, m.title AS meeting_title, a.text, a.owner,
+        a.due_date, a.transcript_time, a.status, a.created_at, a.updated_at
+     FROM action_items a JOIN meetings m ON m.id = a.meeting_id";
+
+/// Item text compared case- and whitespace-insensitively when re-extracting
+fn match_key(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    /// Replaces a meeting's action items with the ones extracted from a new summary.
+    /// Items whose text is unchanged keep their id and status, and the owner if the user
+    /// set it. Items the new summary omits are removed only while still open and untouched,
+    /// so progress tracked by the user survives regenerating the summary. Recording times
+    /// are located in the meeting's transcript.
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        items: &[ExtractedActionItem],
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let mut existing: Vec<(String, String, String, bool)> = sqlx::query_as(
+            "SELECT id, text, status, owner_edited FROM action_items WHERE meeting_id = ?",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        let segments: Vec<(f64, String)> = sqlx::query_as(
+            "SELECT audio_start_time, transcript FROM transcripts
+             WHERE meeting_id = ? AND audio_start_time IS NOT NULL
+             ORDER BY audio_start_time ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        let now = Utc::now().to_rfc3339();
+        let mut kept = 0;
+        for item in items {
+            let transcript_time = action_items::transcript_time(item, &segments);
+            let key = match_key(&item.text);
+            match existing
+                .iter()
+                .position(|(_, text, _, _)| match_key(text) == key)
+            {
+                Some(index) => {
+                    let (id, _, _, _) = existing.remove(index);
+                    sqlx::query(
+                        "UPDATE action_items
+                         SET text = ?, owner = CASE WHEN owner_edited THEN owner ELSE ? END,
+                             due_date = ?, transcript_time = ?, updated_at = ?
+                         WHERE id = ?",
+                    )
+                    .bind(&item.text)
+                    .bind(&item.owner)
+                    .bind(&item.due_date)
+                    .bind(transcript_time)
+                    .bind(&now)
+                    .bind(&id)
+                    .execute(&mut *transaction)
+                    .await?;
+                    kept += 1;
+                }
+                None => {
+                    sqlx::query(
+                        "INSERT INTO action_items (id, meeting_id, text, owner, due_date, transcript_time, status, created_at, updated_at)
+                         VALUES (?, ?, ?, ?, ?, ?, 'open', ?, ?)",
+                    )
+                    .bind(format!("action-item-{}", Uuid::new_v4()))
+                    .bind(meeting_id)
+                    .bind(&item.text)
+                    .bind(&item.owner)
+                    .bind(&item.due_date)
+                    .bind(transcript_time)
+                    .bind(&now)
+                    .bind(&now)
+                    .execute(&mut *transaction)
+                    .await?;
+                }
+            }
+        }
+
+        // Items no longer in the summary, unless the user has worked on them
+        let mut removed = 0;
+        for (id, _, status, owner_edited) in &existing {
+            if status != "open" || *owner_edited {
+                continue;
+            }
+            sqlx::query("DELETE FROM action_items WHERE id = ?")
+                .bind(id)
+                .execute(&mut *transaction)
+                .await?;
+            removed += 1;
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Saved {} action items for meeting {} ({} kept, {} removed)",
+            items.len(),
+            meeting_id,
+            kept,
+            removed
+        );
+        Ok(())
+    }
+
+    /// Returns a meeting's action items in summary order.
+    pub async fn get_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(&format!(
+            "{} WHERE a.meeting_id = ? ORDER BY a.created_at ASC, a.rowid ASC",
+            SELECT_ACTION_ITEMS
+        ))
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns the open items of all meetings, earliest due date first (undated last).
+    pub async fn list_open(pool: &SqlitePool) -> Result<Vec<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(&format!(
+            "{} WHERE a.status = 'open'
+             ORDER BY a.due_date IS NULL, a.due_date ASC, m.created_at DESC, a.rowid ASC",
+            SELECT_ACTION_ITEMS
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns the open items of meetings in the same series that took place before
+    /// `meeting_id`. Empty when the meeting is not part of a series.
+    pub async fn open_items_earlier_in_series(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, SqlxError> {
+        sqlx::query_as::<_, ActionItem>(&format!(
+            "{} JOIN meetings cur ON cur.id = ?
+             WHERE a.status = 'open'
+               AND cur.series_id IS NOT NULL
+               AND m.series_id = cur.series_id
+               AND m.id != cur.id
+               AND m.created_at < cur.created_at
+             ORDER BY m.created_at ASC, a.rowid ASC",
+            SELECT_ACTION_ITEMS
+        ))
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Sets an item's status. Returns false if the item does not exist.
+    pub async fn update_status(
+        pool: &SqlitePool,
+        item_id: &str,
+        status: &str,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE action_items SET status = ?, updated_at = ? WHERE id = ?")
+            .bind(status)
+            .bind(Utc::now().to_rfc3339())
+            .bind(item_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Sets (or clears) an item's owner; regenerating the summary no longer changes it.
+    /// Returns false if the item does not exist.
+    pub async fn reassign(
+        pool: &SqlitePool,
+        item_id: &str,
+        owner: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE action_items SET owner = ?, owner_edited = 1, updated_at = ? WHERE id = ?",
+        )
+        .bind(owner)
+        .bind(Utc::now().to_rfc3339())
+        .bind(item_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
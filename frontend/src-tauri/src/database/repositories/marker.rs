@@ -0,0 +1,64 @@
+use crate::audio::recording_saver::RecordingMarker as SessionMarker;
+use crate::database::models::RecordingMarker;
+use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use tracing::{error, info};
+
+pub struct MarkersRepository;
+
+impl MarkersRepository {
+    /// Saves the markers captured during a recording session for a meeting.
+    /// Uses a transaction so either all markers are stored or none.
+    pub async fn save_markers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        markers: &[SessionMarker],
+    ) -> Result<usize, SqlxError> {
+        if markers.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        for marker in markers {
+            let result = sqlx::query(
+                "INSERT OR REPLACE INTO recording_markers (id, meeting_id, recording_time, label, created_at)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&marker.id)
+            .bind(meeting_id)
+            .bind(marker.recording_time)
+            .bind(&marker.label)
+            .bind(&marker.created_at)
+            .execute(&mut *transaction)
+            .await;
+
+            if let Err(e) = result {
+                error!("Failed to save marker for meeting {}: {}", meeting_id, e);
+                transaction.rollback().await?;
+                return Err(e);
+            }
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Saved {} recording markers for meeting {}",
+            markers.len(),
+            meeting_id
+        );
+        Ok(markers.len())
+    }
+
+    /// Returns all markers of a meeting ordered by recording time.
+    pub async fn get_markers_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<RecordingMarker>, SqlxError> {
+        sqlx::query_as::<_, RecordingMarker>(
+            "SELECT * FROM recording_markers WHERE meeting_id = ? ORDER BY recording_time ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
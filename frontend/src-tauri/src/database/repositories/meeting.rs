@@ -1,5 +1,5 @@
-use crate::api::{MeetingDetails, MeetingTranscript};
-use crate::database::models::{MeetingModel, Transcript};
+use crate::api::{MeetingDetails, MeetingMarker, MeetingTranscript};
+use crate::database::models::{MeetingModel, RecordingMarker, Transcript};
 use chrono::Utc;
 use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
 use tracing::{error, info};
@@ -80,18 +80,29 @@ impl MeetingsRepository {
                     .fetch_all(&mut *transaction)
                     .await?;
 
+            // Get markers stamped during recording
+            let markers = sqlx::query_as::<_, RecordingMarker>(
+                "SELECT * FROM recording_markers WHERE meeting_id = ? ORDER BY recording_time ASC",
+            )
+            .bind(meeting_id)
+            .fetch_all(&mut *transaction)
+            .await?;
+
             transaction.commit().await?;
 
             // Convert Transcript to MeetingTranscript
             let meeting_transcripts = transcripts
                 .into_iter()
-                .map(|t| MeetingTranscript {
-                    id: t.id,
-                    text: t.transcript,
-                    timestamp: t.timestamp,
-                    audio_start_time: t.audio_start_time,
-                    audio_end_time: t.audio_end_time,
-                    duration: t.duration,
+                .map(to_meeting_transcript)
+                .collect::<Vec<_>>();
+
+            let meeting_markers = markers
+                .into_iter()
+                .map(|m| MeetingMarker {
+                    id: m.id,
+                    recording_time: m.recording_time,
+                    label: m.label,
+                    created_at: m.created_at,
                 })
                 .collect::<Vec<_>>();
 
@@ -101,6 +112,7 @@ impl MeetingsRepository {
                 created_at: meeting.created_at.0.to_rfc3339(),
                 updated_at: meeting.updated_at.0.to_rfc3339(),
                 transcripts: meeting_transcripts,
+                markers: meeting_markers,
             }))
         } else {
             transaction.rollback().await?;
@@ -139,6 +151,21 @@ impl MeetingsRepository {
         Ok(true)
     }
 
+    /// Puts a meeting into a series of recurring meetings (or takes it out with `None`).
+    /// Returns false if the meeting does not exist.
+    pub async fn update_meeting_series(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        series_id: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET series_id = ? WHERE id = ?")
+            .bind(series_id)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn update_meeting_name(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -173,6 +200,28 @@ impl MeetingsRepository {
     }
 }
 
+/// Converts a stored transcript row into the segment shape returned to the frontend.
+pub(crate) fn to_meeting_transcript(t: Transcript) -> MeetingTranscript {
+    MeetingTranscript {
+        id: t.id,
+        text: t.transcript,
+        timestamp: t.timestamp,
+        audio_start_time: t.audio_start_time,
+        audio_end_time: t.audio_end_time,
+        duration: t.duration,
+        words: t
+            .words
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default(),
+        translation: t.translation,
+        translation_language: t.translation_language,
+        speaker: t.speaker,
+        user_edited: t.user_edited,
+        confidence: t.confidence,
+    }
+}
+
 async fn delete_meeting_with_transaction(
     transaction: &mut SqliteConnection,
     meeting_id: &str,
@@ -207,6 +256,24 @@ async fn delete_meeting_with_transaction(
         .execute(&mut *transaction)
         .await?;
 
+    // 3a. Delete archived transcript versions
+    sqlx::query("DELETE FROM transcript_versions WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 3b. Delete recording markers
+    sqlx::query("DELETE FROM recording_markers WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 3c. Delete redaction reports
+    sqlx::query("DELETE FROM redaction_reports WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
     // 4. Finally, delete the meeting
     let result = sqlx::query("DELETE FROM meetings WHERE id = ?")
         .bind(meeting_id)
@@ -1,5 +1,10 @@
+pub mod action_item;
+pub mod marker;
 pub mod meeting;
+pub mod model_benchmark;
+pub mod redaction;
 pub mod setting;
 pub mod summary;
 pub mod transcript;
 pub mod transcript_chunk;
+pub mod transcript_version;
@@ -0,0 +1,54 @@
+use crate::database::models::ModelBenchmark;
+use sqlx::{Error as SqlxError, SqlitePool};
+use tracing::info;
+
+pub struct ModelBenchmarksRepository;
+
+impl ModelBenchmarksRepository {
+    /// Stores a benchmark run, replacing the previous run of the same model.
+    pub async fn save(pool: &SqlitePool, benchmark: &ModelBenchmark) -> Result<(), SqlxError> {
+        sqlx::query(
+            "INSERT INTO model_benchmarks (engine, model_name, real_time_factor, load_time_ms, peak_memory_mb,
+                                           word_error_rate, audio_duration_secs, performance_tier, sample, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT (engine, model_name) DO UPDATE SET
+                real_time_factor = excluded.real_time_factor,
+                load_time_ms = excluded.load_time_ms,
+                peak_memory_mb = excluded.peak_memory_mb,
+                word_error_rate = excluded.word_error_rate,
+                audio_duration_secs = excluded.audio_duration_secs,
+                performance_tier = excluded.performance_tier,
+                sample = excluded.sample,
+                created_at = excluded.created_at",
+        )
+        .bind(&benchmark.engine)
+        .bind(&benchmark.model_name)
+        .bind(benchmark.real_time_factor)
+        .bind(benchmark.load_time_ms)
+        .bind(benchmark.peak_memory_mb)
+        .bind(benchmark.word_error_rate)
+        .bind(benchmark.audio_duration_secs)
+        .bind(&benchmark.performance_tier)
+        .bind(&benchmark.sample)
+        .bind(&benchmark.created_at)
+        .execute(pool)
+        .await?;
+
+        info!(
+            "Saved benchmark for {} model {} (RTF {:.2})",
+            benchmark.engine, benchmark.model_name, benchmark.real_time_factor
+        );
+        Ok(())
+    }
+
+    /// Returns all stored runs, fastest first.
+    pub async fn get_all(pool: &SqlitePool) -> Result<Vec<ModelBenchmark>, SqlxError> {
+        sqlx::query_as::<_, ModelBenchmark>(
+            "SELECT engine, model_name, real_time_factor, load_time_ms, peak_memory_mb, word_error_rate,
+                    audio_duration_secs, performance_tier, sample, created_at
+             FROM model_benchmarks ORDER BY real_time_factor ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
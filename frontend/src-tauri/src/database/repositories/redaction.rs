@@ -0,0 +1,99 @@
+use crate::database::models::RedactionReportEntry;
+use crate::redaction::{RedactionReportItem, RedactionStage};
+use chrono::Utc;
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::info;
+
+pub struct RedactionReportsRepository;
+
+impl RedactionReportsRepository {
+    /// Replaces a meeting's report for one stage, e.g. when the transcript is first saved
+    /// or the summary regenerated.
+    pub async fn replace_report(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        stage: RedactionStage,
+        items: &[RedactionReportItem],
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        sqlx::query("DELETE FROM redaction_reports WHERE meeting_id = ? AND stage = ?")
+            .bind(meeting_id)
+            .bind(stage.as_str())
+            .execute(&mut *transaction)
+            .await?;
+        insert_items(&mut transaction, meeting_id, stage, items).await?;
+
+        transaction.commit().await?;
+        info!(
+            "Saved {} redaction report entries ({}) for meeting {}",
+            items.len(),
+            stage.as_str(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// Adds entries to a meeting's report for one stage, for placeholders handed out when
+    /// an existing transcript was extended, edited or re-transcribed.
+    pub async fn add_to_report(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        stage: RedactionStage,
+        items: &[RedactionReportItem],
+    ) -> Result<(), SqlxError> {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+        insert_items(&mut transaction, meeting_id, stage, items).await?;
+        transaction.commit().await?;
+        info!(
+            "Added {} redaction report entries ({}) for meeting {}",
+            items.len(),
+            stage.as_str(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// Returns a meeting's report, storage entries first.
+    pub async fn get_report(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<RedactionReportEntry>, SqlxError> {
+        sqlx::query_as::<_, RedactionReportEntry>(
+            "SELECT meeting_id, stage, category, placeholder, occurrences, created_at
+             FROM redaction_reports WHERE meeting_id = ? ORDER BY stage DESC, id ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
+
+async fn insert_items(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    stage: RedactionStage,
+    items: &[RedactionReportItem],
+) -> Result<(), SqlxError> {
+    let now = Utc::now().to_rfc3339();
+    for item in items {
+        sqlx::query(
+            "INSERT INTO redaction_reports (meeting_id, stage, category, placeholder, occurrences, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(meeting_id)
+        .bind(stage.as_str())
+        .bind(&item.category)
+        .bind(&item.placeholder)
+        .bind(item.occurrences as i64)
+        .bind(&now)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
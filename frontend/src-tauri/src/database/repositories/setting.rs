@@ -23,7 +23,7 @@ pub struct SaveTranscriptConfigRequest {
 
 pub struct SettingsRepository;
 
-// Transcript providers: localWhisper, deepgram, elevenLabs, groq, openai
+// Transcript providers: localWhisper, parakeet, deepgram, elevenLabs, groq, openai, openaiCompatible
 // Summary providers: openai, claude, ollama, groq, added openrouter
 // NOTE: Handle data exclusion in the higher layer as this is database abstraction layer(using SELECT *)
 
@@ -136,22 +136,32 @@ impl SettingsRepository {
         // return default values parakeet and parakeet-tdt-0.6b-v3-int8
     }
 
+    /// Saves the selected transcription provider. The remote server settings are only
+    /// written when saving a provider that uses them, so picking a local model keeps them.
     pub async fn save_transcript_config(
         pool: &SqlitePool,
         provider: &str,
         model: &str,
+        remote_endpoint: Option<&str>,
+        remote_max_retries: Option<u32>,
     ) -> std::result::Result<(), sqlx::Error> {
         sqlx::query(
             r#"
-            INSERT INTO transcript_settings (id, provider, model)
-            VALUES ('1', $1, $2)
+            INSERT INTO transcript_settings (id, provider, model, remoteEndpoint, remoteMaxRetries)
+            VALUES ('1', $1, $2, $3, $4)
             ON CONFLICT(id) DO UPDATE SET
                 provider = excluded.provider,
-                model = excluded.model
+                model = excluded.model,
+                remoteEndpoint = CASE WHEN excluded.provider = 'openaiCompatible'
+                    THEN excluded.remoteEndpoint ELSE transcript_settings.remoteEndpoint END,
+                remoteMaxRetries = CASE WHEN excluded.provider IN ('openaiCompatible', 'openai', 'groq')
+                    THEN excluded.remoteMaxRetries ELSE transcript_settings.remoteMaxRetries END
             "#,
         )
         .bind(provider)
         .bind(model)
+        .bind(remote_endpoint)
+        .bind(remote_max_retries)
         .execute(pool)
         .await?;
 
@@ -166,10 +176,12 @@ impl SettingsRepository {
         let api_key_column = match provider {
             "localWhisper" => "whisperApiKey",
             "parakeet" => return Ok(()), // Parakeet doesn't need an API key, return early
+            "onnxAsr" => return Ok(()), // Local ONNX models don't need an API key either
             "deepgram" => "deepgramApiKey",
             "elevenLabs" => "elevenLabsApiKey",
             "groq" => "groqApiKey",
             "openai" => "openaiApiKey",
+            "openaiCompatible" => "openaiCompatibleApiKey",
             _ => {
                 return Err(sqlx::Error::Protocol(
                     format!("Invalid provider: {}", provider).into(),
@@ -198,10 +210,12 @@ impl SettingsRepository {
         let api_key_column = match provider {
             "localWhisper" => "whisperApiKey",
             "parakeet" => return Ok(None), // Parakeet doesn't need an API key
+            "onnxAsr" => return Ok(None),
             "deepgram" => "deepgramApiKey",
             "elevenLabs" => "elevenLabsApiKey",
             "groq" => "groqApiKey",
             "openai" => "openaiApiKey",
+            "openaiCompatible" => "openaiCompatibleApiKey",
             _ => {
                 return Err(sqlx::Error::Protocol(
                     format!("Invalid provider: {}", provider).into(),
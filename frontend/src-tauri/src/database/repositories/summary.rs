@@ -40,7 +40,27 @@ impl SummaryProcessesRepository {
             return Ok(false);
         }
 
-        let result_json = serde_json::to_string(summary);
+        // Edits from the editor carry markdown/blocks only. The structured summary generated
+        // for templates with typed fields is kept while the markdown still matches it, and
+        // dropped once the user has edited the content so it never goes stale.
+        let mut summary = summary.clone();
+        if summary.get("structured").is_none() {
+            let existing: Option<Option<String>> =
+                sqlx::query_scalar("SELECT result FROM summary_processes WHERE meeting_id = ?")
+                    .bind(meeting_id)
+                    .fetch_optional(&mut *transaction)
+                    .await?;
+            let structured = existing
+                .flatten()
+                .and_then(|result| serde_json::from_str::<Value>(&result).ok())
+                .filter(|result| result.get("markdown") == summary.get("markdown"))
+                .and_then(|mut result| result.get_mut("structured").map(Value::take));
+            if let (Some(structured), Some(object)) = (structured, summary.as_object_mut()) {
+                object.insert("structured".to_string(), structured);
+            }
+        }
+
+        let result_json = serde_json::to_string(&summary);
         if result_json.is_err() {
             error!("Can't convert the json to string for saving to Database");
             transaction.rollback().await?;
@@ -162,4 +182,26 @@ impl SummaryProcessesRepository {
         .await?;
         Ok(())
     }
+
+    /// Marks a process that hasn't finished as cancelled. Returns false when there was no
+    /// unfinished process for the meeting.
+    pub async fn update_process_cancelled(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let now = Utc::now();
+        let result = sqlx::query(
+            r#"
+            UPDATE summary_processes
+            SET status = 'cancelled', error = 'Cancelled by user', updated_at = ?, end_time = ?
+            WHERE meeting_id = ? AND status NOT IN ('completed', 'failed', 'cancelled')
+            "#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
 }
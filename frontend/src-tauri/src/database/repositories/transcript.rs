@@ -1,6 +1,6 @@
 use crate::api::{TranscriptSearchResult, TranscriptSegment};
 use chrono::Utc;
-use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
 use tracing::{error, info};
 use uuid::Uuid;
 
@@ -45,22 +45,7 @@ impl TranscriptsRepository {
 
         // 2. Save each transcript segment with audio timing fields
         for segment in transcripts {
-            let transcript_id = format!("transcript-{}", Uuid::new_v4());
-            let result = sqlx::query(
-                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)"
-            )
-            .bind(&transcript_id)
-            .bind(&meeting_id)
-            .bind(&segment.text)
-            .bind(&segment.timestamp)
-            .bind(segment.audio_start_time)
-            .bind(segment.audio_end_time)
-            .bind(segment.duration)
-            .execute(&mut *transaction)
-            .await;
-
-            if let Err(e) = result {
+            if let Err(e) = insert_segment(&mut transaction, &meeting_id, segment).await {
                 error!(
                     "Failed to save transcript segment for meeting {}: {}",
                     meeting_id, e
@@ -82,6 +67,73 @@ impl TranscriptsRepository {
         Ok(meeting_id)
     }
 
+    /// Adds transcript segments to an existing meeting, e.g. passages transcribed after
+    /// the meeting was saved. Either all segments are saved or none.
+    pub async fn append_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        transcripts: &[TranscriptSegment],
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        for segment in transcripts {
+            if let Err(e) = insert_segment(&mut transaction, meeting_id, segment).await {
+                error!(
+                    "Failed to append transcript segment to meeting {}: {}",
+                    meeting_id, e
+                );
+                transaction.rollback().await?;
+                return Err(e);
+            }
+        }
+
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+
+        info!(
+            "Appended {} transcript segments to meeting {}",
+            transcripts.len(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// Returns the meeting a segment belongs to.
+    pub async fn meeting_id_of_segment(
+        pool: &SqlitePool,
+        transcript_id: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        sqlx::query_scalar("SELECT meeting_id FROM transcripts WHERE id = ?")
+            .bind(transcript_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Updates a segment's text and speaker label and marks it as edited by the user,
+    /// so re-transcription keeps it. Returns false when the segment does not exist.
+    pub async fn update_segment(
+        pool: &SqlitePool,
+        transcript_id: &str,
+        text: &str,
+        speaker: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE transcripts SET transcript = ?, speaker = ?, user_edited = 1 WHERE id = ?",
+        )
+        .bind(text)
+        .bind(speaker)
+        .bind(transcript_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Searches for a query string within the transcripts.
     /// It returns a list of matching transcripts with context.
     pub async fn search_transcripts(
@@ -144,3 +196,36 @@ impl TranscriptsRepository {
         }
     }
 }
+
+/// Inserts one segment row with its audio timing, word timings and translation
+async fn insert_segment(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    segment: &TranscriptSegment,
+) -> Result<(), SqlxError> {
+    let transcript_id = format!("transcript-{}", Uuid::new_v4());
+    // Word timings are stored as JSON; NULL when the engine reported none
+    let words_json = if segment.words.is_empty() {
+        None
+    } else {
+        serde_json::to_string(&segment.words).ok()
+    };
+    sqlx::query(
+        "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, words, translation, translation_language, confidence)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+    .bind(&transcript_id)
+    .bind(meeting_id)
+    .bind(&segment.text)
+    .bind(&segment.timestamp)
+    .bind(segment.audio_start_time)
+    .bind(segment.audio_end_time)
+    .bind(segment.duration)
+    .bind(words_json)
+    .bind(&segment.translation)
+    .bind(&segment.translation_language)
+    .bind(segment.confidence)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
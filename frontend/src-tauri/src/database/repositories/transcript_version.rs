@@ -0,0 +1,192 @@
+use crate::api::MeetingTranscript;
+use crate::database::models::{Transcript, TranscriptVersion};
+use crate::database::repositories::meeting::to_meeting_transcript;
+use chrono::Utc;
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use tracing::{error, info};
+use uuid::Uuid;
+
+pub struct TranscriptVersionsRepository;
+
+impl TranscriptVersionsRepository {
+    /// Returns the current segments of a meeting in recording order.
+    pub async fn get_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingTranscript>, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        load_segments(&mut conn, meeting_id).await
+    }
+
+    /// Replaces a meeting's segments, archiving the current ones as a new version first.
+    /// Returns the number of the archived version.
+    pub async fn replace_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        segments: &[MeetingTranscript],
+        label: &str,
+    ) -> Result<i64, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        match replace_with_transaction(&mut transaction, meeting_id, segments, label).await {
+            Ok(version) => {
+                transaction.commit().await?;
+                info!(
+                    "Replaced transcript of meeting {} with {} segments (archived as version {})",
+                    meeting_id,
+                    segments.len(),
+                    version
+                );
+                Ok(version)
+            }
+            Err(e) => {
+                let _ = transaction.rollback().await;
+                error!(
+                    "Failed to replace transcript of meeting {}: {}",
+                    meeting_id, e
+                );
+                Err(e)
+            }
+        }
+    }
+
+    /// Lists the archived versions of a meeting, newest first.
+    pub async fn list_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<TranscriptVersion>, SqlxError> {
+        sqlx::query_as::<_, TranscriptVersion>(
+            "SELECT * FROM transcript_versions WHERE meeting_id = ? ORDER BY version DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Restores an archived version. The segments being replaced are archived too,
+    /// so a restore can itself be undone. Returns false when the version does not exist.
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let version: Option<TranscriptVersion> =
+            sqlx::query_as("SELECT * FROM transcript_versions WHERE id = ? AND meeting_id = ?")
+                .bind(version_id)
+                .bind(meeting_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+
+        let Some(version) = version else {
+            transaction.rollback().await?;
+            return Ok(false);
+        };
+
+        let segments: Vec<MeetingTranscript> =
+            serde_json::from_str(&version.segments).map_err(|e| SqlxError::Decode(Box::new(e)))?;
+        let label = format!("Before restoring version {}", version.version);
+
+        match replace_with_transaction(&mut transaction, meeting_id, &segments, &label).await {
+            Ok(_) => {
+                transaction.commit().await?;
+                info!(
+                    "Restored transcript version {} of meeting {}",
+                    version.version, meeting_id
+                );
+                Ok(true)
+            }
+            Err(e) => {
+                let _ = transaction.rollback().await;
+                error!(
+                    "Failed to restore version {} of meeting {}: {}",
+                    version.version, meeting_id, e
+                );
+                Err(e)
+            }
+        }
+    }
+}
+
+async fn load_segments(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+) -> Result<Vec<MeetingTranscript>, SqlxError> {
+    let rows = sqlx::query_as::<_, Transcript>(
+        "SELECT * FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time ASC",
+    )
+    .bind(meeting_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(rows.into_iter().map(to_meeting_transcript).collect())
+}
+
+async fn replace_with_transaction(
+    transaction: &mut SqliteConnection,
+    meeting_id: &str,
+    segments: &[MeetingTranscript],
+    label: &str,
+) -> Result<i64, SqlxError> {
+    // 1. Archive the current segments
+    let current = load_segments(transaction, meeting_id).await?;
+    let snapshot = serde_json::to_string(&current).map_err(|e| SqlxError::Decode(Box::new(e)))?;
+
+    let (latest,): (Option<i64>,) =
+        sqlx::query_as("SELECT MAX(version) FROM transcript_versions WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_one(&mut *transaction)
+            .await?;
+    let version = latest.unwrap_or(0) + 1;
+
+    sqlx::query(
+        "INSERT INTO transcript_versions (id, meeting_id, version, label, segments, created_at)
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(format!("transcript-version-{}", Uuid::new_v4()))
+    .bind(meeting_id)
+    .bind(version)
+    .bind(label)
+    .bind(snapshot)
+    .bind(Utc::now().to_rfc3339())
+    .execute(&mut *transaction)
+    .await?;
+
+    // 2. Swap in the new segments
+    sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    for segment in segments {
+        let words_json = if segment.words.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&segment.words).ok()
+        };
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, words, translation, translation_language, speaker, user_edited, confidence)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&segment.id)
+        .bind(meeting_id)
+        .bind(&segment.text)
+        .bind(&segment.timestamp)
+        .bind(segment.audio_start_time)
+        .bind(segment.audio_end_time)
+        .bind(segment.duration)
+        .bind(words_json)
+        .bind(&segment.translation)
+        .bind(&segment.translation_language)
+        .bind(&segment.speaker)
+        .bind(segment.user_edited)
+        .bind(segment.confidence)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    Ok(version)
+}
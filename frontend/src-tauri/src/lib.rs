@@ -39,12 +39,17 @@ pub mod analytics;
 pub mod api;
 pub mod audio;
 pub mod console_utils;
+pub mod custom_models;
 pub mod database;
 pub mod meeting_detector;
+pub mod model_download;
 pub mod notifications;
 pub mod ollama;
+pub mod onnx_asr;
 pub mod openrouter;
 pub mod parakeet_engine;
+pub mod redaction;
+pub mod settings_store;
 pub mod state;
 pub mod summary;
 pub mod tray;
@@ -101,6 +106,7 @@ async fn start_recording<R: Runtime>(
         mic_device_name,
         system_device_name,
         meeting_name.clone(),
+        false,
     )
     .await
     {
@@ -208,9 +214,10 @@ async fn is_recording() -> bool {
 
 #[tauri::command]
 fn get_transcription_status() -> TranscriptionStatus {
+    let metrics = audio::transcription::current_metrics();
     TranscriptionStatus {
-        chunks_in_queue: 0,
-        is_processing: false,
+        chunks_in_queue: metrics.queue_depth as usize,
+        is_processing: metrics.is_running,
         last_activity_ms: 0,
     }
 }
@@ -290,16 +297,21 @@ async fn start_recording_with_devices<R: Runtime>(
     mic_device_name: Option<String>,
     system_device_name: Option<String>,
 ) -> Result<(), String> {
-    start_recording_with_devices_and_meeting(app, mic_device_name, system_device_name, None).await
+    start_recording_with_devices_and_meeting(app, mic_device_name, system_device_name, None, None)
+        .await
 }
 
+/// `auto_detected` marks recordings auto-started by meeting detection; only those get the
+/// pre-roll buffer prepended
 #[tauri::command]
 async fn start_recording_with_devices_and_meeting<R: Runtime>(
     app: AppHandle<R>,
     mic_device_name: Option<String>,
     system_device_name: Option<String>,
     meeting_name: Option<String>,
+    auto_detected: Option<bool>,
 ) -> Result<(), String> {
+    let auto_detected = auto_detected.unwrap_or(false);
     log_info!("🚀 CALLED start_recording_with_devices_and_meeting - Mic: {:?}, System: {:?}, Meeting: {:?}",
              mic_device_name, system_device_name, meeting_name);
 
@@ -313,8 +325,12 @@ async fn start_recording_with_devices_and_meeting<R: Runtime>(
                 "No devices specified, starting with defaults and meeting: {:?}",
                 meeting_name
             );
-            audio::recording_commands::start_recording_with_meeting_name(app.clone(), meeting_name)
-                .await
+            audio::recording_commands::start_recording_with_meeting_name(
+                app.clone(),
+                meeting_name,
+                auto_detected,
+            )
+            .await
         }
         _ => {
             log_info!(
@@ -328,6 +344,7 @@ async fn start_recording_with_devices_and_meeting<R: Runtime>(
                 mic_device_name,
                 system_device_name,
                 meeting_name,
+                auto_detected,
             )
             .await
         }
@@ -454,6 +471,13 @@ pub fn run() {
                 }
             });
 
+            // Initialize ONNX ASR engine (manifest-driven local models) on startup
+            tauri::async_runtime::spawn(async {
+                if let Err(e) = onnx_asr::commands::onnx_asr_init().await {
+                    log::error!("Failed to initialize ONNX ASR engine on startup: {}", e);
+                }
+            });
+
             // Trigger system audio permission request on startup (similar to microphone permission)
             // #[cfg(target_os = "macos")]
             // {
@@ -480,6 +504,9 @@ pub fn run() {
                 log::warn!("Failed to resolve resource directory for templates");
             }
 
+            // Finish transcribing chunks an earlier session left queued when it quit
+            audio::transcription::durable_queue::spawn_startup_resume(_app.handle().clone());
+
             // Auto-start meeting detection monitor if enabled in settings
             let app_for_meeting_detection = _app.handle().clone();
             tauri::async_runtime::spawn(async move {
@@ -542,6 +569,8 @@ pub fn run() {
             whisper_engine::commands::whisper_download_model,
             whisper_engine::commands::whisper_cancel_download,
             whisper_engine::commands::whisper_delete_corrupted_model,
+            whisper_engine::commands::whisper_import_model,
+            whisper_engine::commands::whisper_remove_custom_model,
             // Parakeet engine commands
             parakeet_engine::commands::parakeet_init,
             parakeet_engine::commands::parakeet_get_available_models,
@@ -555,7 +584,23 @@ pub fn run() {
             parakeet_engine::commands::parakeet_download_model,
             parakeet_engine::commands::parakeet_cancel_download,
             parakeet_engine::commands::parakeet_delete_corrupted_model,
+            parakeet_engine::commands::parakeet_import_model,
+            parakeet_engine::commands::parakeet_remove_custom_model,
             parakeet_engine::commands::open_parakeet_models_folder,
+            // ONNX ASR engine commands (manifest-driven local models)
+            onnx_asr::commands::onnx_asr_init,
+            onnx_asr::commands::onnx_asr_get_available_models,
+            onnx_asr::commands::onnx_asr_load_model,
+            onnx_asr::commands::onnx_asr_get_current_model,
+            onnx_asr::commands::onnx_asr_get_models_directory,
+            onnx_asr::commands::onnx_asr_import_model,
+            onnx_asr::commands::onnx_asr_remove_model,
+            onnx_asr::runtime::get_onnx_runtime_settings,
+            onnx_asr::runtime::set_onnx_runtime_settings,
+            onnx_asr::runtime::get_onnx_runtime_diagnostics,
+            // Model download source (mirror, checksum verification)
+            model_download::get_model_download_settings,
+            model_download::set_model_download_settings,
             // Parallel processing commands
             whisper_engine::parallel_commands::initialize_parallel_processor,
             whisper_engine::parallel_commands::start_parallel_processing,
@@ -583,10 +628,53 @@ pub fn run() {
             // Reload sync commands (retrieve transcript history and meeting name)
             audio::recording_commands::get_transcript_history,
             audio::recording_commands::get_recording_meeting_name,
+            // Recording markers (bookmarks)
+            audio::recording_commands::add_recording_marker,
+            audio::recording_commands::get_recording_markers,
             // Device monitoring commands (AirPods/Bluetooth disconnect/reconnect)
             audio::recording_commands::poll_audio_device_events,
             audio::recording_commands::get_reconnection_status,
             audio::recording_commands::attempt_device_reconnect,
+            audio::recording_commands::switch_recording_device,
+            // Live audio quality warnings
+            audio::quality_monitor::get_audio_quality_thresholds,
+            audio::quality_monitor::set_audio_quality_thresholds,
+            // Transcription worker pool metrics (queue depth, lag)
+            audio::transcription::worker::get_transcription_metrics,
+            audio::transcription::translation::get_translation_settings,
+            audio::transcription::translation::set_translation_settings,
+            audio::transcription::glossary::get_glossary,
+            audio::transcription::glossary::set_glossary,
+            audio::transcription::glossary::add_glossary_term,
+            audio::transcription::glossary::remove_glossary_term,
+            audio::transcription::glossary::add_glossary_replacement,
+            audio::transcription::glossary::remove_glossary_replacement,
+            audio::transcription::glossary::preview_glossary,
+            audio::transcription::retranscription::start_retranscription,
+            audio::transcription::retranscription::cancel_retranscription,
+            audio::transcription::retranscription::get_retranscription_status,
+            audio::transcription::retranscription::list_transcript_versions,
+            audio::transcription::retranscription::restore_transcript_version,
+            audio::transcription::confidence::get_confidence_settings,
+            audio::transcription::confidence::set_confidence_settings,
+            audio::transcription::confidence::get_low_confidence_passages,
+            audio::transcription::hallucination::get_hallucination_filter_settings,
+            audio::transcription::hallucination::set_hallucination_filter_settings,
+            audio::transcription::hallucination::get_dropped_segments,
+            // Local model benchmark and recommendation
+            audio::transcription::benchmark::run_model_benchmark,
+            audio::transcription::benchmark::cancel_model_benchmark,
+            audio::transcription::benchmark::get_model_benchmarks,
+            audio::transcription::benchmark::get_model_recommendation,
+            audio::transcription::backlog::get_backlog_settings,
+            audio::transcription::backlog::set_backlog_settings,
+            audio::transcription::durable_queue::get_pending_transcriptions,
+            audio::transcription::durable_queue::resume_pending_transcriptions,
+            // PII redaction (storage and LLM prompts)
+            redaction::get_redaction_settings,
+            redaction::set_redaction_settings,
+            redaction::preview_redaction,
+            redaction::get_redaction_report,
             // Playback device detection (Bluetooth warning)
             audio::recording_commands::get_active_audio_output,
             console_utils::show_console,
@@ -613,6 +701,7 @@ pub fn run() {
             api::api_get_meeting,
             api::api_save_meeting_title,
             api::api_save_transcript,
+            api::api_update_transcript_segment,
             api::open_meeting_folder,
             api::test_backend_connection,
             api::debug_backend_connection,
@@ -620,7 +709,14 @@ pub fn run() {
             // Summary commands
             summary::api_process_transcript,
             summary::api_get_summary,
+            summary::api_cancel_summary,
             summary::api_save_meeting_summary,
+            // Action item tracking
+            summary::api_list_open_action_items,
+            summary::api_get_meeting_action_items,
+            summary::api_update_action_item_status,
+            summary::api_reassign_action_item,
+            summary::api_set_meeting_series,
             // Template commands
             summary::api_list_templates,
             summary::api_get_template_details,
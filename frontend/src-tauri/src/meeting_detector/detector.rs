@@ -45,6 +45,17 @@ pub struct MeetingDetectionSettings {
     pub notify_on_detection: bool,
     /// Polling interval in seconds
     pub poll_interval_secs: u64,
+    /// Buffer audio in memory while a meeting is detected but not yet recording,
+    /// and prepend it to the recording when it starts
+    #[serde(default)]
+    pub pre_roll_enabled: bool,
+    /// How many seconds of audio the pre-roll buffer keeps
+    #[serde(default = "default_pre_roll_seconds")]
+    pub pre_roll_seconds: u32,
+}
+
+fn default_pre_roll_seconds() -> u32 {
+    60
 }
 
 impl Default for MeetingDetectionSettings {
@@ -58,6 +69,8 @@ impl Default for MeetingDetectionSettings {
             detect_google_meet: true,
             notify_on_detection: true,
             poll_interval_secs: 5,
+            pre_roll_enabled: false, // Opt-in: captures audio before recording is started
+            pre_roll_seconds: default_pre_roll_seconds(),
         }
     }
 }
@@ -127,6 +140,8 @@ pub struct MeetingDetectionStatus {
     pub settings: MeetingDetectionSettings,
     /// Whether recording was auto-started by the detector
     pub auto_recording_active: bool,
+    /// Whether the pre-roll buffer is currently capturing audio
+    pub pre_roll_active: bool,
 }
 
 /// Meeting detector that monitors for video conferencing applications
@@ -185,6 +200,7 @@ impl MeetingDetector {
             current_meeting: self.current_meeting.read().await.clone(),
             settings: self.get_settings().await,
             auto_recording_active: self.auto_recording_active.load(Ordering::SeqCst),
+            pre_roll_active: crate::audio::pre_roll::is_pre_roll_active().await,
         }
     }
 
@@ -299,6 +315,11 @@ impl MeetingDetector {
             while is_monitoring.load(Ordering::SeqCst) {
                 let current_settings = settings.read().await.clone();
 
+                // Drop any buffered audio as soon as detection or pre-roll is switched off
+                if !current_settings.enabled || !current_settings.pre_roll_enabled {
+                    crate::audio::pre_roll::discard_pre_roll().await;
+                }
+
                 if !current_settings.enabled {
                     tokio::time::sleep(Duration::from_secs(current_settings.poll_interval_secs))
                         .await;
@@ -325,6 +346,17 @@ impl MeetingDetector {
                             *current = Some(meeting_info.clone());
                         }
 
+                        // Start buffering audio so the start of the meeting isn't lost
+                        // if recording is started a bit later
+                        if current_settings.pre_roll_enabled
+                            && !crate::audio::recording_commands::is_recording().await
+                        {
+                            match crate::audio::pre_roll::start_pre_roll(current_settings.pre_roll_seconds).await {
+                                Ok(()) => info!("Pre-roll buffer started for {}", meeting_info.app_name),
+                                Err(e) => warn!("Failed to start pre-roll buffer: {}", e),
+                            }
+                        }
+
                         // Emit event to frontend
                         let _ = app.emit("meeting-detected", &meeting_info);
 
@@ -369,6 +401,9 @@ impl MeetingDetector {
                             *current = None;
                         }
 
+                        // Meeting ended without a recording taking the pre-roll
+                        crate::audio::pre_roll::discard_pre_roll().await;
+
                         // Emit event to frontend
                         let _ = app.emit("meeting-ended", ());
 
@@ -389,6 +424,7 @@ impl MeetingDetector {
                 tokio::time::sleep(Duration::from_secs(current_settings.poll_interval_secs)).await;
             }
 
+            crate::audio::pre_roll::discard_pre_roll().await;
             info!("Meeting detection monitor stopped");
         });
     }
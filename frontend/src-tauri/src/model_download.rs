@@ -0,0 +1,923 @@
+// model_download.rs
+//
+// Verified, resumable model downloads shared by the Whisper and Parakeet engines. Files are
+// streamed to `<file>.part` and resumed with an HTTP Range request after a failure. Once
+// complete, the size and SHA-256 are checked before the file is moved into place; an
+// installed file is only ever replaced by a verified download. Checksums
+// come from a manifest (a local override file, or `manifest.json` on the mirror), falling
+// back to the SHA-256 ETag Hugging Face reports for LFS files. A mirror base URL (e.g. an
+// internal artifact server) can replace Hugging Face entirely.
+
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use reqwest::header::{HeaderMap, CONTENT_RANGE, ETAG, RANGE};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+use crate::settings_store;
+
+pub const HUGGING_FACE_BASE_URL: &str = "https://huggingface.co";
+
+/// Persisted download configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelDownloadSettings {
+    /// Base URL serving `<repo>/<file>` (and optionally `manifest.json`) instead of Hugging Face
+    #[serde(default)]
+    pub mirror_url: Option<String>,
+    /// Refuse files whose SHA-256 is not known from a manifest or the server
+    #[serde(default)]
+    pub require_checksum: bool,
+}
+
+impl ModelDownloadSettings {
+    /// Load settings from disk
+    pub fn load() -> Self {
+        settings_store::load("model_download.json", "model download settings")
+    }
+
+    /// Save settings to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "model_download.json", "model download settings")
+    }
+
+    /// Mirror base URL without trailing slashes, if one is configured
+    pub fn mirror(&self) -> Option<&str> {
+        self.mirror_url
+            .as_deref()
+            .map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+    }
+}
+
+static DOWNLOAD_SETTINGS: Lazy<RwLock<ModelDownloadSettings>> =
+    Lazy::new(|| RwLock::new(ModelDownloadSettings::load()));
+
+pub fn current_settings() -> ModelDownloadSettings {
+    DOWNLOAD_SETTINGS
+        .read()
+        .map(|s| s.clone())
+        .unwrap_or_default()
+}
+
+/// A file of a model, identified by its Hugging Face repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelArtifact {
+    pub repo: String,
+    pub file: String,
+}
+
+impl ModelArtifact {
+    pub fn new(repo: &str, file: &str) -> Self {
+        Self {
+            repo: repo.to_string(),
+            file: file.to_string(),
+        }
+    }
+
+    /// Key of this file in a manifest: `<repo>/<file>`
+    pub fn manifest_key(&self) -> String {
+        format!("{}/{}", self.repo, self.file)
+    }
+
+    /// Hugging Face `resolve/main` URL, or `<mirror>/<repo>/<file>` when a mirror is set
+    pub fn url(&self, settings: &ModelDownloadSettings) -> String {
+        match settings.mirror() {
+            Some(mirror) => format!("{}/{}", mirror, self.manifest_key()),
+            None => format!(
+                "{}/{}/resolve/main/{}",
+                HUGGING_FACE_BASE_URL, self.repo, self.file
+            ),
+        }
+    }
+}
+
+/// Expected size and checksum of one file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub sha256: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+}
+
+/// Checksums keyed by `<repo>/<file>`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModelManifest {
+    #[serde(default)]
+    pub files: HashMap<String, ManifestEntry>,
+}
+
+impl ModelManifest {
+    /// Local override, for pinning checksums without a mirror
+    fn local_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|p| p.join("com.meetily.ai").join("model_manifest.json"))
+    }
+
+    fn load_local() -> Self {
+        let Some(path) = Self::local_path().filter(|p| p.exists()) else {
+            return Self::default();
+        };
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|c| serde_json::from_str(&c).map_err(|e| e.to_string()))
+        {
+            Ok(manifest) => {
+                info!("Loaded model manifest from {:?}", path);
+                manifest
+            }
+            Err(e) => {
+                warn!("Ignoring unreadable model manifest {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    async fn fetch(client: &Client, mirror: &str) -> Option<Self> {
+        let url = format!("{}/manifest.json", mirror);
+        let response = client.get(&url).send().await.ok()?;
+        if !response.status().is_success() {
+            info!("No manifest on mirror ({}): {}", url, response.status());
+            return None;
+        }
+        match response.json::<Self>().await {
+            Ok(manifest) => Some(manifest),
+            Err(e) => {
+                warn!("Failed to parse mirror manifest {}: {}", url, e);
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, artifact: &ModelArtifact) -> Option<&ManifestEntry> {
+        self.files.get(&artifact.manifest_key())
+    }
+}
+
+/// Manifest for the configured source. Entries of the local override win over the mirror's.
+pub async fn load_manifest(client: &Client, settings: &ModelDownloadSettings) -> ModelManifest {
+    let mut manifest = match settings.mirror() {
+        Some(mirror) => ModelManifest::fetch(client, mirror)
+            .await
+            .unwrap_or_default(),
+        None => ModelManifest::default(),
+    };
+    manifest.files.extend(ModelManifest::load_local().files);
+    manifest
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DownloadError {
+    #[error("Network error while downloading {url}: {message}")]
+    Network { url: String, message: String },
+    #[error("Server returned HTTP {status} for {url}")]
+    HttpStatus { url: String, status: u16 },
+    #[error("File error: {0}")]
+    Io(String),
+    #[error("Downloaded {file} is {actual} bytes, expected {expected}")]
+    SizeMismatch {
+        file: String,
+        expected: u64,
+        actual: u64,
+    },
+    #[error("Checksum mismatch for {file}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+    #[error(
+        "No SHA-256 known for {0}; add it to the model manifest or turn off strict verification"
+    )]
+    MissingChecksum(String),
+}
+
+impl From<std::io::Error> for DownloadError {
+    fn from(e: std::io::Error) -> Self {
+        DownloadError::Io(e.to_string())
+    }
+}
+
+/// Where the in-progress download of `dest` is kept
+pub fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// A file being downloaded into `<dest>.part`, resumed from whatever is already there
+pub struct ResumableDownload {
+    url: String,
+    dest: PathBuf,
+    part_path: PathBuf,
+    /// None when the installed file was verified and nothing needs downloading
+    file: Option<fs::File>,
+    stream: Option<BoxStream<'static, reqwest::Result<bytes::Bytes>>>,
+    hasher: Sha256,
+    downloaded: u64,
+    total: Option<u64>,
+    expected_sha256: Option<String>,
+    expected_size: Option<u64>,
+}
+
+impl ResumableDownload {
+    /// Start downloading `url` to `dest`, resuming a previous partial download when the
+    /// server supports Range requests. An existing `dest` is verified in place and kept when
+    /// its checksum matches; otherwise it stays untouched until a new download has been
+    /// verified and replaces it.
+    pub async fn start(
+        client: &Client,
+        url: &str,
+        dest: &Path,
+        expected: Option<&ManifestEntry>,
+    ) -> Result<Self, DownloadError> {
+        let part_path = partial_path(dest);
+        if dest.exists() && !part_path.exists() {
+            if let Some(installed) = Self::verify_installed(client, url, dest, expected).await? {
+                return Ok(installed);
+            }
+        }
+
+        let expected_size = expected.and_then(|e| e.size);
+        let mut existing = fs::metadata(&part_path).await.map(|m| m.len()).unwrap_or(0);
+        if expected_size.is_some_and(|size| existing > size) {
+            warn!(
+                "Partial download {} is larger than expected, starting over",
+                part_path.display()
+            );
+            existing = 0;
+        }
+
+        // At most two requests: the Range request, then a full one if the partial is unusable
+        loop {
+            let mut request = client.get(url);
+            if existing > 0 {
+                request = request.header(RANGE, format!("bytes={}-", existing));
+            }
+            let response = request.send().await.map_err(|e| DownloadError::Network {
+                url: url.to_string(),
+                message: e.to_string(),
+            })?;
+            let status = response.status();
+            let headers = response.headers().clone();
+
+            let (resume_from, total, stream) =
+                if existing > 0 && status == StatusCode::PARTIAL_CONTENT {
+                    match parse_content_range(&headers) {
+                        Some((start, total)) if start == existing => {
+                            info!(
+                                "⏯️ Resuming {} at {:.1} MB",
+                                url,
+                                existing as f64 / 1_048_576.0
+                            );
+                            (existing, total, Some(response.bytes_stream().boxed()))
+                        }
+                        _ => {
+                            warn!("Unexpected Content-Range from {}, starting over", url);
+                            existing = 0;
+                            continue;
+                        }
+                    }
+                } else if existing > 0 && status == StatusCode::RANGE_NOT_SATISFIABLE {
+                    // Nothing left to fetch if the partial already has every byte
+                    match parse_content_range(&headers).and_then(|(_, total)| total) {
+                        Some(total) if total == existing => {
+                            info!("{} is already fully downloaded", part_path.display());
+                            (existing, Some(total), None)
+                        }
+                        _ => {
+                            existing = 0;
+                            continue;
+                        }
+                    }
+                } else if status.is_success() {
+                    if existing > 0 {
+                        info!("Server does not support resuming {}, starting over", url);
+                    }
+                    let total = response.content_length();
+                    (0, total, Some(response.bytes_stream().boxed()))
+                } else {
+                    return Err(DownloadError::HttpStatus {
+                        url: url.to_string(),
+                        status: status.as_u16(),
+                    });
+                };
+
+            let mut download = Self::open(url, dest, &part_path, resume_from, stream).await?;
+            download.total = total.or(expected_size);
+            download.expected_size = expected_size;
+            download.expected_sha256 = expected
+                .map(|e| e.sha256.to_lowercase())
+                .or_else(|| sha256_from_etag(&headers));
+            return Ok(download);
+        }
+    }
+
+    /// Check an installed `dest` against the manifest entry, or the checksum the server
+    /// reports. Returns a finished download when it matches, None when it has to be fetched.
+    async fn verify_installed(
+        client: &Client,
+        url: &str,
+        dest: &Path,
+        expected: Option<&ManifestEntry>,
+    ) -> Result<Option<Self>, DownloadError> {
+        let expected_sha256 = match expected {
+            Some(entry) => Some(entry.sha256.to_lowercase()),
+            None => match client.head(url).send().await {
+                Ok(response) if response.status().is_success() => {
+                    sha256_from_etag(response.headers())
+                }
+                _ => None,
+            },
+        };
+        let Some(expected_sha256) = expected_sha256 else {
+            info!(
+                "No checksum known for installed {}, downloading it again",
+                dest.display()
+            );
+            return Ok(None);
+        };
+
+        let (size, actual) = hash_file(dest).await?;
+        let wrong_size = expected
+            .and_then(|e| e.size)
+            .is_some_and(|expected_size| expected_size != size);
+        if wrong_size || actual != expected_sha256 {
+            warn!(
+                "Installed {} does not match its checksum, downloading it again",
+                dest.display()
+            );
+            return Ok(None);
+        }
+
+        info!("✅ Installed {} is already up to date", dest.display());
+        Ok(Some(Self {
+            url: url.to_string(),
+            dest: dest.to_path_buf(),
+            part_path: partial_path(dest),
+            file: None,
+            stream: None,
+            hasher: Sha256::new(),
+            downloaded: size,
+            total: Some(size),
+            expected_sha256: Some(expected_sha256),
+            expected_size: Some(size),
+        }))
+    }
+
+    /// Open the partial file, keeping (and hashing) its first `resume_from` bytes
+    async fn open(
+        url: &str,
+        dest: &Path,
+        part_path: &Path,
+        resume_from: u64,
+        stream: Option<BoxStream<'static, reqwest::Result<bytes::Bytes>>>,
+    ) -> Result<Self, DownloadError> {
+        let mut hasher = Sha256::new();
+        let file = if resume_from > 0 {
+            let mut existing = fs::File::open(part_path).await?;
+            let mut buffer = vec![0u8; 1 << 20];
+            let mut remaining = resume_from;
+            while remaining > 0 {
+                let n = existing.read(&mut buffer).await?;
+                if n == 0 {
+                    break;
+                }
+                let n = n.min(remaining as usize);
+                hasher.update(&buffer[..n]);
+                remaining -= n as u64;
+            }
+            let mut file = fs::OpenOptions::new().write(true).open(part_path).await?;
+            file.set_len(resume_from).await?;
+            file.seek(SeekFrom::End(0)).await?;
+            file
+        } else {
+            fs::File::create(part_path).await?
+        };
+
+        Ok(Self {
+            url: url.to_string(),
+            dest: dest.to_path_buf(),
+            part_path: part_path.to_path_buf(),
+            file: Some(file),
+            stream,
+            hasher,
+            downloaded: resume_from,
+            total: None,
+            expected_sha256: None,
+            expected_size: None,
+        })
+    }
+
+    /// Write the next chunk to disk. Returns its size, or None once the body is complete.
+    pub async fn next_chunk(&mut self) -> Result<Option<usize>, DownloadError> {
+        let Some(stream) = self.stream.as_mut() else {
+            return Ok(None);
+        };
+        match stream.next().await {
+            Some(Ok(chunk)) => {
+                if let Some(file) = self.file.as_mut() {
+                    file.write_all(&chunk).await?;
+                }
+                self.hasher.update(&chunk);
+                self.downloaded += chunk.len() as u64;
+                Ok(Some(chunk.len()))
+            }
+            Some(Err(e)) => Err(DownloadError::Network {
+                url: self.url.clone(),
+                message: e.to_string(),
+            }),
+            None => {
+                self.stream = None;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Bytes on disk so far, including resumed ones
+    pub fn downloaded(&self) -> u64 {
+        self.downloaded
+    }
+
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Verify the file and move it into place. A file with the wrong contents is deleted,
+    /// since resuming it would only reproduce the corruption; a short one is kept for resume.
+    /// Only the `.part` file is touched on failure, never an installed `dest`.
+    pub async fn finish(self, require_checksum: bool) -> Result<PathBuf, DownloadError> {
+        let Some(mut file) = self.file else {
+            // Installed file, already verified by `start`
+            return Ok(self.dest);
+        };
+        file.flush().await?;
+        drop(file);
+
+        let file_name = self
+            .dest
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if let Some(total) = self.total {
+            if self.downloaded < total {
+                return Err(DownloadError::Network {
+                    url: self.url,
+                    message: format!(
+                        "connection closed after {} of {} bytes",
+                        self.downloaded, total
+                    ),
+                });
+            }
+        }
+
+        if let Some(expected) = self.expected_size {
+            if self.downloaded != expected {
+                let _ = fs::remove_file(&self.part_path).await;
+                return Err(DownloadError::SizeMismatch {
+                    file: file_name,
+                    expected,
+                    actual: self.downloaded,
+                });
+            }
+        }
+
+        let actual = format!("{:x}", self.hasher.finalize());
+        match &self.expected_sha256 {
+            Some(expected) if *expected != actual => {
+                let _ = fs::remove_file(&self.part_path).await;
+                return Err(DownloadError::ChecksumMismatch {
+                    file: file_name,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+            Some(_) => info!("✅ Verified SHA-256 of {}", file_name),
+            None if require_checksum => return Err(DownloadError::MissingChecksum(file_name)),
+            None => warn!(
+                "⚠️ No checksum known for {}, only the size was checked (SHA-256 {})",
+                file_name, actual
+            ),
+        }
+
+        fs::rename(&self.part_path, &self.dest).await?;
+        Ok(self.dest)
+    }
+}
+
+/// Check a file that did not come through `ResumableDownload` (e.g. a copied import)
+/// against its manifest entry, with the same rules as `finish`
+pub async fn verify_file(
+    path: &Path,
+    file_name: &str,
+    expected: Option<&ManifestEntry>,
+    require_checksum: bool,
+) -> Result<(), DownloadError> {
+    let Some(expected) = expected else {
+        if require_checksum {
+            return Err(DownloadError::MissingChecksum(file_name.to_string()));
+        }
+        warn!("⚠️ No checksum known for {}, not verified", file_name);
+        return Ok(());
+    };
+
+    let (size, actual) = hash_file(path).await?;
+    if let Some(expected_size) = expected.size.filter(|s| *s != size) {
+        return Err(DownloadError::SizeMismatch {
+            file: file_name.to_string(),
+            expected: expected_size,
+            actual: size,
+        });
+    }
+    let expected_sha256 = expected.sha256.to_lowercase();
+    if actual != expected_sha256 {
+        return Err(DownloadError::ChecksumMismatch {
+            file: file_name.to_string(),
+            expected: expected_sha256,
+            actual,
+        });
+    }
+    info!("✅ Verified SHA-256 of {}", file_name);
+    Ok(())
+}
+
+/// Size and SHA-256 of a file on disk
+async fn hash_file(path: &Path) -> Result<(u64, String), DownloadError> {
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    let mut size = 0u64;
+    loop {
+        let n = file.read(&mut buffer).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+        size += n as u64;
+    }
+    Ok((size, format!("{:x}", hasher.finalize())))
+}
+
+/// `Content-Range: bytes <start>-<end>/<total>` or `bytes */<total>` as (start, total)
+fn parse_content_range(headers: &HeaderMap) -> Option<(u64, Option<u64>)> {
+    let value = headers.get(CONTENT_RANGE)?.to_str().ok()?;
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let total = total.trim().parse().ok();
+    let start = match range.trim() {
+        "*" => 0,
+        range => range.split_once('-')?.0.parse().ok()?,
+    };
+    Some((start, total))
+}
+
+/// Hugging Face reports the SHA-256 of LFS files as their (linked) ETag
+fn sha256_from_etag(headers: &HeaderMap) -> Option<String> {
+    ["x-linked-etag", ETAG.as_str()]
+        .iter()
+        .filter_map(|name| headers.get(*name)?.to_str().ok())
+        .map(|value| {
+            value
+                .trim()
+                .trim_start_matches("W/")
+                .trim_matches('"')
+                .to_lowercase()
+        })
+        .find(|value| value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[tauri::command]
+pub async fn get_model_download_settings() -> Result<ModelDownloadSettings, String> {
+    DOWNLOAD_SETTINGS
+        .read()
+        .map(|s| s.clone())
+        .map_err(|e| format!("Failed to read model download settings: {}", e))
+}
+
+#[tauri::command]
+pub async fn set_model_download_settings(settings: ModelDownloadSettings) -> Result<(), String> {
+    let mirror_url = settings
+        .mirror_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| !url.is_empty());
+    if let Some(url) = mirror_url {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err("Mirror URL must start with http:// or https://".to_string());
+        }
+    }
+
+    let settings = ModelDownloadSettings {
+        mirror_url: mirror_url.map(str::to_string),
+        ..settings
+    };
+    settings.save()?;
+    *DOWNLOAD_SETTINGS
+        .write()
+        .map_err(|e| format!("Failed to update model download settings: {}", e))? =
+        settings.clone();
+    info!(
+        "📦 Model downloads now use {}",
+        settings.mirror().unwrap_or(HUGGING_FACE_BASE_URL)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn sha256_hex(data: &[u8]) -> String {
+        format!("{:x}", Sha256::digest(data))
+    }
+
+    fn test_body() -> Vec<u8> {
+        (0..50_000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Serve `body` at any path, honoring `Range: bytes=N-` when `honor_range` is set.
+    /// Returns the base URL and the Range header of every request.
+    async fn spawn_file_server(
+        body: Vec<u8>,
+        honor_range: bool,
+    ) -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let seen = ranges.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    break;
+                };
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                while !String::from_utf8_lossy(&buf).contains("\r\n\r\n") {
+                    let n = stream.read(&mut chunk).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                }
+                let head = String::from_utf8_lossy(&buf).to_lowercase();
+                let range = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("range:"))
+                    .map(|v| v.trim().to_string());
+                seen.lock().unwrap().push(range.clone());
+
+                let start = range
+                    .as_deref()
+                    .filter(|_| honor_range)
+                    .and_then(|r| r.strip_prefix("bytes="))
+                    .and_then(|r| r.trim_end_matches('-').parse::<usize>().ok());
+                let (status, extra, slice) = match start {
+                    Some(start) if start >= body.len() => (
+                        "416 Range Not Satisfiable",
+                        format!("Content-Range: bytes */{}\r\n", body.len()),
+                        &body[0..0],
+                    ),
+                    Some(start) => (
+                        "206 Partial Content",
+                        format!(
+                            "Content-Range: bytes {}-{}/{}\r\n",
+                            start,
+                            body.len() - 1,
+                            body.len()
+                        ),
+                        &body[start..],
+                    ),
+                    None => ("200 OK", String::new(), &body[..]),
+                };
+
+                let head = format!(
+                    "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    extra,
+                    slice.len()
+                );
+                stream.write_all(head.as_bytes()).await.unwrap();
+                stream.write_all(slice).await.unwrap();
+                stream.shutdown().await.ok();
+            }
+        });
+
+        (url, ranges)
+    }
+
+    async fn download_all(
+        url: &str,
+        dest: &Path,
+        expected: Option<&ManifestEntry>,
+    ) -> Result<PathBuf, DownloadError> {
+        let client = Client::new();
+        let mut download = ResumableDownload::start(&client, url, dest, expected).await?;
+        while download.next_chunk().await?.is_some() {}
+        download.finish(false).await
+    }
+
+    #[test]
+    fn test_artifact_urls() {
+        let artifact = ModelArtifact::new("ggerganov/whisper.cpp", "ggml-base.bin");
+        assert_eq!(
+            artifact.url(&ModelDownloadSettings::default()),
+            "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.bin"
+        );
+
+        let mirrored = ModelDownloadSettings {
+            mirror_url: Some("https://artifacts.example.com/models/".to_string()),
+            require_checksum: false,
+        };
+        assert_eq!(
+            artifact.url(&mirrored),
+            "https://artifacts.example.com/models/ggerganov/whisper.cpp/ggml-base.bin"
+        );
+    }
+
+    #[test]
+    fn test_header_parsing() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_RANGE, "bytes 100-199/200".parse().unwrap());
+        assert_eq!(parse_content_range(&headers), Some((100, Some(200))));
+        headers.insert(CONTENT_RANGE, "bytes */200".parse().unwrap());
+        assert_eq!(parse_content_range(&headers), Some((0, Some(200))));
+
+        let sha = "a".repeat(64);
+        headers.insert(ETAG, format!("\"{}\"", sha).parse().unwrap());
+        assert_eq!(sha256_from_etag(&headers), Some(sha));
+        headers.insert(ETAG, "\"abc123\"".parse().unwrap());
+        assert_eq!(sha256_from_etag(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn test_download_verifies_checksum() {
+        let body = test_body();
+        let (url, _) = spawn_file_server(body.clone(), true).await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        let expected = ManifestEntry {
+            sha256: sha256_hex(&body),
+            size: Some(body.len() as u64),
+        };
+
+        download_all(&format!("{}/model.bin", url), &dest, Some(&expected))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), body);
+        assert!(!partial_path(&dest).exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_resumes_partial_file() {
+        let body = test_body();
+        let (url, ranges) = spawn_file_server(body.clone(), true).await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        std::fs::write(partial_path(&dest), &body[..20_000]).unwrap();
+        let expected = ManifestEntry {
+            sha256: sha256_hex(&body),
+            size: None,
+        };
+
+        download_all(&format!("{}/model.bin", url), &dest, Some(&expected))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), body);
+        assert_eq!(
+            ranges.lock().unwrap().as_slice(),
+            &[Some("bytes=20000-".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_file_is_only_verified() {
+        let body = test_body();
+        let (url, _) = spawn_file_server(body.clone(), true).await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        std::fs::write(&dest, &body).unwrap();
+        let expected = ManifestEntry {
+            sha256: sha256_hex(&body),
+            size: Some(body.len() as u64),
+        };
+
+        download_all(&format!("{}/model.bin", url), &dest, Some(&expected))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn test_failed_redownload_keeps_installed_file() {
+        let body = test_body();
+        let installed = vec![7u8; 1_000];
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        std::fs::write(&dest, &installed).unwrap();
+        let expected = ManifestEntry {
+            sha256: sha256_hex(&body),
+            size: Some(body.len() as u64),
+        };
+
+        // Server unreachable
+        let port = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let offline = format!("http://127.0.0.1:{}/model.bin", port);
+        let result = download_all(&offline, &dest, Some(&expected)).await;
+        assert!(matches!(result, Err(DownloadError::Network { .. })));
+        assert_eq!(std::fs::read(&dest).unwrap(), installed);
+
+        // New download fails verification
+        let (url, _) = spawn_file_server(body.clone(), true).await;
+        let wrong = ManifestEntry {
+            sha256: "0".repeat(64),
+            size: None,
+        };
+        let result = download_all(&format!("{}/model.bin", url), &dest, Some(&wrong)).await;
+        assert!(matches!(
+            result,
+            Err(DownloadError::ChecksumMismatch { .. })
+        ));
+        assert_eq!(std::fs::read(&dest).unwrap(), installed);
+        assert!(!partial_path(&dest).exists());
+
+        // A verified download replaces it
+        download_all(&format!("{}/model.bin", url), &dest, Some(&expected))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn test_restarts_when_server_ignores_range() {
+        let body = test_body();
+        let (url, _) = spawn_file_server(body.clone(), false).await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        std::fs::write(partial_path(&dest), vec![0xFFu8; 10_000]).unwrap();
+
+        download_all(&format!("{}/model.bin", url), &dest, None)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn test_checksum_mismatch_discards_file() {
+        let body = test_body();
+        let (url, _) = spawn_file_server(body, true).await;
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.bin");
+        let expected = ManifestEntry {
+            sha256: "0".repeat(64),
+            size: None,
+        };
+
+        let result = download_all(&format!("{}/model.bin", url), &dest, Some(&expected)).await;
+        assert!(matches!(
+            result,
+            Err(DownloadError::ChecksumMismatch { .. })
+        ));
+        assert!(!dest.exists());
+        assert!(!partial_path(&dest).exists());
+    }
+
+    #[tokio::test]
+    async fn test_http_error_status() {
+        let dir = tempfile::tempdir().unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/missing.bin", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            stream
+                .write_all(
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+        });
+
+        let result = download_all(&url, &dir.path().join("missing.bin"), None).await;
+        assert!(matches!(
+            result,
+            Err(DownloadError::HttpStatus { status: 404, .. })
+        ));
+    }
+}
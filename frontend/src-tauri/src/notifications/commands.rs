@@ -457,4 +457,18 @@ pub async fn show_system_error_notification(
         log_error!("Cannot show system error notification: manager not initialized");
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Show audio quality warning notification (internal use)
+pub async fn show_audio_quality_warning_notification<R: Runtime>(
+    manager_state: &NotificationManagerState<R>,
+    message: String,
+) -> Result<()> {
+    let manager_lock = manager_state.read().await;
+    if let Some(manager) = manager_lock.as_ref() {
+        manager.show_audio_quality_warning(message).await
+    } else {
+        log_error!("Cannot show audio quality warning notification: manager not initialized");
+        Ok(())
+    }
+}
@@ -183,6 +183,17 @@ impl<R: Runtime> NotificationManager<R> {
         self.show_notification(notification).await
     }
 
+    /// Show a live audio quality warning notification
+    pub async fn show_audio_quality_warning(&self, message: String) -> Result<()> {
+        let settings = self.settings.read().await;
+        if !settings.notification_preferences.show_audio_quality_warnings {
+            return Ok(());
+        }
+
+        let notification = Notification::audio_quality_warning(message);
+        self.show_notification(notification).await
+    }
+
     /// Show a test notification
     pub async fn show_test_notification(&self) -> Result<()> {
         let notification = Notification::test_notification();
@@ -300,6 +311,7 @@ impl<R: Runtime> NotificationManager<R> {
             NotificationType::TranscriptionComplete => settings.notification_preferences.show_transcription_complete,
             NotificationType::MeetingReminder(_) => settings.notification_preferences.show_meeting_reminders,
             NotificationType::SystemError(_) => settings.notification_preferences.show_system_errors,
+            NotificationType::AudioQualityWarning(_) => settings.notification_preferences.show_audio_quality_warnings,
             NotificationType::Test => true, // Always show test notifications
         }
     }
@@ -58,6 +58,10 @@ pub struct NotificationPreferences {
     /// Show system error notifications
     pub show_system_errors: bool,
 
+    /// Show live audio quality warnings during recording (muted mic, clipping, silent system audio)
+    #[serde(default = "default_show_audio_quality_warnings")]
+    pub show_audio_quality_warnings: bool,
+
     /// Minutes before meeting to show reminder (0 = disabled)
     pub meeting_reminder_minutes: Vec<u64>,
 }
@@ -88,11 +92,16 @@ impl Default for NotificationPreferences {
             show_transcription_complete: true,
             show_meeting_reminders: true,
             show_system_errors: true,
+            show_audio_quality_warnings: true,
             meeting_reminder_minutes: vec![15, 5], // 15 minutes and 5 minutes before
         }
     }
 }
 
+fn default_show_audio_quality_warnings() -> bool {
+    true
+}
+
 /// Manages notification consent and user preferences
 pub struct ConsentManager<R: Runtime> {
     #[allow(dead_code)] // Reserved for future functionality
@@ -22,6 +22,7 @@ pub enum NotificationType {
     TranscriptionComplete,
     MeetingReminder(u64), // Duration in minutes
     SystemError(String),
+    AudioQualityWarning(String),
     Test, // For testing notifications
 }
 
@@ -187,6 +188,17 @@ impl Notification {
         .with_timeout(NotificationTimeout::Never)
     }
 
+    pub fn audio_quality_warning(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Notification::new(
+            "Meetily - Audio Check",
+            message.clone(),
+            NotificationType::AudioQualityWarning(message)
+        )
+        .with_priority(NotificationPriority::High)
+        .with_timeout(NotificationTimeout::Seconds(8))
+    }
+
     pub fn test_notification() -> Self {
         Notification::new(
             "Meetily",
@@ -0,0 +1,165 @@
+use super::engine::{OnnxAsrEngine, OnnxAsrModelInfo, OnnxAsrModelStatus};
+use std::sync::{Arc, Mutex};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+
+// Global ONNX ASR engine
+pub static ONNX_ASR_ENGINE: Mutex<Option<Arc<OnnxAsrEngine>>> = Mutex::new(None);
+
+fn current_engine() -> Result<Arc<OnnxAsrEngine>, String> {
+    ONNX_ASR_ENGINE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .cloned()
+        .ok_or_else(|| "ONNX ASR engine not initialized".to_string())
+}
+
+#[command]
+pub async fn onnx_asr_init() -> Result<(), String> {
+    let mut guard = ONNX_ASR_ENGINE.lock().unwrap();
+    if guard.is_some() {
+        return Ok(());
+    }
+
+    // Shares the app's models directory with Whisper and Parakeet
+    let models_dir = crate::parakeet_engine::commands::get_models_directory();
+    let engine = OnnxAsrEngine::new_with_models_dir(models_dir)
+        .map_err(|e| format!("Failed to initialize ONNX ASR engine: {}", e))?;
+    *guard = Some(Arc::new(engine));
+    Ok(())
+}
+
+#[command]
+pub async fn onnx_asr_get_available_models() -> Result<Vec<OnnxAsrModelInfo>, String> {
+    current_engine()?
+        .discover_models()
+        .await
+        .map_err(|e| format!("Failed to discover ONNX ASR models: {}", e))
+}
+
+#[command]
+pub async fn onnx_asr_load_model<R: Runtime>(
+    app_handle: AppHandle<R>,
+    model_name: String,
+) -> Result<(), String> {
+    let engine = current_engine()?;
+
+    if let Err(e) = app_handle.emit(
+        "onnx-asr-model-loading-started",
+        serde_json::json!({ "modelName": model_name }),
+    ) {
+        log::error!("Failed to emit onnx-asr-model-loading-started event: {}", e);
+    }
+
+    let result = engine
+        .load_model(&model_name)
+        .await
+        .map_err(|e| format!("Failed to load ONNX ASR model: {}", e));
+
+    let (event, payload) = match &result {
+        Ok(()) => (
+            "onnx-asr-model-loading-completed",
+            serde_json::json!({ "modelName": model_name }),
+        ),
+        Err(error) => (
+            "onnx-asr-model-loading-failed",
+            serde_json::json!({ "modelName": model_name, "error": error }),
+        ),
+    };
+    if let Err(e) = app_handle.emit(event, payload) {
+        log::error!("Failed to emit {} event: {}", event, e);
+    }
+
+    result
+}
+
+#[command]
+pub async fn onnx_asr_get_current_model() -> Result<Option<String>, String> {
+    Ok(current_engine()?.get_current_model().await)
+}
+
+#[command]
+pub async fn onnx_asr_get_models_directory() -> Result<String, String> {
+    Ok(current_engine()?.models_dir().to_string_lossy().to_string())
+}
+
+/// Import a model directory containing a `manifest.json`
+#[command]
+pub async fn onnx_asr_import_model(
+    source: String,
+    name: String,
+) -> Result<OnnxAsrModelInfo, String> {
+    current_engine()?
+        .import_model(&source, &name)
+        .await
+        .map_err(|e| format!("Failed to import ONNX ASR model: {}", e))
+}
+
+#[command]
+pub async fn onnx_asr_remove_model(model_name: String) -> Result<(), String> {
+    current_engine()?
+        .remove_model(&model_name)
+        .await
+        .map_err(|e| format!("Failed to remove ONNX ASR model: {}", e))
+}
+
+/// Make sure the model selected in the transcript config is loaded, falling back to the
+/// first valid model. Mirrors `parakeet_validate_model_ready_with_config`.
+pub async fn onnx_asr_validate_model_ready_with_config<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<String, String> {
+    let engine = current_engine()?;
+
+    let configured =
+        match crate::api::api::api_get_transcript_config(app.clone(), app.state(), None).await {
+            Ok(Some(config)) if config.provider == "onnxAsr" && !config.model.is_empty() => {
+                Some(config.model)
+            }
+            Ok(_) => None,
+            Err(e) => {
+                log::warn!(
+                    "Failed to get transcript config from API: {}, will auto-select ONNX ASR model",
+                    e
+                );
+                None
+            }
+        };
+
+    let models = engine
+        .discover_models()
+        .await
+        .map_err(|e| format!("Failed to discover ONNX ASR models: {}", e))?;
+    let available: Vec<&OnnxAsrModelInfo> = models
+        .iter()
+        .filter(|m| matches!(m.status, OnnxAsrModelStatus::Available))
+        .collect();
+
+    let model_name = match configured {
+        Some(name) if available.iter().any(|m| m.name == name) => name,
+        other => {
+            let fallback = available.first().map(|m| m.name.clone()).ok_or_else(|| {
+                "No ONNX ASR models are available. Import a model directory with a manifest.json first."
+                    .to_string()
+            })?;
+            if let Some(name) = other {
+                log::warn!(
+                    "Configured ONNX ASR model '{}' not available, falling back to '{}'",
+                    name,
+                    fallback
+                );
+            }
+            fallback
+        }
+    };
+
+    if engine.get_current_model().await.as_deref() == Some(model_name.as_str()) {
+        log::info!("ONNX ASR model already loaded: {}", model_name);
+        return Ok(model_name);
+    }
+
+    engine
+        .load_model(&model_name)
+        .await
+        .map_err(|e| format!("Failed to load ONNX ASR model {}: {}", model_name, e))?;
+    Ok(model_name)
+}
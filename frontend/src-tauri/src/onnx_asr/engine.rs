@@ -0,0 +1,368 @@
+// onnx_asr/engine.rs
+//
+// Model management for manifest-driven ONNX ASR models. Every directory below
+// `models/onnx-asr` holding a `manifest.json` is a model; its directory name is the
+// model ID stored in the transcript settings.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::RwLock;
+
+use super::manifest::{OnnxAsrManifest, MANIFEST_FILE};
+use super::model::{OnnxAsrModel, TimestampedResult};
+use crate::custom_models::{self, ImportSource};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OnnxAsrModelStatus {
+    Available,
+    /// The manifest is unreadable or names files that are missing
+    Invalid(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnnxAsrModelInfo {
+    /// Directory name, used as the model ID
+    pub name: String,
+    /// Model family from the manifest, e.g. "Moonshine Base"
+    pub display_name: String,
+    pub path: PathBuf,
+    pub description: String,
+    pub languages: Vec<String>,
+    /// "ctc", "transducer" or "attention"
+    pub decoder: String,
+    /// int8 sessions are declared and present
+    pub quantized: bool,
+    pub size_mb: u32,
+    pub status: OnnxAsrModelStatus,
+}
+
+pub struct OnnxAsrEngine {
+    models_dir: PathBuf,
+    current_model: Arc<RwLock<Option<OnnxAsrModel>>>,
+    current_model_name: Arc<RwLock<Option<String>>>,
+    available_models: Arc<RwLock<HashMap<String, OnnxAsrModelInfo>>>,
+}
+
+impl OnnxAsrEngine {
+    /// Create the engine over `<models_dir>/onnx-asr`
+    pub fn new_with_models_dir(models_dir: Option<PathBuf>) -> Result<Self> {
+        let models_dir = match models_dir {
+            Some(dir) => dir.join("onnx-asr"),
+            None => dirs::data_dir()
+                .or_else(dirs::home_dir)
+                .ok_or_else(|| anyhow!("Could not find system data directory"))?
+                .join("Meetily")
+                .join("models")
+                .join("onnx-asr"),
+        };
+
+        log::info!(
+            "OnnxAsrEngine using models directory: {}",
+            models_dir.display()
+        );
+        if !models_dir.exists() {
+            std::fs::create_dir_all(&models_dir)?;
+        }
+
+        Ok(Self {
+            models_dir,
+            current_model: Arc::new(RwLock::new(None)),
+            current_model_name: Arc::new(RwLock::new(None)),
+            available_models: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    pub fn models_dir(&self) -> &Path {
+        &self.models_dir
+    }
+
+    /// Scan the models directory for manifests
+    pub async fn discover_models(&self) -> Result<Vec<OnnxAsrModelInfo>> {
+        let mut models = Vec::new();
+        let mut entries = fs::read_dir(&self.models_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() && path.join(MANIFEST_FILE).exists() {
+                models.push(Self::describe_model(&path));
+            }
+        }
+        models.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut available = self.available_models.write().await;
+        available.clear();
+        for model in &models {
+            available.insert(model.name.clone(), model.clone());
+        }
+
+        Ok(models)
+    }
+
+    fn describe_model(path: &Path) -> OnnxAsrModelInfo {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let size_mb = custom_models::size_on_disk_mb(path);
+
+        match OnnxAsrManifest::load(path) {
+            Ok(manifest) => {
+                let quantized = Self::is_quantized(path, &manifest);
+                let missing: Vec<String> = manifest
+                    .required_files(quantized)
+                    .into_iter()
+                    .filter(|file| !path.join(file).exists())
+                    .collect();
+                let status = if missing.is_empty() {
+                    OnnxAsrModelStatus::Available
+                } else {
+                    OnnxAsrModelStatus::Invalid(format!("Missing files: {}", missing.join(", ")))
+                };
+                OnnxAsrModelInfo {
+                    name,
+                    display_name: manifest.name.clone(),
+                    path: path.to_path_buf(),
+                    description: manifest.description.clone(),
+                    languages: manifest.languages.clone(),
+                    decoder: manifest.decoder_kind().to_string(),
+                    quantized,
+                    size_mb,
+                    status,
+                }
+            }
+            Err(e) => OnnxAsrModelInfo {
+                display_name: name.clone(),
+                name,
+                path: path.to_path_buf(),
+                description: String::new(),
+                languages: Vec::new(),
+                decoder: String::new(),
+                quantized: false,
+                size_mb,
+                status: OnnxAsrModelStatus::Invalid(e.to_string()),
+            },
+        }
+    }
+
+    /// Use the int8 sessions when the manifest declares them and they were shipped
+    fn is_quantized(path: &Path, manifest: &OnnxAsrManifest) -> bool {
+        manifest.has_int8_variant()
+            && manifest
+                .required_files(true)
+                .iter()
+                .all(|file| path.join(file).exists())
+    }
+
+    /// Load a model, replacing the current one
+    pub async fn load_model(&self, model_name: &str) -> Result<()> {
+        if self.current_model_name.read().await.as_deref() == Some(model_name) {
+            log::info!(
+                "ONNX ASR model {} is already loaded, skipping reload",
+                model_name
+            );
+            return Ok(());
+        }
+
+        // Bind first: the read guard must be released before discovery takes the write lock
+        let known = self.available_models.read().await.get(model_name).cloned();
+        let info = match known {
+            Some(info) => info,
+            None => self
+                .discover_models()
+                .await?
+                .into_iter()
+                .find(|m| m.name == model_name)
+                .ok_or_else(|| anyhow!("Model {} not found", model_name))?,
+        };
+        if let OnnxAsrModelStatus::Invalid(reason) = &info.status {
+            return Err(anyhow!(
+                "ONNX ASR model {} cannot be loaded: {}",
+                model_name,
+                reason
+            ));
+        }
+
+        self.unload_model().await;
+        log::info!("Loading ONNX ASR model: {}", model_name);
+
+        let model = Self::load_from_dir(info.path.clone()).await?;
+        *self.current_model.write().await = Some(model);
+        *self.current_model_name.write().await = Some(model_name.to_string());
+
+        log::info!(
+            "Successfully loaded ONNX ASR model: {} ({}, {})",
+            model_name,
+            info.decoder,
+            if info.quantized {
+                "Int8 quantized"
+            } else {
+                "FP32"
+            }
+        );
+        Ok(())
+    }
+
+    async fn load_from_dir(path: PathBuf) -> Result<OnnxAsrModel> {
+        tokio::task::spawn_blocking(move || {
+            let manifest = OnnxAsrManifest::load(&path)?;
+            let quantized = Self::is_quantized(&path, &manifest);
+            OnnxAsrModel::load(&path, manifest, quantized)
+                .map_err(|e| anyhow!("ONNX Runtime could not load the model: {}", e))
+        })
+        .await
+        .map_err(|e| anyhow!("Model loading task failed: {}", e))?
+    }
+
+    /// Unload the current model
+    pub async fn unload_model(&self) -> bool {
+        let unloaded = self.current_model.write().await.take().is_some();
+        if unloaded {
+            log::info!("ONNX ASR model unloaded");
+        }
+        self.current_model_name.write().await.take();
+        unloaded
+    }
+
+    pub async fn get_current_model(&self) -> Option<String> {
+        self.current_model_name.read().await.clone()
+    }
+
+    pub async fn is_model_loaded(&self) -> bool {
+        self.current_model.read().await.is_some()
+    }
+
+    /// Whether the loaded model can transcribe `language` (false when nothing is loaded)
+    pub async fn supports_language(&self, language: &str) -> bool {
+        self.current_model
+            .read()
+            .await
+            .as_ref()
+            .is_some_and(|model| model.manifest().supports_language(language))
+    }
+
+    /// Transcribe 16 kHz audio with the loaded model. Inference runs on the blocking
+    /// pool so it does not stall the async runtime.
+    pub async fn transcribe_audio_with_timestamps(
+        &self,
+        audio_data: Vec<f32>,
+        language: Option<&str>,
+    ) -> Result<TimestampedResult> {
+        let mut model_guard = self.current_model.clone().write_owned().await;
+        if model_guard.is_none() {
+            return Err(anyhow!(
+                "No ONNX ASR model loaded. Please load a model first."
+            ));
+        }
+
+        log::debug!(
+            "ONNX ASR transcribing {} samples ({:.1}s duration)",
+            audio_data.len(),
+            audio_data.len() as f64 / 16000.0
+        );
+
+        let language = language.map(str::to_string);
+        let result = tokio::task::spawn_blocking(move || {
+            let model = model_guard
+                .as_mut()
+                .ok_or_else(|| anyhow!("No ONNX ASR model loaded. Please load a model first."))?;
+            model
+                .transcribe_samples(audio_data, language.as_deref())
+                .map_err(|e| anyhow!("ONNX ASR transcription failed: {}", e))
+        })
+        .await
+        .map_err(|e| anyhow!("Transcription task failed: {}", e))??;
+
+        log::debug!("ONNX ASR transcription result: '{}'", result.text);
+        Ok(result)
+    }
+
+    /// Import a model directory (manifest plus the files it names) under a user-chosen
+    /// name. The model is loaded once to make sure ONNX Runtime accepts it.
+    pub async fn import_model(&self, source: &str, name: &str) -> Result<OnnxAsrModelInfo> {
+        let src_dir = match ImportSource::parse(source)? {
+            ImportSource::Path(path) if path.is_dir() => path,
+            _ => {
+                return Err(anyhow!(
+                    "ONNX ASR models are imported from a local directory containing {}",
+                    MANIFEST_FILE
+                ))
+            }
+        };
+        OnnxAsrManifest::load(&src_dir)?;
+
+        let taken: Vec<String> = self
+            .discover_models()
+            .await?
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        let name = custom_models::validate_model_name(name, &taken)?;
+        let model_dir = self.models_dir.join(&name);
+
+        log::info!(
+            "📥 Importing ONNX ASR model '{}' from {}",
+            name,
+            src_dir.display()
+        );
+        fs::create_dir_all(&model_dir)
+            .await
+            .map_err(|e| anyhow!("Failed to create model directory: {}", e))?;
+
+        let imported = match Self::copy_model_files(&src_dir, &model_dir).await {
+            Ok(()) => Self::load_from_dir(model_dir.clone()).await.map(drop),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = imported {
+            let _ = fs::remove_dir_all(&model_dir).await;
+            return Err(e);
+        }
+
+        log::info!("✅ Imported ONNX ASR model '{}'", name);
+        self.discover_models()
+            .await?
+            .into_iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| anyhow!("Imported model '{}' was not discovered", name))
+    }
+
+    /// Copy the top-level files of an exported model directory
+    async fn copy_model_files(src_dir: &Path, model_dir: &Path) -> Result<()> {
+        let mut entries = fs::read_dir(src_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_file() {
+                custom_models::copy_file(&entry.path(), &model_dir.join(entry.file_name())).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete a model directory, unloading it first if needed
+    pub async fn remove_model(&self, model_name: &str) -> Result<()> {
+        let model_dir = self
+            .discover_models()
+            .await?
+            .into_iter()
+            .find(|m| m.name == model_name)
+            .map(|m| m.path)
+            .ok_or_else(|| anyhow!("ONNX ASR model '{}' not found", model_name))?;
+
+        if self.current_model_name.read().await.as_deref() == Some(model_name) {
+            self.unload_model().await;
+        }
+
+        fs::remove_dir_all(&model_dir).await.map_err(|e| {
+            anyhow!(
+                "Failed to delete directory '{}': {}",
+                model_dir.display(),
+                e
+            )
+        })?;
+        self.discover_models().await?;
+
+        log::info!("🗑️ Removed ONNX ASR model '{}'", model_name);
+        Ok(())
+    }
+}
@@ -0,0 +1,473 @@
+// onnx_asr/manifest.rs
+//
+// `manifest.json` describing how to run an exported ONNX speech recognition model:
+// which sessions it has, their tensor names, how the decoder works and where the
+// vocabulary lives. Parakeet's built-in layout is expressed with the same structure.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Placeholder in `start_tokens` replaced by the requested language code
+pub const LANGUAGE_PLACEHOLDER: &str = "{language}";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnnxAsrManifest {
+    /// Human readable model family, e.g. "Moonshine Base"
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// ISO 639-1 codes the model can transcribe; empty means it detects the language itself
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default = "default_sample_rate")]
+    pub sample_rate: u32,
+    /// Audio seconds covered by one encoder output frame (token timestamps)
+    pub frame_seconds: f32,
+    pub preprocessor: PreprocessorSpec,
+    pub encoder: EncoderSpec,
+    pub decoder: DecoderSpec,
+    pub vocabulary: VocabularySpec,
+}
+
+/// Feature extraction in front of the encoder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PreprocessorSpec {
+    /// A separate ONNX session turning waveforms into features (NeMo's `nemo128.onnx`)
+    #[serde(rename_all = "camelCase")]
+    Onnx {
+        file: String,
+        #[serde(default = "default_waveform_input")]
+        waveform_input: String,
+        #[serde(default = "default_waveform_length_input")]
+        length_input: String,
+        #[serde(default = "default_features_output")]
+        features_output: String,
+        #[serde(default = "default_features_length_output")]
+        length_output: String,
+    },
+    /// The encoder takes the raw 16 kHz waveform itself (Moonshine)
+    Waveform,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncoderSpec {
+    pub file: String,
+    /// Quantized variant, used instead of `file` when present and quantization is wanted
+    #[serde(default)]
+    pub int8_file: Option<String>,
+    pub input: String,
+    /// Length input next to `input`; omitted by exports that infer it from the shape
+    #[serde(default)]
+    pub length_input: Option<String>,
+    pub output: String,
+    #[serde(default)]
+    pub length_output: Option<String>,
+    /// Output is `[batch, channels, frames]` and needs transposing to `[batch, frames, channels]`
+    #[serde(default)]
+    pub channels_first: bool,
+}
+
+/// How tokens are produced from the encoder output
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DecoderSpec {
+    /// The encoder output already holds per-frame token logits (SenseVoice, CTC Canary/NeMo)
+    Ctc,
+    /// RNN-T / TDT prediction + joint network stepped frame by frame (Parakeet)
+    #[serde(rename_all = "camelCase")]
+    Transducer {
+        file: String,
+        #[serde(default)]
+        int8_file: Option<String>,
+        #[serde(default = "default_transducer_encoder_input")]
+        encoder_input: String,
+        #[serde(default = "default_targets_input")]
+        targets_input: String,
+        #[serde(default = "default_target_length_input")]
+        target_length_input: String,
+        #[serde(default = "default_transducer_logits_output")]
+        logits_output: String,
+        /// Recurrent state tensors, paired with `state_outputs` in order
+        #[serde(default = "default_state_inputs")]
+        state_inputs: Vec<String>,
+        #[serde(default = "default_state_outputs")]
+        state_outputs: Vec<String>,
+        #[serde(default = "default_max_tokens_per_step")]
+        max_tokens_per_step: usize,
+    },
+    /// Autoregressive attention decoder re-run over the whole prefix each step
+    /// (Moonshine, Canary)
+    #[serde(rename_all = "camelCase")]
+    Attention {
+        file: String,
+        #[serde(default)]
+        int8_file: Option<String>,
+        #[serde(default = "default_input_ids_input")]
+        input_ids_input: String,
+        #[serde(default = "default_encoder_states_input")]
+        encoder_states_input: String,
+        #[serde(default = "default_attention_logits_output")]
+        logits_output: String,
+        /// Prompt tokens; `{language}` is replaced with the requested language
+        start_tokens: Vec<String>,
+        end_token: String,
+        /// Upper bound on generated tokens per second of audio
+        #[serde(default = "default_max_tokens_per_second")]
+        max_tokens_per_second: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VocabularyFormat {
+    /// `token id` per line (NeMo `vocab.txt`)
+    TokenId,
+    /// One token per line, the line number being its id (`tokens.txt`)
+    Lines,
+    /// JSON object mapping tokens to ids (`vocab.json`)
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VocabularySpec {
+    pub file: String,
+    pub format: VocabularyFormat,
+    /// Blank token of CTC and transducer models
+    #[serde(default)]
+    pub blank_token: Option<String>,
+    /// SentencePiece word boundary marker rendered as a space
+    #[serde(default = "default_word_boundary")]
+    pub word_boundary: String,
+}
+
+impl OnnxAsrManifest {
+    /// Read and check `manifest.json` from a model directory
+    pub fn load(model_dir: &Path) -> Result<Self> {
+        let path = model_dir.join(MANIFEST_FILE);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let manifest: Self = serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("Invalid {}: {}", path.display(), e))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Catch manifests that could never run before any session is created
+    pub fn validate(&self) -> Result<()> {
+        if self.sample_rate != 16000 {
+            return Err(anyhow!(
+                "Only 16 kHz models are supported (manifest says {} Hz)",
+                self.sample_rate
+            ));
+        }
+        if self.frame_seconds.is_nan() || self.frame_seconds <= 0.0 {
+            return Err(anyhow!("frameSeconds must be positive"));
+        }
+        match &self.decoder {
+            DecoderSpec::Ctc | DecoderSpec::Transducer { .. }
+                if self.vocabulary.blank_token.is_none() =>
+            {
+                Err(anyhow!(
+                    "CTC and transducer models need vocabulary.blankToken"
+                ))
+            }
+            DecoderSpec::Transducer {
+                state_inputs,
+                state_outputs,
+                ..
+            } if state_inputs.len() != state_outputs.len() => Err(anyhow!(
+                "Transducer stateInputs and stateOutputs must have the same length"
+            )),
+            DecoderSpec::Attention { start_tokens, .. } if start_tokens.is_empty() => {
+                Err(anyhow!("Attention decoders need at least one start token"))
+            }
+            DecoderSpec::Attention { start_tokens, .. }
+                if self.languages.is_empty()
+                    && start_tokens
+                        .iter()
+                        .any(|t| t.contains(LANGUAGE_PLACEHOLDER)) =>
+            {
+                Err(anyhow!(
+                    "startTokens use {} but the manifest lists no languages",
+                    LANGUAGE_PLACEHOLDER
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Files the model needs, quantized variants included when they are used
+    pub fn required_files(&self, quantized: bool) -> Vec<String> {
+        let pick = |file: &String, int8: &Option<String>| match int8 {
+            Some(int8) if quantized => int8.clone(),
+            _ => file.clone(),
+        };
+
+        let mut files = vec![
+            pick(&self.encoder.file, &self.encoder.int8_file),
+            self.vocabulary.file.clone(),
+        ];
+        if let PreprocessorSpec::Onnx { file, .. } = &self.preprocessor {
+            files.push(file.clone());
+        }
+        match &self.decoder {
+            DecoderSpec::Ctc => {}
+            DecoderSpec::Transducer {
+                file, int8_file, ..
+            }
+            | DecoderSpec::Attention {
+                file, int8_file, ..
+            } => {
+                files.push(pick(file, int8_file));
+            }
+        }
+        files
+    }
+
+    /// Whether a quantized variant is declared for every session that has one
+    pub fn has_int8_variant(&self) -> bool {
+        let decoder_int8 = match &self.decoder {
+            DecoderSpec::Ctc => true,
+            DecoderSpec::Transducer { int8_file, .. }
+            | DecoderSpec::Attention { int8_file, .. } => int8_file.is_some(),
+        };
+        self.encoder.int8_file.is_some() && decoder_int8
+    }
+
+    /// Whether `language` can be requested. Models without a language list accept anything
+    /// (they detect it), and "auto" is always accepted.
+    pub fn supports_language(&self, language: &str) -> bool {
+        language == "auto"
+            || self.languages.is_empty()
+            || self
+                .languages
+                .iter()
+                .any(|l| l.eq_ignore_ascii_case(language))
+    }
+
+    /// Short decoder kind shown in the UI
+    pub fn decoder_kind(&self) -> &'static str {
+        match self.decoder {
+            DecoderSpec::Ctc => "ctc",
+            DecoderSpec::Transducer { .. } => "transducer",
+            DecoderSpec::Attention { .. } => "attention",
+        }
+    }
+
+    /// NeMo Parakeet TDT export as published by istupakov/parakeet-tdt-*-onnx
+    pub fn parakeet_tdt() -> Self {
+        Self {
+            name: "Parakeet TDT".to_string(),
+            description: "NVIDIA NeMo Parakeet TDT transducer".to_string(),
+            languages: Vec::new(),
+            sample_rate: default_sample_rate(),
+            // 10 ms hop, 8x encoder subsampling
+            frame_seconds: 0.08,
+            preprocessor: PreprocessorSpec::Onnx {
+                file: "nemo128.onnx".to_string(),
+                waveform_input: default_waveform_input(),
+                length_input: default_waveform_length_input(),
+                features_output: default_features_output(),
+                length_output: default_features_length_output(),
+            },
+            encoder: EncoderSpec {
+                file: "encoder-model.onnx".to_string(),
+                int8_file: Some("encoder-model.int8.onnx".to_string()),
+                input: "audio_signal".to_string(),
+                length_input: Some("length".to_string()),
+                output: "outputs".to_string(),
+                length_output: Some("encoded_lengths".to_string()),
+                channels_first: true,
+            },
+            decoder: DecoderSpec::Transducer {
+                file: "decoder_joint-model.onnx".to_string(),
+                int8_file: Some("decoder_joint-model.int8.onnx".to_string()),
+                encoder_input: default_transducer_encoder_input(),
+                targets_input: default_targets_input(),
+                target_length_input: default_target_length_input(),
+                logits_output: default_transducer_logits_output(),
+                state_inputs: default_state_inputs(),
+                state_outputs: default_state_outputs(),
+                max_tokens_per_step: default_max_tokens_per_step(),
+            },
+            vocabulary: VocabularySpec {
+                file: "vocab.txt".to_string(),
+                format: VocabularyFormat::TokenId,
+                blank_token: Some("<blk>".to_string()),
+                word_boundary: default_word_boundary(),
+            },
+        }
+    }
+}
+
+fn default_sample_rate() -> u32 {
+    16000
+}
+
+fn default_waveform_input() -> String {
+    "waveforms".to_string()
+}
+
+fn default_waveform_length_input() -> String {
+    "waveforms_lens".to_string()
+}
+
+fn default_features_output() -> String {
+    "features".to_string()
+}
+
+fn default_features_length_output() -> String {
+    "features_lens".to_string()
+}
+
+fn default_transducer_encoder_input() -> String {
+    "encoder_outputs".to_string()
+}
+
+fn default_targets_input() -> String {
+    "targets".to_string()
+}
+
+fn default_target_length_input() -> String {
+    "target_length".to_string()
+}
+
+fn default_transducer_logits_output() -> String {
+    "outputs".to_string()
+}
+
+fn default_state_inputs() -> Vec<String> {
+    vec!["input_states_1".to_string(), "input_states_2".to_string()]
+}
+
+fn default_state_outputs() -> Vec<String> {
+    vec!["output_states_1".to_string(), "output_states_2".to_string()]
+}
+
+fn default_max_tokens_per_step() -> usize {
+    10
+}
+
+fn default_input_ids_input() -> String {
+    "input_ids".to_string()
+}
+
+fn default_encoder_states_input() -> String {
+    "encoder_hidden_states".to_string()
+}
+
+fn default_attention_logits_output() -> String {
+    "logits".to_string()
+}
+
+fn default_max_tokens_per_second() -> f32 {
+    6.5
+}
+
+fn default_word_boundary() -> String {
+    "\u{2581}".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_attention_manifest_with_defaults() {
+        let json = r#"{
+            "name": "Moonshine Base",
+            "frameSeconds": 0.02,
+            "languages": ["en"],
+            "preprocessor": { "type": "waveform" },
+            "encoder": { "file": "encoder_model.onnx", "input": "input_values", "output": "last_hidden_state" },
+            "decoder": {
+                "type": "attention",
+                "file": "decoder_model.onnx",
+                "startTokens": ["<s>"],
+                "endToken": "</s>"
+            },
+            "vocabulary": { "file": "tokens.txt", "format": "lines" }
+        }"#;
+        let manifest: OnnxAsrManifest = serde_json::from_str(json).unwrap();
+        manifest.validate().unwrap();
+
+        assert_eq!(manifest.sample_rate, 16000);
+        assert_eq!(manifest.preprocessor, PreprocessorSpec::Waveform);
+        assert_eq!(manifest.decoder_kind(), "attention");
+        match &manifest.decoder {
+            DecoderSpec::Attention {
+                input_ids_input,
+                logits_output,
+                ..
+            } => {
+                assert_eq!(input_ids_input, "input_ids");
+                assert_eq!(logits_output, "logits");
+            }
+            other => panic!("unexpected decoder {:?}", other),
+        }
+        assert_eq!(
+            manifest.required_files(true),
+            vec!["encoder_model.onnx", "tokens.txt", "decoder_model.onnx"]
+        );
+    }
+
+    #[test]
+    fn rejects_ctc_without_blank_token() {
+        let mut manifest = OnnxAsrManifest::parakeet_tdt();
+        manifest.decoder = DecoderSpec::Ctc;
+        manifest.vocabulary.blank_token = None;
+        assert!(manifest.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_language_placeholder_without_languages() {
+        let mut manifest = OnnxAsrManifest::parakeet_tdt();
+        manifest.decoder = DecoderSpec::Attention {
+            file: "decoder.onnx".to_string(),
+            int8_file: None,
+            input_ids_input: default_input_ids_input(),
+            encoder_states_input: default_encoder_states_input(),
+            logits_output: default_attention_logits_output(),
+            start_tokens: vec![
+                "<|startoftranscript|>".to_string(),
+                "<|{language}|>".to_string(),
+            ],
+            end_token: "<|endoftext|>".to_string(),
+            max_tokens_per_second: default_max_tokens_per_second(),
+        };
+        assert!(manifest.validate().is_err());
+
+        manifest.languages = vec!["en".to_string(), "de".to_string()];
+        manifest.validate().unwrap();
+        assert!(manifest.supports_language("DE"));
+        assert!(manifest.supports_language("auto"));
+        assert!(!manifest.supports_language("fr"));
+    }
+
+    #[test]
+    fn parakeet_manifest_round_trips() {
+        let manifest = OnnxAsrManifest::parakeet_tdt();
+        manifest.validate().unwrap();
+        assert!(manifest.has_int8_variant());
+        assert_eq!(
+            manifest.required_files(true),
+            vec![
+                "encoder-model.int8.onnx",
+                "vocab.txt",
+                "nemo128.onnx",
+                "decoder_joint-model.int8.onnx"
+            ]
+        );
+
+        let json = serde_json::to_string(&manifest).unwrap();
+        let parsed: OnnxAsrManifest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, manifest);
+    }
+}
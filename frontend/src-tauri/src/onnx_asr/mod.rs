@@ -0,0 +1,27 @@
+//! Manifest-driven ONNX speech recognition models.
+//!
+//! A model is a directory of ONNX sessions plus a `manifest.json` describing the
+//! feature extractor, encoder, decoder type (CTC, transducer or attention), vocabulary
+//! and supported languages. This lets exports such as Moonshine, SenseVoice or Canary
+//! run without model-specific code; Parakeet uses the same runner with a built-in manifest.
+//!
+//! # Module Structure
+//!
+//! - `manifest`: `manifest.json` schema and the built-in Parakeet TDT layout
+//! - `vocabulary`: token tables and text reconstruction
+//! - `model`: ONNX Runtime sessions and greedy decoding
+//! - `runtime`: session tuning (threads, optimization, memory, execution providers)
+//! - `engine`: model discovery, loading, import and removal
+//! - `commands`: Tauri command interface for frontend integration
+
+pub mod commands;
+pub mod engine;
+pub mod manifest;
+pub mod model;
+pub mod runtime;
+pub mod vocabulary;
+
+pub use commands::*;
+pub use engine::{OnnxAsrEngine, OnnxAsrModelInfo, OnnxAsrModelStatus};
+pub use manifest::OnnxAsrManifest;
+pub use model::{OnnxAsrError, OnnxAsrModel, TimestampedResult};
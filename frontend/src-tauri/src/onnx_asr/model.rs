@@ -0,0 +1,621 @@
+// onnx_asr/model.rs
+//
+// Runs an ONNX speech recognition model as described by its manifest: optional feature
+// extraction session, encoder, then greedy CTC, transducer or attention decoding.
+
+use ndarray::{Array1, Array2, ArrayD, ArrayView2, Axis, Ix2, IxDyn};
+use ort::inputs;
+use ort::session::Session;
+use ort::value::TensorRef;
+use std::path::{Path, PathBuf};
+
+use super::manifest::{DecoderSpec, OnnxAsrManifest, PreprocessorSpec, LANGUAGE_PLACEHOLDER};
+use super::runtime::{self, ResolvedRuntimeConfig};
+use super::vocabulary::Vocabulary;
+
+#[derive(Debug, Clone)]
+pub struct TimestampedResult {
+    pub text: String,
+    /// Start of each token in seconds. Empty for attention decoders, which have no alignment.
+    pub timestamps: Vec<f32>,
+    pub tokens: Vec<String>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum OnnxAsrError {
+    #[error("ORT error: {0}")]
+    Ort(#[from] ort::Error),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Model input not found: {0}")]
+    InputNotFound(String),
+    #[error("Model output not found: {0}")]
+    OutputNotFound(String),
+    #[error("Failed to get tensor shape for input: {0}")]
+    TensorShape(String),
+    #[error("Invalid model: {0}")]
+    InvalidModel(String),
+    #[error("Language '{0}' is not supported by this model")]
+    UnsupportedLanguage(String),
+}
+
+pub struct OnnxAsrModel {
+    manifest: OnnxAsrManifest,
+    preprocessor: Option<Session>,
+    encoder: Session,
+    /// Transducer or attention decoder; CTC models have none
+    decoder: Option<Session>,
+    vocab: Vocabulary,
+}
+
+impl Drop for OnnxAsrModel {
+    fn drop(&mut self) {
+        log::debug!(
+            "Dropping ONNX ASR model '{}' with {} vocab tokens",
+            self.manifest.name,
+            self.vocab.len()
+        );
+    }
+}
+
+impl OnnxAsrModel {
+    /// Create every session of the model. With `quantized`, the int8 variants declared in
+    /// the manifest are used when their files exist.
+    pub fn load<P: AsRef<Path>>(
+        model_dir: P,
+        manifest: OnnxAsrManifest,
+        quantized: bool,
+    ) -> Result<Self, OnnxAsrError> {
+        let model_dir = model_dir.as_ref();
+        manifest
+            .validate()
+            .map_err(|e| OnnxAsrError::InvalidModel(e.to_string()))?;
+
+        let runtime_config = runtime::resolved_config();
+        let preprocessor = match &manifest.preprocessor {
+            PreprocessorSpec::Onnx { file, .. } => Some(Self::init_session(
+                model_dir,
+                file,
+                &None,
+                false,
+                &runtime_config,
+            )?),
+            PreprocessorSpec::Waveform => None,
+        };
+        let encoder = Self::init_session(
+            model_dir,
+            &manifest.encoder.file,
+            &manifest.encoder.int8_file,
+            quantized,
+            &runtime_config,
+        )?;
+        let decoder = match &manifest.decoder {
+            DecoderSpec::Ctc => None,
+            DecoderSpec::Transducer {
+                file, int8_file, ..
+            }
+            | DecoderSpec::Attention {
+                file, int8_file, ..
+            } => Some(Self::init_session(
+                model_dir,
+                file,
+                int8_file,
+                quantized,
+                &runtime_config,
+            )?),
+        };
+
+        let vocab = Vocabulary::load(model_dir, &manifest.vocabulary)
+            .map_err(|e| OnnxAsrError::InvalidModel(e.to_string()))?;
+        if let DecoderSpec::Attention {
+            start_tokens,
+            end_token,
+            ..
+        } = &manifest.decoder
+        {
+            let literal = start_tokens
+                .iter()
+                .filter(|t| !t.contains(LANGUAGE_PLACEHOLDER));
+            if let Some(missing) = literal
+                .chain([end_token])
+                .find(|t| vocab.id_of(t).is_none())
+            {
+                return Err(OnnxAsrError::InvalidModel(format!(
+                    "Token '{}' is not in the vocabulary",
+                    missing
+                )));
+            }
+        }
+
+        log::info!(
+            "Loaded ONNX ASR model '{}' ({} decoder) with {} vocab tokens",
+            manifest.name,
+            manifest.decoder_kind(),
+            vocab.len()
+        );
+        runtime::report_applied(&manifest.name, &runtime_config);
+
+        Ok(Self {
+            manifest,
+            preprocessor,
+            encoder,
+            decoder,
+            vocab,
+        })
+    }
+
+    fn init_session(
+        model_dir: &Path,
+        file: &str,
+        int8_file: &Option<String>,
+        try_quantized: bool,
+        runtime_config: &ResolvedRuntimeConfig,
+    ) -> Result<Session, OnnxAsrError> {
+        // Try quantized version first if requested, fallback to regular version
+        let path: PathBuf = match int8_file {
+            Some(int8) if try_quantized && model_dir.join(int8).exists() => {
+                log::info!("Loading quantized ONNX session from {}...", int8);
+                model_dir.join(int8)
+            }
+            _ => {
+                log::info!("Loading ONNX session from {}...", file);
+                model_dir.join(file)
+            }
+        };
+
+        let session = runtime::session_builder(runtime_config)?.commit_from_file(&path)?;
+
+        for input in &session.inputs {
+            log::info!(
+                "ONNX session '{}' input: name={}, type={:?}",
+                path.display(),
+                input.name,
+                input.input_type
+            );
+        }
+
+        Ok(session)
+    }
+
+    pub fn manifest(&self) -> &OnnxAsrManifest {
+        &self.manifest
+    }
+
+    /// Transcribe 16 kHz mono samples. `language` is only used by attention decoders
+    /// prompted with a language token, but is checked against the manifest for every model.
+    pub fn transcribe_samples(
+        &mut self,
+        samples: Vec<f32>,
+        language: Option<&str>,
+    ) -> Result<TimestampedResult, OnnxAsrError> {
+        let language = self.resolve_language(language)?;
+        let duration_seconds = samples.len() as f32 / self.manifest.sample_rate as f32;
+
+        let samples_len = samples.len();
+        let waveforms = Array2::from_shape_vec((1, samples_len), samples)?.into_dyn();
+        let waveforms_lens = Array1::from_vec(vec![samples_len as i64]).into_dyn();
+
+        let (features, features_lens) = self.preprocess(waveforms, waveforms_lens)?;
+        let (encoder_out, encoded_len) = self.encode(&features, &features_lens)?;
+        let encodings = encoder_out
+            .index_axis(Axis(0), 0)
+            .into_dimensionality::<Ix2>()?;
+
+        let (ids, frames) = match self.manifest.decoder_kind() {
+            "ctc" => self.decode_ctc(encodings, encoded_len)?,
+            "transducer" => self.decode_transducer(encodings, encoded_len)?,
+            _ => {
+                let max_tokens = self.max_generated_tokens(duration_seconds);
+                let ids = self.decode_attention(&encoder_out, language.as_deref(), max_tokens)?;
+                (ids, Vec::new())
+            }
+        };
+
+        if ids.is_empty() {
+            log::debug!(
+                "ONNX ASR decoded zero tokens for {:.1}s of audio - audio may be too short or low energy",
+                duration_seconds
+            );
+        }
+
+        if frames.is_empty() {
+            let (tokens, text) = self.vocab.decode(&ids);
+            return Ok(TimestampedResult {
+                text,
+                timestamps: Vec::new(),
+                tokens,
+            });
+        }
+
+        let (tokens, frames, text) = self.vocab.decode_with_frames(&ids, &frames);
+        let timestamps = frames
+            .iter()
+            .map(|&frame| frame as f32 * self.manifest.frame_seconds)
+            .collect();
+        Ok(TimestampedResult {
+            text,
+            timestamps,
+            tokens,
+        })
+    }
+
+    /// The language to prompt with: the requested one when the model lists it, else the
+    /// first listed language ("auto" or no request)
+    fn resolve_language(&self, language: Option<&str>) -> Result<Option<String>, OnnxAsrError> {
+        match language {
+            Some(lang) if lang != "auto" => {
+                if !self.manifest.supports_language(lang) {
+                    return Err(OnnxAsrError::UnsupportedLanguage(lang.to_string()));
+                }
+                Ok(Some(lang.to_lowercase()))
+            }
+            _ => Ok(self.manifest.languages.first().map(|l| l.to_lowercase())),
+        }
+    }
+
+    fn preprocess(
+        &mut self,
+        waveforms: ArrayD<f32>,
+        waveforms_lens: ArrayD<i64>,
+    ) -> Result<(ArrayD<f32>, ArrayD<i64>), OnnxAsrError> {
+        let (
+            Some(session),
+            PreprocessorSpec::Onnx {
+                waveform_input,
+                length_input,
+                features_output,
+                length_output,
+                ..
+            },
+        ) = (self.preprocessor.as_mut(), &self.manifest.preprocessor)
+        else {
+            return Ok((waveforms, waveforms_lens));
+        };
+
+        log::trace!("Running ONNX ASR preprocessor inference...");
+        let inputs = inputs![
+            waveform_input.as_str() => TensorRef::from_array_view(waveforms.view())?,
+            length_input.as_str() => TensorRef::from_array_view(waveforms_lens.view())?,
+        ];
+        let outputs = session.run(inputs)?;
+
+        let features = outputs
+            .get(features_output.as_str())
+            .ok_or_else(|| OnnxAsrError::OutputNotFound(features_output.clone()))?
+            .try_extract_array::<f32>()?;
+        let features_lens = outputs
+            .get(length_output.as_str())
+            .ok_or_else(|| OnnxAsrError::OutputNotFound(length_output.clone()))?
+            .try_extract_array::<i64>()?;
+
+        Ok((features.to_owned(), features_lens.to_owned()))
+    }
+
+    /// Encoder output as `[batch, frames, channels]` plus the number of valid frames
+    fn encode(
+        &mut self,
+        features: &ArrayD<f32>,
+        features_lens: &ArrayD<i64>,
+    ) -> Result<(ArrayD<f32>, usize), OnnxAsrError> {
+        log::trace!("Running ONNX ASR encoder inference...");
+        let spec = &self.manifest.encoder;
+
+        let mut inputs = inputs![
+            spec.input.as_str() => TensorRef::from_array_view(features.view())?,
+        ];
+        if let Some(length_input) = &spec.length_input {
+            inputs.push((
+                length_input.as_str().into(),
+                TensorRef::from_array_view(features_lens.view())?.into(),
+            ));
+        }
+        let outputs = self.encoder.run(inputs)?;
+
+        let encoder_out = outputs
+            .get(spec.output.as_str())
+            .ok_or_else(|| OnnxAsrError::OutputNotFound(spec.output.clone()))?
+            .try_extract_array::<f32>()?;
+        if encoder_out.ndim() != 3 {
+            return Err(OnnxAsrError::InvalidModel(format!(
+                "Encoder output '{}' has shape {:?}, expected 3 dimensions",
+                spec.output,
+                encoder_out.shape()
+            )));
+        }
+        let encoder_out = if spec.channels_first {
+            encoder_out.permuted_axes(IxDyn(&[0, 2, 1]))
+        } else {
+            encoder_out
+        };
+        let frames = encoder_out.shape()[1];
+
+        let encoded_len = match &spec.length_output {
+            Some(name) => outputs
+                .get(name.as_str())
+                .ok_or_else(|| OnnxAsrError::OutputNotFound(name.clone()))?
+                .try_extract_array::<i64>()?
+                .iter()
+                .next()
+                .map(|&len| (len.max(0) as usize).min(frames))
+                .unwrap_or(frames),
+            None => frames,
+        };
+
+        Ok((encoder_out.as_standard_layout().into_owned(), encoded_len))
+    }
+
+    /// Greedy CTC: best token per frame, repeats merged, blanks dropped
+    fn decode_ctc(
+        &self,
+        logits: ArrayView2<f32>, // [frames, vocab]
+        frames: usize,
+    ) -> Result<(Vec<i64>, Vec<usize>), OnnxAsrError> {
+        let blank = self.vocab.blank_id().ok_or_else(|| {
+            OnnxAsrError::InvalidModel("CTC model without blank token".to_string())
+        })?;
+        let best = logits
+            .outer_iter()
+            .take(frames)
+            .map(|frame| argmax(frame.iter().take(self.vocab.len())).map_or(blank, |i| i as i64));
+        Ok(collapse_ctc(best, blank))
+    }
+
+    /// Greedy RNN-T / TDT decoding, one prediction network step at a time
+    fn decode_transducer(
+        &mut self,
+        encodings: ArrayView2<f32>, // [frames, channels]
+        frames: usize,
+    ) -> Result<(Vec<i64>, Vec<usize>), OnnxAsrError> {
+        let DecoderSpec::Transducer {
+            encoder_input,
+            targets_input,
+            target_length_input,
+            logits_output,
+            state_inputs,
+            state_outputs,
+            max_tokens_per_step,
+            ..
+        } = &self.manifest.decoder
+        else {
+            return Err(OnnxAsrError::InvalidModel(
+                "Not a transducer model".to_string(),
+            ));
+        };
+        let session = self.decoder.as_mut().ok_or_else(|| {
+            OnnxAsrError::InvalidModel("Transducer model without decoder".to_string())
+        })?;
+        let blank = self.vocab.blank_id().ok_or_else(|| {
+            OnnxAsrError::InvalidModel("Transducer model without blank token".to_string())
+        })?;
+        let vocab_size = self.vocab.len();
+
+        let mut state = initial_states(session, state_inputs)?;
+        let mut tokens: Vec<i64> = Vec::new();
+        let mut timestamps = Vec::new();
+
+        let mut t = 0;
+        let mut emitted_tokens = 0;
+
+        while t < frames {
+            // encoder frame [channels] -> [1, channels, 1]
+            let encoder_step = encodings
+                .row(t)
+                .to_owned()
+                .insert_axis(Axis(0))
+                .insert_axis(Axis(2));
+            let target_token = tokens.last().copied().unwrap_or(blank) as i32;
+            let targets = Array2::from_shape_vec((1, 1), vec![target_token])?;
+            let target_length = Array1::from_vec(vec![1i32]);
+
+            let mut inputs = inputs![
+                encoder_input.as_str() => TensorRef::from_array_view(encoder_step.view())?,
+                targets_input.as_str() => TensorRef::from_array_view(targets.view())?,
+                target_length_input.as_str() => TensorRef::from_array_view(target_length.view())?,
+            ];
+            for (name, value) in state_inputs.iter().zip(&state) {
+                inputs.push((
+                    name.as_str().into(),
+                    TensorRef::from_array_view(value.view())?.into(),
+                ));
+            }
+            let outputs = session.run(inputs)?;
+
+            let logits: Vec<f32> = outputs
+                .get(logits_output.as_str())
+                .ok_or_else(|| OnnxAsrError::OutputNotFound(logits_output.clone()))?
+                .try_extract_array::<f32>()?
+                .iter()
+                .copied()
+                .collect();
+
+            // TDT models append duration logits after the vocabulary logits
+            let vocab_logits = &logits[..logits.len().min(vocab_size)];
+            let token = argmax(vocab_logits.iter()).map_or(blank, |i| i as i64);
+
+            if token != blank {
+                let new_state = state_outputs
+                    .iter()
+                    .map(|name| {
+                        Ok(outputs
+                            .get(name.as_str())
+                            .ok_or_else(|| OnnxAsrError::OutputNotFound(name.clone()))?
+                            .try_extract_array::<f32>()?
+                            .to_owned())
+                    })
+                    .collect::<Result<Vec<_>, OnnxAsrError>>()?;
+                state = new_state;
+                tokens.push(token);
+                timestamps.push(t);
+                emitted_tokens += 1;
+            }
+
+            if token == blank || emitted_tokens == *max_tokens_per_step {
+                t += 1;
+                emitted_tokens = 0;
+            }
+        }
+
+        Ok((tokens, timestamps))
+    }
+
+    /// Greedy autoregressive decoding without a KV cache: the decoder sees the whole prefix
+    /// every step, which keeps the export requirements minimal
+    fn decode_attention(
+        &mut self,
+        encoder_out: &ArrayD<f32>, // [1, frames, channels]
+        language: Option<&str>,
+        max_tokens: usize,
+    ) -> Result<Vec<i64>, OnnxAsrError> {
+        let DecoderSpec::Attention {
+            input_ids_input,
+            encoder_states_input,
+            logits_output,
+            start_tokens,
+            end_token,
+            ..
+        } = &self.manifest.decoder
+        else {
+            return Err(OnnxAsrError::InvalidModel(
+                "Not an attention model".to_string(),
+            ));
+        };
+        let session = self.decoder.as_mut().ok_or_else(|| {
+            OnnxAsrError::InvalidModel("Attention model without decoder".to_string())
+        })?;
+
+        let mut ids = start_tokens
+            .iter()
+            .map(|token| {
+                let token = token.replace(LANGUAGE_PLACEHOLDER, language.unwrap_or_default());
+                self.vocab.id_of(&token).ok_or_else(|| {
+                    OnnxAsrError::InvalidModel(format!(
+                        "Token '{}' is not in the vocabulary",
+                        token
+                    ))
+                })
+            })
+            .collect::<Result<Vec<i64>, OnnxAsrError>>()?;
+        let prompt_len = ids.len();
+        let end_id = self.vocab.id_of(end_token).ok_or_else(|| {
+            OnnxAsrError::InvalidModel(format!("Token '{}' is not in the vocabulary", end_token))
+        })?;
+
+        while ids.len() - prompt_len < max_tokens {
+            let input_ids = Array2::from_shape_vec((1, ids.len()), ids.clone())?;
+            let inputs = inputs![
+                input_ids_input.as_str() => TensorRef::from_array_view(input_ids.view())?,
+                encoder_states_input.as_str() => TensorRef::from_array_view(encoder_out.view())?,
+            ];
+            let outputs = session.run(inputs)?;
+
+            let logits = outputs
+                .get(logits_output.as_str())
+                .ok_or_else(|| OnnxAsrError::OutputNotFound(logits_output.clone()))?
+                .try_extract_array::<f32>()?;
+            // [1, prefix, vocab]: only the last position predicts the next token
+            let last = logits
+                .shape()
+                .get(1)
+                .copied()
+                .unwrap_or(1)
+                .saturating_sub(1);
+            let next = argmax(
+                logits
+                    .index_axis(Axis(0), 0)
+                    .index_axis(Axis(0), last)
+                    .iter(),
+            )
+            .map_or(end_id, |i| i as i64);
+
+            if next == end_id {
+                break;
+            }
+            ids.push(next);
+        }
+
+        Ok(ids.split_off(prompt_len))
+    }
+
+    fn max_generated_tokens(&self, duration_seconds: f32) -> usize {
+        match &self.manifest.decoder {
+            DecoderSpec::Attention {
+                max_tokens_per_second,
+                ..
+            } => ((duration_seconds * max_tokens_per_second).ceil() as usize).max(1),
+            _ => 0,
+        }
+    }
+}
+
+/// Zeroed recurrent states shaped like the decoder's state inputs, batch size 1
+/// (dynamic dimensions such as `[2, -1, 640]` become 1)
+fn initial_states(session: &Session, names: &[String]) -> Result<Vec<ArrayD<f32>>, OnnxAsrError> {
+    names
+        .iter()
+        .map(|name| {
+            let shape = session
+                .inputs
+                .iter()
+                .find(|input| &input.name == name)
+                .ok_or_else(|| OnnxAsrError::InputNotFound(name.clone()))?
+                .input_type
+                .tensor_shape()
+                .ok_or_else(|| OnnxAsrError::TensorShape(name.clone()))?;
+            let dims: Vec<usize> = shape
+                .iter()
+                .map(|&dim| if dim < 0 { 1 } else { dim as usize })
+                .collect();
+            Ok(ArrayD::zeros(IxDyn(&dims)))
+        })
+        .collect()
+}
+
+fn argmax<'a, I: Iterator<Item = &'a f32>>(values: I) -> Option<usize> {
+    values
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(idx, _)| idx)
+}
+
+/// Merge repeated frame predictions and drop blanks, keeping the frame each token starts at
+fn collapse_ctc<I: Iterator<Item = i64>>(best: I, blank: i64) -> (Vec<i64>, Vec<usize>) {
+    let mut tokens = Vec::new();
+    let mut frames = Vec::new();
+    let mut previous = blank;
+    for (frame, token) in best.enumerate() {
+        if token != blank && token != previous {
+            tokens.push(token);
+            frames.push(frame);
+        }
+        previous = token;
+    }
+    (tokens, frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ctc_collapse_merges_repeats_but_not_across_blanks() {
+        // blank = 0: "a a _ a b b _ _ c"
+        let (tokens, frames) = collapse_ctc([1, 1, 0, 1, 2, 2, 0, 0, 3].into_iter(), 0);
+        assert_eq!(tokens, vec![1, 1, 2, 3]);
+        assert_eq!(frames, vec![0, 3, 4, 8]);
+    }
+
+    #[test]
+    fn ctc_collapse_of_silence_is_empty() {
+        let (tokens, frames) = collapse_ctc([5, 5, 5].into_iter(), 5);
+        assert!(tokens.is_empty());
+        assert!(frames.is_empty());
+    }
+
+    #[test]
+    fn argmax_picks_highest_value() {
+        assert_eq!(argmax([0.1, 0.7, 0.2].iter()), Some(1));
+        assert_eq!(argmax(std::iter::empty()), None);
+    }
+}
@@ -0,0 +1,402 @@
+// onnx_asr/runtime.rs
+//
+// ONNX Runtime session tuning shared by every ONNX ASR model (Parakeet included). Each knob
+// is either set explicitly in the persisted settings or left on "auto", in which case it is
+// derived from the detected `HardwareProfile`. The resolved configuration of the last
+// loaded model is kept for the diagnostics command and logged when sessions are built.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use ort::execution_providers::{CPUExecutionProvider, ExecutionProviderDispatch};
+use ort::session::builder::{GraphOptimizationLevel, SessionBuilder};
+use ort::session::Session;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+use crate::audio::{HardwareProfile, PerformanceTier};
+use crate::settings_store;
+
+/// Graph optimizations applied when a session is built
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptimizationLevel {
+    Disabled,
+    Basic,
+    Extended,
+    All,
+}
+
+impl OptimizationLevel {
+    fn to_ort(self) -> GraphOptimizationLevel {
+        match self {
+            OptimizationLevel::Disabled => GraphOptimizationLevel::Disable,
+            OptimizationLevel::Basic => GraphOptimizationLevel::Level1,
+            OptimizationLevel::Extended => GraphOptimizationLevel::Level2,
+            OptimizationLevel::All => GraphOptimizationLevel::Level3,
+        }
+    }
+}
+
+/// Execution provider preference. Providers other than the CPU one only work when the app
+/// was built with the matching `onnx-*` cargo feature; otherwise the CPU provider is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExecutionProviderKind {
+    Cpu,
+    OpenVino,
+    Xnnpack,
+}
+
+/// Persisted ONNX Runtime configuration. `None` means "derive from the hardware profile".
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OnnxRuntimeSettings {
+    /// Threads used inside a single operator
+    #[serde(default)]
+    pub intra_op_threads: Option<usize>,
+    /// Threads used to run independent operators in parallel (only with parallel execution)
+    #[serde(default)]
+    pub inter_op_threads: Option<usize>,
+    #[serde(default)]
+    pub optimization_level: Option<OptimizationLevel>,
+    /// Keep freed CPU buffers in an arena for reuse (faster, higher resident memory)
+    #[serde(default)]
+    pub memory_arena: Option<bool>,
+    /// Pre-plan allocations from the first run's tensor shapes
+    #[serde(default)]
+    pub memory_pattern: Option<bool>,
+    #[serde(default)]
+    pub execution_provider: Option<ExecutionProviderKind>,
+}
+
+/// Configuration actually applied to sessions, after auto-tuning
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResolvedRuntimeConfig {
+    pub intra_op_threads: usize,
+    pub inter_op_threads: usize,
+    pub parallel_execution: bool,
+    pub optimization_level: OptimizationLevel,
+    pub memory_arena: bool,
+    pub memory_pattern: bool,
+    /// Provider requested by settings or auto-tuning
+    pub requested_provider: ExecutionProviderKind,
+    /// Provider the sessions run on (CPU when the requested one is unavailable)
+    pub active_provider: ExecutionProviderKind,
+}
+
+/// Payload of `get_onnx_runtime_diagnostics`
+#[derive(Debug, Clone, Serialize)]
+pub struct OnnxRuntimeDiagnostics {
+    pub settings: OnnxRuntimeSettings,
+    /// What the current settings resolve to on this machine
+    pub resolved: ResolvedRuntimeConfig,
+    /// Configuration of the most recently loaded model, if any
+    pub last_applied: Option<ResolvedRuntimeConfig>,
+    /// Providers compiled into this build and usable on this machine
+    pub available_providers: Vec<ExecutionProviderKind>,
+    pub cpu_cores: u8,
+    pub performance_tier: String,
+}
+
+impl OnnxRuntimeSettings {
+    /// Load settings from disk
+    pub fn load() -> Self {
+        settings_store::load("onnx_runtime.json", "ONNX Runtime settings")
+    }
+
+    /// Save settings to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "onnx_runtime.json", "ONNX Runtime settings")
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let max_threads = 64;
+        for (name, threads) in [
+            ("Intra-op", self.intra_op_threads),
+            ("Inter-op", self.inter_op_threads),
+        ] {
+            if let Some(n) = threads {
+                if n == 0 || n > max_threads {
+                    return Err(format!(
+                        "{} threads must be between 1 and {}",
+                        name, max_threads
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill the "auto" fields from the hardware profile
+    pub fn resolve(&self, profile: &HardwareProfile) -> ResolvedRuntimeConfig {
+        let cores = profile.cpu_cores.max(1) as usize;
+        // Leave a core for audio capture and the UI, and cap by tier: beyond a few threads
+        // the encoder gains little while live Whisper/LLM work starves
+        let tier_cap = match profile.performance_tier {
+            PerformanceTier::Low => 2,
+            PerformanceTier::Medium => 4,
+            PerformanceTier::High => 6,
+            PerformanceTier::Ultra => 8,
+        };
+        let intra_op_threads = self
+            .intra_op_threads
+            .unwrap_or_else(|| cores.saturating_sub(1).clamp(1, tier_cap));
+        let inter_op_threads = self
+            .inter_op_threads
+            .unwrap_or(if cores >= 8 { 2 } else { 1 });
+
+        let requested_provider = self.execution_provider.unwrap_or_else(preferred_provider);
+        let active_provider = if available_providers().contains(&requested_provider) {
+            requested_provider
+        } else {
+            ExecutionProviderKind::Cpu
+        };
+
+        ResolvedRuntimeConfig {
+            intra_op_threads,
+            inter_op_threads,
+            parallel_execution: inter_op_threads > 1,
+            optimization_level: self.optimization_level.unwrap_or(OptimizationLevel::All),
+            // The arena holds on to peak encoder memory; skip it on low-memory machines
+            memory_arena: self.memory_arena.unwrap_or(profile.memory_gb >= 8),
+            memory_pattern: self.memory_pattern.unwrap_or(true),
+            requested_provider,
+            active_provider,
+        }
+    }
+}
+
+static ONNX_RUNTIME_SETTINGS: Lazy<RwLock<OnnxRuntimeSettings>> =
+    Lazy::new(|| RwLock::new(OnnxRuntimeSettings::load()));
+
+static LAST_APPLIED: Lazy<RwLock<Option<ResolvedRuntimeConfig>>> = Lazy::new(|| RwLock::new(None));
+
+/// Current settings
+pub fn current_settings() -> OnnxRuntimeSettings {
+    ONNX_RUNTIME_SETTINGS
+        .read()
+        .map(|s| s.clone())
+        .unwrap_or_default()
+}
+
+/// Configuration for sessions built now, resolved against the detected hardware
+pub fn resolved_config() -> ResolvedRuntimeConfig {
+    current_settings().resolve(HardwareProfile::detect())
+}
+
+/// Best compiled-in provider for this machine when the user hasn't picked one
+fn preferred_provider() -> ExecutionProviderKind {
+    let available = available_providers();
+    [
+        ExecutionProviderKind::OpenVino,
+        ExecutionProviderKind::Xnnpack,
+    ]
+    .into_iter()
+    .find(|kind| available.contains(kind))
+    .unwrap_or(ExecutionProviderKind::Cpu)
+}
+
+/// Providers compiled into this build whose runtime libraries are present
+pub fn available_providers() -> Vec<ExecutionProviderKind> {
+    #[allow(unused_mut)]
+    let mut providers = vec![ExecutionProviderKind::Cpu];
+
+    #[cfg(feature = "onnx-openvino")]
+    {
+        use ort::execution_providers::{ExecutionProvider, OpenVINOExecutionProvider};
+        if OpenVINOExecutionProvider::default()
+            .is_available()
+            .unwrap_or(false)
+        {
+            providers.push(ExecutionProviderKind::OpenVino);
+        }
+    }
+
+    #[cfg(feature = "onnx-xnnpack")]
+    {
+        use ort::execution_providers::{ExecutionProvider, XNNPACKExecutionProvider};
+        if XNNPACKExecutionProvider::default()
+            .is_available()
+            .unwrap_or(false)
+        {
+            providers.push(ExecutionProviderKind::Xnnpack);
+        }
+    }
+
+    providers
+}
+
+fn execution_providers(config: &ResolvedRuntimeConfig) -> Vec<ExecutionProviderDispatch> {
+    let mut providers = Vec::new();
+    #[cfg(feature = "onnx-openvino")]
+    if config.active_provider == ExecutionProviderKind::OpenVino {
+        providers.push(ort::execution_providers::OpenVINOExecutionProvider::default().build());
+    }
+    #[cfg(feature = "onnx-xnnpack")]
+    if config.active_provider == ExecutionProviderKind::Xnnpack {
+        providers.push(ort::execution_providers::XNNPACKExecutionProvider::default().build());
+    }
+    // Always keep the CPU provider as the fallback for operators the others don't cover
+    providers.push(
+        CPUExecutionProvider::default()
+            .with_arena_allocator(config.memory_arena)
+            .build(),
+    );
+    providers
+}
+
+/// Session builder configured with the current settings
+pub fn session_builder(config: &ResolvedRuntimeConfig) -> ort::Result<SessionBuilder> {
+    Session::builder()?
+        .with_optimization_level(config.optimization_level.to_ort())?
+        .with_intra_threads(config.intra_op_threads)?
+        .with_inter_threads(config.inter_op_threads)?
+        .with_parallel_execution(config.parallel_execution)?
+        .with_memory_pattern(config.memory_pattern)?
+        .with_execution_providers(execution_providers(config))
+}
+
+/// Record and log the configuration a model was loaded with
+pub fn report_applied(model_name: &str, config: &ResolvedRuntimeConfig) {
+    info!("╔═══════════════════════════════════════════════════════════╗");
+    info!("║ ONNX Runtime Configuration                                ║");
+    info!("╠═══════════════════════════════════════════════════════════╣");
+    info!("  Model:             {}", model_name);
+    info!(
+        "  Provider:          {:?} (requested {:?})",
+        config.active_provider, config.requested_provider
+    );
+    info!(
+        "  Threads:           {} intra-op, {} inter-op",
+        config.intra_op_threads, config.inter_op_threads
+    );
+    info!("  Parallel exec:     {}", config.parallel_execution);
+    info!("  Optimization:      {:?}", config.optimization_level);
+    info!("  Memory arena:      {}", config.memory_arena);
+    info!("  Memory pattern:    {}", config.memory_pattern);
+    info!("╚═══════════════════════════════════════════════════════════╝");
+
+    if config.active_provider != config.requested_provider {
+        warn!(
+            "⚠️ {:?} execution provider is not available in this build, using CPU",
+            config.requested_provider
+        );
+    }
+
+    if let Ok(mut last) = LAST_APPLIED.write() {
+        *last = Some(config.clone());
+    }
+}
+
+#[tauri::command]
+pub async fn get_onnx_runtime_settings() -> Result<OnnxRuntimeSettings, String> {
+    ONNX_RUNTIME_SETTINGS
+        .read()
+        .map(|s| s.clone())
+        .map_err(|e| format!("Failed to read ONNX Runtime settings: {}", e))
+}
+
+/// Changes apply to the next model load
+#[tauri::command]
+pub async fn set_onnx_runtime_settings(settings: OnnxRuntimeSettings) -> Result<(), String> {
+    settings.validate()?;
+    settings.save()?;
+    let mut current = ONNX_RUNTIME_SETTINGS
+        .write()
+        .map_err(|e| format!("Failed to update ONNX Runtime settings: {}", e))?;
+    *current = settings;
+    info!("⚙️ ONNX Runtime settings updated: {:?}", *current);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_onnx_runtime_diagnostics() -> Result<OnnxRuntimeDiagnostics, String> {
+    let settings = current_settings();
+    let profile = HardwareProfile::detect();
+    Ok(OnnxRuntimeDiagnostics {
+        resolved: settings.resolve(profile),
+        settings,
+        last_applied: LAST_APPLIED.read().ok().and_then(|last| last.clone()),
+        available_providers: available_providers(),
+        cpu_cores: profile.cpu_cores,
+        performance_tier: format!("{:?}", profile.performance_tier),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::GpuType;
+
+    fn profile(cpu_cores: u8, memory_gb: u8, performance_tier: PerformanceTier) -> HardwareProfile {
+        HardwareProfile {
+            cpu_cores,
+            has_gpu_acceleration: false,
+            gpu_type: GpuType::None,
+            memory_gb,
+            performance_tier,
+        }
+    }
+
+    #[test]
+    fn auto_tuning_leaves_headroom_and_follows_the_tier() {
+        let settings = OnnxRuntimeSettings::default();
+
+        let low = settings.resolve(&profile(4, 4, PerformanceTier::Low));
+        assert_eq!(low.intra_op_threads, 2);
+        assert_eq!(low.inter_op_threads, 1);
+        assert!(!low.parallel_execution);
+        assert!(!low.memory_arena);
+
+        let medium = settings.resolve(&profile(16, 32, PerformanceTier::Medium));
+        assert_eq!(medium.intra_op_threads, 4);
+        assert_eq!(medium.inter_op_threads, 2);
+        assert!(medium.parallel_execution);
+        assert!(medium.memory_arena);
+        assert_eq!(medium.optimization_level, OptimizationLevel::All);
+
+        let single_core = settings.resolve(&profile(1, 8, PerformanceTier::Low));
+        assert_eq!(single_core.intra_op_threads, 1);
+    }
+
+    #[test]
+    fn explicit_settings_override_auto_tuning() {
+        let settings = OnnxRuntimeSettings {
+            intra_op_threads: Some(12),
+            inter_op_threads: Some(1),
+            optimization_level: Some(OptimizationLevel::Basic),
+            memory_arena: Some(true),
+            memory_pattern: Some(false),
+            execution_provider: Some(ExecutionProviderKind::Cpu),
+        };
+        let resolved = settings.resolve(&profile(4, 4, PerformanceTier::Low));
+        assert_eq!(resolved.intra_op_threads, 12);
+        assert!(!resolved.parallel_execution);
+        assert_eq!(resolved.optimization_level, OptimizationLevel::Basic);
+        assert!(resolved.memory_arena);
+        assert!(!resolved.memory_pattern);
+    }
+
+    #[test]
+    fn unavailable_provider_falls_back_to_cpu() {
+        if cfg!(feature = "onnx-openvino") {
+            return;
+        }
+        let settings = OnnxRuntimeSettings {
+            execution_provider: Some(ExecutionProviderKind::OpenVino),
+            ..Default::default()
+        };
+        let resolved = settings.resolve(&profile(8, 16, PerformanceTier::Medium));
+        assert_eq!(resolved.requested_provider, ExecutionProviderKind::OpenVino);
+        assert_eq!(resolved.active_provider, ExecutionProviderKind::Cpu);
+    }
+
+    #[test]
+    fn rejects_zero_threads() {
+        let settings = OnnxRuntimeSettings {
+            intra_op_threads: Some(0),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+        assert!(OnnxRuntimeSettings::default().validate().is_ok());
+    }
+}
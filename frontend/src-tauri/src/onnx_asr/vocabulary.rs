@@ -0,0 +1,236 @@
+// onnx_asr/vocabulary.rs
+//
+// Token tables of ONNX ASR models and turning decoded token ids back into text.
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::manifest::{VocabularyFormat, VocabularySpec};
+
+/// Drops the space a leading word boundary produces and collapses runs of spaces
+static DECODE_SPACE_RE: Lazy<Result<Regex, regex::Error>> =
+    Lazy::new(|| Regex::new(r"\A\s|\s\B|(\s)\b"));
+
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    /// Token text by id, word boundary markers already replaced by spaces
+    tokens: Vec<String>,
+    ids: HashMap<String, i64>,
+    blank_id: Option<i64>,
+}
+
+impl Vocabulary {
+    pub fn load(model_dir: &Path, spec: &VocabularySpec) -> Result<Self> {
+        let path = model_dir.join(&spec.file);
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read vocabulary {}: {}", path.display(), e))?;
+        Self::parse(&content, spec)
+    }
+
+    pub fn parse(content: &str, spec: &VocabularySpec) -> Result<Self> {
+        let entries: Vec<(String, usize)> = match spec.format {
+            VocabularyFormat::TokenId => content
+                .lines()
+                .filter_map(|line| {
+                    let (token, id) = line.trim_end().rsplit_once(' ')?;
+                    Some((token.to_string(), id.parse().ok()?))
+                })
+                .collect(),
+            VocabularyFormat::Lines => content
+                .lines()
+                .enumerate()
+                .map(|(id, line)| (line.trim_end_matches(['\r', '\n']).to_string(), id))
+                .collect(),
+            VocabularyFormat::Json => {
+                let map: HashMap<String, usize> = serde_json::from_str(content)
+                    .map_err(|e| anyhow!("Invalid JSON vocabulary: {}", e))?;
+                map.into_iter().collect()
+            }
+        };
+
+        let Some(max_id) = entries.iter().map(|(_, id)| *id).max() else {
+            return Err(anyhow!("Vocabulary {} is empty", spec.file));
+        };
+        // Ids index the token table: a sparse or huge id would allocate it far too large
+        if max_id >= entries.len() {
+            return Err(anyhow!(
+                "Vocabulary {} has token id {} but only {} tokens",
+                spec.file,
+                max_id,
+                entries.len()
+            ));
+        }
+        let size = max_id + 1;
+
+        let mut tokens = vec![String::new(); size];
+        let mut ids = HashMap::with_capacity(entries.len());
+        for (token, id) in entries {
+            tokens[id] = token.replace(spec.word_boundary.as_str(), " ");
+            ids.insert(token, id as i64);
+        }
+
+        let blank_id = match &spec.blank_token {
+            Some(blank) => Some(
+                *ids.get(blank)
+                    .ok_or_else(|| anyhow!("Missing {} token in vocabulary", blank))?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            tokens,
+            ids,
+            blank_id,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn blank_id(&self) -> Option<i64> {
+        self.blank_id
+    }
+
+    /// Id of a token as written in the vocabulary file
+    pub fn id_of(&self, token: &str) -> Option<i64> {
+        self.ids.get(token).copied()
+    }
+
+    /// Token texts and the joined transcript of decoded ids. Control tokens such as
+    /// `<|en|>` or `</s>` are left out of both.
+    pub fn decode(&self, ids: &[i64]) -> (Vec<String>, String) {
+        let tokens: Vec<String> = ids
+            .iter()
+            .filter_map(|&id| self.tokens.get(usize::try_from(id).ok()?))
+            .filter(|token| !is_control_token(token))
+            .cloned()
+            .collect();
+
+        let text = join_tokens(&tokens);
+        (tokens, text)
+    }
+
+    /// Like `decode`, keeping the frame index of each token that survives
+    pub fn decode_with_frames(
+        &self,
+        ids: &[i64],
+        frames: &[usize],
+    ) -> (Vec<String>, Vec<usize>, String) {
+        let kept: Vec<(i64, usize)> = ids
+            .iter()
+            .zip(frames)
+            .filter(|(&id, _)| {
+                usize::try_from(id)
+                    .ok()
+                    .and_then(|i| self.tokens.get(i))
+                    .is_some_and(|token| !is_control_token(token))
+            })
+            .map(|(&id, &frame)| (id, frame))
+            .collect();
+        let kept_ids: Vec<i64> = kept.iter().map(|(id, _)| *id).collect();
+        let (tokens, text) = self.decode(&kept_ids);
+        (
+            tokens,
+            kept.into_iter().map(|(_, frame)| frame).collect(),
+            text,
+        )
+    }
+}
+
+/// Join decoded token texts (word boundaries already turned into spaces) into a transcript
+pub fn join_tokens(tokens: &[String]) -> String {
+    let joined = tokens.concat();
+    match &*DECODE_SPACE_RE {
+        Ok(regex) => regex
+            .replace_all(&joined, |caps: &regex::Captures| {
+                if caps.get(1).is_some() {
+                    " "
+                } else {
+                    ""
+                }
+            })
+            .to_string(),
+        Err(_) => joined, // Fallback if regex failed to compile
+    }
+}
+
+fn is_control_token(token: &str) -> bool {
+    token.len() > 2 && token.starts_with('<') && token.ends_with('>')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(format: VocabularyFormat, blank: Option<&str>) -> VocabularySpec {
+        VocabularySpec {
+            file: "vocab".to_string(),
+            format,
+            blank_token: blank.map(str::to_string),
+            word_boundary: "\u{2581}".to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_token_id_vocabulary() {
+        let vocab = Vocabulary::parse(
+            "<unk> 0\n\u{2581}hello 1\n\u{2581}wor 2\nld 3\n<blk> 4\n",
+            &spec(VocabularyFormat::TokenId, Some("<blk>")),
+        )
+        .unwrap();
+
+        assert_eq!(vocab.len(), 5);
+        assert_eq!(vocab.blank_id(), Some(4));
+        let (tokens, text) = vocab.decode(&[1, 2, 3]);
+        assert_eq!(tokens, vec![" hello", " wor", "ld"]);
+        assert_eq!(text, "hello world");
+    }
+
+    #[test]
+    fn line_vocabulary_uses_line_numbers_and_skips_control_tokens() {
+        let vocab = Vocabulary::parse(
+            "<s>\n</s>\n\u{2581}Hi\n\u{2581}there\n<|en|>\n",
+            &spec(VocabularyFormat::Lines, None),
+        )
+        .unwrap();
+
+        assert_eq!(vocab.id_of("<|en|>"), Some(4));
+        assert_eq!(vocab.blank_id(), None);
+        let (tokens, frames, text) = vocab.decode_with_frames(&[0, 4, 2, 3, 1], &[0, 1, 2, 5, 7]);
+        assert_eq!(tokens, vec![" Hi", " there"]);
+        assert_eq!(frames, vec![2, 5]);
+        assert_eq!(text, "Hi there");
+    }
+
+    #[test]
+    fn json_vocabulary_requires_blank_token_when_declared() {
+        let content = r#"{"<pad>": 0, "▁ok": 1}"#;
+        assert!(
+            Vocabulary::parse(content, &spec(VocabularyFormat::Json, Some("<blank>"))).is_err()
+        );
+
+        let vocab =
+            Vocabulary::parse(content, &spec(VocabularyFormat::Json, Some("<pad>"))).unwrap();
+        assert_eq!(vocab.blank_id(), Some(0));
+        assert_eq!(vocab.decode(&[1, 99]).1, "ok");
+    }
+
+    #[test]
+    fn rejects_ids_beyond_the_token_count() {
+        let content = r#"{"<pad>": 0, "▁ok": 18446744073709551615}"#;
+        assert!(Vocabulary::parse(content, &spec(VocabularyFormat::Json, None)).is_err());
+        assert!(Vocabulary::parse(
+            "<unk> 0\n\u{2581}hi 4000000000\n",
+            &spec(VocabularyFormat::TokenId, None)
+        )
+        .is_err());
+    }
+}
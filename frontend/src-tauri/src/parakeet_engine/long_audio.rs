@@ -0,0 +1,253 @@
+// parakeet_engine/long_audio.rs
+//
+// Windowed inference for audio longer than a single Parakeet pass. The audio is cut into
+// overlapping windows; consecutive hypotheses are spliced inside the overlap where both
+// windows agree on the same tokens at the same time, so words at window edges are neither
+// cut nor repeated and token timestamps stay on one timeline.
+
+use crate::onnx_asr::vocabulary::join_tokens;
+use crate::parakeet_engine::model::TimestampedResult;
+
+/// Window length. Parakeet handles far longer inputs, but encoder memory grows with length.
+pub const WINDOW_SECS: f64 = 20.0;
+/// Audio shared by consecutive windows
+pub const OVERLAP_SECS: f64 = 4.0;
+/// Tokens of two windows count as the same when their start times are this close
+const MATCH_TOLERANCE_SECS: f32 = 0.5;
+/// Shortest agreeing token run accepted as a splice point
+const MIN_MATCH_TOKENS: usize = 2;
+
+/// Sample ranges of the windows covering `total` samples. The last window is pulled back
+/// to end at `total`, so it can overlap its predecessor by more than `overlap`.
+pub fn plan_windows(total: usize, window: usize, overlap: usize) -> Vec<(usize, usize)> {
+    if total <= window || window <= overlap {
+        return vec![(0, total)];
+    }
+
+    let step = window - overlap;
+    let mut windows = Vec::new();
+    let mut start = 0;
+    loop {
+        if start + window >= total {
+            windows.push((total - window, total));
+            break;
+        }
+        windows.push((start, start + window));
+        start += step;
+    }
+    windows
+}
+
+/// Window plan for 16 kHz samples with the default window and overlap
+pub fn plan_windows_16k(total: usize) -> Vec<(usize, usize)> {
+    plan_windows(
+        total,
+        (WINDOW_SECS * 16000.0) as usize,
+        (OVERLAP_SECS * 16000.0) as usize,
+    )
+}
+
+/// Accumulates window hypotheses (pushed in order) into one result
+#[derive(Debug, Default)]
+pub struct WindowMerger {
+    tokens: Vec<String>,
+    /// Absolute start time of each token
+    timestamps: Vec<f32>,
+    /// End of the audio covered so far
+    covered_until: f32,
+}
+
+impl WindowMerger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the result of the window `[start_secs, end_secs)`; its timestamps are relative
+    /// to the window start
+    pub fn push(&mut self, start_secs: f32, end_secs: f32, result: TimestampedResult) {
+        let count = result.tokens.len().min(result.timestamps.len());
+        let tokens: Vec<String> = result.tokens.into_iter().take(count).collect();
+        let times: Vec<f32> = result.timestamps[..count]
+            .iter()
+            .map(|t| t + start_secs)
+            .collect();
+
+        if self.tokens.is_empty() || start_secs >= self.covered_until {
+            self.tokens.extend(tokens);
+            self.timestamps.extend(times);
+        } else {
+            self.splice(tokens, times, start_secs, self.covered_until);
+        }
+        self.covered_until = self.covered_until.max(end_secs);
+    }
+
+    pub fn finish(self) -> TimestampedResult {
+        TimestampedResult {
+            text: join_tokens(&self.tokens),
+            timestamps: self.timestamps,
+            tokens: self.tokens,
+        }
+    }
+
+    fn splice(
+        &mut self,
+        tokens: Vec<String>,
+        times: Vec<f32>,
+        overlap_start: f32,
+        overlap_end: f32,
+    ) {
+        let left_from = self
+            .timestamps
+            .iter()
+            .position(|&t| t >= overlap_start)
+            .unwrap_or(self.tokens.len());
+        let right_to = times
+            .iter()
+            .position(|&t| t >= overlap_end)
+            .unwrap_or(tokens.len());
+
+        let (keep_left, skip_right) =
+            match self.longest_agreement(left_from, &tokens[..right_to], &times) {
+                Some((left_end, right_end)) => (left_end, right_end),
+                None => {
+                    // No agreement (e.g. silence in the overlap): cut both at the first word
+                    // starting after the middle of the overlap
+                    let cut = (overlap_start + overlap_end) / 2.0;
+                    let first_word_after = |tokens: &[String], times: &[f32], from: usize| {
+                        (from..tokens.len())
+                            .find(|&i| times[i] >= cut && starts_word(&tokens[i]))
+                            .unwrap_or(tokens.len())
+                    };
+                    (
+                        first_word_after(&self.tokens, &self.timestamps, left_from),
+                        first_word_after(&tokens, &times, 0),
+                    )
+                }
+            };
+
+        self.tokens.truncate(keep_left);
+        self.timestamps.truncate(keep_left);
+        self.tokens.extend(tokens.into_iter().skip(skip_right));
+        self.timestamps.extend(times.into_iter().skip(skip_right));
+    }
+
+    /// Longest run of identical tokens at matching times between the accumulated tail
+    /// (from `left_from`) and the head of the new window. Returns the exclusive end of the
+    /// run on both sides.
+    fn longest_agreement(
+        &self,
+        left_from: usize,
+        right: &[String],
+        right_times: &[f32],
+    ) -> Option<(usize, usize)> {
+        let left = &self.tokens[left_from..];
+        let left_times = &self.timestamps[left_from..];
+
+        // previous[j + 1] = length of the run ending at (previous left token, right token j)
+        let mut previous = vec![0usize; right.len() + 1];
+        let mut best = (0, 0, 0);
+        for (i, (token, &time)) in left.iter().zip(left_times).enumerate() {
+            let mut current = vec![0usize; right.len() + 1];
+            for (j, (other, &other_time)) in right.iter().zip(right_times).enumerate() {
+                if token == other && (time - other_time).abs() <= MATCH_TOLERANCE_SECS {
+                    current[j + 1] = previous[j] + 1;
+                    if current[j + 1] > best.0 {
+                        best = (current[j + 1], i + 1, j + 1);
+                    }
+                }
+            }
+            previous = current;
+        }
+
+        let (length, left_end, right_end) = best;
+        (length >= MIN_MATCH_TOKENS).then_some((left_from + left_end, right_end))
+    }
+}
+
+fn starts_word(token: &str) -> bool {
+    token.starts_with(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(tokens: &[(&str, f32)]) -> TimestampedResult {
+        TimestampedResult {
+            text: String::new(),
+            tokens: tokens.iter().map(|(t, _)| t.to_string()).collect(),
+            timestamps: tokens.iter().map(|(_, s)| *s).collect(),
+        }
+    }
+
+    #[test]
+    fn short_audio_is_a_single_window() {
+        assert_eq!(plan_windows(100, 200, 40), vec![(0, 100)]);
+    }
+
+    #[test]
+    fn windows_overlap_and_the_last_one_ends_at_the_audio_end() {
+        assert_eq!(
+            plan_windows(500, 200, 40),
+            vec![(0, 200), (160, 360), (300, 500)]
+        );
+    }
+
+    #[test]
+    fn splices_where_both_windows_agree() {
+        let mut merger = WindowMerger::new();
+        // Window 1 covers 0-10s and garbles the word cut at its edge
+        merger.push(
+            0.0,
+            10.0,
+            result(&[
+                (" the", 6.0),
+                (" quick", 7.0),
+                (" brown", 8.0),
+                (" fo", 9.75),
+            ]),
+        );
+        // Window 2 starts at 6s and sees the word whole
+        merger.push(
+            6.0,
+            16.0,
+            result(&[
+                (" the", 0.0),
+                (" quick", 1.0),
+                (" brown", 2.25),
+                (" fox", 3.75),
+                (" jumps", 5.0),
+            ]),
+        );
+
+        let merged = merger.finish();
+        assert_eq!(merged.text, "the quick brown fox jumps");
+        assert_eq!(merged.timestamps, vec![6.0, 7.0, 8.0, 9.75, 11.0]);
+    }
+
+    #[test]
+    fn falls_back_to_a_word_boundary_cut_without_agreement() {
+        let mut merger = WindowMerger::new();
+        merger.push(0.0, 10.0, result(&[(" hello", 1.0), (" there", 6.5)]));
+        merger.push(
+            6.0,
+            16.0,
+            result(&[(" their", 0.6), (" general", 2.5), ("s", 2.75)]),
+        );
+
+        let merged = merger.finish();
+        // Cut at 8s: "there" (6.5s) from the first window, "generals" (8.5s) from the second
+        assert_eq!(merged.text, "hello there generals");
+        assert_eq!(merged.timestamps, vec![1.0, 6.5, 8.5, 8.75]);
+    }
+
+    #[test]
+    fn windows_without_overlap_are_appended() {
+        let mut merger = WindowMerger::new();
+        merger.push(0.0, 5.0, result(&[(" one", 1.0)]));
+        merger.push(5.0, 10.0, result(&[(" two", 1.0)]));
+        let merged = merger.finish();
+        assert_eq!(merged.text, "one two");
+        assert_eq!(merged.timestamps, vec![1.0, 6.0]);
+    }
+}
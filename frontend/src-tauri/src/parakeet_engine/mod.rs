@@ -14,11 +14,13 @@
 //! # Module Structure
 //!
 //! - `parakeet_engine`: Main engine implementation
-//! - `model`: ONNX model wrapper and inference logic
+//! - `model`: Parakeet TDT on top of the generic `crate::onnx_asr` runner
+//! - `long_audio`: overlapping windows and hypothesis merging for long inputs
 //! - `commands`: Tauri command interface for frontend integration
 
 pub mod parakeet_engine;
 pub mod model;
+pub mod long_audio;
 pub mod commands;
 
 pub use parakeet_engine::{ParakeetEngine, ParakeetEngineError, QuantizationType, ModelInfo, ModelStatus};
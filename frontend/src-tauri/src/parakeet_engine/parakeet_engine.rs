@@ -1,11 +1,13 @@
-use crate::parakeet_engine::model::ParakeetModel;
+use crate::custom_models::{self, CustomModelRegistry, ImportSource};
+use crate::model_download::{self, DownloadError, ManifestEntry, ModelArtifact, ResumableDownload};
+use crate::parakeet_engine::long_audio;
+use crate::parakeet_engine::model::{ParakeetModel, TimestampedResult};
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
 use tokio::sync::RwLock;
 
 /// Quantization type for Parakeet models
@@ -41,6 +43,9 @@ pub struct ModelInfo {
     pub speed: String,     // Performance description
     pub status: ModelStatus,
     pub description: String,
+    /// Imported by the user (see `crate::custom_models`)
+    #[serde(default)]
+    pub custom: bool,
 }
 
 #[derive(Debug)]
@@ -198,11 +203,38 @@ impl ParakeetEngine {
                 speed: speed.to_string(),
                 status,
                 description: description.to_string(),
+                custom: false,
             };
 
             models.push(model_info);
         }
 
+        // Imported models, listed after the built-in ones
+        for entry in CustomModelRegistry::load(models_dir).models {
+            let model_path = models_dir.join(&entry.path);
+            let status = if !model_path.is_dir() {
+                ModelStatus::Error(format!("Model directory {} not found", model_path.display()))
+            } else if self.validate_model_directory(&model_path).await.is_ok() {
+                ModelStatus::Available
+            } else {
+                ModelStatus::Corrupted {
+                    file_size: custom_models::size_on_disk_mb(&model_path) as u64 * 1024 * 1024,
+                    expected_min_size: 0,
+                }
+            };
+
+            models.push(ModelInfo {
+                name: entry.name,
+                size_mb: custom_models::size_on_disk_mb(&model_path),
+                path: model_path,
+                quantization: if entry.quantized { QuantizationType::Int8 } else { QuantizationType::FP32 },
+                speed: "Custom".to_string(),
+                status,
+                description: entry.description,
+                custom: true,
+            });
+        }
+
         // Update internal cache
         let mut available_models = self.available_models.write().await;
         available_models.clear();
@@ -317,8 +349,36 @@ impl ParakeetEngine {
         self.current_model.read().await.is_some()
     }
 
-    /// Transcribe audio samples using the loaded Parakeet model
+    /// Transcribe audio samples using the loaded Parakeet model (any length)
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>) -> Result<String> {
+        Ok(self.transcribe_long_audio(audio_data).await?.text)
+    }
+
+    /// Transcribe audio of any length: inputs longer than one window are run as overlapping
+    /// windows whose hypotheses are merged on their token timestamps (see `long_audio`)
+    pub async fn transcribe_long_audio(&self, audio_data: Vec<f32>) -> Result<TimestampedResult> {
+        let windows = long_audio::plan_windows_16k(audio_data.len());
+        if windows.len() == 1 {
+            return self.transcribe_audio_with_timestamps(audio_data).await;
+        }
+
+        log::info!(
+            "Parakeet transcribing {:.1}s of audio in {} overlapping windows",
+            audio_data.len() as f64 / 16000.0,
+            windows.len()
+        );
+        let mut merger = long_audio::WindowMerger::new();
+        for (start, end) in windows {
+            let result = self
+                .transcribe_audio_with_timestamps(audio_data[start..end].to_vec())
+                .await?;
+            merger.push(start as f32 / 16000.0, end as f32 / 16000.0, result);
+        }
+        Ok(merger.finish())
+    }
+
+    /// Transcribe audio keeping per-token text and start times (seconds from audio start)
+    pub async fn transcribe_audio_with_timestamps(&self, audio_data: Vec<f32>) -> Result<TimestampedResult> {
         let mut model_guard = self.current_model.write().await;
         let model = model_guard
             .as_mut()
@@ -338,7 +398,7 @@ impl ParakeetEngine {
 
         log::debug!("Parakeet transcription result: '{}'", result.text);
 
-        Ok(result.text)
+        Ok(result)
     }
 
     /// Get the models directory path
@@ -392,7 +452,174 @@ impl ParakeetEngine {
         }
     }
 
-    /// Download a Parakeet model from HuggingFace
+    /// Import a Parakeet ONNX export from a local directory, or from a URL serving its
+    /// files (`<url>/encoder-model.int8.onnx`, ...), under a user-chosen name. The model is
+    /// loaded once to make sure ONNX Runtime accepts it before it is registered. Files are
+    /// verified against the import manifest with the same strictness as regular downloads.
+    pub async fn import_model(&self, source: &str, name: &str, description: Option<String>) -> Result<ModelInfo> {
+        let source = ImportSource::parse(source)?;
+        let taken: Vec<String> = self.discover_models().await?.into_iter().map(|m| m.name).collect();
+        let name = custom_models::validate_model_name(name, &taken)?;
+
+        let model_dir = self.models_dir.join(&name);
+        log::info!("📥 Importing Parakeet model '{}' from {}", name, source.describe());
+        fs::create_dir_all(&model_dir)
+            .await
+            .map_err(|e| anyhow!("Failed to create model directory: {}", e))?;
+
+        let checksums = custom_models::load_import_manifest(&source).await;
+        let require_checksum = model_download::current_settings().require_checksum;
+        let imported = match &source {
+            ImportSource::Path(path) if !path.is_dir() => {
+                Err(anyhow!("Parakeet models are directories of ONNX files and vocab.txt"))
+            }
+            ImportSource::Path(path) => {
+                Self::copy_model_files(path, &model_dir, &checksums, require_checksum).await
+            }
+            ImportSource::Url(url) => {
+                Self::download_model_files_from(url, &model_dir, &checksums, require_checksum).await
+            }
+        };
+        let quantized = match imported {
+            Ok(()) => self.validate_by_loading(&model_dir).await,
+            Err(e) => Err(e),
+        };
+        let quantized = match quantized {
+            Ok(quantized) => quantized,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&model_dir).await;
+                return Err(e);
+            }
+        };
+
+        let mut registry = CustomModelRegistry::load(&self.models_dir);
+        registry.insert(custom_models::new_entry(&name, &model_dir, description, &source, quantized));
+        registry.save(&self.models_dir)?;
+
+        log::info!("✅ Imported Parakeet model '{}' ({})", name, if quantized { "int8" } else { "FP32" });
+        self.discover_models()
+            .await?
+            .into_iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| anyhow!("Imported model '{}' was not discovered", name))
+    }
+
+    /// Copy the files of an exported model (ONNX sessions, external weights, vocabulary)
+    async fn copy_model_files(
+        src_dir: &PathBuf,
+        model_dir: &PathBuf,
+        checksums: &HashMap<String, ManifestEntry>,
+        require_checksum: bool,
+    ) -> Result<()> {
+        let mut entries = fs::read_dir(src_dir).await?;
+        let mut copied = 0;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let is_model_file = file_name.ends_with(".onnx")
+                || file_name.ends_with(".onnx.data")
+                || file_name == "vocab.txt";
+            if is_model_file && entry.file_type().await?.is_file() {
+                custom_models::copy_verified_file(
+                    &entry.path(),
+                    &model_dir.join(&file_name),
+                    checksums.get(&file_name),
+                    require_checksum,
+                )
+                .await?;
+                copied += 1;
+            }
+        }
+        if copied == 0 {
+            return Err(anyhow!("No ONNX model files found in {}", src_dir.display()));
+        }
+        Ok(())
+    }
+
+    /// Fetch an exported model from a URL prefix: the int8 files when the server has them,
+    /// otherwise the FP32 ones (plus the encoder's external weights, if present). Only a
+    /// missing file falls back; a file that fails verification aborts the import.
+    async fn download_model_files_from(
+        base_url: &str,
+        model_dir: &PathBuf,
+        checksums: &HashMap<String, ManifestEntry>,
+        require_checksum: bool,
+    ) -> Result<()> {
+        let fetch = |file: &'static str| {
+            let url = format!("{}/{}", base_url, file);
+            let dest = model_dir.join(file);
+            async move {
+                custom_models::download_verified_file(&url, &dest, checksums.get(file), require_checksum).await
+            }
+        };
+        let is_missing = |e: &anyhow::Error| {
+            matches!(e.downcast_ref::<DownloadError>(), Some(DownloadError::HttpStatus { .. }))
+        };
+
+        for file in ["vocab.txt", "nemo128.onnx"] {
+            fetch(file).await?;
+        }
+        match fetch("encoder-model.int8.onnx").await {
+            Ok(()) => fetch("decoder_joint-model.int8.onnx").await?,
+            Err(e) if is_missing(&e) => {
+                fetch("encoder-model.onnx").await?;
+                fetch("decoder_joint-model.onnx").await?;
+                match fetch("encoder-model.onnx.data").await {
+                    Ok(()) => {}
+                    Err(e) if is_missing(&e) => {
+                        log::info!("No external encoder weights at {}: {}", base_url, e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+
+    /// Check the files, then load the model and drop it again. Returns whether the
+    /// export is int8-quantized.
+    async fn validate_by_loading(&self, model_dir: &PathBuf) -> Result<bool> {
+        self.validate_model_directory(model_dir).await?;
+
+        let quantized = model_dir.join("encoder-model.int8.onnx").exists()
+            && model_dir.join("decoder_joint-model.int8.onnx").exists();
+        let dir = model_dir.clone();
+        tokio::task::spawn_blocking(move || {
+            ParakeetModel::new(&dir, quantized)
+                .map(drop)
+                .map_err(|e| anyhow!("ONNX Runtime could not load the model: {}", e))
+        })
+        .await
+        .map_err(|e| anyhow!("Model validation task failed: {}", e))??;
+
+        Ok(quantized)
+    }
+
+    /// Remove an imported model and its directory
+    pub async fn remove_custom_model(&self, model_name: &str) -> Result<()> {
+        let mut registry = CustomModelRegistry::load(&self.models_dir);
+        let entry = registry
+            .remove(model_name)
+            .ok_or_else(|| anyhow!("'{}' is not an imported model", model_name))?;
+
+        if self.current_model_name.read().await.as_deref() == Some(model_name) {
+            self.unload_model().await;
+        }
+
+        let model_dir = self.models_dir.join(&entry.path);
+        if model_dir.exists() {
+            fs::remove_dir_all(&model_dir)
+                .await
+                .map_err(|e| anyhow!("Failed to delete directory '{}': {}", model_dir.display(), e))?;
+        }
+        registry.save(&self.models_dir)?;
+        self.discover_models().await?;
+
+        log::info!("🗑️ Removed imported Parakeet model '{}'", model_name);
+        Ok(())
+    }
+
+    /// Download a Parakeet model from HuggingFace (or the configured mirror)
     pub async fn download_model(
         &self,
         model_name: &str,
@@ -421,18 +648,44 @@ impl ParakeetEngine {
             *cancel_flag = None;
         }
 
+        let result = self.download_model_files(model_name, progress_callback).await;
+
+        // Remove from active downloads whatever the outcome
+        {
+            let mut active = self.active_downloads.write().await;
+            active.remove(model_name);
+        }
+
+        // Surface failures in the model list; a cancelled download goes back to Missing
+        if let Err(e) = &result {
+            let cancelled = self.cancel_download_flag.read().await.as_deref() == Some(model_name);
+            let mut models = self.available_models.write().await;
+            if let Some(model) = models.get_mut(model_name) {
+                model.status = if cancelled {
+                    ModelStatus::Missing
+                } else {
+                    ModelStatus::Error(e.to_string())
+                };
+            }
+        }
+
+        result
+    }
+
+    /// Download every file of a model. Files already downloaded by an earlier attempt are
+    /// only verified, and an interrupted file resumes where it stopped (see `crate::model_download`).
+    async fn download_model_files(
+        &self,
+        model_name: &str,
+        progress_callback: Option<Box<dyn Fn(u8) + Send>>,
+    ) -> Result<()> {
         // Get model info
         let model_info = {
             let models = self.available_models.read().await;
-            match models.get(model_name).cloned() {
-                Some(info) => info,
-                None => {
-                    // Remove from active downloads on error
-                    let mut active = self.active_downloads.write().await;
-                    active.remove(model_name);
-                    return Err(anyhow!("Model {} not found", model_name));
-                }
-            }
+            models
+                .get(model_name)
+                .cloned()
+                .ok_or_else(|| anyhow!("Model {} not found", model_name))?
         };
 
         // Update model status to downloading
@@ -443,12 +696,12 @@ impl ParakeetEngine {
             }
         }
 
-        // HuggingFace base URL for Parakeet models (version-specific)
-        let base_url = if model_name.contains("-v2-") {
-            "https://huggingface.co/istupakov/parakeet-tdt-0.6b-v2-onnx/resolve/main"
+        // HuggingFace repository for Parakeet models (version-specific)
+        let repo = if model_name.contains("-v2-") {
+            "istupakov/parakeet-tdt-0.6b-v2-onnx"
         } else {
             // Default to v3 for v3 models
-            "https://huggingface.co/istupakov/parakeet-tdt-0.6b-v3-onnx/resolve/main"
+            "istupakov/parakeet-tdt-0.6b-v3-onnx"
         };
 
         // Determine which files to download based on quantization
@@ -470,16 +723,14 @@ impl ParakeetEngine {
         // Create model directory
         let model_dir = &model_info.path;
         if !model_dir.exists() {
-            if let Err(e) = fs::create_dir_all(model_dir).await {
-                // Remove from active downloads on error
-                let mut active = self.active_downloads.write().await;
-                active.remove(model_name);
-                return Err(anyhow!("Failed to create model directory: {}", e));
-            }
+            fs::create_dir_all(model_dir)
+                .await
+                .map_err(|e| anyhow!("Failed to create model directory: {}", e))?;
         }
 
-        // Download each file
+        let settings = model_download::current_settings();
         let client = reqwest::Client::new();
+        let manifest = model_download::load_manifest(&client, &settings).await;
         let total_files = files_to_download.len();
 
         // Calculate total download size for weighted progress
@@ -522,7 +773,8 @@ impl ParakeetEngine {
             .copied()
             .sum();
 
-        let mut total_downloaded: u64 = 0;
+        // Bytes of the files finished so far
+        let mut completed_bytes: u64 = 0;
 
         log::info!(
             "Starting weighted download for {} files, total size: {:.2} MB",
@@ -531,75 +783,30 @@ impl ParakeetEngine {
         );
 
         for (index, filename) in files_to_download.iter().enumerate() {
-            let file_url = format!("{}/{}", base_url, filename);
+            let artifact = ModelArtifact::new(repo, filename);
+            let file_url = artifact.url(&settings);
             let file_path = model_dir.join(filename);
 
-            log::info!("Downloading file {}/{}: {}", index + 1, total_files, filename);
-
-            // Download file
-            let response = client.get(&file_url).send().await
-                .map_err(|e| {
-                    // Note: cleanup will happen at function end via drop or explicit cleanup
-                    anyhow!("Failed to start download for {}: {}", filename, e)
-                })?;
-
-            if !response.status().is_success() {
-                // Remove from active downloads on error
-                let mut active = self.active_downloads.write().await;
-                active.remove(model_name);
-                return Err(anyhow!("Download failed for {} with status: {}", filename, response.status()));
-            }
-
-            let total_size = response.content_length().unwrap_or(0);
-            let mut file = match fs::File::create(&file_path).await {
-                Ok(f) => f,
-                Err(e) => {
-                    // Remove from active downloads on error
-                    let mut active = self.active_downloads.write().await;
-                    active.remove(model_name);
-                    return Err(anyhow!("Failed to create file {}: {}", filename, e));
-                }
-            };
+            log::info!("Downloading file {}/{}: {}", index + 1, total_files, file_url);
 
-            // Stream download
-            use futures_util::StreamExt;
-            let mut stream = response.bytes_stream();
-            let mut downloaded = 0u64;
+            let mut download =
+                ResumableDownload::start(&client, &file_url, &file_path, manifest.get(&artifact)).await?;
+            let total_size = download.total().unwrap_or(0);
             let mut last_reported_mb = 0u64;
             let mut last_reported_progress = 0u8;
 
-            while let Some(chunk_result) = stream.next().await {
-                // Check for cancellation before processing chunk
+            while download.next_chunk().await?.is_some() {
+                // Check for cancellation after each chunk; cancel_download removes the directory
                 {
                     let cancel_flag = self.cancel_download_flag.read().await;
-                    if cancel_flag.as_ref() == Some(&model_name.to_string()) {
+                    if cancel_flag.as_deref() == Some(model_name) {
                         log::info!("Download cancelled for {}", model_name);
-                        // Remove from active downloads on cancellation
-                        let mut active = self.active_downloads.write().await;
-                        active.remove(model_name);
                         return Err(anyhow!("Download cancelled by user"));
                     }
                 }
 
-                let chunk = match chunk_result {
-                    Ok(c) => c,
-                    Err(e) => {
-                        // Remove from active downloads on error
-                        let mut active = self.active_downloads.write().await;
-                        active.remove(model_name);
-                        return Err(anyhow!("Failed to read chunk: {}", e));
-                    }
-                };
-
-                if let Err(e) = file.write_all(&chunk).await {
-                    // Remove from active downloads on error
-                    let mut active = self.active_downloads.write().await;
-                    active.remove(model_name);
-                    return Err(anyhow!("Failed to write chunk to file: {}", e));
-                }
-
-                downloaded += chunk.len() as u64;
-                total_downloaded += chunk.len() as u64;
+                let downloaded = download.downloaded();
+                let total_downloaded = completed_bytes + downloaded;
 
                 // Calculate weighted overall progress based on total bytes downloaded
                 let overall_progress = if total_size_bytes > 0 {
@@ -637,18 +844,15 @@ impl ParakeetEngine {
                 }
             }
 
-            if let Err(e) = file.flush().await {
-                // Remove from active downloads on error
-                let mut active = self.active_downloads.write().await;
-                active.remove(model_name);
-                return Err(anyhow!("Failed to flush file {}: {}", filename, e));
-            }
+            let downloaded = download.downloaded();
+            download.finish(settings.require_checksum).await?;
+            completed_bytes += downloaded;
 
             log::info!(
                 "Completed download: {} ({:.2} MB, overall progress: {:.1}%)",
                 filename,
                 downloaded as f64 / 1_048_576.0,
-                (total_downloaded as f64 / total_size_bytes as f64) * 100.0
+                (completed_bytes as f64 / total_size_bytes.max(1) as f64) * 100.0
             );
         }
 
@@ -666,20 +870,6 @@ impl ParakeetEngine {
             }
         }
 
-        // Remove from active downloads on completion
-        {
-            let mut active = self.active_downloads.write().await;
-            active.remove(model_name);
-        }
-
-        // Clear cancellation flag on successful completion
-        {
-            let mut cancel_flag = self.cancel_download_flag.write().await;
-            if cancel_flag.as_ref() == Some(&model_name.to_string()) {
-                *cancel_flag = None;
-            }
-        }
-
         log::info!("Download completed for Parakeet model: {}", model_name);
         Ok(())
     }
@@ -711,13 +901,22 @@ impl ParakeetEngine {
         // Clean up partially downloaded files
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await; // Brief delay to let download loop exit
 
+        // Only the `.part` files: files of an installed model stay until a re-download has
+        // replaced them with verified ones
         let model_path = self.models_dir.join(model_name);
-        if model_path.exists() {
-            if let Err(e) = fs::remove_dir_all(&model_path).await {
-                log::warn!("Failed to clean up cancelled download directory: {}", e);
-            } else {
-                log::info!("Cleaned up cancelled download directory: {}", model_path.display());
+        if let Ok(mut entries) = fs::read_dir(&model_path).await {
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let path = entry.path();
+                if path.extension().is_some_and(|ext| ext == "part") {
+                    if let Err(e) = fs::remove_file(&path).await {
+                        log::warn!("Failed to clean up cancelled download file: {}", e);
+                    } else {
+                        log::info!("Cleaned up cancelled download file: {}", path.display());
+                    }
+                }
             }
+            // Remove the directory again if the download had just created it
+            let _ = fs::remove_dir(&model_path).await;
         }
 
         Ok(())
@@ -0,0 +1,623 @@
+// redaction.rs
+//
+// PII redaction. Emails, phone numbers, card numbers, IBANs and user-defined patterns are
+// replaced with numbered placeholders ("[EMAIL_1]") when transcripts are saved, only in
+// the prompts sent to the summary LLM, or both. The placeholder map of an LLM request
+// stays in memory so the returned summary can be re-hydrated locally. Originals are never
+// written to the database; each meeting only gets a report of what was redacted. Maps
+// that add to text which already holds placeholders reserve those first, so a new value
+// never reuses a placeholder that stands for something else.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::database::models::RedactionReportEntry;
+use crate::database::repositories::redaction::RedactionReportsRepository;
+use crate::settings_store;
+
+const EMAIL_PATTERN: &str = r"\b[A-Z0-9._%+-]+@[A-Z0-9.-]+\.[A-Z]{2,}\b";
+const IBAN_PATTERN: &str = r"\b[A-Z]{2}\d{2}(?: ?[A-Z0-9]){11,30}\b";
+const CARD_PATTERN: &str = r"\b(?:\d[ -]?){12,18}\d\b";
+/// A placeholder handed out earlier: category and number
+const PLACEHOLDER_PATTERN: &str = r"\[([A-Z0-9_]+)_(\d+)\]";
+/// International numbers, "(555) 123-4567" and dash/dot-separated local numbers. Plain
+/// space-separated digit groups are left alone; in transcripts those are mostly years
+/// and amounts.
+const PHONE_PATTERN: &str = r"(?:\+\d{1,3}[ .-]?(?:\(\d{1,4}\)[ .-]?)?\d{1,4}(?:[ .-]?\d{2,4}){1,4})|(?:\(\d{2,4}\)[ .-]?\d{3,4}[ .-]?\d{3,4})|(?:\b\d{3}[-.]\d{3,4}[-.]\d{3,4}\b)|(?:\b\d{3}[-.]\d{4}\b)";
+
+fn default_true() -> bool {
+    true
+}
+
+/// Where redaction applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionMode {
+    /// Transcripts are redacted before they are saved (summaries then see placeholders too)
+    Storage,
+    /// Transcripts are stored verbatim; only LLM prompts are redacted
+    LlmOnly,
+    Both,
+}
+
+impl RedactionMode {
+    pub fn at_storage(self) -> bool {
+        matches!(self, Self::Storage | Self::Both)
+    }
+
+    pub fn at_llm(self) -> bool {
+        matches!(self, Self::LlmOnly | Self::Both)
+    }
+}
+
+/// Where a redaction happened, as recorded in the meeting's report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionStage {
+    Storage,
+    Llm,
+}
+
+impl RedactionStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Storage => "storage",
+            Self::Llm => "llm",
+        }
+    }
+}
+
+/// User-defined pattern, e.g. `{ name: "employee_id", pattern: "EMP-\d{6}" }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomPattern {
+    pub name: String,
+    pub pattern: String,
+}
+
+/// Persisted redaction configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedactionSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_mode")]
+    pub mode: RedactionMode,
+    #[serde(default = "default_true")]
+    pub emails: bool,
+    #[serde(default = "default_true")]
+    pub phone_numbers: bool,
+    #[serde(default = "default_true")]
+    pub card_numbers: bool,
+    #[serde(default = "default_true")]
+    pub ibans: bool,
+    #[serde(default)]
+    pub custom_patterns: Vec<CustomPattern>,
+    /// Also redact prompts for Ollama, which normally runs on this machine
+    #[serde(default)]
+    pub include_local_llm: bool,
+}
+
+fn default_mode() -> RedactionMode {
+    RedactionMode::LlmOnly
+}
+
+impl Default for RedactionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: default_mode(),
+            emails: true,
+            phone_numbers: true,
+            card_numbers: true,
+            ibans: true,
+            custom_patterns: Vec::new(),
+            include_local_llm: false,
+        }
+    }
+}
+
+impl RedactionSettings {
+    /// Load the settings from disk
+    pub fn load() -> Self {
+        settings_store::load("redaction.json", "redaction settings")
+    }
+
+    /// Save the settings to disk
+    pub fn save(&self) -> Result<(), String> {
+        settings_store::save(self, "redaction.json", "redaction settings")
+    }
+
+    /// Reject custom patterns that don't compile or that match empty text
+    fn validate(&self) -> Result<(), String> {
+        for custom in &self.custom_patterns {
+            if custom.name.trim().is_empty() {
+                return Err("Custom patterns need a name".to_string());
+            }
+            let regex = Regex::new(&custom.pattern)
+                .map_err(|e| format!("Invalid pattern '{}': {}", custom.name, e))?;
+            if regex.is_match("") {
+                return Err(format!("Pattern '{}' matches empty text", custom.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One redacted value and the placeholder that replaced it
+#[derive(Debug, Clone)]
+struct RedactedValue {
+    placeholder: String,
+    category: String,
+    key: String,
+    original: String,
+    occurrences: u32,
+}
+
+/// What a report records about a placeholder (never the original value)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedactionReportItem {
+    pub category: String,
+    pub placeholder: String,
+    pub occurrences: u32,
+}
+
+/// Placeholders handed out while redacting one meeting or prompt. The same value always
+/// gets the same placeholder, so the LLM can still tell "[EMAIL_1]" and "[EMAIL_2]" apart.
+#[derive(Debug, Clone, Default)]
+pub struct RedactionMap {
+    values: Vec<RedactedValue>,
+    /// Placeholders already in the text from an earlier pass, whose originals are unknown
+    reserved: Vec<String>,
+}
+
+impl RedactionMap {
+    /// Map that continues after the placeholders found in `texts`
+    pub fn reserving<'a>(texts: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut map = Self::default();
+        for text in texts {
+            map.reserve_placeholders_in(text);
+        }
+        map
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Keep the placeholders in `text` from being handed out again
+    pub fn reserve_placeholders_in(&mut self, text: &str) {
+        for found in PLACEHOLDER_REGEX.find_iter(text) {
+            let placeholder = found.as_str();
+            if !self.is_placeholder(placeholder) {
+                self.reserved.push(placeholder.to_string());
+            }
+        }
+    }
+
+    fn placeholder_for(&mut self, category: &str, original: &str) -> String {
+        let key: String = original
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == '@')
+            .flat_map(|c| c.to_lowercase())
+            .collect();
+
+        if let Some(value) = self
+            .values
+            .iter_mut()
+            .find(|v| v.category == category && v.key == key)
+        {
+            value.occurrences += 1;
+            return value.placeholder.clone();
+        }
+
+        let number = self
+            .values
+            .iter()
+            .map(|v| v.placeholder.as_str())
+            .chain(self.reserved.iter().map(String::as_str))
+            .filter_map(|p| {
+                let caps = PLACEHOLDER_REGEX.captures(p)?;
+                if &caps[1] != category {
+                    return None;
+                }
+                caps[2].parse::<u32>().ok()
+            })
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let placeholder = format!("[{}_{}]", category, number);
+        self.values.push(RedactedValue {
+            placeholder: placeholder.clone(),
+            category: category.to_string(),
+            key,
+            original: original.to_string(),
+            occurrences: 1,
+        });
+        placeholder
+    }
+
+    fn is_placeholder(&self, text: &str) -> bool {
+        self.values.iter().any(|v| v.placeholder == text) || self.reserved.iter().any(|r| r == text)
+    }
+
+    /// Put the original values back in place of their placeholders
+    pub fn rehydrate(&self, text: &str) -> String {
+        let mut restored = text.to_string();
+        for value in &self.values {
+            restored = restored.replace(&value.placeholder, &value.original);
+        }
+        restored
+    }
+
+    pub fn report(&self) -> Vec<RedactionReportItem> {
+        self.values
+            .iter()
+            .map(|v| RedactionReportItem {
+                category: v.category.clone(),
+                placeholder: v.placeholder.clone(),
+                occurrences: v.occurrences,
+            })
+            .collect()
+    }
+}
+
+struct Rule {
+    category: String,
+    regex: Regex,
+    is_valid: fn(&str) -> bool,
+}
+
+/// Redaction settings with compiled rules
+pub struct Redactor {
+    settings: RedactionSettings,
+    rules: Vec<Rule>,
+}
+
+impl Redactor {
+    pub fn new(settings: RedactionSettings) -> Self {
+        fn any(_: &str) -> bool {
+            true
+        }
+        fn build(pattern: &str, case_insensitive: bool) -> Regex {
+            RegexBuilder::new(pattern)
+                .case_insensitive(case_insensitive)
+                .build()
+                .expect("built-in redaction pattern")
+        }
+
+        // Order matters: IBANs and card numbers before phone numbers, which would
+        // otherwise claim their digit groups
+        let mut rules = Vec::new();
+        if settings.emails {
+            rules.push(Rule {
+                category: "EMAIL".to_string(),
+                regex: build(EMAIL_PATTERN, true),
+                is_valid: any,
+            });
+        }
+        if settings.ibans {
+            rules.push(Rule {
+                category: "IBAN".to_string(),
+                regex: build(IBAN_PATTERN, false),
+                is_valid: is_valid_iban,
+            });
+        }
+        if settings.card_numbers {
+            rules.push(Rule {
+                category: "CARD".to_string(),
+                regex: build(CARD_PATTERN, false),
+                is_valid: is_valid_card_number,
+            });
+        }
+        if settings.phone_numbers {
+            rules.push(Rule {
+                category: "PHONE".to_string(),
+                regex: build(PHONE_PATTERN, false),
+                is_valid: is_plausible_phone_number,
+            });
+        }
+        for custom in &settings.custom_patterns {
+            match Regex::new(&custom.pattern) {
+                Ok(regex) => rules.push(Rule {
+                    category: custom_category(&custom.name),
+                    regex,
+                    is_valid: any,
+                }),
+                Err(e) => warn!(
+                    "Skipping invalid redaction pattern '{}': {}",
+                    custom.name, e
+                ),
+            }
+        }
+
+        Self { settings, rules }
+    }
+
+    pub fn settings(&self) -> &RedactionSettings {
+        &self.settings
+    }
+
+    /// Replace everything the rules match with placeholders from `map`
+    pub fn redact(&self, text: &str, map: &mut RedactionMap) -> String {
+        let mut redacted = text.to_string();
+        for rule in &self.rules {
+            redacted = rule
+                .regex
+                .replace_all(&redacted, |caps: &regex::Captures| {
+                    let matched = &caps[0];
+                    if map.is_placeholder(matched) || !(rule.is_valid)(matched) {
+                        matched.to_string()
+                    } else {
+                        map.placeholder_for(&rule.category, matched)
+                    }
+                })
+                .into_owned();
+        }
+        redacted
+    }
+
+    /// Redact `text` in place; returns whether anything was replaced
+    pub fn redact_in_place(&self, text: &mut String, map: &mut RedactionMap) -> bool {
+        let redacted = self.redact(text, map);
+        let changed = redacted != *text;
+        *text = redacted;
+        changed
+    }
+}
+
+/// "employee id" → "EMPLOYEE_ID"
+fn custom_category(name: &str) -> String {
+    let category: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if category.is_empty() {
+        "CUSTOM".to_string()
+    } else {
+        category
+    }
+}
+
+/// Luhn checksum over the digits of a candidate card number
+fn is_valid_card_number(candidate: &str) -> bool {
+    let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// ISO 13616 mod-97 check
+fn is_valid_iban(candidate: &str) -> bool {
+    let compact: String = candidate.chars().filter(|c| !c.is_whitespace()).collect();
+    if !(15..=34).contains(&compact.len()) {
+        return false;
+    }
+
+    let rearranged = compact[4..].chars().chain(compact[..4].chars());
+    let mut remainder: u32 = 0;
+    for c in rearranged {
+        let value = match c.to_digit(36) {
+            Some(v) => v,
+            None => return false,
+        };
+        remainder = if value >= 10 {
+            (remainder * 100 + value) % 97
+        } else {
+            (remainder * 10 + value) % 97
+        };
+    }
+    remainder == 1
+}
+
+fn is_plausible_phone_number(candidate: &str) -> bool {
+    let digits = candidate.chars().filter(|c| c.is_ascii_digit()).count();
+    (7..=15).contains(&digits)
+}
+
+static PLACEHOLDER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(PLACEHOLDER_PATTERN).expect("placeholder pattern"));
+
+static REDACTOR: Lazy<RwLock<Arc<Redactor>>> =
+    Lazy::new(|| RwLock::new(Arc::new(Redactor::new(RedactionSettings::load()))));
+
+fn current_redactor() -> Option<Arc<Redactor>> {
+    REDACTOR.read().ok().map(|r| Arc::clone(&r))
+}
+
+/// Redactor for transcripts about to be saved, when storage-time redaction is on
+pub fn storage_redactor() -> Option<Arc<Redactor>> {
+    current_redactor().filter(|r| r.settings.enabled && r.settings.mode.at_storage())
+}
+
+/// Redactor for LLM prompts, when LLM-time redaction is on for this kind of provider
+pub fn llm_redactor(local_provider: bool) -> Option<Arc<Redactor>> {
+    current_redactor().filter(|r| {
+        r.settings.enabled
+            && r.settings.mode.at_llm()
+            && (!local_provider || r.settings.include_local_llm)
+    })
+}
+
+#[tauri::command]
+pub async fn get_redaction_settings() -> Result<RedactionSettings, String> {
+    current_redactor()
+        .map(|r| r.settings.clone())
+        .ok_or_else(|| "Failed to read redaction settings".to_string())
+}
+
+#[tauri::command]
+pub async fn set_redaction_settings(settings: RedactionSettings) -> Result<(), String> {
+    settings.validate()?;
+    settings.save()?;
+
+    let mut redactor = REDACTOR
+        .write()
+        .map_err(|e| format!("Failed to update redaction settings: {}", e))?;
+    *redactor = Arc::new(Redactor::new(settings));
+    info!("🛡️ Redaction settings updated");
+    Ok(())
+}
+
+/// Run the configured rules on sample text (for trying out custom patterns)
+#[tauri::command]
+pub async fn preview_redaction(text: String) -> Result<String, String> {
+    let redactor = current_redactor().ok_or_else(|| "Redaction is unavailable".to_string())?;
+    Ok(redactor.redact(&text, &mut RedactionMap::default()))
+}
+
+/// What was redacted in a meeting, per stage
+#[tauri::command]
+pub async fn get_redaction_report<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<RedactionReportEntry>, String> {
+    let state = app
+        .try_state::<crate::state::AppState>()
+        .ok_or_else(|| "App state not available".to_string())?;
+    RedactionReportsRepository::get_report(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load redaction report: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redactor(custom: &[(&str, &str)]) -> Redactor {
+        Redactor::new(RedactionSettings {
+            enabled: true,
+            custom_patterns: custom
+                .iter()
+                .map(|(name, pattern)| CustomPattern {
+                    name: name.to_string(),
+                    pattern: pattern.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn emails_and_phone_numbers_get_stable_placeholders() {
+        let r = redactor(&[]);
+        let mut map = RedactionMap::default();
+
+        let first = r.redact(
+            "Mail Jane.Doe@example.com or call +44 20 7946 0958.",
+            &mut map,
+        );
+        let second = r.redact("Again, jane.doe@example.com or (555) 123-4567.", &mut map);
+
+        assert_eq!(first, "Mail [EMAIL_1] or call [PHONE_1].");
+        assert_eq!(second, "Again, [EMAIL_1] or [PHONE_2].");
+        let report = map.report();
+        assert_eq!(report[0].placeholder, "[EMAIL_1]");
+        assert_eq!(report[0].occurrences, 2);
+    }
+
+    #[test]
+    fn cards_and_ibans_must_pass_their_checksums() {
+        let r = redactor(&[]);
+        let mut map = RedactionMap::default();
+
+        assert_eq!(
+            r.redact(
+                "Card 4111 1111 1111 1111, IBAN GB82 WEST 1234 5698 7654 32.",
+                &mut map
+            ),
+            "Card [CARD_1], IBAN [IBAN_1]."
+        );
+        // Wrong check digits: left alone
+        assert_eq!(
+            r.redact(
+                "Card 4111 1111 1111 1112, IBAN GB83 WEST 1234 5698 7654 32.",
+                &mut map
+            ),
+            "Card 4111 1111 1111 1112, IBAN GB83 WEST 1234 5698 7654 32."
+        );
+    }
+
+    #[test]
+    fn ordinary_numbers_are_left_alone() {
+        let r = redactor(&[]);
+        let text = "Revenue grew 12.5% in 2023 2024 to 1,200,000 dollars at 10:30.";
+        assert_eq!(r.redact(text, &mut RedactionMap::default()), text);
+    }
+
+    #[test]
+    fn custom_patterns_and_rehydration_round_trip() {
+        let r = redactor(&[("employee id", r"EMP-\d{6}")]);
+        let mut map = RedactionMap::default();
+
+        let text = "EMP-123456 will email ops@example.com.";
+        let redacted = r.redact(text, &mut map);
+        assert_eq!(redacted, "[EMPLOYEE_ID_1] will email [EMAIL_1].");
+
+        let summary = "- [EMPLOYEE_ID_1] to contact [EMAIL_1]";
+        assert_eq!(
+            map.rehydrate(summary),
+            "- EMP-123456 to contact ops@example.com"
+        );
+    }
+
+    #[test]
+    fn reserved_placeholders_are_not_reused() {
+        let r = redactor(&[("employee id", r"EMP-\d{6}")]);
+        let stored = "Ask [EMAIL_1] and [EMPLOYEE_ID_2] about [EMAIL_3].";
+        let mut map = RedactionMap::reserving([stored]);
+
+        let redacted = r.redact("Also cc bob@example.com and EMP-654321.", &mut map);
+        assert_eq!(redacted, "Also cc [EMAIL_4] and [EMPLOYEE_ID_3].");
+        assert_eq!(map.report().len(), 2);
+
+        // Stored placeholders stay as they are; only the new ones are re-hydrated
+        assert_eq!(
+            map.rehydrate("[EMAIL_1] and [EMAIL_4]"),
+            "[EMAIL_1] and bob@example.com"
+        );
+    }
+
+    #[test]
+    fn mode_and_validation() {
+        assert!(RedactionMode::Both.at_storage() && RedactionMode::Both.at_llm());
+        assert!(!RedactionMode::LlmOnly.at_storage());
+        assert!(!RedactionMode::Storage.at_llm());
+
+        let mut settings = RedactionSettings::default();
+        settings.custom_patterns.push(CustomPattern {
+            name: "anything".to_string(),
+            pattern: r"\d*".to_string(),
+        });
+        assert!(settings.validate().is_err());
+        settings.custom_patterns[0].pattern = "(".to_string();
+        assert!(settings.validate().is_err());
+    }
+}
@@ -0,0 +1,51 @@
+// settings_store.rs
+//
+// JSON settings files in the app data directory (`com.meetily.ai/<file_name>`). Each
+// settings type keeps its own defaults and validation and loads/saves through here;
+// `what` names the settings in log lines and errors (e.g. "backlog settings").
+
+use log::{info, warn};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Path of a settings file in the app data directory
+pub fn settings_path(file_name: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join("com.meetily.ai").join(file_name))
+}
+
+/// Load settings from disk, falling back to the defaults when the file is missing or invalid
+pub fn load<T: DeserializeOwned + Default>(file_name: &str, what: &str) -> T {
+    if let Some(path) = settings_path(file_name) {
+        if path.exists() {
+            match std::fs::read_to_string(&path) {
+                Ok(contents) => match serde_json::from_str(&contents) {
+                    Ok(settings) => {
+                        info!("Loaded {} from {:?}", what, path);
+                        return settings;
+                    }
+                    Err(e) => warn!("Failed to parse {}: {}", what, e),
+                },
+                Err(e) => warn!("Failed to read {}: {}", what, e),
+            }
+        }
+    }
+    T::default()
+}
+
+/// Save settings to disk, creating the settings directory if needed
+pub fn save<T: Serialize>(settings: &T, file_name: &str, what: &str) -> Result<(), String> {
+    let path =
+        settings_path(file_name).ok_or_else(|| format!("Could not determine {} path", what))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+
+    let contents = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize {}: {}", what, e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", what, e))?;
+
+    info!("Saved {} to {:?}", what, path);
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::database::models::ActionItem;
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+};
+use crate::state::AppState;
+use crate::summary::action_items::STATUSES;
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+/// Lists the open action items of all meetings, earliest due date first
+#[tauri::command]
+pub async fn api_list_open_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<ActionItem>, String> {
+    ActionItemsRepository::list_open(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list open action items: {}", e);
+            format!("Failed to list open action items: {}", e)
+        })
+}
+
+/// Lists the action items of one meeting, whatever their status
+#[tauri::command]
+pub async fn api_get_meeting_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<ActionItem>, String> {
+    ActionItemsRepository::get_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items for {}: {}", meeting_id, e))
+}
+
+/// Sets the status of an action item ("open", "done" or "cancelled")
+#[tauri::command]
+pub async fn api_update_action_item_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+    status: String,
+) -> Result<(), String> {
+    if !STATUSES.contains(&status.as_str()) {
+        return Err(format!(
+            "Invalid status '{}'. Must be one of: {}",
+            status,
+            STATUSES.join(", ")
+        ));
+    }
+
+    match ActionItemsRepository::update_status(state.db_manager.pool(), &item_id, &status).await {
+        Ok(true) => {
+            log_info!("Action item {} marked {}", item_id, status);
+            Ok(())
+        }
+        Ok(false) => Err(format!("Action item not found: {}", item_id)),
+        Err(e) => Err(format!("Failed to update action item {}: {}", item_id, e)),
+    }
+}
+
+/// Assigns an action item to someone else; an empty or missing owner unassigns it
+#[tauri::command]
+pub async fn api_reassign_action_item<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    item_id: String,
+    owner: Option<String>,
+) -> Result<(), String> {
+    let owner = owner
+        .as_deref()
+        .map(str::trim)
+        .filter(|owner| !owner.is_empty());
+
+    match ActionItemsRepository::reassign(state.db_manager.pool(), &item_id, owner).await {
+        Ok(true) => {
+            log_info!("Action item {} reassigned to {:?}", item_id, owner);
+            Ok(())
+        }
+        Ok(false) => Err(format!("Action item not found: {}", item_id)),
+        Err(e) => Err(format!("Failed to reassign action item {}: {}", item_id, e)),
+    }
+}
+
+/// Puts a meeting into a series of recurring meetings; open action items of earlier
+/// meetings in the series are included in its summary prompt. An empty or missing
+/// series id takes the meeting out of its series.
+#[tauri::command]
+pub async fn api_set_meeting_series<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    series_id: Option<String>,
+) -> Result<(), String> {
+    let series_id = series_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|series_id| !series_id.is_empty());
+
+    match MeetingsRepository::update_meeting_series(state.db_manager.pool(), &meeting_id, series_id)
+        .await
+    {
+        Ok(true) => {
+            log_info!("Meeting {} series set to {:?}", meeting_id, series_id);
+            Ok(())
+        }
+        Ok(false) => Err(format!("Meeting not found: {}", meeting_id)),
+        Err(e) => Err(format!(
+            "Failed to set series of meeting {}: {}",
+            meeting_id, e
+        )),
+    }
+}
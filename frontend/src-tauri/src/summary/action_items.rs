@@ -0,0 +1,332 @@
+// summary/action_items.rs
+//
+// Extracts action items from a generated summary so they can be tracked as records:
+// from the `action_items` section of a structured summary when there is one, otherwise
+// from the "Action Items" section of the markdown (table rows or list items). The LLM
+// only sees the transcript text, so an item's recording time is found by matching its
+// reference quote (or its text) against the stored transcript segments.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// Statuses an action item can have
+pub const STATUSES: [&str; 3] = ["open", "done", "cancelled"];
+
+/// JSON key / heading of the section holding action items
+const SECTION_KEY: &str = "action_items";
+const SECTION_TITLE: &str = "action items";
+
+/// Placeholder values that mean "no value" in a summary cell
+const EMPTY_VALUES: [&str; 7] = ["", "-", "n/a", "na", "none", "tbd", "unassigned"];
+
+/// Words an item has to share with a transcript segment before it is located there
+const MIN_MATCH_WORDS: usize = 2;
+/// Share of the item's words a transcript segment must contain to be its source
+const MIN_MATCH_SHARE: f64 = 0.6;
+
+/// An action item as found in a summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedActionItem {
+    pub text: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    /// Quote of the transcript the item came from, when the summary gives one
+    pub reference: Option<String>,
+}
+
+/// Extracts the action items of a summary, preferring the structured result
+pub fn extract(markdown: &str, structured: Option<&Value>) -> Vec<ExtractedActionItem> {
+    match structured.and_then(|summary| summary.get(SECTION_KEY)) {
+        Some(Value::Array(items)) => items.iter().filter_map(from_structured_item).collect(),
+        _ => from_markdown(markdown),
+    }
+}
+
+fn from_structured_item(item: &Value) -> Option<ExtractedActionItem> {
+    match item {
+        Value::String(text) => non_empty(text).map(|text| ExtractedActionItem {
+            text,
+            owner: None,
+            due_date: None,
+            reference: None,
+        }),
+        Value::Object(fields) => {
+            let text = first_value(fields, &["task", "text", "action", "item", "description"])?;
+            Some(ExtractedActionItem {
+                text,
+                owner: first_value(fields, &["owner", "assignee"]),
+                due_date: first_value(fields, &["due_date", "due"]),
+                reference: first_value(fields, &["reference", "quote", "source"]),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn first_value(fields: &Map<String, Value>, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| match fields.get(*name) {
+        Some(Value::String(s)) => non_empty(s),
+        Some(Value::Number(n)) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches('*').trim();
+    (!EMPTY_VALUES.contains(&value.to_lowercase().as_str())).then(|| value.to_string())
+}
+
+/// Column roles recognised in an action item table header
+#[derive(Clone, Copy, PartialEq)]
+enum Column {
+    Text,
+    Owner,
+    Due,
+    Time,
+    Reference,
+    Other,
+}
+
+fn column_role(header: &str) -> Column {
+    let header = header.trim().trim_matches('*').trim().to_lowercase();
+    if header.contains("owner") || header.contains("assignee") {
+        Column::Owner
+    } else if header.contains("due") || header.contains("deadline") {
+        Column::Due
+    } else if header.contains("time") {
+        Column::Time
+    } else if header.contains("reference") || header.contains("quote") || header.contains("segment")
+    {
+        Column::Reference
+    } else if header.contains("task") || header.contains("action") || header == "item" {
+        Column::Text
+    } else {
+        Column::Other
+    }
+}
+
+/// Extracts action items from the "Action Items" section of a markdown summary
+pub fn from_markdown(markdown: &str) -> Vec<ExtractedActionItem> {
+    let mut items = Vec::new();
+    let mut in_section = false;
+    let mut columns: Option<Vec<Column>> = None;
+
+    for line in markdown.lines().map(str::trim) {
+        if let Some(heading) = section_heading(line) {
+            in_section = heading.eq_ignore_ascii_case(SECTION_TITLE);
+            columns = None;
+            continue;
+        }
+        if !in_section || line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('|') {
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+            if cells
+                .iter()
+                .all(|cell| !cell.is_empty() && cell.chars().all(|c| c == '-' || c == ':'))
+            {
+                continue; // Separator row
+            }
+            match &columns {
+                None => columns = Some(cells.iter().map(|cell| column_role(cell)).collect()),
+                Some(roles) => items.extend(from_table_row(roles, &cells)),
+            }
+        } else if let Some(text) = list_item(line) {
+            if let Some(text) = non_empty(text) {
+                items.push(ExtractedActionItem {
+                    text,
+                    owner: None,
+                    due_date: None,
+                    reference: None,
+                });
+            }
+        }
+    }
+
+    items
+}
+
+/// Title of a `## Heading` or `**Heading**` line
+fn section_heading(line: &str) -> Option<&str> {
+    if line.starts_with('#') {
+        return Some(line.trim_start_matches('#').trim());
+    }
+    line.strip_prefix("**")
+        .and_then(|rest| rest.strip_suffix("**"))
+        .filter(|title| !title.contains("**"))
+        .map(|title| title.trim().trim_end_matches(':'))
+}
+
+/// Text of a bullet, numbered or checkbox list item
+fn list_item(line: &str) -> Option<&str> {
+    let text = if let Some(rest) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        rest
+    } else {
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        line[digits..].strip_prefix(". ").filter(|_| digits > 0)?
+    };
+    let text = text.trim();
+    Some(
+        text.strip_prefix("[ ]")
+            .or_else(|| text.strip_prefix("[x]"))
+            .unwrap_or(text)
+            .trim(),
+    )
+}
+
+fn from_table_row(roles: &[Column], cells: &[&str]) -> Option<ExtractedActionItem> {
+    let cell = |role: Column| {
+        roles
+            .iter()
+            .position(|r| *r == role)
+            .and_then(|i| cells.get(i))
+            .and_then(|cell| non_empty(cell))
+    };
+    // Tables without a task column: the first column that is not owner/due/time/reference
+    let text = cell(Column::Text).or_else(|| cell(Column::Other))?;
+    Some(ExtractedActionItem {
+        text,
+        owner: cell(Column::Owner),
+        due_date: cell(Column::Due),
+        reference: cell(Column::Reference).map(|quote| quote.trim_matches('"').to_string()),
+    })
+}
+
+/// Lowercased words of three or more characters, the ones worth matching on
+fn significant_words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Recording time (seconds) of the transcript segment an item was taken from: the segment
+/// sharing the most words with its reference quote, or with its text when there is none,
+/// preferring the one with the fewest other words. `None` when no segment is a close
+/// enough match.
+pub fn transcript_time(item: &ExtractedActionItem, segments: &[(f64, String)]) -> Option<f64> {
+    let words = significant_words(item.reference.as_deref().unwrap_or(&item.text));
+    if words.len() < MIN_MATCH_WORDS {
+        return None;
+    }
+
+    // (shared words, other words, start)
+    let mut best: Option<(usize, usize, f64)> = None;
+    for (start, text) in segments {
+        let segment_words = significant_words(text);
+        let shared = words.intersection(&segment_words).count();
+        let other = segment_words.len() - shared;
+        let better = match best {
+            Some((most, fewest, _)) => shared > most || (shared == most && other < fewest),
+            None => true,
+        };
+        if better {
+            best = Some((shared, other, *start));
+        }
+    }
+    best.filter(|(shared, _, _)| *shared as f64 >= words.len() as f64 * MIN_MATCH_SHARE)
+        .map(|(_, _, start)| start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn extracts_from_a_structured_summary() {
+        let structured = json!({
+            "title": "Weekly sync",
+            "action_items": [
+                { "task": "Send the notes", "owner": "Ana", "due_date": "2025-10-31", "reference": "I'll send the notes" },
+                { "task": "Book a room", "owner": null, "due_date": null }
+            ]
+        });
+        let items = extract("ignored", Some(&structured));
+        assert_eq!(
+            items,
+            vec![
+                ExtractedActionItem {
+                    text: "Send the notes".to_string(),
+                    owner: Some("Ana".to_string()),
+                    due_date: Some("2025-10-31".to_string()),
+                    reference: Some("I'll send the notes".to_string()),
+                },
+                ExtractedActionItem {
+                    text: "Book a room".to_string(),
+                    owner: None,
+                    due_date: None,
+                    reference: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_table_rows_from_markdown() {
+        let markdown = "**Summary**\n\n- Not an action item\n\n**Action Items**\n\n\
+            | **Owner** | Task | Due | Reference Transcript Segment | Segment Time stamp |\n\
+            | --- | --- | --- | --- | --- |\n\
+            | Ana | Send the notes | Friday | \"I'll send them\" | 01:02:03 |\n\
+            | TBD | Book a room | - | | |\n\n\
+            **Discussion Highlights**\n\nNothing";
+        let items = from_markdown(markdown);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].text, "Send the notes");
+        assert_eq!(items[0].owner.as_deref(), Some("Ana"));
+        assert_eq!(items[0].due_date.as_deref(), Some("Friday"));
+        assert_eq!(items[0].reference.as_deref(), Some("I'll send them"));
+        assert_eq!(items[1].owner, None);
+        assert_eq!(items[1].due_date, None);
+    }
+
+    #[test]
+    fn locates_items_in_the_transcript() {
+        let segments = vec![
+            (0.0, "Welcome everyone to the weekly sync.".to_string()),
+            (
+                12.5,
+                "Ana, can you send the release notes by Friday?".to_string(),
+            ),
+            (18.0, "Sure, I'll send the release notes.".to_string()),
+        ];
+        let item = |text: &str, reference: Option<&str>| ExtractedActionItem {
+            text: text.to_string(),
+            owner: None,
+            due_date: None,
+            reference: reference.map(str::to_string),
+        };
+
+        assert_eq!(
+            transcript_time(
+                &item("Notes", Some("I'll send the release notes")),
+                &segments
+            ),
+            Some(18.0)
+        );
+        assert_eq!(
+            transcript_time(&item("Ask Ana for the notes by Friday", None), &segments),
+            Some(12.5)
+        );
+        assert_eq!(
+            transcript_time(&item("Book a meeting room", None), &segments),
+            None
+        );
+        assert_eq!(transcript_time(&item("Send", None), &segments), None);
+    }
+
+    #[test]
+    fn extracts_list_items_and_skips_empty_sections() {
+        let markdown = "## Action Items\n1. [ ] Draft the plan\n- Review budget\n## Notes\n- Other";
+        let texts: Vec<String> = from_markdown(markdown)
+            .into_iter()
+            .map(|i| i.text)
+            .collect();
+        assert_eq!(texts, vec!["Draft the plan", "Review budget"]);
+
+        assert!(from_markdown("**Action Items**\n\nNone noted in this section.").is_empty());
+    }
+}
@@ -218,21 +218,21 @@ pub async fn api_process_transcript<R: Runtime>(
 
     log_info!("✓ Transcript chunks saved for meeting_id: {}", &m_id);
 
-    // Spawn background task for actual processing
+    // Spawn background task for actual processing (cancellable via api_cancel_summary)
     let meeting_id_clone = m_id.clone();
-    tauri::async_runtime::spawn(async move {
+    SummaryService::spawn_cancellable(
+        m_id.clone(),
         SummaryService::process_transcript_background(
             app,
             pool,
-            meeting_id_clone.clone(),
+            meeting_id_clone,
             text,
             model,
             model_name,
             final_prompt,
             final_template_id,
-        )
-        .await;
-    });
+        ),
+    );
 
     log_info!("🚀 Background task spawned for meeting_id: {}", &m_id);
 
@@ -241,3 +241,31 @@ pub async fn api_process_transcript<R: Runtime>(
         process_id: m_id,
     })
 }
+
+/// Cancels an in-flight summary generation
+///
+/// Aborts the background task (dropping its LLM request) and marks the process as cancelled
+#[tauri::command]
+pub async fn api_cancel_summary<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_cancel_summary called for meeting_id: {}", meeting_id);
+    let was_running = SummaryService::cancel(&meeting_id);
+
+    let marked = SummaryProcessesRepository::update_process_cancelled(
+        state.db_manager.pool(),
+        &meeting_id,
+    )
+    .await
+    .map_err(|e| format!("Failed to mark summary as cancelled: {}", e))?;
+
+    if !was_running && !marked {
+        return Err("No summary is being generated for this meeting".to_string());
+    }
+
+    Ok(serde_json::json!({
+        "message": "Summary generation cancelled"
+    }))
+}
@@ -1,3 +1,4 @@
+use futures_util::StreamExt;
 use reqwest::{header, Client};
 use serde::{Deserialize, Serialize};
 use tracing::info;
@@ -14,6 +15,8 @@ pub struct ChatMessage {
 pub struct ChatRequest {
     pub model: String,
     pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub stream: bool,
 }
 
 // Generic structure for OpenAI-compatible API chat responses
@@ -39,6 +42,8 @@ pub struct ClaudeRequest {
     pub max_tokens: u32,
     pub system: String,
     pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub stream: bool,
 }
 
 // Claude-specific response structure
@@ -98,6 +103,171 @@ pub async fn generate_summary(
     user_prompt: &str,
     ollama_endpoint: Option<&str>,
 ) -> Result<String, String> {
+    let response = send_request(
+        client,
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        user_prompt,
+        ollama_endpoint,
+        false,
+    )
+    .await?;
+
+    // Parse response based on provider
+    if provider == &LLMProvider::Claude {
+        let chat_response = response
+            .json::<ClaudeChatResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+
+        info!("🐞 LLM Response received from Claude");
+
+        let content = chat_response
+            .content
+            .get(0)
+            .ok_or("No content in LLM response")?
+            .text
+            .trim();
+        Ok(content.to_string())
+    } else {
+        let chat_response = response
+            .json::<ChatResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+
+        info!("🐞 LLM Response received from {}", provider_name(provider));
+
+        let content = chat_response
+            .choices
+            .get(0)
+            .ok_or("No content in LLM response")?
+            .message
+            .content
+            .trim();
+        Ok(content.to_string())
+    }
+}
+
+/// Like `generate_summary`, but streams the completion and calls `on_delta` with each
+/// piece of text as it arrives (SSE for the OpenAI-compatible providers and Ollama,
+/// Anthropic's event stream for Claude). Dropping the future aborts the request.
+///
+/// # Returns
+/// The full generated text or an error message
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_summary_streaming(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    on_delta: &mut (dyn FnMut(&str) + Send),
+) -> Result<String, String> {
+    let response = send_request(
+        client,
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        user_prompt,
+        ollama_endpoint,
+        true,
+    )
+    .await?;
+
+    let mut stream = response.bytes_stream();
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut content = String::new();
+    let mut handle_line = |line: &[u8], content: &mut String| -> Result<(), String> {
+        let line = String::from_utf8_lossy(line);
+        if let Some(delta) = parse_stream_line(provider, line.trim_end())? {
+            content.push_str(&delta);
+            on_delta(&delta);
+        }
+        Ok(())
+    };
+
+    while let Some(bytes) = stream.next().await {
+        let bytes = bytes.map_err(|e| format!("Failed to read LLM response stream: {}", e))?;
+        buffer.extend_from_slice(&bytes);
+        // Events are line based; a network chunk can end mid-line
+        while let Some(newline) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=newline).collect();
+            handle_line(&line, &mut content)?;
+        }
+    }
+    if !buffer.is_empty() {
+        handle_line(&buffer, &mut content)?;
+    }
+
+    info!(
+        "🐞 LLM streamed response completed from {} ({} chars)",
+        provider_name(provider),
+        content.len()
+    );
+
+    let content = content.trim();
+    if content.is_empty() {
+        return Err("No content in LLM response".to_string());
+    }
+    Ok(content.to_string())
+}
+
+/// Extracts the text delta from one line of a streamed response. Returns `Ok(None)` for
+/// lines without text (event names, keep-alive comments, `[DONE]`, role or stop events)
+/// and an error for error events sent mid-stream.
+fn parse_stream_line(provider: &LLMProvider, line: &str) -> Result<Option<String>, String> {
+    let data = match line.strip_prefix("data:") {
+        Some(data) => data.trim(),
+        None => return Ok(None),
+    };
+    if data.is_empty() || data == "[DONE]" {
+        return Ok(None);
+    }
+
+    let event: serde_json::Value = serde_json::from_str(data)
+        .map_err(|e| format!("Failed to parse LLM stream event: {}", e))?;
+
+    if let Some(error) = event.get("error") {
+        let message = error
+            .get("message")
+            .and_then(|m| m.as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| error.to_string());
+        return Err(format!("LLM API request failed: {}", message));
+    }
+
+    let delta = if provider == &LLMProvider::Claude {
+        match event.get("type").and_then(|t| t.as_str()) {
+            Some("content_block_delta") => event.pointer("/delta/text"),
+            _ => None,
+        }
+    } else {
+        event.pointer("/choices/0/delta/content")
+    };
+
+    Ok(delta
+        .and_then(|text| text.as_str())
+        .filter(|text| !text.is_empty())
+        .map(str::to_string))
+}
+
+/// Builds and sends the chat request, returning the response once it has a success status
+#[allow(clippy::too_many_arguments)]
+async fn send_request(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    stream: bool,
+) -> Result<reqwest::Response, String> {
     let (api_url, mut headers) = match provider {
         LLMProvider::OpenAI => (
             "https://api.openai.com/v1/chat/completions".to_string(),
@@ -168,6 +338,7 @@ pub async fn generate_summary(
                     content: user_prompt.to_string(),
                 }
             ],
+            stream,
         })
     } else {
         serde_json::json!(ClaudeRequest {
@@ -177,7 +348,8 @@ pub async fn generate_summary(
             messages: vec![ChatMessage {
                 role: "user".to_string(),
                 content: user_prompt.to_string(),
-            }]
+            }],
+            stream,
         })
     };
 
@@ -200,39 +372,7 @@ pub async fn generate_summary(
         return Err(format!("LLM API request failed: {}", error_body));
     }
 
-    // Parse response based on provider
-    if provider == &LLMProvider::Claude {
-        let chat_response = response
-            .json::<ClaudeChatResponse>()
-            .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
-
-        info!("🐞 LLM Response received from Claude");
-
-        let content = chat_response
-            .content
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .text
-            .trim();
-        Ok(content.to_string())
-    } else {
-        let chat_response = response
-            .json::<ChatResponse>()
-            .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
-
-        info!("🐞 LLM Response received from {}", provider_name(provider));
-
-        let content = chat_response
-            .choices
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .message
-            .content
-            .trim();
-        Ok(content.to_string())
-    }
+    Ok(response)
 }
 
 /// Helper function to get provider name for logging
@@ -245,3 +385,61 @@ fn provider_name(provider: &LLMProvider) -> &str {
         LLMProvider::OpenRouter => "OpenRouter",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_openai_compatible_stream_lines() {
+        let provider = LLMProvider::OpenRouter;
+        assert_eq!(
+            parse_stream_line(
+                &provider,
+                r#"data: {"choices":[{"delta":{"content":"Notes"}}]}"#
+            ),
+            Ok(Some("Notes".to_string()))
+        );
+        // Role-only first event, keep-alive comment and terminator carry no text
+        assert_eq!(
+            parse_stream_line(
+                &provider,
+                r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#
+            ),
+            Ok(None)
+        );
+        assert_eq!(parse_stream_line(&provider, ": OPENROUTER PROCESSING"), Ok(None));
+        assert_eq!(parse_stream_line(&provider, "data: [DONE]"), Ok(None));
+        assert!(parse_stream_line(
+            &provider,
+            r#"data: {"error":{"message":"Rate limit exceeded"}}"#
+        )
+        .unwrap_err()
+        .contains("Rate limit exceeded"));
+    }
+
+    #[test]
+    fn parses_anthropic_stream_lines() {
+        let provider = LLMProvider::Claude;
+        assert_eq!(
+            parse_stream_line(&provider, "event: content_block_delta"),
+            Ok(None)
+        );
+        assert_eq!(
+            parse_stream_line(
+                &provider,
+                r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello"}}"#
+            ),
+            Ok(Some("Hello".to_string()))
+        );
+        assert_eq!(
+            parse_stream_line(&provider, r#"data: {"type":"message_stop"}"#),
+            Ok(None)
+        );
+        assert!(parse_stream_line(
+            &provider,
+            r#"data: {"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#
+        )
+        .is_err());
+    }
+}
@@ -5,19 +5,34 @@
 /// - Processor for chunking transcripts and generating summaries
 /// - Service layer for orchestrating summary generation
 /// - Templates for structured meeting summary generation
+/// - Structured (JSON) summaries validated against template fields
+/// - Action item extraction and tracking across meetings
 /// - Tauri commands for frontend integration
 
+pub mod action_item_commands;
+pub mod action_items;
 pub mod commands;
 pub mod llm_client;
 pub mod processor;
 pub mod service;
+pub mod structured;
 pub mod template_commands;
 pub mod templates;
 
 // Re-export Tauri commands (with their generated __cmd__ variants)
 pub use commands::{
-    __cmd__api_get_summary, __cmd__api_process_transcript, __cmd__api_save_meeting_summary,
-    api_get_summary, api_process_transcript, api_save_meeting_summary,
+    __cmd__api_cancel_summary, __cmd__api_get_summary, __cmd__api_process_transcript,
+    __cmd__api_save_meeting_summary, api_cancel_summary, api_get_summary, api_process_transcript,
+    api_save_meeting_summary,
+};
+
+// Re-export action item commands
+pub use action_item_commands::{
+    __cmd__api_get_meeting_action_items, __cmd__api_list_open_action_items,
+    __cmd__api_reassign_action_item, __cmd__api_set_meeting_series,
+    __cmd__api_update_action_item_status, api_get_meeting_action_items,
+    api_list_open_action_items, api_reassign_action_item, api_set_meeting_series,
+    api_update_action_item_status,
 };
 
 // Re-export template commands
@@ -1,8 +1,84 @@
-use crate::summary::llm_client::{generate_summary, LLMProvider};
-use crate::summary::templates;
+use crate::database::models::{ActionItem, RecordingMarker};
+use crate::summary::llm_client::{generate_summary_streaming, LLMProvider};
+use crate::summary::structured;
+use crate::summary::templates::{self, Template};
 use regex::Regex;
 use reqwest::Client;
-use tracing::{error, info};
+use serde::Serialize;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+/// Minimum time between progress updates while a completion streams in
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Which LLM pass a progress update belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStage {
+    /// Summarizing one transcript chunk (long transcripts on Ollama)
+    Chunk,
+    /// Merging the chunk summaries
+    Combine,
+    /// Filling in the template; this output becomes the summary
+    Final,
+}
+
+/// Incremental output of a summary run
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryProgress {
+    pub stage: SummaryStage,
+    /// 1-based chunk being summarized (equals `total_chunks` for the combine and final passes)
+    pub chunk: usize,
+    pub total_chunks: usize,
+    /// Output of the current pass so far, cleaned like the final markdown
+    pub partial_markdown: String,
+}
+
+/// Streams one completion, reporting the accumulated output at most every
+/// `PROGRESS_INTERVAL` and once more when it is complete
+#[allow(clippy::too_many_arguments)]
+async fn stream_completion(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    (stage, chunk, total_chunks): (SummaryStage, usize, usize),
+    on_progress: &(dyn Fn(SummaryProgress) + Send + Sync),
+) -> Result<String, String> {
+    let progress = |text: &str| SummaryProgress {
+        stage,
+        chunk,
+        total_chunks,
+        partial_markdown: clean_llm_markdown_output(text),
+    };
+
+    let mut partial = String::new();
+    let mut last_update: Option<Instant> = None;
+    let output = generate_summary_streaming(
+        client,
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        user_prompt,
+        ollama_endpoint,
+        &mut |delta: &str| {
+            partial.push_str(delta);
+            if last_update.map_or(true, |at| at.elapsed() >= PROGRESS_INTERVAL) {
+                last_update = Some(Instant::now());
+                on_progress(progress(&partial));
+            }
+        },
+    )
+    .await?;
+
+    on_progress(progress(&output));
+    Ok(output)
+}
 
 /// Rough token count estimation (4 characters ≈ 1 token)
 pub fn rough_token_count(s: &str) -> usize {
@@ -117,6 +193,53 @@ pub fn extract_meeting_name_from_markdown(markdown: &str) -> Option<String> {
         .map(|line| line.trim_start_matches("# ").trim().to_string())
 }
 
+/// Formats recording markers as a prompt block so the LLM can prioritize those moments
+///
+/// # Arguments
+/// * `markers` - Markers stamped during recording
+///
+/// # Returns
+/// One line per marker like "[12:05] decision here", empty string if there are no markers
+pub fn format_markers_for_prompt(markers: &[RecordingMarker]) -> String {
+    markers
+        .iter()
+        .map(|marker| {
+            let total_seconds = marker.recording_time.max(0.0) as u64;
+            let time = format!("[{:02}:{:02}]", total_seconds / 60, total_seconds % 60);
+            match marker.label.as_deref() {
+                Some(label) if !label.trim().is_empty() => format!("{} {}", time, label.trim()),
+                _ => format!("{} (flagged as important)", time),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats still-open action items of earlier meetings in the series as a prompt block
+///
+/// # Arguments
+/// * `items` - Open action items carried over from previous meetings
+///
+/// # Returns
+/// One line per item like "- Send the notes (owner: Ana; due: 2025-10-31; from: Weekly sync)"
+pub fn format_open_action_items_for_prompt(items: &[ActionItem]) -> String {
+    items
+        .iter()
+        .map(|item| {
+            let mut details = Vec::new();
+            if let Some(owner) = &item.owner {
+                details.push(format!("owner: {}", owner));
+            }
+            if let Some(due_date) = &item.due_date {
+                details.push(format!("due: {}", due_date));
+            }
+            details.push(format!("from: {}", item.meeting_title));
+            format!("- {} ({})", item.text, details.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Generates a complete meeting summary with conditional chunking strategy
 ///
 /// # Arguments
@@ -129,9 +252,14 @@ pub fn extract_meeting_name_from_markdown(markdown: &str) -> Option<String> {
 /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
 /// * `token_threshold` - Token limit for single-pass processing (default 4000)
 /// * `ollama_endpoint` - Optional custom Ollama endpoint
+/// * `markers` - Moments flagged during recording, given priority in the report
+/// * `open_action_items` - Still-open items of earlier meetings in the same series
+/// * `on_progress` - Receives the partial output of each LLM pass as it streams in
 ///
 /// # Returns
-/// Tuple of (final_summary_markdown, number_of_chunks_processed)
+/// Tuple of (final_summary_markdown, structured_summary, number_of_chunks_processed).
+/// The structured summary is only produced for templates with typed fields.
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_meeting_summary(
     client: &Client,
     provider: &LLMProvider,
@@ -142,7 +270,10 @@ pub async fn generate_meeting_summary(
     template_id: &str,
     token_threshold: usize,
     ollama_endpoint: Option<&str>,
-) -> Result<(String, i64), String> {
+    markers: &[RecordingMarker],
+    open_action_items: &[ActionItem],
+    on_progress: &(dyn Fn(SummaryProgress) + Send + Sync),
+) -> Result<(String, Option<Value>, i64), String> {
     info!(
         "Starting summary generation with provider: {:?}, model: {}",
         provider, model_name
@@ -153,6 +284,7 @@ pub async fn generate_meeting_summary(
 
     let content_to_summarize: String;
     let successful_chunk_count: i64;
+    let total_chunks: usize;
 
     // Strategy: Use single-pass for cloud providers or short transcripts
     // Use multi-level chunking for Ollama with long transcripts
@@ -163,6 +295,7 @@ pub async fn generate_meeting_summary(
         );
         content_to_summarize = text.to_string();
         successful_chunk_count = 1;
+        total_chunks = 1;
     } else {
         info!(
             "Using multi-level summarization (tokens: {} exceeds threshold: {})",
@@ -172,6 +305,7 @@ pub async fn generate_meeting_summary(
         // Reserve 300 tokens for prompt overhead
         let chunks = chunk_text(text, token_threshold - 300, 100);
         let num_chunks = chunks.len();
+        total_chunks = num_chunks;
         info!("Split transcript into {} chunks", num_chunks);
 
         let mut chunk_summaries = Vec::new();
@@ -182,7 +316,7 @@ pub async fn generate_meeting_summary(
             info!("⏲️ Processing chunk {}/{}", i + 1, num_chunks);
             let user_prompt_chunk = user_prompt_template_chunk.replace("{}", chunk.as_str());
 
-            match generate_summary(
+            match stream_completion(
                 client,
                 provider,
                 model_name,
@@ -190,6 +324,8 @@ pub async fn generate_meeting_summary(
                 system_prompt_chunk,
                 &user_prompt_chunk,
                 ollama_endpoint,
+                (SummaryStage::Chunk, i + 1, num_chunks),
+                on_progress,
             )
             .await
             {
@@ -227,7 +363,7 @@ pub async fn generate_meeting_summary(
             let user_prompt_combine_template = "The following are consecutive summaries of a meeting. Combine them into a single, coherent, and detailed narrative summary that retains all important details, organized logically.\n\n<summaries>\n{}\n</summaries>";
 
             let user_prompt_combine = user_prompt_combine_template.replace("{}", &combined_text);
-            generate_summary(
+            stream_completion(
                 client,
                 provider,
                 model_name,
@@ -235,6 +371,8 @@ pub async fn generate_meeting_summary(
                 system_prompt_combine,
                 &user_prompt_combine,
                 ollama_endpoint,
+                (SummaryStage::Combine, num_chunks, num_chunks),
+                on_progress,
             )
             .await?
         } else {
@@ -282,13 +420,50 @@ pub async fn generate_meeting_summary(
         content_to_summarize
     );
 
+    if !markers.is_empty() {
+        info!("Including {} recording markers in summary prompt", markers.len());
+        final_user_prompt.push_str("\n\nThe user flagged these moments during the meeting (recording time and label). Give the discussion around them priority and make sure it is reflected in the report:\n\n<marked_moments>\n");
+        final_user_prompt.push_str(&format_markers_for_prompt(markers));
+        final_user_prompt.push_str("\n</marked_moments>");
+    }
+
+    if !open_action_items.is_empty() {
+        info!(
+            "Including {} open action items from earlier meetings in summary prompt",
+            open_action_items.len()
+        );
+        final_user_prompt.push_str("\n\nThese action items from earlier meetings in this series are still open. Report any progress, completion or reassignment discussed in this meeting; do not list them as new action items unless they were assigned again:\n\n<open_action_items>\n");
+        final_user_prompt.push_str(&format_open_action_items_for_prompt(open_action_items));
+        final_user_prompt.push_str("\n</open_action_items>");
+    }
+
     if !custom_prompt.is_empty() {
         final_user_prompt.push_str("\n\nUser Provided Context:\n\n<user_context>\n");
         final_user_prompt.push_str(custom_prompt);
         final_user_prompt.push_str("\n</user_context>");
     }
 
-    let raw_markdown = generate_summary(
+    if template.is_structured() {
+        if let Some((final_markdown, summary)) = generate_structured_summary(
+            client,
+            provider,
+            model_name,
+            api_key,
+            &template,
+            &final_user_prompt,
+            ollama_endpoint,
+            total_chunks,
+            on_progress,
+        )
+        .await?
+        {
+            info!("Structured summary generation completed successfully");
+            return Ok((final_markdown, summary, successful_chunk_count));
+        }
+        warn!("⚠️ Falling back to a markdown summary for template: {}", template_id);
+    }
+
+    let raw_markdown = stream_completion(
         client,
         provider,
         model_name,
@@ -296,6 +471,8 @@ pub async fn generate_meeting_summary(
         &final_system_prompt,
         &final_user_prompt,
         ollama_endpoint,
+        (SummaryStage::Final, total_chunks, total_chunks),
+        on_progress,
     )
     .await?;
 
@@ -303,5 +480,93 @@ pub async fn generate_meeting_summary(
     let final_markdown = clean_llm_markdown_output(&raw_markdown);
 
     info!("Summary generation completed successfully");
-    Ok((final_markdown, successful_chunk_count))
+    Ok((final_markdown, None, successful_chunk_count))
+}
+
+/// Asks for the summary as JSON following the template's schema, streaming a rendered
+/// preview. An invalid reply is sent back for repair up to `structured::MAX_REPAIR_ATTEMPTS`
+/// times; if it stays invalid the last reply that parsed is rendered without keeping the
+/// structured result. Returns `None` when the model did not answer with JSON at all, so at
+/// most two requests are made including the markdown fallback. Request errors are
+/// returned as is.
+#[allow(clippy::too_many_arguments)]
+async fn generate_structured_summary(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    template: &Template,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    total_chunks: usize,
+    on_progress: &(dyn Fn(SummaryProgress) + Send + Sync),
+) -> Result<Option<(String, Option<Value>)>, String> {
+    let system_prompt = structured::system_prompt(template);
+    // Raw JSON makes a poor preview, so the part of the object received so far is rendered
+    let preview = |progress: SummaryProgress| {
+        if let Some(partial_markdown) =
+            structured::preview_markdown(template, &progress.partial_markdown)
+        {
+            on_progress(SummaryProgress {
+                partial_markdown,
+                ..progress
+            });
+        }
+    };
+    on_progress(SummaryProgress {
+        stage: SummaryStage::Final,
+        chunk: total_chunks,
+        total_chunks,
+        partial_markdown: String::new(),
+    });
+
+    let mut last_parsed = None;
+    let mut prompt = user_prompt.to_string();
+    for attempt in 0..=structured::MAX_REPAIR_ATTEMPTS {
+        let reply = stream_completion(
+            client,
+            provider,
+            model_name,
+            api_key,
+            &system_prompt,
+            &prompt,
+            ollama_endpoint,
+            (SummaryStage::Final, total_chunks, total_chunks),
+            &preview,
+        )
+        .await?;
+
+        // A model that does not answer with JSON rarely manages on a repair
+        let summary = match structured::parse_reply(&reply) {
+            Ok(summary) => summary,
+            Err(e) => {
+                warn!("⚠️ Structured summary attempt {} is invalid: {}", attempt + 1, e);
+                break;
+            }
+        };
+        let errors = match structured::validate(template, &summary) {
+            Ok(()) => {
+                let markdown = structured::render_markdown(template, &summary);
+                return Ok(Some((markdown, Some(summary))));
+            }
+            Err(errors) => errors,
+        };
+
+        warn!(
+            "⚠️ Structured summary attempt {} is invalid: {}",
+            attempt + 1,
+            errors.join("; ")
+        );
+        prompt = format!(
+            "{}\n\n{}",
+            user_prompt,
+            structured::repair_prompt(&reply, &errors)
+        );
+        last_parsed = Some(summary);
+    }
+
+    Ok(last_parsed.map(|summary| {
+        warn!("⚠️ Keeping the unvalidated structured reply as markdown only");
+        (structured::render_markdown(template, &summary), None)
+    }))
 }
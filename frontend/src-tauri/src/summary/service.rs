@@ -1,12 +1,25 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    action_item::ActionItemsRepository, marker::MarkersRepository, meeting::MeetingsRepository,
+    redaction::RedactionReportsRepository, setting::SettingsRepository,
+    summary::SummaryProcessesRepository,
 };
+use crate::redaction::{self, RedactionMap, RedactionStage};
+use crate::summary::action_items;
 use crate::summary::llm_client::LLMProvider;
-use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
+use crate::summary::processor::{
+    extract_meeting_name_from_markdown, generate_meeting_summary, SummaryProgress,
+};
+use crate::summary::structured;
 use crate::ollama::metadata::ModelMetadataCache;
+use futures_util::future::{AbortHandle, Abortable};
+use serde::Serialize;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 use tracing::{error, info, warn};
 use once_cell::sync::Lazy;
 
@@ -15,17 +28,75 @@ static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
     ModelMetadataCache::new(Duration::from_secs(300))
 });
 
+/// Summary runs in flight by meeting id: (run id, handle that aborts the task)
+static RUNNING_SUMMARIES: Lazy<Mutex<HashMap<String, (u64, AbortHandle)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_RUN_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Payload of the `summary-progress` event
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryProgressEvent {
+    pub meeting_id: String,
+    #[serde(flatten)]
+    pub progress: SummaryProgress,
+}
+
 /// Summary service - handles all summary generation logic
 pub struct SummaryService;
 
 impl SummaryService {
+    /// Spawns `task` for `meeting_id` so it can be aborted with `cancel`. A run already in
+    /// flight for the same meeting is aborted first.
+    pub fn spawn_cancellable<F>(meeting_id: String, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let run_id = NEXT_RUN_ID.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut running) = RUNNING_SUMMARIES.lock() {
+            if let Some((_, previous)) =
+                running.insert(meeting_id.clone(), (run_id, abort_handle))
+            {
+                info!("⏹️ Aborting previous summary run for meeting_id: {}", meeting_id);
+                previous.abort();
+            }
+        }
+
+        tauri::async_runtime::spawn(async move {
+            let _ = Abortable::new(task, registration).await;
+            if let Ok(mut running) = RUNNING_SUMMARIES.lock() {
+                // A newer run may have replaced this one
+                if running.get(&meeting_id).is_some_and(|(id, _)| *id == run_id) {
+                    running.remove(&meeting_id);
+                }
+            }
+        });
+    }
+
+    /// Aborts the summary run of `meeting_id`, dropping its in-flight LLM request.
+    /// Returns whether a run was found.
+    pub fn cancel(meeting_id: &str) -> bool {
+        let run = RUNNING_SUMMARIES
+            .lock()
+            .ok()
+            .and_then(|mut running| running.remove(meeting_id));
+        match run {
+            Some((_, abort_handle)) => {
+                abort_handle.abort();
+                info!("⏹️ Summary generation cancelled for meeting_id: {}", meeting_id);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Processes transcript in the background and generates summary
     ///
     /// This function is designed to be spawned as an async task and does not block
     /// the main thread. It updates the database with progress and results.
     ///
     /// # Arguments
-    /// * `_app` - Tauri app handle (for future use)
+    /// * `app` - Tauri app handle, used to emit `summary-progress` events
     /// * `pool` - SQLx connection pool
     /// * `meeting_id` - Unique identifier for the meeting
     /// * `text` - Full transcript text
@@ -34,7 +105,7 @@ impl SummaryService {
     /// * `custom_prompt` - Optional user-provided context
     /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
     pub async fn process_transcript_background<R: tauri::Runtime>(
-        _app: AppHandle<R>,
+        app: AppHandle<R>,
         pool: SqlitePool,
         meeting_id: String,
         text: String,
@@ -115,6 +186,93 @@ impl SummaryService {
             100000  // Effectively unlimited for single-pass processing
         };
 
+        // Markers stamped during recording are given priority in the summary
+        let markers = match MarkersRepository::get_markers_for_meeting(&pool, &meeting_id).await {
+            Ok(markers) => markers,
+            Err(e) => {
+                warn!("Failed to load recording markers for {}: {}", meeting_id, e);
+                Vec::new()
+            }
+        };
+
+        // Open action items of earlier meetings in the same series are carried over
+        let open_action_items =
+            match ActionItemsRepository::open_items_earlier_in_series(&pool, &meeting_id).await {
+                Ok(items) => items,
+                Err(e) => {
+                    warn!("Failed to load open action items for {}: {}", meeting_id, e);
+                    Vec::new()
+                }
+            };
+
+        // PII in the prompt is swapped for placeholders; the map stays here so the
+        // summary can be re-hydrated before it is saved. Placeholders that storage-time
+        // redaction already put in the transcript are reserved, so they keep their meaning.
+        let redactor = redaction::llm_redactor(provider == LLMProvider::Ollama);
+        let mut redactions = RedactionMap::default();
+        if redactor.is_some() {
+            redactions.reserve_placeholders_in(&text);
+            redactions.reserve_placeholders_in(&custom_prompt);
+            for item in &open_action_items {
+                redactions.reserve_placeholders_in(&item.text);
+                redactions.reserve_placeholders_in(item.owner.as_deref().unwrap_or_default());
+                redactions.reserve_placeholders_in(&item.meeting_title);
+            }
+            for marker in &markers {
+                redactions.reserve_placeholders_in(marker.label.as_deref().unwrap_or_default());
+            }
+        }
+        let open_action_items = match &redactor {
+            Some(redactor) => open_action_items
+                .into_iter()
+                .map(|mut item| {
+                    item.text = redactor.redact(&item.text, &mut redactions);
+                    item.owner = item
+                        .owner
+                        .map(|owner| redactor.redact(&owner, &mut redactions));
+                    item.meeting_title = redactor.redact(&item.meeting_title, &mut redactions);
+                    item
+                })
+                .collect(),
+            None => open_action_items,
+        };
+        let (text, custom_prompt, markers) = match &redactor {
+            Some(redactor) => {
+                let text = redactor.redact(&text, &mut redactions);
+                let custom_prompt = redactor.redact(&custom_prompt, &mut redactions);
+                let markers = markers
+                    .into_iter()
+                    .map(|mut marker| {
+                        marker.label = marker
+                            .label
+                            .map(|label| redactor.redact(&label, &mut redactions));
+                        marker
+                    })
+                    .collect();
+                info!(
+                    "🛡️ Redacted {} distinct values from the summary prompt",
+                    redactions.report().len()
+                );
+                (text, custom_prompt, markers)
+            }
+            None => (text, custom_prompt, markers),
+        };
+
+        // Stream partial output to the UI. Placeholders are re-hydrated so the preview
+        // matches the saved summary.
+        let on_progress = |mut progress: SummaryProgress| {
+            progress.partial_markdown = redactions.rehydrate(&progress.partial_markdown);
+            if let Err(e) = app.emit(
+                "summary-progress",
+                SummaryProgressEvent {
+                    meeting_id: meeting_id.clone(),
+                    progress,
+                },
+            ) {
+                warn!("Failed to emit summary progress for {}: {}", meeting_id, e);
+            }
+        };
+
         // Generate summary
         let client = reqwest::Client::new();
         let result = generate_meeting_summary(
@@ -127,13 +285,33 @@ impl SummaryService {
             &template_id,
             token_threshold,
             ollama_endpoint.as_deref(),
+            &markers,
+            &open_action_items,
+            &on_progress,
         )
         .await;
 
         let duration = start_time.elapsed().as_secs_f64();
 
+        if redactor.is_some() {
+            if let Err(e) = RedactionReportsRepository::replace_report(
+                &pool,
+                &meeting_id,
+                RedactionStage::Llm,
+                &redactions.report(),
+            )
+            .await
+            {
+                warn!("Failed to save redaction report for {}: {}", meeting_id, e);
+            }
+        }
+
         match result {
-            Ok((mut final_markdown, num_chunks)) => {
+            Ok((final_markdown, structured_summary, num_chunks)) => {
+                let mut final_markdown = redactions.rehydrate(&final_markdown);
+                let structured_summary = structured_summary.map(|summary| {
+                    structured::map_strings(summary, &|s| redactions.rehydrate(s))
+                });
                 if num_chunks == 0 && final_markdown.is_empty() {
                     Self::update_process_failed(
                         &pool,
@@ -182,10 +360,23 @@ impl SummaryService {
                     }
                 }
 
-                // Create result JSON with markdown only (summary_json will be added on first edit)
-                let result_json = serde_json::json!({
+                // Track the summary's action items as records
+                let extracted =
+                    action_items::extract(&final_markdown, structured_summary.as_ref());
+                if let Err(e) =
+                    ActionItemsRepository::replace_for_meeting(&pool, &meeting_id, &extracted).await
+                {
+                    error!("⚠️ Failed to save action items for {}: {}", meeting_id, e);
+                }
+
+                // Create result JSON with markdown, plus the structured summary for templates
+                // with typed fields (summary_json will be added on first edit)
+                let mut result_json = serde_json::json!({
                     "markdown": final_markdown,
                 });
+                if let Some(summary) = structured_summary {
+                    result_json["structured"] = summary;
+                }
 
                 // Update database with completed status
                 if let Err(e) = SummaryProcessesRepository::update_process_completed(
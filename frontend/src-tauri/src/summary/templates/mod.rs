@@ -46,7 +46,7 @@ pub use loader::{
     get_template, list_template_ids, list_templates, set_bundled_templates_dir,
     validate_and_parse_template,
 };
-pub use types::{Template, TemplateSection};
+pub use types::{FieldType, Template, TemplateField, TemplateSection};
 
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,87 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// Value type of a structured field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldType {
+    /// Free text
+    String,
+    /// Calendar date as `YYYY-MM-DD`
+    Date,
+    Number,
+    Boolean,
+    /// One of the field's `options`
+    Enum,
+}
+
+/// A typed field of the items in a structured list section
+/// (e.g. the owner, due date and priority of an action item)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateField {
+    /// JSON key of the field (snake_case, e.g. "due_date")
+    pub name: String,
+
+    /// Value type
+    #[serde(rename = "type")]
+    pub field_type: FieldType,
+
+    /// Column heading when rendered as markdown (defaults to the name in title case)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// What the LLM should put in the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Required fields must be present and non-null
+    #[serde(default)]
+    pub required: bool,
+
+    /// Allowed values of an enum field
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+impl TemplateField {
+    /// Markdown column heading
+    pub fn label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| {
+            self.name
+                .split('_')
+                .filter(|word| !word.is_empty())
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+    }
+
+    /// JSON schema of the field value
+    fn to_json_schema(&self) -> Value {
+        let mut schema = match self.field_type {
+            FieldType::String => json!({ "type": "string" }),
+            FieldType::Date => json!({ "type": "string", "format": "date" }),
+            FieldType::Number => json!({ "type": "number" }),
+            FieldType::Boolean => json!({ "type": "boolean" }),
+            FieldType::Enum => json!({ "type": "string", "enum": self.options }),
+        };
+        if !self.required {
+            // Unknown values are null rather than guessed
+            let inner = schema.take();
+            schema = json!({ "anyOf": [inner, { "type": "null" }] });
+        }
+        if let Some(description) = &self.description {
+            schema["description"] = json!(description);
+        }
+        schema
+    }
+}
 
 /// Represents a single section in a meeting template
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +102,61 @@ pub struct TemplateSection {
     /// Alternative formatting hint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub example_item_format: Option<String>,
+
+    /// Typed fields of each item ("list" sections only). Templates with typed fields
+    /// produce a structured JSON summary validated against `Template::to_json_schema`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<TemplateField>>,
+}
+
+impl TemplateSection {
+    /// JSON key of the section in a structured summary (title in snake_case)
+    pub fn key(&self) -> String {
+        let mut key = String::new();
+        for c in self.title.chars() {
+            if c.is_alphanumeric() {
+                key.extend(c.to_lowercase());
+            } else if !key.is_empty() && !key.ends_with('_') {
+                key.push('_');
+            }
+        }
+        key.trim_end_matches('_').to_string()
+    }
+
+    /// Typed fields, if any were declared
+    pub fn typed_fields(&self) -> Option<&[TemplateField]> {
+        self.fields.as_deref().filter(|fields| !fields.is_empty())
+    }
+
+    /// JSON schema of the section value
+    fn to_json_schema(&self) -> Value {
+        let mut schema = match (self.format.as_str(), self.typed_fields()) {
+            ("list", Some(fields)) => {
+                let properties: Map<String, Value> = fields
+                    .iter()
+                    .map(|field| (field.name.clone(), field.to_json_schema()))
+                    .collect();
+                let required: Vec<&str> = fields
+                    .iter()
+                    .filter(|field| field.required)
+                    .map(|field| field.name.as_str())
+                    .collect();
+                json!({
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": properties,
+                        "required": required,
+                        "additionalProperties": false,
+                    },
+                })
+            }
+            ("list", None) => json!({ "type": "array", "items": { "type": "string" } }),
+            _ => json!({ "type": "string" }),
+        };
+        schema["description"] = json!(self.instruction);
+        schema
+    }
 }
 
 /// Represents a complete meeting template
@@ -49,6 +187,7 @@ impl Template {
             return Err("Template must have at least one section".to_string());
         }
 
+        let structured = self.is_structured();
         for (i, section) in self.sections.iter().enumerate() {
             if section.title.is_empty() {
                 return Err(format!("Section {} has empty title", i));
@@ -65,11 +204,82 @@ impl Template {
                     section.title, other
                 )),
             }
+
+            // Keys only matter for the JSON of structured summaries
+            if structured {
+                if section.key().is_empty() {
+                    return Err(format!("Section '{}' needs a title with letters or digits", section.title));
+                }
+                if section.key() == "title" {
+                    return Err(format!("Section '{}' clashes with the meeting title", section.title));
+                }
+                if self.sections[..i].iter().any(|other| other.key() == section.key()) {
+                    return Err(format!("Section '{}' duplicates another section's title", section.title));
+                }
+            }
+
+            if let Some(fields) = section.typed_fields() {
+                if section.format != "list" {
+                    return Err(format!(
+                        "Section '{}' declares fields but only 'list' sections can have them",
+                        section.title
+                    ));
+                }
+                for (j, field) in fields.iter().enumerate() {
+                    let valid_name = !field.name.is_empty()
+                        && field.name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+                    if !valid_name {
+                        return Err(format!(
+                            "Field '{}' in section '{}' must be a snake_case name",
+                            field.name, section.title
+                        ));
+                    }
+                    if fields[..j].iter().any(|other| other.name == field.name) {
+                        return Err(format!(
+                            "Field '{}' appears twice in section '{}'",
+                            field.name, section.title
+                        ));
+                    }
+                    if field.field_type == FieldType::Enum && field.options.is_empty() {
+                        return Err(format!(
+                            "Enum field '{}' in section '{}' needs options",
+                            field.name, section.title
+                        ));
+                    }
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Whether the template declares typed fields and so produces a structured summary
+    pub fn is_structured(&self) -> bool {
+        self.sections.iter().any(|section| section.typed_fields().is_some())
+    }
+
+    /// JSON schema of a structured summary: the meeting title plus one property per
+    /// section, keyed by `TemplateSection::key`
+    pub fn to_json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        properties.insert(
+            "title".to_string(),
+            json!({ "type": "string", "description": "Concise, descriptive title for the meeting" }),
+        );
+        let mut required = vec!["title".to_string()];
+        for section in &self.sections {
+            properties.insert(section.key(), section.to_json_schema());
+            required.push(section.key());
+        }
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
     /// Generates a clean markdown template structure
     pub fn to_markdown_structure(&self) -> String {
         let mut markdown = String::from("# <Add Title here>\n\n");
@@ -125,6 +335,7 @@ mod tests {
                     format: "paragraph".to_string(),
                     item_format: None,
                     example_item_format: None,
+                    fields: None,
                 },
             ],
         };
@@ -155,10 +366,98 @@ mod tests {
                     format: "invalid".to_string(),
                     item_format: None,
                     example_item_format: None,
+                    fields: None,
                 },
             ],
         };
 
         assert!(template.validate().is_err());
     }
+
+    fn action_items(fields: Option<Vec<TemplateField>>) -> Template {
+        Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            sections: vec![TemplateSection {
+                title: "Action Items".to_string(),
+                instruction: "List tasks".to_string(),
+                format: "list".to_string(),
+                item_format: None,
+                example_item_format: None,
+                fields,
+            }],
+        }
+    }
+
+    fn field(name: &str, field_type: FieldType, required: bool) -> TemplateField {
+        TemplateField {
+            name: name.to_string(),
+            field_type,
+            label: None,
+            description: None,
+            required,
+            options: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_section_keys_and_field_labels() {
+        let template = action_items(None);
+        assert_eq!(template.sections[0].key(), "action_items");
+        assert_eq!(field("due_date", FieldType::Date, false).label(), "Due Date");
+        assert!(!template.is_structured());
+    }
+
+    #[test]
+    fn test_json_schema_of_typed_fields() {
+        let template = action_items(Some(vec![
+            field("task", FieldType::String, true),
+            field("due_date", FieldType::Date, false),
+        ]));
+        assert!(template.validate().is_ok());
+        assert!(template.is_structured());
+
+        let schema = template.to_json_schema();
+        assert_eq!(schema["required"], json!(["title", "action_items"]));
+        let items = &schema["properties"]["action_items"]["items"];
+        assert_eq!(items["required"], json!(["task"]));
+        assert_eq!(items["properties"]["task"], json!({ "type": "string" }));
+        assert_eq!(
+            items["properties"]["due_date"],
+            json!({ "anyOf": [{ "type": "string", "format": "date" }, { "type": "null" }] })
+        );
+    }
+
+    #[test]
+    fn test_validate_invalid_fields() {
+        let enum_without_options = action_items(Some(vec![field("priority", FieldType::Enum, false)]));
+        assert!(enum_without_options.validate().is_err());
+
+        let bad_name = action_items(Some(vec![field("Due Date", FieldType::Date, false)]));
+        assert!(bad_name.validate().is_err());
+
+        let duplicate = action_items(Some(vec![
+            field("task", FieldType::String, true),
+            field("task", FieldType::String, false),
+        ]));
+        assert!(duplicate.validate().is_err());
+
+        let mut on_paragraph = action_items(Some(vec![field("task", FieldType::String, true)]));
+        on_paragraph.sections[0].format = "paragraph".to_string();
+        assert!(on_paragraph.validate().is_err());
+    }
+
+    #[test]
+    fn test_section_keys_only_checked_for_structured_templates() {
+        let mut markdown = action_items(None);
+        let mut title = markdown.sections[0].clone();
+        title.title = "Title".to_string();
+        markdown.sections.push(title.clone());
+        markdown.sections.push(markdown.sections[0].clone());
+        assert!(markdown.validate().is_ok());
+
+        let mut structured = action_items(Some(vec![field("task", FieldType::String, true)]));
+        structured.sections.push(title);
+        assert!(structured.validate().is_err());
+    }
 }
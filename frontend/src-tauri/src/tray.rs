@@ -38,6 +38,7 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         "pause_recording" => pause_recording_handler(app),
         "resume_recording" => resume_recording_handler(app),
         "stop_recording" => stop_recording_handler(app),
+        "add_marker" => add_marker_handler(app),
         "open_window" => focus_main_window(app),
         "settings" => {
             focus_main_window(app);
@@ -112,6 +113,16 @@ fn resume_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
+fn add_marker_handler<R: Runtime>(app: &AppHandle<R>) {
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match crate::audio::recording_commands::add_recording_marker(app_clone, None).await {
+            Ok(marker) => log::info!("Marker added from tray at {:.1}s", marker.recording_time),
+            Err(e) => log::error!("Failed to add marker from tray: {}", e),
+        }
+    });
+}
+
 fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     // Immediately show stopping state
     set_tray_state(app, RecordingState::Stopping);
@@ -211,6 +222,7 @@ fn build_menu<R: Runtime>(
         }
         RecordingState::Recording => {
             builder = builder
+                .item(&MenuItemBuilder::with_id("add_marker", "🔖 Add Marker").build(app)?)
                 .item(&MenuItemBuilder::with_id("pause_recording", "⏸ Pause Recording").build(app)?)
                 .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
         }
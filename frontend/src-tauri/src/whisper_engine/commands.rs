@@ -32,7 +32,7 @@ pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
 }
 
 /// Get the configured models directory
-fn get_models_directory() -> Option<PathBuf> {
+pub(crate) fn get_models_directory() -> Option<PathBuf> {
     MODELS_DIR.lock().unwrap().clone()
 }
 
@@ -445,6 +445,47 @@ pub async fn whisper_cancel_download(model_name: String) -> Result<(), String> {
     }
 }
 
+/// Import a custom or fine-tuned model from a local path or URL under `model_name`
+#[command]
+pub async fn whisper_import_model(
+    model_name: String,
+    source: String,
+    description: Option<String>,
+    sha256: Option<String>,
+) -> Result<ModelInfo, String> {
+    let engine = {
+        let guard = WHISPER_ENGINE.lock().unwrap();
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine
+            .import_model(&source, &model_name, description, sha256)
+            .await
+            .map_err(|e| format!("Failed to import model: {}", e))
+    } else {
+        Err("Whisper engine not initialized".to_string())
+    }
+}
+
+/// Remove an imported model from disk and from the registry
+#[command]
+pub async fn whisper_remove_custom_model(model_name: String) -> Result<(), String> {
+    let engine = {
+        let guard = WHISPER_ENGINE.lock().unwrap();
+        guard.as_ref().cloned()
+    };
+
+    if let Some(engine) = engine {
+        engine
+            .remove_custom_model(&model_name)
+            .await
+            .map_err(|e| format!("Failed to remove model: {}", e))
+    } else {
+        Err("Whisper engine not initialized".to_string())
+    }
+}
+
 #[command]
 pub async fn whisper_delete_corrupted_model(model_name: String) -> Result<String, String> {
     let engine = {
@@ -7,6 +7,7 @@ use serde::{Serialize, Deserialize};
 
 use super::whisper_engine::WhisperEngine;
 use super::system_monitor::SystemMonitor;
+use crate::audio::transcription::WordTiming;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioChunk {
@@ -25,6 +26,9 @@ pub struct TranscriptionResult {
     pub model_used: String,
     pub start_time_ms: f64,
     pub confidence_score: Option<f32>,
+    // Word timings relative to the chunk start
+    #[serde(default)]
+    pub words: Vec<WordTiming>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -222,7 +226,7 @@ impl ParallelProcessor {
             // Load model for this worker
             {
                 let mut engine_guard = engine_ref.write().await;
-                let engine = WhisperEngine::new().map_err(|e| anyhow!("Failed to create WhisperEngine: {}", e))?;
+                let engine = WhisperEngine::new_with_models_dir(super::commands::get_models_directory()).map_err(|e| anyhow!("Failed to create WhisperEngine: {}", e))?;
                 engine.load_model(&model_name).await.map_err(|e| anyhow!("Failed to load model {}: {}", model_name, e))?;
                 *engine_guard = Some(engine);
                 info!("Worker {} loaded model {}", worker_id, model_name);
@@ -344,10 +348,10 @@ impl ParallelProcessor {
         let language = crate::get_language_preference_internal();
 
         // Transcribe with timeout to prevent hanging
-        let transcription_future = engine.transcribe_audio(chunk.data.clone(), language);
+        let transcription_future = engine.transcribe_audio_with_confidence(chunk.data.clone(), language);
         let timeout_duration = tokio::time::Duration::from_secs(120); // 2 minute timeout per chunk
 
-        let text = tokio::time::timeout(timeout_duration, transcription_future)
+        let (text, confidence, _is_partial, words) = tokio::time::timeout(timeout_duration, transcription_future)
             .await
             .map_err(|_| anyhow!("Transcription timeout for chunk {}", chunk.id))?
             .map_err(|e| anyhow!("Transcription failed for chunk {}: {}", chunk.id, e))?;
@@ -360,7 +364,8 @@ impl ParallelProcessor {
             processing_time_ms: processing_time,
             model_used: model_name.to_string(),
             start_time_ms: chunk.start_time_ms,
-            confidence_score: None, // TODO: Add confidence scoring if available
+            confidence_score: Some(confidence),
+            words,
         };
 
         debug!("Worker {} completed chunk {} in {}ms",
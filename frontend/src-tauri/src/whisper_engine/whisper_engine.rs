@@ -9,8 +9,25 @@ use serde::{Serialize, Deserialize};
 use anyhow::{Result, anyhow};
 use reqwest::Client;
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
 use crate::{perf_debug, perf_trace};
+use crate::audio::transcription::glossary;
+use crate::custom_models::{self, CustomModelRegistry, ImportSource};
+use crate::model_download::{self, ModelArtifact, ResumableDownload};
+use crate::audio::transcription::word_timing::{words_from_tokens, TokenTiming, WordTiming};
+
+/// Hugging Face repository of the official whisper.cpp GGML models
+const WHISPER_MODEL_REPO: &str = "ggerganov/whisper.cpp";
+
+/// Models that can be downloaded as `ggml-<name>.bin` from `WHISPER_MODEL_REPO`
+const DOWNLOADABLE_MODELS: &[&str] = &[
+    // Standard f16 models
+    "tiny", "base", "small", "medium", "large-v3-turbo", "large-v3",
+    // Q5_0 quantized models
+    "small-q5_0", "medium-q5_0", "large-v3-turbo-q5_0", "large-v3-q5_0",
+];
+
+/// Beam width used when re-decoding low-confidence segments
+const HIGH_ACCURACY_BEAM_SIZE: usize = 8;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ModelStatus {
@@ -30,6 +47,9 @@ pub struct ModelInfo {
     pub speed: String,
     pub status: ModelStatus,
     pub description: String,
+    /// Imported by the user (see `crate::custom_models`)
+    #[serde(default)]
+    pub custom: bool,
 }
 
 pub struct WhisperEngine {
@@ -258,10 +278,37 @@ impl WhisperEngine {
                 speed: speed.to_string(),
                 status,
                 description: description.to_string(),
+                custom: false,
             };
             
             models.push(model_info);
         }
+
+        // Imported models, listed after the built-in ones
+        for entry in CustomModelRegistry::load(models_dir).models {
+            let model_path = models_dir.join(&entry.path);
+            let status = if !model_path.exists() {
+                ModelStatus::Error(format!("Model file {} not found", model_path.display()))
+            } else if self.validate_model_file(&model_path).await.is_ok() {
+                ModelStatus::Available
+            } else {
+                ModelStatus::Corrupted {
+                    file_size: std::fs::metadata(&model_path).map(|m| m.len()).unwrap_or(0),
+                    expected_min_size: 0,
+                }
+            };
+
+            models.push(ModelInfo {
+                name: entry.name,
+                size_mb: custom_models::size_on_disk_mb(&model_path),
+                path: model_path,
+                accuracy: "Good".to_string(),
+                speed: "Medium".to_string(),
+                status,
+                description: entry.description,
+                custom: true,
+            });
+        }
         
         // Update internal cache
         let mut available_models = self.available_models.write().await;
@@ -530,18 +577,51 @@ impl WhisperEngine {
     }
     
     /// Transcribe audio with streaming support for partial results and adaptive quality
-    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool)> {
-        let ctx_lock = self.current_context.read().await;
-        let ctx = ctx_lock.as_ref()
-            .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
+    ///
+    /// Returns (text, confidence, is_partial, word timings relative to the audio start)
+    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool, Vec<WordTiming>)> {
+        self.transcribe_with_confidence_internal(audio_data, language, false).await
+    }
 
+    /// Slower re-decode for low-confidence segments: wider beam search and a greedy start
+    /// with temperature fallback. Returns the same tuple as `transcribe_audio_with_confidence`.
+    pub async fn transcribe_audio_high_accuracy(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool, Vec<WordTiming>)> {
+        self.transcribe_with_confidence_internal(audio_data, language, true).await
+    }
+
+    async fn transcribe_with_confidence_internal(&self, audio_data: Vec<f32>, language: Option<String>, high_accuracy: bool) -> Result<(String, f32, bool, Vec<WordTiming>)> {
+        let ctx_lock = self.current_context.clone().read_owned().await;
+        if ctx_lock.is_none() {
+            return Err(anyhow!("No model loaded. Please load a model first."));
+        }
+
+        // whisper.cpp decodes synchronously; keep it off the async runtime's worker threads
+        tokio::task::spawn_blocking(move || {
+            let ctx = ctx_lock.as_ref()
+                .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
+            Self::decode_with_confidence(ctx, audio_data, language, high_accuracy)
+        })
+        .await
+        .map_err(|e| anyhow!("Transcription task failed: {}", e))?
+    }
+
+    fn decode_with_confidence(ctx: &WhisperContext, audio_data: Vec<f32>, language: Option<String>, high_accuracy: bool) -> Result<(String, f32, bool, Vec<WordTiming>)> {
         // Get adaptive configuration based on hardware
         let hardware_profile = crate::audio::HardwareProfile::detect();
         let adaptive_config = hardware_profile.get_whisper_config();
 
+        // Custom vocabulary (glossary) used as Whisper's initial prompt
+        let initial_prompt = glossary::whisper_initial_prompt();
+
         // ADAPTIVE parameters - optimized for current hardware
+        let beam_size = if high_accuracy {
+            (adaptive_config.beam_size * 2).max(HIGH_ACCURACY_BEAM_SIZE)
+        } else {
+            // Narrowed while the live transcription backlog is degraded
+            crate::audio::transcription::backlog::beam_size(adaptive_config.beam_size)
+        };
         let mut params = FullParams::new(SamplingStrategy::BeamSearch {
-            beam_size: adaptive_config.beam_size as i32,
+            beam_size: beam_size as i32,
             patience: 1.0
         });
 
@@ -556,12 +636,15 @@ impl WhisperEngine {
         };
         params.set_language(language_code);
         params.set_translate(should_translate);
+        if let Some(prompt) = initial_prompt.as_deref() {
+            params.set_initial_prompt(prompt); // Bias decoding towards product names and acronyms
+        }
 
         // CRITICAL: Disable timestamp tokens to prevent whisper.cpp chunking heuristics
         // The "single timestamp ending - skip entire chunk" optimization incorrectly discards
         // complete, valid transcriptions. Disabling timestamps forces whisper to return ALL text.
         params.set_no_timestamps(true);     // Prevent timestamp-based segment skipping
-        params.set_token_timestamps(true);  // Keep for any timestamp-aware features
+        params.set_token_timestamps(true);  // Per-token t0/t1, used for word-level timings
 
         // PERFORMANCE: Disable ALL whisper.cpp internal printing
         // This reduces C library log spam significantly
@@ -573,7 +656,14 @@ impl WhisperEngine {
         // Additional suppression to reduce C library verbosity
         params.set_suppress_blank(true);
         params.set_suppress_non_speech_tokens(true);
-        params.set_temperature(adaptive_config.temperature);
+        if high_accuracy {
+            // Start deterministic and let whisper.cpp raise the temperature only when the
+            // decode fails its entropy/logprob checks
+            params.set_temperature(0.0);
+            params.set_temperature_inc(0.2);
+        } else {
+            params.set_temperature(adaptive_config.temperature);
+        }
         params.set_max_initial_ts(1.0);
         params.set_entropy_thold(2.4);
         params.set_logprob_thold(-1.0);
@@ -606,8 +696,9 @@ impl WhisperEngine {
             // Suppressor dropped here, stderr restored
         };
         let mut result = String::new();
-        let mut total_confidence = 0.0;
-        let mut segment_count = 0;
+        let mut tokens: Vec<TokenTiming> = Vec::new();
+        // Special tokens ([_BEG_], timestamps, <|endoftext|>...) all have ids >= EOT
+        let eot_token = ctx.token_eot();
 
         let num_segments = num_segments?;
         for i in 0..num_segments {
@@ -616,15 +707,22 @@ impl WhisperEngine {
                 Err(_) => continue,
             };
 
-            // Calculate confidence based on segment length and duration (simplified approach)
-            let segment_length = segment_text.len() as f32;
-            let segment_confidence = if segment_length > 0.0 {
-                (segment_length / 100.0).min(0.9) + 0.1 // 0.1 to 1.0 confidence based on text length
-            } else {
-                0.1
-            };
-            total_confidence += segment_confidence;
-            segment_count += 1;
+            // Collect token timings (centiseconds from audio start) for word-level timestamps
+            let num_tokens = state.full_n_tokens(i).unwrap_or(0);
+            for j in 0..num_tokens {
+                let (Ok(data), Ok(text)) = (state.full_get_token_data(i, j), state.full_get_token_text_lossy(i, j)) else {
+                    continue;
+                };
+                if data.id >= eot_token {
+                    continue;
+                }
+                tokens.push(TokenTiming {
+                    text,
+                    start: data.t0 as f64 / 100.0,
+                    end: data.t1 as f64 / 100.0,
+                    probability: Some(data.p),
+                });
+            }
 
             let cleaned_text = segment_text.trim();
             if !cleaned_text.is_empty() {
@@ -638,71 +736,30 @@ impl WhisperEngine {
         let final_result = result.trim().to_string();
         let cleaned_result = Self::clean_repetitive_text(&final_result);
 
-        let avg_confidence = if segment_count > 0 {
-            total_confidence / segment_count as f32
-        } else {
+        // Confidence is the mean probability of the decoded text tokens
+        let probabilities: Vec<f32> = tokens.iter().filter_map(|t| t.probability).collect();
+        let avg_confidence = if probabilities.is_empty() {
             0.0
+        } else {
+            probabilities.iter().sum::<f32>() / probabilities.len() as f32
         };
 
-        Ok((cleaned_result, avg_confidence, is_partial))
-    }
-
-    pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<String> {
-        let ctx_lock = self.current_context.read().await;
-        let ctx = ctx_lock.as_ref()
-            .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
-
-        // Get adaptive configuration based on hardware
-        let hardware_profile = crate::audio::HardwareProfile::detect();
-        let adaptive_config = hardware_profile.get_whisper_config();
-
-        // ADAPTIVE parameters - optimized for current hardware
-        let mut params = FullParams::new(SamplingStrategy::BeamSearch {
-            beam_size: adaptive_config.beam_size as i32,
-            patience: 1.0
-        });
-
-        // Configure for good quality
-        // If language is "auto" or None, use automatic language detection (pass None)
-        // If language is "auto-translate", enable translation to English
-        // Otherwise, use the specified language code
-        let (language_code, should_translate) = match language.as_deref() {
-            Some("auto") | None => (None, false),
-            Some("auto-translate") => (None, true),
-            Some(lang) => (Some(lang), false),
+        // Word timings describe the raw decode; if repetition cleanup rewrote the text
+        // they no longer match it, so drop them rather than highlight removed words
+        let words = if cleaned_result == final_result {
+            words_from_tokens(tokens)
+        } else {
+            Vec::new()
         };
-        params.set_language(language_code);
-        params.set_translate(should_translate);
 
-        // CRITICAL: Disable timestamp tokens to prevent whisper.cpp chunking heuristics
-        // The "single timestamp ending - skip entire chunk" optimization incorrectly discards
-        // complete, valid transcriptions. Disabling timestamps forces whisper to return ALL text.
-        params.set_no_timestamps(true);     // Prevent timestamp-based segment skipping
-        params.set_token_timestamps(true);  // Keep for any timestamp-aware features
-
-        params.set_print_special(false);
-        params.set_print_progress(false);
-        params.set_print_realtime(false);
-        params.set_print_timestamps(false);
-
-        // BALANCED settings - good quality with reasonable speed
-        params.set_suppress_blank(true);
-        params.set_suppress_non_speech_tokens(true);
-        params.set_temperature(0.3);             // Lower than 0.4 for consistency, higher than 0.0 for quality
-        params.set_max_initial_ts(1.0);
-        params.set_entropy_thold(2.4);
-        params.set_logprob_thold(-1.0);
-        // BALANCED FIX: Lowered from 0.75 to 0.55 to allow quiet speech detection
-        // Previous value was too aggressive and rejected valid quiet speech
-        // 0.55 is balanced - prevents hallucinations while preserving quiet speech
-        params.set_no_speech_thold(0.55);
-
-        // Reasonable length limits
-        params.set_max_len(200);                 // Reasonable length
-        params.set_single_segment(false);        // Allow multiple segments for better accuracy
+        Ok((cleaned_result, avg_confidence, is_partial, words))
+    }
 
-        // Note: compression_ratio_threshold would be ideal but not available in current whisper-rs
-        // This would help detect repetitive outputs: params.set_compression_ratio_threshold(2.4);
+    pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<String> {
+        let ctx_lock = self.current_context.clone().read_owned().await;
+        if ctx_lock.is_none() {
+            return Err(anyhow!("No model loaded. Please load a model first."));
+        }
 
         // Duration-based optimization is handled by beam search parameters
         let duration_seconds = audio_data.len() as f64 / 16000.0; // Assuming 16kHz
@@ -753,46 +810,114 @@ impl WhisperEngine {
             log::info!("Starting transcription #{} of {} samples ({:.1}s duration)",
                       transcription_count, audio_data.len(), duration_seconds);
         }
-        let mut state = ctx.create_state()?;
-        state.full(params, &audio_data)?;
 
-        // Extract text with improved segment handling
-        let num_segments = state.full_n_segments()?;
+        // whisper.cpp decodes synchronously; keep it off the async runtime's worker threads
+        let (num_segments, result) = tokio::task::spawn_blocking(move || -> Result<(i32, String)> {
+            let ctx = ctx_lock.as_ref()
+                .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
+
+            // Get adaptive configuration based on hardware
+            let hardware_profile = crate::audio::HardwareProfile::detect();
+            let adaptive_config = hardware_profile.get_whisper_config();
+
+            // Custom vocabulary (glossary) used as Whisper's initial prompt
+            let initial_prompt = glossary::whisper_initial_prompt();
+
+            // ADAPTIVE parameters - optimized for current hardware
+            let mut params = FullParams::new(SamplingStrategy::BeamSearch {
+                beam_size: crate::audio::transcription::backlog::beam_size(adaptive_config.beam_size) as i32,
+                patience: 1.0
+            });
+
+            // Configure for good quality
+            // If language is "auto" or None, use automatic language detection (pass None)
+            // If language is "auto-translate", enable translation to English
+            // Otherwise, use the specified language code
+            let (language_code, should_translate) = match language.as_deref() {
+                Some("auto") | None => (None, false),
+                Some("auto-translate") => (None, true),
+                Some(lang) => (Some(lang), false),
+            };
+            params.set_language(language_code);
+            params.set_translate(should_translate);
+            if let Some(prompt) = initial_prompt.as_deref() {
+                params.set_initial_prompt(prompt); // Bias decoding towards product names and acronyms
+            }
+
+            // CRITICAL: Disable timestamp tokens to prevent whisper.cpp chunking heuristics
+            // The "single timestamp ending - skip entire chunk" optimization incorrectly discards
+            // complete, valid transcriptions. Disabling timestamps forces whisper to return ALL text.
+            params.set_no_timestamps(true);     // Prevent timestamp-based segment skipping
+            params.set_token_timestamps(true);  // Keep for any timestamp-aware features
+
+            params.set_print_special(false);
+            params.set_print_progress(false);
+            params.set_print_realtime(false);
+            params.set_print_timestamps(false);
+
+            // BALANCED settings - good quality with reasonable speed
+            params.set_suppress_blank(true);
+            params.set_suppress_non_speech_tokens(true);
+            params.set_temperature(0.3);             // Lower than 0.4 for consistency, higher than 0.0 for quality
+            params.set_max_initial_ts(1.0);
+            params.set_entropy_thold(2.4);
+            params.set_logprob_thold(-1.0);
+            // BALANCED FIX: Lowered from 0.75 to 0.55 to allow quiet speech detection
+            // Previous value was too aggressive and rejected valid quiet speech
+            // 0.55 is balanced - prevents hallucinations while preserving quiet speech
+            params.set_no_speech_thold(0.55);
+
+            // Reasonable length limits
+            params.set_max_len(200);                 // Reasonable length
+            params.set_single_segment(false);        // Allow multiple segments for better accuracy
+
+            // Note: compression_ratio_threshold would be ideal but not available in current whisper-rs
+            // This would help detect repetitive outputs: params.set_compression_ratio_threshold(2.4);
 
-        // Performance optimization: reduce segment completion logging
-        // Only log for significant transcriptions to avoid I/O overhead
-        if (should_log_transcription || num_segments > 0) && (num_segments > 3 || duration_seconds > 5.0) {
-            perf_debug!("Transcription #{} completed with {} segments ({:.1}s)", transcription_count, num_segments, duration_seconds);
-        }
-        let mut result = String::new();
+            let mut state = ctx.create_state()?;
+            state.full(params, &audio_data)?;
 
-        for i in 0..num_segments {
-            let segment_text = match state.full_get_segment_text_lossy(i) {
-                Ok(text) => text,
-                Err(_) => continue,
-            };
+            // Extract text with improved segment handling
+            let num_segments = state.full_n_segments()?;
+            let mut result = String::new();
 
-            let _start_time = state.full_get_segment_t0(i).unwrap_or(0);
-            let _end_time = state.full_get_segment_t1(i).unwrap_or(0);
+            for i in 0..num_segments {
+                let segment_text = match state.full_get_segment_text_lossy(i) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                };
 
-            // Performance optimization: remove per-segment debug logging
-            // This was causing significant I/O overhead during transcription
-            // Only log segments for very long audio (>30s) or when explicitly debugging
-            if duration_seconds > 30.0 {
-                perf_trace!("Segment {} ({:.2}s-{:.2}s): '{}'",
-                           i, _start_time as f64 / 100.0, _end_time as f64 / 100.0, segment_text);
-            }
+                let _start_time = state.full_get_segment_t0(i).unwrap_or(0);
+                let _end_time = state.full_get_segment_t1(i).unwrap_or(0);
 
-            // Clean and append segment text
-            let cleaned_text = segment_text.trim();
-            if !cleaned_text.is_empty() {
-                if !result.is_empty() {
-                    result.push(' ');
+                // Performance optimization: remove per-segment debug logging
+                // This was causing significant I/O overhead during transcription
+                // Only log segments for very long audio (>30s) or when explicitly debugging
+                if duration_seconds > 30.0 {
+                    perf_trace!("Segment {} ({:.2}s-{:.2}s): '{}'",
+                               i, _start_time as f64 / 100.0, _end_time as f64 / 100.0, segment_text);
+                }
+
+                // Clean and append segment text
+                let cleaned_text = segment_text.trim();
+                if !cleaned_text.is_empty() {
+                    if !result.is_empty() {
+                        result.push(' ');
+                    }
+                    result.push_str(cleaned_text);
                 }
-                result.push_str(cleaned_text);
             }
-        }
 
+            Ok((num_segments, result))
+        })
+        .await
+        .map_err(|e| anyhow!("Transcription task failed: {}", e))??;
+
+        // Performance optimization: reduce segment completion logging
+        // Only log for significant transcriptions to avoid I/O overhead
+        if (should_log_transcription || num_segments > 0) && (num_segments > 3 || duration_seconds > 5.0) {
+            perf_debug!("Transcription #{} completed with {} segments ({:.1}s)", transcription_count, num_segments, duration_seconds);
+        }
         let final_result = result.trim().to_string();
 
         // Check for repetition loops and clean them up
@@ -911,9 +1036,90 @@ impl WhisperEngine {
         }
     }
     
+    /// Import a ggml/gguf model from a local file or URL under a user-chosen name. The
+    /// file is copied into the models directory and loaded once to make sure whisper.cpp
+    /// accepts it before it is registered.
+    pub async fn import_model(&self, source: &str, name: &str, description: Option<String>, sha256: Option<String>) -> Result<ModelInfo> {
+        let source = ImportSource::parse(source)?;
+        let taken: Vec<String> = self.discover_models().await?.into_iter().map(|m| m.name).collect();
+        let name = custom_models::validate_model_name(name, &taken)?;
+
+        let file_path = self.models_dir.join(format!("ggml-{}.bin", name));
+        log::info!("📥 Importing Whisper model '{}' from {}", name, source.describe());
+
+        if !self.models_dir.exists() {
+            fs::create_dir_all(&self.models_dir).await
+                .map_err(|e| anyhow!("Failed to create models directory: {}", e))?;
+        }
+
+        match &source {
+            ImportSource::Path(path) if path.is_dir() => {
+                return Err(anyhow!("Whisper models are single ggml .bin files, not directories"));
+            }
+            ImportSource::Path(path) => custom_models::copy_file(path, &file_path).await?,
+            ImportSource::Url(url) => custom_models::download_file(url, &file_path, sha256.as_deref()).await?,
+        }
+
+        if let Err(e) = self.validate_by_loading(&file_path).await {
+            let _ = fs::remove_file(&file_path).await;
+            return Err(e);
+        }
+
+        let mut registry = CustomModelRegistry::load(&self.models_dir);
+        registry.insert(custom_models::new_entry(&name, &file_path, description, &source, false));
+        registry.save(&self.models_dir)?;
+
+        log::info!("✅ Imported Whisper model '{}'", name);
+        self.discover_models().await?
+            .into_iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| anyhow!("Imported model '{}' was not discovered", name))
+    }
+
+    /// Check the GGML header, then load the model on the CPU and drop it again
+    async fn validate_by_loading(&self, model_path: &PathBuf) -> Result<()> {
+        self.validate_model_file(model_path).await?;
+
+        let path = model_path.to_string_lossy().to_string();
+        tokio::task::spawn_blocking(move || {
+            let params = WhisperContextParameters { use_gpu: false, ..Default::default() };
+            WhisperContext::new_with_params(&path, params)
+                .map(drop)
+                .map_err(|e| anyhow!("whisper.cpp could not load the model: {}", e))
+        })
+        .await
+        .map_err(|e| anyhow!("Model validation task failed: {}", e))?
+    }
+
+    /// Remove an imported model and its file
+    pub async fn remove_custom_model(&self, model_name: &str) -> Result<()> {
+        let mut registry = CustomModelRegistry::load(&self.models_dir);
+        let entry = registry.remove(model_name)
+            .ok_or_else(|| anyhow!("'{}' is not an imported model", model_name))?;
+
+        if self.current_model.read().await.as_deref() == Some(model_name) {
+            self.unload_model().await;
+        }
+
+        let file_path = self.models_dir.join(&entry.path);
+        if file_path.exists() {
+            fs::remove_file(&file_path).await
+                .map_err(|e| anyhow!("Failed to delete file '{}': {}", file_path.display(), e))?;
+        }
+        registry.save(&self.models_dir)?;
+        self.discover_models().await?;
+
+        log::info!("🗑️ Removed imported Whisper model '{}'", model_name);
+        Ok(())
+    }
+
     pub async fn download_model(&self, model_name: &str, progress_callback: Option<Box<dyn Fn(u8) + Send>>) -> Result<()> {
         log::info!("Starting download for model: {}", model_name);
 
+        if !DOWNLOADABLE_MODELS.contains(&model_name) {
+            return Err(anyhow!("Unsupported model: {}", model_name));
+        }
+
         // Check if download is already in progress for this model
         {
             let active = self.active_downloads.read().await;
@@ -935,39 +1141,49 @@ impl WhisperEngine {
             *cancel_flag = None;
         }
 
-        // Official ggerganov/whisper.cpp model URLs from Hugging Face
-        let model_url = match model_name {
-            // Standard f16 models
-            "tiny" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin",
-            "base" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.bin",
-            "small" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin",
-            "medium" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium.bin",
-            "large-v3-turbo" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3-turbo.bin",
-            "large-v3" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3.bin",
-            
-            "small-q5_0" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small-q5_0.bin",
-            "medium-q5_0" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium-q5_0.bin",
-            "large-v3-turbo-q5_0" => "https://huggingface.co/ggerganov/whisper.cpp/blob/main/ggml-large-v3-turbo-q5_0.bin",
-            "large-v3-q5_0" => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3-q5_0.bin",
-            // Quantized int8 models
-            
-            _ => return Err(anyhow!("Unsupported model: {}", model_name))
-        };
-        
-        log::info!("Model URL for {}: {}", model_name, model_url);
-        
+        let result = self.download_model_file(model_name, progress_callback).await;
+
+        // Remove from active downloads whatever the outcome
+        {
+            let mut active = self.active_downloads.write().await;
+            active.remove(model_name);
+        }
+
+        // Surface failures in the model list; a cancelled download goes back to Missing
+        if let Err(e) = &result {
+            let cancelled = self.cancel_download_flag.read().await.as_deref() == Some(model_name);
+            let mut models = self.available_models.write().await;
+            if let Some(model_info) = models.get_mut(model_name) {
+                model_info.status = if cancelled {
+                    ModelStatus::Missing
+                } else {
+                    ModelStatus::Error(e.to_string())
+                };
+            }
+        }
+
+        result
+    }
+
+    /// Stream `ggml-<model>.bin` into the models directory, resuming a previous partial
+    /// download and verifying the result (see `crate::model_download`)
+    async fn download_model_file(&self, model_name: &str, progress_callback: Option<Box<dyn Fn(u8) + Send>>) -> Result<()> {
         // Generate correct filename - all models follow ggml-{model_name}.bin pattern
         let filename = format!("ggml-{}.bin", model_name);
         let file_path = self.models_dir.join(&filename);
-        
+
+        let settings = model_download::current_settings();
+        let artifact = ModelArtifact::new(WHISPER_MODEL_REPO, &filename);
+        let model_url = artifact.url(&settings);
+        log::info!("Model URL for {}: {}", model_name, model_url);
         log::info!("Downloading to file path: {}", file_path.display());
-        
+
         // Create models directory if it doesn't exist
         if !self.models_dir.exists() {
             fs::create_dir_all(&self.models_dir).await
                 .map_err(|e| anyhow!("Failed to create models directory: {}", e))?;
         }
-        
+
         // Update model status to downloading
         {
             let mut models = self.available_models.write().await;
@@ -975,80 +1191,52 @@ impl WhisperEngine {
                 model_info.status = ModelStatus::Downloading { progress: 0 };
             }
         }
-        
-        log::info!("Creating HTTP client and starting request...");
+
         let client = Client::new();
-        
-        log::info!("Sending GET request to: {}", model_url);
-        let response = client.get(model_url).send().await
-            .map_err(|e| anyhow!("Failed to start download: {}", e))?;
-        
-        log::info!("Received response with status: {}", response.status());
-        if !response.status().is_success() {
-            // Remove from active downloads on error
-            let mut active = self.active_downloads.write().await;
-            active.remove(model_name);
-            return Err(anyhow!("Download failed with status: {}", response.status()));
-        }
-        
-        let total_size = response.content_length().unwrap_or(0);
-        log::info!("Response successful, content length: {} bytes ({:.1} MB)", total_size, total_size as f64 / (1024.0 * 1024.0));
-        
+        let manifest = model_download::load_manifest(&client, &settings).await;
+        let mut download = ResumableDownload::start(&client, &model_url, &file_path, manifest.get(&artifact)).await?;
+
+        let total_size = download.total().unwrap_or(0);
+        log::info!("Expected size: {:.1} MB", total_size as f64 / (1024.0 * 1024.0));
         if total_size == 0 {
             log::warn!("Content length is 0 or unknown - download may not show accurate progress");
         }
-        
-        let mut file = fs::File::create(&file_path).await
-            .map_err(|e| anyhow!("Failed to create file: {}", e))?;
-        
-        log::info!("File created successfully at: {}", file_path.display());
-        
-        // Stream download with real progress reporting
-        log::info!("Starting streaming download...");
-        log::info!("Expected size: {:.1} MB", total_size as f64 / (1024.0 * 1024.0));
 
-        use futures_util::StreamExt;
-        let mut stream = response.bytes_stream();
-        let mut downloaded = 0u64;
         let mut last_progress_report = 0u8;
         let mut last_report_time = std::time::Instant::now();
 
-        // Emit initial 0% progress immediately
+        // Emit initial progress immediately (non-zero when resuming)
+        let initial_progress = if total_size > 0 {
+            ((download.downloaded() as f64 / total_size as f64) * 100.0) as u8
+        } else {
+            0
+        };
         if let Some(ref callback) = progress_callback {
-            callback(0);
+            callback(initial_progress);
         }
 
-        while let Some(chunk_result) = stream.next().await {
-            // Check for cancellation before processing chunk
+        while download.next_chunk().await?.is_some() {
+            // Check for cancellation after each chunk; the partial file is removed by cancel_download
             {
                 let cancel_flag = self.cancel_download_flag.read().await;
-                if cancel_flag.as_ref() == Some(&model_name.to_string()) {
+                if cancel_flag.as_deref() == Some(model_name) {
                     log::info!("Download cancelled for {}", model_name);
-                    // Remove from active downloads on cancellation
-                    let mut active = self.active_downloads.write().await;
-                    active.remove(model_name);
                     return Err(anyhow!("Download cancelled by user"));
                 }
             }
 
-            let chunk = chunk_result
-                .map_err(|e| anyhow!("Failed to read chunk: {}", e))?;
-
-            file.write_all(&chunk).await
-                .map_err(|e| anyhow!("Failed to write chunk to file: {}", e))?;
-
-            downloaded += chunk.len() as u64;
+            let downloaded = download.downloaded();
 
             // Calculate progress
             let progress = if total_size > 0 {
-                ((downloaded as f64 / total_size as f64) * 100.0) as u8
+                ((downloaded as f64 / total_size as f64) * 100.0).min(99.0) as u8
             } else {
                 0
             };
 
             // Report progress every 1% or every 2 seconds for better UI responsiveness
             let time_since_last_report = last_report_time.elapsed().as_secs();
-            if progress >= last_progress_report + 1 || progress == 100 || time_since_last_report >= 2 {
+            if progress >= last_progress_report + 1 || time_since_last_report >= 2 {
                 log::info!("Download progress: {}% ({:.1} MB / {:.1} MB)",
                          progress,
                          downloaded as f64 / (1024.0 * 1024.0),
@@ -1072,25 +1260,16 @@ impl WhisperEngine {
             }
         }
 
-        log::info!("Streaming download completed: {} bytes", downloaded);
-        
-        // Ensure 100% progress is always reported
-        {
-            let mut models = self.available_models.write().await;
-            if let Some(model_info) = models.get_mut(model_name) {
-                model_info.status = ModelStatus::Downloading { progress: 100 };
-            }
-        }
-        
+        log::info!("Streaming download completed: {} bytes, verifying...", download.downloaded());
+        download.finish(settings.require_checksum).await?;
+
+        // Report 100% only once the file is verified and in place
         if let Some(ref callback) = progress_callback {
             callback(100);
         }
-        
-        file.flush().await
-            .map_err(|e| anyhow!("Failed to flush file: {}", e))?;
-        
+
         log::info!("Download completed for model: {}", model_name);
-        
+
         // Update model status to available
         {
             let mut models = self.available_models.write().await;
@@ -1100,12 +1279,6 @@ impl WhisperEngine {
             }
         }
 
-        // Remove from active downloads on completion
-        {
-            let mut active = self.active_downloads.write().await;
-            active.remove(model_name);
-        }
-
         Ok(())
     }
     
@@ -1136,7 +1309,7 @@ impl WhisperEngine {
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await; // Brief delay to let download loop detect cancellation
 
         let filename = format!("ggml-{}.bin", model_name);
-        let file_path = self.models_dir.join(&filename);
+        let file_path = model_download::partial_path(&self.models_dir.join(&filename));
         if file_path.exists() {
             if let Err(e) = fs::remove_file(&file_path).await {
                 log::warn!("Failed to clean up cancelled download file: {}", e);
@@ -9,7 +9,7 @@ import { SummaryPanel } from '@/components/MeetingDetails/SummaryPanel';
 
 // Custom hooks
 import { useMeetingData } from '@/hooks/meeting-details/useMeetingData';
-import { useSummaryGeneration } from '@/hooks/meeting-details/useSummaryGeneration';
+import { useSummaryGeneration, TranscriptSource } from '@/hooks/meeting-details/useSummaryGeneration';
 import { useModelConfiguration } from '@/hooks/meeting-details/useModelConfiguration';
 import { useTemplates } from '@/hooks/meeting-details/useTemplates';
 import { useCopyOperations } from '@/hooks/meeting-details/useCopyOperations';
@@ -38,6 +38,7 @@ export default function PageContent({
   const [customPrompt, setCustomPrompt] = useState<string>('');
   const [isRecording] = useState(false);
   const [summaryResponse] = useState<SummaryResponse | null>(null);
+  const [transcriptSource, setTranscriptSource] = useState<TranscriptSource>('original');
 
   // Sidebar context
   const { serverAddress } = useSidebar();
@@ -53,6 +54,7 @@ export default function PageContent({
     modelConfig: modelConfig.modelConfig,
     isModelConfigLoading: modelConfig.isLoading,
     selectedTemplate: templates.selectedTemplate,
+    transcriptSource,
     onMeetingUpdated,
     updateMeetingTitle: meetingData.updateMeetingTitle,
     setAiSummary: meetingData.setAiSummary,
@@ -103,12 +105,16 @@ export default function PageContent({
       
 
         <TranscriptPanel
+          meetingId={meeting.id}
           transcripts={meetingData.transcripts}
           customPrompt={customPrompt}
           onPromptChange={setCustomPrompt}
           onCopyTranscript={copyOperations.handleCopyTranscript}
           onOpenMeetingFolder={meetingOperations.handleOpenMeetingFolder}
+          onTranscriptReplaced={onMeetingUpdated}
           isRecording={isRecording}
+          transcriptSource={transcriptSource}
+          onTranscriptSourceChange={setTranscriptSource}
         />
 
           <SummaryPanel
@@ -137,6 +143,8 @@ export default function PageContent({
           onSummaryChange={meetingData.handleSummaryChange}
           onDirtyChange={meetingData.setIsSummaryDirty}
           summaryError={summaryGeneration.summaryError}
+          summaryProgress={summaryGeneration.summaryProgress}
+          onCancelSummary={summaryGeneration.handleCancelSummary}
           onRegenerateSummary={summaryGeneration.handleRegenerateSummary}
           getSummaryStatusMessage={summaryGeneration.getSummaryStatusMessage}
           availableTemplates={templates.availableTemplates}
@@ -2,7 +2,7 @@
 
 import { useState, useEffect, useContext, useCallback, useRef } from 'react';
 import { motion } from 'framer-motion';
-import { Transcript, TranscriptUpdate, Summary, SummaryResponse } from '@/types';
+import { Transcript, TranscriptUpdate, TranslationUpdate, TranscriptionLagEvent, TranscriptionResumeOutcome, Summary, SummaryResponse } from '@/types';
 import { EditableTitle } from '@/components/EditableTitle';
 import { TranscriptView } from '@/components/TranscriptView';
 import { RecordingControls } from '@/components/RecordingControls';
@@ -11,6 +11,7 @@ import { DeviceSelection, SelectedDevices } from '@/components/DeviceSelection';
 import { useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { TranscriptSettings, TranscriptModelProps } from '@/components/TranscriptSettings';
 import { LanguageSelection } from '@/components/LanguageSelection';
+import { TranslationSelection } from '@/components/TranslationSelection';
 import { PermissionWarning } from '@/components/PermissionWarning';
 import { PreferenceSettings } from '@/components/PreferenceSettings';
 import { usePermissionCheck } from '@/hooks/usePermissionCheck';
@@ -48,6 +49,7 @@ interface OllamaModel {
 export default function Home() {
   const [isRecording, setIsRecordingState] = useState(false);
   const [transcripts, setTranscripts] = useState<Transcript[]>([]);
+  const [partialTranscript, setPartialTranscript] = useState<Transcript | null>(null);
   const [showSummary, setShowSummary] = useState(false);
   const [summaryStatus, setSummaryStatus] = useState<SummaryStatus>('idle');
   const [barHeights, setBarHeights] = useState(['58%', '76%', '58%']);
@@ -122,6 +124,9 @@ export default function Home() {
   // Ref to avoid stale closure issues with transcripts
   const transcriptsRef = useRef<Transcript[]>(transcripts);
 
+  // Highest utterance with a final result; older partials are stale
+  const lastFinalUtteranceRef = useRef<number>(-1);
+
   const isUserAtBottomRef = useRef<boolean>(true);
 
   // Ref for the transcript scrollable container
@@ -234,7 +239,9 @@ export default function Home() {
           setTranscriptModelConfig({
             provider: config.provider || 'parakeet',
             model: config.model || 'parakeet-tdt-0.6b-v3-int8',
-            apiKey: config.apiKey || null
+            apiKey: config.apiKey || null,
+            endpoint: config.endpoint || null,
+            maxRetries: config.maxRetries ?? null
           });
         }
       } catch (error) {
@@ -314,8 +321,17 @@ export default function Home() {
     setSidebarIsRecording(recordingState.isRecording);
   }, [recordingState.isRecording, setSidebarIsRecording]);
 
+  // Utterance IDs restart with every recording; partials never outlive one
+  useEffect(() => {
+    lastFinalUtteranceRef.current = -1;
+    setPartialTranscript(null);
+  }, [recordingState.isRecording]);
+
   useEffect(() => {
     let unlistenFn: (() => void) | undefined;
+    let unlistenTranslationFn: (() => void) | undefined;
+    let unlistenPartialFn: (() => void) | undefined;
+    let unlistenPartialDiscardedFn: (() => void) | undefined;
     let transcriptCounter = 0;
     let transcriptBuffer = new Map<number, Transcript>();
     let lastProcessedSequence = 0;
@@ -451,8 +467,16 @@ export default function Home() {
             audio_start_time: event.payload.audio_start_time,
             audio_end_time: event.payload.audio_end_time,
             duration: event.payload.duration,
+            words: event.payload.words,
+            utterance_id: event.payload.utterance_id,
           };
 
+          // The final result replaces the partial of its utterance
+          lastFinalUtteranceRef.current = Math.max(lastFinalUtteranceRef.current, event.payload.utterance_id ?? -1);
+          setPartialTranscript(prev =>
+            prev && (prev.utterance_id ?? -1) <= lastFinalUtteranceRef.current ? null : prev
+          );
+
           // Add to buffer
           transcriptBuffer.set(event.payload.sequence_id, newTranscript);
           console.log(`✅ MAIN LISTENER: Buffered transcript with sequence_id ${event.payload.sequence_id}. Buffer size: ${transcriptBuffer.size}, Last processed: ${lastProcessedSequence}`);
@@ -466,6 +490,45 @@ export default function Home() {
           processingTimer = setTimeout(processBufferedTranscripts, 10);
         });
         console.log('✅ MAIN transcript listener setup complete');
+
+        // Partial hypotheses of the utterance still being spoken, replaced in place
+        unlistenPartialFn = await listen<TranscriptUpdate>('transcript-partial', (event) => {
+          const { utterance_id } = event.payload;
+          if (utterance_id === undefined || utterance_id <= lastFinalUtteranceRef.current) return;
+          setPartialTranscript({
+            id: `partial-${utterance_id}`,
+            text: event.payload.text,
+            timestamp: event.payload.timestamp,
+            is_partial: true,
+            confidence: event.payload.confidence,
+            audio_start_time: event.payload.audio_start_time,
+            audio_end_time: event.payload.audio_end_time,
+            duration: event.payload.duration,
+            words: event.payload.words,
+            utterance_id,
+          });
+        });
+
+        // The utterance's final result was empty or rejected
+        unlistenPartialDiscardedFn = await listen<{ utterance_id: number }>('transcript-partial-discarded', (event) => {
+          const { utterance_id } = event.payload;
+          lastFinalUtteranceRef.current = Math.max(lastFinalUtteranceRef.current, utterance_id);
+          setPartialTranscript(prev => (prev?.utterance_id === utterance_id ? null : prev));
+        });
+
+        // Bilingual meetings: translations arrive after their segment, matched by sequence_id
+        unlistenTranslationFn = await listen<TranslationUpdate>('transcript-translation', (event) => {
+          const { sequence_id, text, target_language } = event.payload;
+          const buffered = transcriptBuffer.get(sequence_id);
+          if (buffered) {
+            transcriptBuffer.set(sequence_id, { ...buffered, translation: text, translation_language: target_language });
+          }
+          setTranscripts(prev => prev.map(t =>
+            t.sequence_id === sequence_id
+              ? { ...t, translation: text, translation_language: target_language }
+              : t
+          ));
+        });
       } catch (error) {
         console.error('❌ Failed to setup MAIN transcript listener:', error);
         alert('Failed to setup transcript listener. Check console for details.');
@@ -485,6 +548,11 @@ export default function Home() {
         unlistenFn();
         console.log('🧹 CLEANUP: MAIN transcript listener cleaned up');
       }
+      if (unlistenTranslationFn) {
+        unlistenTranslationFn();
+      }
+      unlistenPartialFn?.();
+      unlistenPartialDiscardedFn?.();
     };
   }, []);
 
@@ -513,6 +581,9 @@ export default function Home() {
             audio_start_time: segment.audio_start_time,
             audio_end_time: segment.audio_end_time,
             duration: segment.duration,
+            words: segment.words,
+            translation: segment.translation,
+            translation_language: segment.translation_language,
           }));
 
           setTranscripts(formattedTranscripts);
@@ -567,6 +638,55 @@ export default function Home() {
     };
   }, []);
 
+  // Warn when live transcription falls behind, and when it has caught up again
+  useEffect(() => {
+    let unlistenFn: (() => void) | undefined;
+
+    listen<TranscriptionLagEvent>('transcription-lag', (event) => {
+      const { lagging, lag_seconds, degradation_level, deferred_chunks } = event.payload;
+      if (lagging) {
+        const degraded = degradation_level !== 'normal'
+          ? ' Switched to faster transcription settings until it catches up.'
+          : '';
+        toast.warning(`Transcription is ${Math.round(lag_seconds)}s behind`, {
+          id: 'transcription-lag',
+          description: `Transcripts will arrive late.${degraded}`,
+        });
+      } else if (degradation_level === 'normal') {
+        toast.success('Transcription caught up', { id: 'transcription-lag' });
+      } else if (deferred_chunks > 0) {
+        toast.info(`${deferred_chunks} audio chunks will be transcribed once the backlog clears`, { id: 'transcription-lag' });
+      }
+    }).then((fn) => { unlistenFn = fn; });
+
+    return () => unlistenFn?.();
+  }, []);
+
+  // Passages an earlier session left queued are transcribed after restart
+  useEffect(() => {
+    let unlistenFn: (() => void) | undefined;
+
+    listen<TranscriptionResumeOutcome>('transcription-resumed', (event) => {
+      const { meeting_name, recovered_segments, unrecoverable_chunks, created_meeting, error } = event.payload;
+      const name = meeting_name || 'a previous meeting';
+      if (error) {
+        toast.error(`Could not finish the transcript of ${name}`, { description: error });
+        return;
+      }
+      const lost = unrecoverable_chunks > 0
+        ? ` ${unrecoverable_chunks} passages were lost because their audio was never saved.`
+        : '';
+      toast.success(`Finished the transcript of ${name}`, {
+        description: `${recovered_segments} passages transcribed from the saved audio.${lost}`,
+      });
+      if (created_meeting || recovered_segments > 0) {
+        refetchMeetings();
+      }
+    }).then((fn) => { unlistenFn = fn; });
+
+    return () => unlistenFn?.();
+  }, [refetchMeetings]);
+
   // Refs for meeting detection to avoid stale closures
   const isRecordingRef = useRef(isRecording);
   const isMeetingActiveRef = useRef(isMeetingActive);
@@ -613,7 +733,9 @@ export default function Home() {
             const result = await invoke('start_recording_with_devices_and_meeting', {
               mic_device_name: selectedDevicesRef.current?.micDevice || null,
               system_device_name: selectedDevicesRef.current?.systemDevice || null,
-              meeting_name: meetingName
+              meeting_name: meetingName,
+              // Detector-started recordings get the pre-roll buffer prepended
+              auto_detected: true
             });
             console.log('Auto-start recording result:', result);
             
@@ -1599,7 +1721,9 @@ export default function Home() {
       await invoke('api_save_transcript_config', {
         provider: config.provider,
         model: config.model,
-        apiKey: config.apiKey
+        apiKey: config.apiKey,
+        endpoint: config.endpoint ?? null,
+        maxRetries: config.maxRetries ?? null
       });
       console.log('[HomePage] ✅ Successfully saved transcript config');
     } catch (error) {
@@ -1956,6 +2080,7 @@ export default function Home() {
               <div className="w-2/3 max-w-[750px]">
                 <TranscriptView
                   transcripts={transcripts}
+                  partialTranscript={partialTranscript}
                   isRecording={recordingState.isRecording}
                   isPaused={recordingState.isPaused}
                   isProcessing={isProcessingStop}
@@ -2215,6 +2340,13 @@ export default function Home() {
                   provider={transcriptModelConfig.provider}
                 />
 
+                <div className="mt-6 pt-6 border-t border-gray-200">
+                  <TranslationSelection
+                    disabled={isRecording}
+                    provider={transcriptModelConfig.provider}
+                  />
+                </div>
+
                 <div className="mt-6 flex justify-end">
                   <button
                     onClick={() => setShowLanguageSettings(false)}
@@ -1,7 +1,7 @@
 'use client';
 
 import React, { useState, useEffect } from 'react';
-import { ArrowLeft, Settings2, Mic, Database as DatabaseIcon, SparkleIcon, Video } from 'lucide-react';
+import { ArrowLeft, Settings2, Mic, Database as DatabaseIcon, SparkleIcon, Video, BookOpen, Shield } from 'lucide-react';
 import { useRouter } from 'next/navigation';
 import { invoke } from '@/lib/tauri';
 import { TranscriptSettings, TranscriptModelProps } from '@/components/TranscriptSettings';
@@ -9,8 +9,16 @@ import { RecordingSettings } from '@/components/RecordingSettings';
 import { PreferenceSettings } from '@/components/PreferenceSettings';
 import { SummaryModelSettings } from '@/components/SummaryModelSettings';
 import { MeetingDetectionSettings } from '@/components/Settings/MeetingDetection';
+import { GlossarySettings } from '@/components/Settings/Glossary';
+import { ConfidenceSettings } from '@/components/Settings/ConfidenceSettings';
+import { HallucinationFilterSettings } from '@/components/Settings/HallucinationFilterSettings';
+import { ModelBenchmarkSettings } from '@/components/Settings/ModelBenchmark';
+import { BacklogSettings } from '@/components/Settings/BacklogSettings';
+import { ModelDownloadSettings } from '@/components/Settings/ModelDownloadSettings';
+import { OnnxRuntimeSettings } from '@/components/Settings/OnnxRuntimeSettings';
+import { RedactionSettings } from '@/components/Settings/Redaction';
 
-type SettingsTab = 'general' | 'recording' | 'meetingDetection' | 'Transcriptionmodels' | 'summaryModels';
+type SettingsTab = 'general' | 'recording' | 'meetingDetection' | 'Transcriptionmodels' | 'glossary' | 'summaryModels' | 'privacy';
 
 export default function SettingsPage() {
   const router = useRouter();
@@ -26,7 +34,9 @@ export default function SettingsPage() {
     { id: 'recording' as const, label: 'Recordings', icon: <Mic className="w-4 h-4" /> },
     { id: 'meetingDetection' as const, label: 'Auto-Detection', icon: <Video className="w-4 h-4" /> },
     { id: 'Transcriptionmodels' as const, label: 'Transcription', icon: <DatabaseIcon className="w-4 h-4" /> },
-    { id: 'summaryModels' as const, label: 'Summary', icon: <SparkleIcon className="w-4 h-4" /> }
+    { id: 'glossary' as const, label: 'Vocabulary', icon: <BookOpen className="w-4 h-4" /> },
+    { id: 'summaryModels' as const, label: 'Summary', icon: <SparkleIcon className="w-4 h-4" /> },
+    { id: 'privacy' as const, label: 'Privacy', icon: <Shield className="w-4 h-4" /> }
   ];
 
   // Load saved transcript configuration on mount
@@ -39,7 +49,9 @@ export default function SettingsPage() {
           setTranscriptModelConfig({
             provider: config.provider || 'localWhisper',
             model: config.model || 'large-v3',
-            apiKey: config.apiKey || null
+            apiKey: config.apiKey || null,
+            endpoint: config.endpoint || null,
+            maxRetries: config.maxRetries ?? null
           });
         }
       } catch (error) {
@@ -56,7 +68,9 @@ export default function SettingsPage() {
       await invoke('api_save_transcript_config', {
         provider: config.provider,
         model: config.model,
-        apiKey: config.apiKey
+        apiKey: config.apiKey,
+        endpoint: config.endpoint ?? null,
+        maxRetries: config.maxRetries ?? null
       });
       console.log('[SettingsPage] ✅ Successfully saved transcript config');
     } catch (error) {
@@ -109,13 +123,23 @@ export default function SettingsPage() {
               {activeTab === 'recording' && <RecordingSettings />}
               {activeTab === 'meetingDetection' && <MeetingDetectionSettings />}
               {activeTab === 'Transcriptionmodels' && (
-                <TranscriptSettings
-                  transcriptModelConfig={transcriptModelConfig}
-                  setTranscriptModelConfig={setTranscriptModelConfig}
-                  // onSave={handleSaveConfig}
-                />
+                <>
+                  <TranscriptSettings
+                    transcriptModelConfig={transcriptModelConfig}
+                    setTranscriptModelConfig={setTranscriptModelConfig}
+                    // onSave={handleSaveConfig}
+                  />
+                  <ConfidenceSettings />
+                  <HallucinationFilterSettings />
+                  <BacklogSettings />
+                  <ModelBenchmarkSettings />
+                  <ModelDownloadSettings />
+                  <OnnxRuntimeSettings />
+                </>
               )}
+              {activeTab === 'glossary' && <GlossarySettings />}
               {activeTab === 'summaryModels' && <SummaryModelSettings />}
+              {activeTab === 'privacy' && <RedactionSettings />}
             </div>
           </div>
         </div>
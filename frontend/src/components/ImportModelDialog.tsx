@@ -0,0 +1,151 @@
+"use client";
+
+import { useState } from 'react';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  DialogTrigger,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { Upload } from 'lucide-react';
+import { toast } from 'sonner';
+import { WhisperAPI } from '@/lib/whisper';
+import { ParakeetAPI } from '@/lib/parakeet';
+import { OnnxAsrAPI } from '@/lib/onnxAsr';
+
+interface ImportModelDialogProps {
+  engine: 'whisper' | 'parakeet' | 'onnxAsr';
+  onImported: (modelName: string) => void;
+}
+
+const SOURCE_HINTS = {
+  whisper: {
+    placeholder: '/path/to/ggml-model.bin or https://…/ggml-model.bin',
+    help: 'A ggml/gguf Whisper model file, such as a fine-tuned model converted with whisper.cpp.',
+  },
+  parakeet: {
+    placeholder: '/path/to/model-folder or https://…/model-folder',
+    help: 'A folder with the ONNX export (encoder, decoder_joint, nemo128.onnx and vocab.txt). Int8 files are used when present. Files are checked against a manifest.json next to them.',
+  },
+  onnxAsr: {
+    placeholder: '/path/to/model-folder',
+    help: 'A local folder with a manifest.json describing the ONNX sessions, decoder type, vocabulary and languages.',
+  },
+};
+
+export function ImportModelDialog({ engine, onImported }: ImportModelDialogProps) {
+  const [open, setOpen] = useState(false);
+  const [source, setSource] = useState('');
+  const [name, setName] = useState('');
+  const [description, setDescription] = useState('');
+  const [sha256, setSha256] = useState('');
+  const [isImporting, setIsImporting] = useState(false);
+
+  const isUrl = /^https?:\/\//.test(source.trim());
+
+  const resetForm = () => {
+    setSource('');
+    setName('');
+    setDescription('');
+    setSha256('');
+  };
+
+  const handleImport = async () => {
+    setIsImporting(true);
+    try {
+      const model = engine === 'whisper'
+        ? await WhisperAPI.importModel(name.trim(), source.trim(), description.trim(), sha256.trim())
+        : engine === 'onnxAsr'
+          ? await OnnxAsrAPI.importModel(name.trim(), source.trim())
+          : await ParakeetAPI.importModel(name.trim(), source.trim(), description.trim());
+
+      toast.success(`Imported ${model.name}`, {
+        description: 'The model passed a test load and is ready to select',
+        duration: 3000
+      });
+      resetForm();
+      setOpen(false);
+      onImported(model.name);
+    } catch (error) {
+      console.error('Failed to import model:', error);
+      toast.error('Failed to import model', {
+        description: String(error),
+        duration: 5000
+      });
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={(next) => !isImporting && setOpen(next)}>
+      <DialogTrigger asChild>
+        <Button variant="outline" size="sm">
+          <Upload className="w-4 h-4" />
+          Import model
+        </Button>
+      </DialogTrigger>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>Import a custom model</DialogTitle>
+          <DialogDescription>{SOURCE_HINTS[engine].help}</DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-3">
+          <div>
+            <Label className="block text-sm font-medium text-gray-700 mb-1">{engine === 'onnxAsr' ? 'Folder' : 'Path or URL'}</Label>
+            <Input
+              value={source}
+              onChange={(e) => setSource(e.target.value)}
+              placeholder={SOURCE_HINTS[engine].placeholder}
+            />
+          </div>
+          <div>
+            <Label className="block text-sm font-medium text-gray-700 mb-1">Name</Label>
+            <Input
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              placeholder="medical-small"
+            />
+            <p className="text-xs text-gray-500 mt-1">Letters, digits, &apos;-&apos;, &apos;_&apos; and &apos;.&apos; only.</p>
+          </div>
+          {engine !== 'onnxAsr' && (
+            <div>
+              <Label className="block text-sm font-medium text-gray-700 mb-1">Description (optional)</Label>
+              <Input
+                value={description}
+                onChange={(e) => setDescription(e.target.value)}
+                placeholder="Fine-tuned on clinical dictation"
+              />
+            </div>
+          )}
+          {engine === 'whisper' && isUrl && (
+            <div>
+              <Label className="block text-sm font-medium text-gray-700 mb-1">SHA-256 (optional)</Label>
+              <Input
+                value={sha256}
+                onChange={(e) => setSha256(e.target.value)}
+                placeholder="Checksum to verify the download against"
+              />
+            </div>
+          )}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={() => setOpen(false)} disabled={isImporting}>
+            Cancel
+          </Button>
+          <Button onClick={handleImport} disabled={isImporting || !source.trim() || !name.trim()}>
+            {isImporting ? 'Importing…' : 'Import'}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
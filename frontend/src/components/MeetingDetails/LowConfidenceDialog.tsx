@@ -0,0 +1,125 @@
+"use client";
+
+import { useCallback, useEffect, useState } from 'react';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+  DialogTrigger,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { AlertTriangle } from 'lucide-react';
+import { invoke } from '@/lib/tauri';
+import Analytics from '@/lib/analytics';
+import { ConfidenceSettings, LowConfidencePassage } from '@/types';
+
+interface LowConfidenceDialogProps {
+  meetingId: string;
+  transcriptCount: number;
+}
+
+function formatSeconds(seconds?: number): string {
+  if (seconds === undefined || seconds === null) return '--:--';
+  const total = Math.floor(seconds);
+  const mins = Math.floor(total / 60);
+  const secs = total % 60;
+  return `${mins.toString().padStart(2, '0')}:${secs.toString().padStart(2, '0')}`;
+}
+
+export function LowConfidenceDialog({ meetingId, transcriptCount }: LowConfidenceDialogProps) {
+  const [open, setOpen] = useState(false);
+  const [threshold, setThreshold] = useState(0.6);
+  const [passages, setPassages] = useState<LowConfidencePassage[]>([]);
+  const [isLoading, setIsLoading] = useState(false);
+
+  useEffect(() => {
+    invoke<ConfidenceSettings>('get_confidence_settings')
+      .then((settings) => setThreshold(settings.low_confidence_threshold))
+      .catch((error) => console.error('Failed to load confidence settings:', error));
+  }, []);
+
+  const loadPassages = useCallback(async () => {
+    setIsLoading(true);
+    try {
+      setPassages(await invoke<LowConfidencePassage[]>('get_low_confidence_passages', { meetingId, threshold }));
+    } catch (error) {
+      console.error('Failed to load low-confidence passages:', error);
+      setPassages([]);
+    } finally {
+      setIsLoading(false);
+    }
+  }, [meetingId, threshold]);
+
+  // Refresh whenever the dialog opens or the transcript changes underneath it
+  useEffect(() => {
+    if (open) loadPassages();
+  }, [open, loadPassages, transcriptCount]);
+
+  return (
+    <Dialog open={open} onOpenChange={setOpen}>
+      <DialogTrigger asChild>
+        <Button
+          size="sm"
+          variant="outline"
+          disabled={transcriptCount === 0}
+          title="Review passages the engine was unsure about"
+          onClick={() => Analytics.trackButtonClick('review_low_confidence', 'meeting_details')}
+        >
+          <AlertTriangle />
+          <span className="hidden lg:inline">Review</span>
+        </Button>
+      </DialogTrigger>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>Low-confidence passages</DialogTitle>
+          <DialogDescription>
+            Segments recognized below {Math.round(threshold * 100)}% confidence. Check these against
+            the recording; segments you have edited are not listed.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-3">
+          <div className="flex items-center gap-3">
+            <label className="text-sm text-gray-700 whitespace-nowrap">Threshold</label>
+            <input
+              type="range"
+              min={0.1}
+              max={0.95}
+              step={0.05}
+              value={threshold}
+              onChange={(e) => setThreshold(parseFloat(e.target.value))}
+              className="flex-1"
+            />
+            <span className="text-sm text-gray-600 w-10 text-right">{Math.round(threshold * 100)}%</span>
+          </div>
+
+          <div className="max-h-80 overflow-y-auto space-y-2">
+            {isLoading ? (
+              <p className="text-sm text-gray-500">Loading…</p>
+            ) : passages.length === 0 ? (
+              <p className="text-sm text-gray-500">
+                No low-confidence passages. Meetings recorded before confidence was saved have nothing to review.
+              </p>
+            ) : (
+              passages.map((passage) => (
+                <div key={passage.transcript_ids[0]} className="p-2 border border-amber-200 bg-amber-50 rounded-md">
+                  <div className="flex justify-between text-xs text-gray-600 mb-1">
+                    <span>
+                      {formatSeconds(passage.audio_start_time)} – {formatSeconds(passage.audio_end_time)}
+                    </span>
+                    <span>
+                      {Math.round(passage.avg_confidence * 100)}% avg · {Math.round(passage.min_confidence * 100)}% min
+                    </span>
+                  </div>
+                  <p className="text-sm text-gray-800">{passage.text}</p>
+                </div>
+              ))
+            )}
+          </div>
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
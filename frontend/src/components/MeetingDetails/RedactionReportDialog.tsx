@@ -0,0 +1,95 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+  DialogTrigger,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { ShieldCheck } from 'lucide-react';
+import { invoke } from '@/lib/tauri';
+import Analytics from '@/lib/analytics';
+import { RedactionReportEntry } from '@/types';
+
+interface RedactionReportDialogProps {
+  meetingId: string;
+}
+
+const STAGE_LABELS: Record<RedactionReportEntry['stage'], string> = {
+  storage: 'Redacted in the saved transcript',
+  llm: 'Redacted from the last summary prompt',
+};
+
+export function RedactionReportDialog({ meetingId }: RedactionReportDialogProps) {
+  const [open, setOpen] = useState(false);
+  const [entries, setEntries] = useState<RedactionReportEntry[]>([]);
+  const [isLoading, setIsLoading] = useState(false);
+
+  useEffect(() => {
+    if (!open) return;
+    setIsLoading(true);
+    invoke<RedactionReportEntry[]>('get_redaction_report', { meetingId })
+      .then(setEntries)
+      .catch((error) => {
+        console.error('Failed to load redaction report:', error);
+        setEntries([]);
+      })
+      .finally(() => setIsLoading(false));
+  }, [open, meetingId]);
+
+  const stages = (['storage', 'llm'] as const)
+    .map((stage) => ({ stage, items: entries.filter((e) => e.stage === stage) }))
+    .filter(({ items }) => items.length > 0);
+
+  return (
+    <Dialog open={open} onOpenChange={setOpen}>
+      <DialogTrigger asChild>
+        <Button
+          size="sm"
+          variant="outline"
+          title="See what personal data was redacted"
+          onClick={() => Analytics.trackButtonClick('view_redaction_report', 'meeting_details')}
+        >
+          <ShieldCheck />
+          <span className="hidden lg:inline">Redactions</span>
+        </Button>
+      </DialogTrigger>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>Redaction report</DialogTitle>
+          <DialogDescription>
+            Placeholders used for personal data in this meeting. The original values are not stored.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="max-h-80 overflow-y-auto space-y-4">
+          {isLoading ? (
+            <p className="text-sm text-gray-500">Loading…</p>
+          ) : stages.length === 0 ? (
+            <p className="text-sm text-gray-500">
+              Nothing was redacted. Redaction can be turned on under Settings → Privacy.
+            </p>
+          ) : (
+            stages.map(({ stage, items }) => (
+              <div key={stage} className="space-y-2">
+                <h4 className="text-sm font-medium text-gray-800">{STAGE_LABELS[stage]}</h4>
+                {items.map((item) => (
+                  <div key={item.placeholder} className="flex items-center justify-between text-sm p-2 border border-gray-200 rounded-md">
+                    <code className="text-gray-800">{item.placeholder}</code>
+                    <span className="text-gray-500">
+                      {item.occurrences} {item.occurrences === 1 ? 'occurrence' : 'occurrences'}
+                    </span>
+                  </div>
+                ))}
+              </div>
+            ))
+          )}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,225 @@
+"use client";
+
+import { useCallback, useEffect, useState } from 'react';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+  DialogTrigger,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { RefreshCw, History, Loader2 } from 'lucide-react';
+import { invoke, listen } from '@/lib/tauri';
+import { toast } from 'sonner';
+import Analytics from '@/lib/analytics';
+import {
+  RetranscriptionEngine,
+  RetranscriptionProgress,
+  TranscriptVersion,
+} from '@/types';
+
+interface RetranscribeDialogProps {
+  meetingId: string;
+  onTranscriptReplaced?: () => Promise<void>;
+}
+
+interface AvailableModel {
+  name: string;
+  status: unknown;
+}
+
+const STAGE_LABELS: Record<RetranscriptionProgress['stage'], string> = {
+  decoding: 'Reading recording…',
+  loading_model: 'Loading model…',
+  transcribing: 'Transcribing',
+  paused: 'Paused while the system is busy',
+  saving: 'Saving transcript…',
+  completed: 'Completed',
+  cancelled: 'Cancelled',
+  failed: 'Failed',
+};
+
+const FINISHED_STAGES: RetranscriptionProgress['stage'][] = ['completed', 'cancelled', 'failed'];
+
+export function RetranscribeDialog({ meetingId, onTranscriptReplaced }: RetranscribeDialogProps) {
+  const [open, setOpen] = useState(false);
+  const [engine, setEngine] = useState<RetranscriptionEngine>('whisper');
+  const [models, setModels] = useState<string[]>([]);
+  const [modelName, setModelName] = useState('');
+  const [progress, setProgress] = useState<RetranscriptionProgress | null>(null);
+  const [versions, setVersions] = useState<TranscriptVersion[]>([]);
+
+  const isRunning = progress !== null && !FINISHED_STAGES.includes(progress.stage);
+
+  const loadVersions = useCallback(async () => {
+    try {
+      setVersions(await invoke<TranscriptVersion[]>('list_transcript_versions', { meetingId }));
+    } catch (error) {
+      console.error('Failed to load transcript versions:', error);
+    }
+  }, [meetingId]);
+
+  // Pick up a job that is already running for this meeting
+  useEffect(() => {
+    invoke<RetranscriptionProgress | null>('get_retranscription_status', { meetingId })
+      .then(setProgress)
+      .catch((error) => console.error('Failed to get re-transcription status:', error));
+  }, [meetingId]);
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    listen<RetranscriptionProgress>('retranscription-progress', async (event) => {
+      if (event.payload.meeting_id !== meetingId) return;
+      setProgress(event.payload);
+
+      if (event.payload.stage === 'completed') {
+        toast.success('Transcript updated', { description: `Re-transcribed with ${event.payload.model_name}` });
+        await onTranscriptReplaced?.();
+        loadVersions();
+      } else if (event.payload.stage === 'failed') {
+        toast.error('Re-transcription failed', { description: event.payload.message });
+      }
+    }).then((fn) => { unlisten = fn; });
+
+    return () => unlisten?.();
+  }, [meetingId, onTranscriptReplaced, loadVersions]);
+
+  // Downloaded models of the selected engine
+  useEffect(() => {
+    if (!open) return;
+    const command = engine === 'whisper' ? 'whisper_get_available_models' : 'parakeet_get_available_models';
+    invoke<AvailableModel[]>(command)
+      .then((all) => {
+        const available = all.filter((m) => m.status === 'Available').map((m) => m.name);
+        setModels(available);
+        setModelName((current) => (available.includes(current) ? current : available[0] ?? ''));
+      })
+      .catch((error) => {
+        console.error(`Failed to list ${engine} models:`, error);
+        setModels([]);
+        setModelName('');
+      });
+    loadVersions();
+  }, [open, engine, loadVersions]);
+
+  const handleStart = async () => {
+    Analytics.trackButtonClick('retranscribe_meeting', 'meeting_details');
+    try {
+      await invoke('start_retranscription', { meetingId, engine, modelName });
+    } catch (error) {
+      toast.error('Could not start re-transcription', { description: String(error) });
+    }
+  };
+
+  const handleCancel = async () => {
+    try {
+      await invoke('cancel_retranscription', { meetingId });
+    } catch (error) {
+      console.error('Failed to cancel re-transcription:', error);
+    }
+  };
+
+  const handleRestore = async (version: TranscriptVersion) => {
+    try {
+      await invoke('restore_transcript_version', { meetingId, versionId: version.id });
+      toast.success(`Restored version ${version.version}`);
+      await onTranscriptReplaced?.();
+      loadVersions();
+    } catch (error) {
+      toast.error('Failed to restore version', { description: String(error) });
+    }
+  };
+
+  const percent = progress && progress.total_chunks > 0
+    ? Math.round((progress.completed_chunks / progress.total_chunks) * 100)
+    : 0;
+
+  return (
+    <Dialog open={open} onOpenChange={setOpen}>
+      <DialogTrigger asChild>
+        <Button size="sm" variant="outline" title="Re-transcribe with a larger model">
+          {isRunning ? <Loader2 className="animate-spin" /> : <RefreshCw />}
+          <span className="hidden lg:inline">{isRunning ? `${percent}%` : 'Improve'}</span>
+        </Button>
+      </DialogTrigger>
+      <DialogContent className="max-w-md">
+        <DialogHeader>
+          <DialogTitle>Re-transcribe recording</DialogTitle>
+          <DialogDescription>
+            Run the saved audio through a larger model in the background. Your edits and speaker
+            labels are kept, and the current transcript is saved as a version you can restore.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-3">
+          <div className="flex gap-2">
+            <select
+              value={engine}
+              onChange={(e) => setEngine(e.target.value as RetranscriptionEngine)}
+              disabled={isRunning}
+              className="px-2 py-1 text-sm border border-gray-300 rounded-md bg-white"
+            >
+              <option value="whisper">Whisper</option>
+              <option value="parakeet">Parakeet</option>
+            </select>
+            <select
+              value={modelName}
+              onChange={(e) => setModelName(e.target.value)}
+              disabled={isRunning || models.length === 0}
+              className="flex-1 px-2 py-1 text-sm border border-gray-300 rounded-md bg-white"
+            >
+              {models.length === 0 && <option value="">No downloaded models</option>}
+              {models.map((name) => (
+                <option key={name} value={name}>{name}</option>
+              ))}
+            </select>
+          </div>
+
+          {progress && (
+            <div className="space-y-1">
+              <div className="flex justify-between text-xs text-gray-600">
+                <span>{STAGE_LABELS[progress.stage]}</span>
+                {progress.total_chunks > 0 && (
+                  <span>{progress.completed_chunks}/{progress.total_chunks} chunks</span>
+                )}
+              </div>
+              <div className="h-2 bg-gray-100 rounded-full overflow-hidden">
+                <div className="h-full bg-blue-500 transition-all" style={{ width: `${percent}%` }} />
+              </div>
+              {progress.message && <p className="text-xs text-gray-500">{progress.message}</p>}
+            </div>
+          )}
+
+          <div className="flex justify-end gap-2">
+            {isRunning ? (
+              <Button variant="outline" onClick={handleCancel}>Cancel</Button>
+            ) : (
+              <Button onClick={handleStart} disabled={!modelName}>Start</Button>
+            )}
+          </div>
+
+          {versions.length > 0 && (
+            <div className="pt-3 border-t space-y-2">
+              <div className="flex items-center gap-2 text-sm font-medium text-gray-900">
+                <History className="w-4 h-4" /> Previous versions
+              </div>
+              {versions.map((version) => (
+                <div key={version.id} className="flex items-center justify-between text-sm">
+                  <div>
+                    <p className="text-gray-800">{version.label}</p>
+                    <p className="text-xs text-gray-500">{new Date(version.created_at).toLocaleString()}</p>
+                  </div>
+                  <Button size="sm" variant="ghost" onClick={() => handleRestore(version)} disabled={isRunning}>
+                    Restore
+                  </Button>
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
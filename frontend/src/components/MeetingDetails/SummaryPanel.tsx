@@ -1,6 +1,6 @@
 "use client";
 
-import { Summary, SummaryResponse, Transcript } from '@/types';
+import { Summary, SummaryProgressEvent, SummaryResponse, Transcript } from '@/types';
 import { EditableTitle } from '@/components/EditableTitle';
 import { BlockNoteSummaryView, BlockNoteSummaryViewRef } from '@/components/AISummary/BlockNoteSummaryView';
 import { EmptyStateSummary } from '@/components/EmptyStateSummary';
@@ -40,6 +40,8 @@ interface SummaryPanelProps {
   onSummaryChange: (summary: Summary) => void;
   onDirtyChange: (isDirty: boolean) => void;
   summaryError: string | null;
+  summaryProgress?: SummaryProgressEvent | null;
+  onCancelSummary?: () => Promise<void>;
   onRegenerateSummary: () => Promise<void>;
   getSummaryStatusMessage: (status: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error') => string;
   availableTemplates: Array<{id: string, name: string, description: string}>;
@@ -74,6 +76,8 @@ export function SummaryPanel({
   onSummaryChange,
   onDirtyChange,
   summaryError,
+  summaryProgress = null,
+  onCancelSummary,
   onRegenerateSummary,
   getSummaryStatusMessage,
   availableTemplates,
@@ -152,13 +156,33 @@ export function SummaryPanel({
               isModelConfigLoading={isModelConfigLoading}
             />
           </div>
-          {/* Loading spinner */}
-          <div className="flex items-center justify-center flex-1">
-            <div className="text-center">
-              <div className="inline-block animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500 mb-4"></div>
-              <p className="text-gray-600">Generating AI Summary...</p>
+          {summaryProgress?.partial_markdown ? (
+            /* Streamed output so far */
+            <div className="flex-1 overflow-y-auto min-h-0 px-6 pb-6">
+              <div className="flex items-center justify-between mb-2">
+                <p className="text-sm text-gray-500">{getSummaryStatusMessage(summaryStatus)}</p>
+                {onCancelSummary && (
+                  <button onClick={onCancelSummary} className="text-sm text-gray-500 hover:text-red-600">
+                    Cancel
+                  </button>
+                )}
+              </div>
+              <pre className="whitespace-pre-wrap font-sans text-sm text-gray-800">{summaryProgress.partial_markdown}</pre>
             </div>
-          </div>
+          ) : (
+            /* Loading spinner */
+            <div className="flex items-center justify-center flex-1">
+              <div className="text-center">
+                <div className="inline-block animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500 mb-4"></div>
+                <p className="text-gray-600">Generating AI Summary...</p>
+                {onCancelSummary && (
+                  <button onClick={onCancelSummary} className="mt-2 text-sm text-gray-500 hover:text-red-600">
+                    Cancel
+                  </button>
+                )}
+              </div>
+            </div>
+          )}
         </div>
       ) : !aiSummary ? (
         <div className="flex flex-col h-full">
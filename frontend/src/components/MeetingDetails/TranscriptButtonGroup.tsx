@@ -4,19 +4,26 @@ import { Button } from '@/components/ui/button';
 import { ButtonGroup } from '@/components/ui/button-group';
 import { Copy, FolderOpen } from 'lucide-react';
 import Analytics from '@/lib/analytics';
+import { RetranscribeDialog } from './RetranscribeDialog';
+import { LowConfidenceDialog } from './LowConfidenceDialog';
+import { RedactionReportDialog } from './RedactionReportDialog';
 
 
 interface TranscriptButtonGroupProps {
+  meetingId: string;
   transcriptCount: number;
   onCopyTranscript: () => void;
   onOpenMeetingFolder: () => Promise<void>;
+  onTranscriptReplaced?: () => Promise<void>;
 }
 
 
 export function TranscriptButtonGroup({
+  meetingId,
   transcriptCount,
   onCopyTranscript,
-  onOpenMeetingFolder
+  onOpenMeetingFolder,
+  onTranscriptReplaced
 }: TranscriptButtonGroupProps) {
   return (
     <div className="flex items-center justify-center w-full gap-2">
@@ -48,6 +55,12 @@ export function TranscriptButtonGroup({
           <FolderOpen className="xl:mr-2" size={18} />
           <span className="hidden lg:inline">Recording</span>
         </Button>
+
+        <RetranscribeDialog meetingId={meetingId} onTranscriptReplaced={onTranscriptReplaced} />
+
+        <LowConfidenceDialog meetingId={meetingId} transcriptCount={transcriptCount} />
+
+        <RedactionReportDialog meetingId={meetingId} />
       </ButtonGroup>
     </div>
   );
@@ -3,32 +3,47 @@
 import { Transcript } from '@/types';
 import { TranscriptView } from '@/components/TranscriptView';
 import { TranscriptButtonGroup } from './TranscriptButtonGroup';
+import { TranscriptSource } from '@/hooks/meeting-details/useSummaryGeneration';
 
 interface TranscriptPanelProps {
+  meetingId: string;
   transcripts: Transcript[];
   customPrompt: string;
   onPromptChange: (value: string) => void;
   onCopyTranscript: () => void;
   onOpenMeetingFolder: () => Promise<void>;
+  onTranscriptReplaced?: () => Promise<void>;
   isRecording: boolean;
+  transcriptSource: TranscriptSource;
+  onTranscriptSourceChange: (source: TranscriptSource) => void;
 }
 
 export function TranscriptPanel({
+  meetingId,
   transcripts,
   customPrompt,
   onPromptChange,
   onCopyTranscript,
   onOpenMeetingFolder,
-  isRecording
+  onTranscriptReplaced,
+  isRecording,
+  transcriptSource,
+  onTranscriptSourceChange
 }: TranscriptPanelProps) {
+  // Bilingual meetings carry a translation track that can be summarized instead
+  const translationLanguage = transcripts.find(t => t.translation)?.translation_language;
+  const hasTranslation = transcripts.some(t => t.translation);
+
   return (
     <div className="hidden md:flex md:w-1/4 lg:w-1/3 min-w-0 border-r border-gray-200 bg-white flex-col relative shrink-0">
       {/* Title area */}
       <div className="p-4 border-b border-gray-200">
         <TranscriptButtonGroup
+          meetingId={meetingId}
           transcriptCount={transcripts?.length || 0}
           onCopyTranscript={onCopyTranscript}
           onOpenMeetingFolder={onOpenMeetingFolder}
+          onTranscriptReplaced={onTranscriptReplaced}
         />
       </div>
 
@@ -40,6 +55,21 @@ export function TranscriptPanel({
       {/* Custom prompt input at bottom of transcript section */}
       {!isRecording && transcripts.length > 0 && (
         <div className="p-1 border-t border-gray-200">
+          {hasTranslation && (
+            <div className="flex items-center justify-between px-2 py-1 text-xs text-gray-600">
+              <span>Summarize from</span>
+              <select
+                value={transcriptSource}
+                onChange={(e) => onTranscriptSourceChange(e.target.value as TranscriptSource)}
+                className="px-2 py-1 text-xs bg-white border border-gray-200 rounded-md focus:outline-none focus:ring-1 focus:ring-blue-500"
+              >
+                <option value="original">Original transcript</option>
+                <option value="translation">
+                  Translation{translationLanguage ? ` (${translationLanguage})` : ''}
+                </option>
+              </select>
+            </div>
+          )}
           <textarea
             placeholder="Add context for AI summary. For example people involved, meeting overview, objective etc..."
             className="w-full px-3 py-2 border border-gray-200 rounded-md text-sm focus:outline-none focus:ring-1 focus:ring-blue-500 focus:border-blue-500 bg-white shadow-sm min-h-[80px] resize-y"
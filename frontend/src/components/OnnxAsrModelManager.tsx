@@ -0,0 +1,175 @@
+import React, { useState, useEffect } from 'react';
+import { invoke } from '@/lib/tauri';
+import { toast } from 'sonner';
+import { Trash2 } from 'lucide-react';
+import { OnnxAsrAPI, OnnxAsrModelInfo } from '../lib/onnxAsr';
+import { ImportModelDialog } from '@/components/ImportModelDialog';
+
+interface OnnxAsrModelManagerProps {
+  selectedModel?: string;
+  onModelSelect?: (modelName: string) => void;
+  className?: string;
+  autoSave?: boolean;
+}
+
+const DECODER_LABELS: Record<string, string> = {
+  ctc: 'CTC',
+  transducer: 'Transducer',
+  attention: 'Encoder-decoder',
+};
+
+export function OnnxAsrModelManager({
+  selectedModel,
+  onModelSelect,
+  className = '',
+  autoSave = false
+}: OnnxAsrModelManagerProps) {
+  const [models, setModels] = useState<OnnxAsrModelInfo[]>([]);
+  const [modelsDir, setModelsDir] = useState<string>('');
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    const initializeModels = async () => {
+      try {
+        await OnnxAsrAPI.init();
+        setModels(await OnnxAsrAPI.getAvailableModels());
+        setModelsDir(await OnnxAsrAPI.getModelsDirectory());
+      } catch (err) {
+        console.error('Failed to initialize ONNX ASR:', err);
+        setError(err instanceof Error ? err.message : String(err));
+      } finally {
+        setLoading(false);
+      }
+    };
+
+    initializeModels();
+  }, []);
+
+  const saveModelSelection = async (modelName: string) => {
+    try {
+      await invoke('api_save_transcript_config', {
+        provider: 'onnxAsr',
+        model: modelName,
+        apiKey: null
+      });
+    } catch (error) {
+      console.error('Failed to save model selection:', error);
+    }
+  };
+
+  const selectModel = async (model: OnnxAsrModelInfo) => {
+    if (model.status !== 'Available') return;
+
+    if (onModelSelect) {
+      onModelSelect(model.name);
+    }
+    if (autoSave) {
+      await saveModelSelection(model.name);
+    }
+    toast.success(`Switched to ${model.display_name}`, {
+      duration: 3000
+    });
+  };
+
+  const removeModel = async (modelName: string) => {
+    try {
+      await OnnxAsrAPI.removeModel(modelName);
+      setModels(await OnnxAsrAPI.getAvailableModels());
+      toast.success(`${modelName} removed`, {
+        duration: 3000
+      });
+
+      if (selectedModel === modelName && onModelSelect) {
+        onModelSelect('');
+      }
+    } catch (err) {
+      console.error('Failed to remove ONNX ASR model:', err);
+      toast.error(`Failed to remove ${modelName}`, {
+        description: err instanceof Error ? err.message : String(err),
+        duration: 4000
+      });
+    }
+  };
+
+  const handleImported = async () => {
+    setModels(await OnnxAsrAPI.getAvailableModels());
+  };
+
+  if (loading) {
+    return (
+      <div className={`space-y-3 ${className}`}>
+        <div className="animate-pulse h-20 bg-gray-100 rounded-lg"></div>
+      </div>
+    );
+  }
+
+  if (error) {
+    return (
+      <div className={`bg-red-50 border border-red-200 rounded-lg p-4 ${className}`}>
+        <p className="text-sm text-red-800">Failed to load models</p>
+        <p className="text-xs text-red-600 mt-1">{error}</p>
+      </div>
+    );
+  }
+
+  return (
+    <div className={`space-y-3 ${className}`}>
+      <div className="flex items-center justify-between">
+        <span className="text-sm font-medium text-gray-700">ONNX Models</span>
+        <ImportModelDialog engine="onnxAsr" onImported={handleImported} />
+      </div>
+
+      {models.length === 0 && (
+        <p className="text-xs text-gray-500">
+          No models yet. Import a folder containing a <code>manifest.json</code> and its ONNX files
+          {modelsDir && <>, or place one in <code>{modelsDir}</code></>}.
+        </p>
+      )}
+
+      {models.map(model => {
+        const isSelected = selectedModel === model.name;
+        const invalidReason = typeof model.status === 'object' ? model.status.Invalid : null;
+        return (
+          <div
+            key={model.name}
+            onClick={() => selectModel(model)}
+            className={`rounded-lg border p-3 transition-colors ${
+              isSelected ? 'border-blue-500 bg-blue-50' : 'border-gray-200'
+            } ${invalidReason ? 'opacity-70 cursor-not-allowed' : 'cursor-pointer hover:border-gray-300'}`}
+          >
+            <div className="flex items-start justify-between gap-2">
+              <div>
+                <p className="text-sm font-medium text-gray-900">
+                  {model.display_name}
+                  <span className="ml-2 text-xs text-gray-500">{model.name}</span>
+                </p>
+                {model.description && <p className="text-xs text-gray-600 mt-0.5">{model.description}</p>}
+                <p className="text-xs text-gray-500 mt-1">
+                  {[
+                    DECODER_LABELS[model.decoder],
+                    model.quantized ? 'Int8' : 'FP32',
+                    model.languages.length > 0 ? model.languages.join(', ') : 'Auto language',
+                    `${model.size_mb} MB`,
+                  ].filter(Boolean).join(' • ')}
+                </p>
+                {invalidReason && <p className="text-xs text-red-600 mt-1">{invalidReason}</p>}
+              </div>
+              <button
+                type="button"
+                title="Remove model"
+                className="text-gray-400 hover:text-red-600"
+                onClick={(e) => {
+                  e.stopPropagation();
+                  removeModel(model.name);
+                }}
+              >
+                <Trash2 className="w-4 h-4" />
+              </button>
+            </div>
+          </div>
+        );
+      })}
+    </div>
+  );
+}
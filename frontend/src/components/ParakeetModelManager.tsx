@@ -10,6 +10,7 @@ import {
   getModelDisplayName,
   formatFileSize
 } from '../lib/parakeet';
+import { ImportModelDialog } from '@/components/ImportModelDialog';
 
 interface ParakeetModelManagerProps {
   selectedModel?: string;
@@ -340,6 +341,31 @@ export function ParakeetModelManager({
     }
   };
 
+  const removeCustomModel = async (modelName: string) => {
+    try {
+      await ParakeetAPI.removeCustomModel(modelName);
+      setModels(await ParakeetAPI.getAvailableModels());
+
+      toast.success(`${modelName} removed`, {
+        duration: 3000
+      });
+
+      if (selectedModel === modelName && onModelSelect) {
+        onModelSelect('');
+      }
+    } catch (err) {
+      console.error('Failed to remove custom model:', err);
+      toast.error(`Failed to remove ${modelName}`, {
+        description: err instanceof Error ? err.message : String(err),
+        duration: 4000
+      });
+    }
+  };
+
+  const handleImported = async () => {
+    setModels(await ParakeetAPI.getAvailableModels());
+  };
+
   if (loading) {
     return (
       <div className={`space-y-3 ${className}`}>
@@ -364,8 +390,9 @@ export function ParakeetModelManager({
     m.name === 'parakeet-tdt-0.6b-v3-int8'
   );
   const otherModels = models.filter(m =>
-    m.name !== 'parakeet-tdt-0.6b-v3-int8'
+    m.name !== 'parakeet-tdt-0.6b-v3-int8' && !m.custom
   );
+  const customModels = models.filter(m => m.custom);
 
   return (
     <div className={`space-y-3 ${className}`}>
@@ -410,6 +437,31 @@ export function ParakeetModelManager({
         </div>
       )}
 
+      {/* Custom Models */}
+      <div className="space-y-3 pt-2">
+        <div className="flex items-center justify-between">
+          <span className="text-sm font-medium text-gray-700">Custom Models</span>
+          <ImportModelDialog engine="parakeet" onImported={handleImported} />
+        </div>
+        {customModels.map(model => (
+          <ModelCard
+            key={model.name}
+            model={model}
+            isSelected={selectedModel === model.name}
+            isRecommended={false}
+            onSelect={() => {
+              if (model.status === 'Available') {
+                selectModel(model.name);
+              }
+            }}
+            onDownload={() => {}}
+            onCancel={() => {}}
+            onDelete={() => removeCustomModel(model.name)}
+            isDownloading={false}
+          />
+        ))}
+      </div>
+
       {/* Helper text */}
       {selectedModel && (
         <motion.div
@@ -454,6 +506,7 @@ function ModelCard({
 
   const isAvailable = model.status === 'Available';
   const isMissing = model.status === 'Missing';
+  const isCustom = !!model.custom;
   const isError = typeof model.status === 'object' && 'Error' in model.status;
   const isCorrupted = typeof model.status === 'object' && 'Corrupted' in model.status;
   const downloadProgress =
@@ -531,7 +584,7 @@ function ModelCard({
                         onDelete();
                       }}
                       className="text-gray-400 hover:text-red-600 transition-colors p-1"
-                      title="Delete model to free up space"
+                      title={isCustom ? 'Remove imported model' : 'Delete model to free up space'}
                     >
                       <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                         <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16" />
@@ -554,7 +607,19 @@ function ModelCard({
               </button>
             )}
 
-            {downloadProgress === null && isError && (
+            {downloadProgress === null && (isError || isCorrupted) && isCustom && (
+              <button
+                onClick={(e) => {
+                  e.stopPropagation();
+                  onDelete();
+                }}
+                className="bg-red-600 text-white px-3 py-1.5 rounded-md text-sm font-medium hover:bg-red-700 transition-colors"
+              >
+                Remove
+              </button>
+            )}
+
+            {downloadProgress === null && isError && !isCustom && (
               <button
                 onClick={(e) => {
                   e.stopPropagation();
@@ -566,7 +631,7 @@ function ModelCard({
               </button>
             )}
 
-            {isCorrupted && (
+            {isCorrupted && !isCustom && (
               <div className="flex gap-2">
                 <button
                   onClick={(e) => {
@@ -31,6 +31,7 @@ interface NotificationSettings {
     show_transcription_complete: boolean
     show_meeting_reminders: boolean
     show_system_errors: boolean
+    show_audio_quality_warnings?: boolean
     meeting_reminder_minutes: number[]
   }
 }
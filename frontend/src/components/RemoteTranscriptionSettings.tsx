@@ -0,0 +1,151 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@/lib/tauri';
+import { toast } from 'sonner';
+import { Input } from './ui/input';
+import { Button } from './ui/button';
+import { Label } from './ui/label';
+import { Eye, EyeOff } from 'lucide-react';
+import type { TranscriptModelProps } from './TranscriptSettings';
+
+interface RemoteTranscriptionSettingsProps {
+    config: TranscriptModelProps;
+    onSaved: (config: TranscriptModelProps) => void;
+}
+
+// Matches DEFAULT_MAX_RETRIES / MAX_RETRIES_LIMIT in remote_provider.rs
+const DEFAULT_MAX_RETRIES = 2;
+const MAX_RETRIES_LIMIT = 5;
+
+const ENDPOINT_PRESETS = [
+    { label: 'OpenAI', endpoint: 'https://api.openai.com/v1', model: 'whisper-1' },
+    { label: 'Groq', endpoint: 'https://api.groq.com/openai/v1', model: 'whisper-large-v3-turbo' },
+    { label: 'Local server', endpoint: 'http://localhost:8000/v1', model: 'Systran/faster-whisper-small' },
+];
+
+export function RemoteTranscriptionSettings({ config, onSaved }: RemoteTranscriptionSettingsProps) {
+    const [endpoint, setEndpoint] = useState<string>(config.endpoint || '');
+    const [model, setModel] = useState<string>(config.model || '');
+    const [apiKey, setApiKey] = useState<string>('');
+    const [maxRetries, setMaxRetries] = useState<number>(config.maxRetries ?? DEFAULT_MAX_RETRIES);
+    const [showApiKey, setShowApiKey] = useState<boolean>(false);
+    const [isSaving, setIsSaving] = useState<boolean>(false);
+
+    useEffect(() => {
+        invoke<string>('api_get_transcript_api_key', { provider: 'openaiCompatible' })
+            .then((key) => setApiKey(key || ''))
+            .catch((err) => console.error('Error fetching API key:', err));
+    }, []);
+
+    const isValidEndpoint = /^https?:\/\/\S+$/.test(endpoint.trim());
+
+    const handleSave = async () => {
+        setIsSaving(true);
+        try {
+            const saved: TranscriptModelProps = {
+                provider: 'openaiCompatible',
+                model: model.trim(),
+                apiKey: apiKey.trim() || null,
+                endpoint: endpoint.trim(),
+                maxRetries,
+            };
+            await invoke('api_save_transcript_config', {
+                provider: saved.provider,
+                model: saved.model,
+                apiKey: saved.apiKey,
+                endpoint: saved.endpoint,
+                maxRetries: saved.maxRetries,
+            });
+            onSaved(saved);
+            toast.success('Transcription server saved', {
+                description: 'Chunks fall back to a local model when the server is unreachable.',
+            });
+        } catch (error) {
+            console.error('Failed to save remote transcription config:', error);
+            toast.error('Failed to save transcription server', { description: String(error) });
+        } finally {
+            setIsSaving(false);
+        }
+    };
+
+    return (
+        <div className="space-y-4 mx-1">
+            <div>
+                <Label className="block text-sm font-medium text-gray-700 mb-1">Server URL</Label>
+                <Input
+                    value={endpoint}
+                    onChange={(e) => setEndpoint(e.target.value)}
+                    placeholder="http://localhost:8000/v1"
+                    className="focus:ring-1 focus:ring-blue-500 focus:border-blue-500"
+                />
+                <div className="flex flex-wrap gap-2 mt-2">
+                    {ENDPOINT_PRESETS.map((preset) => (
+                        <Button
+                            key={preset.label}
+                            type="button"
+                            size="sm"
+                            variant="outline"
+                            onClick={() => {
+                                setEndpoint(preset.endpoint);
+                                setModel(preset.model);
+                            }}
+                        >
+                            {preset.label}
+                        </Button>
+                    ))}
+                </div>
+                <p className="text-xs text-gray-500 mt-1">
+                    Any server exposing <code>/v1/audio/transcriptions</code> (OpenAI, Groq, faster-whisper, whisper.cpp server).
+                </p>
+            </div>
+
+            <div>
+                <Label className="block text-sm font-medium text-gray-700 mb-1">Model</Label>
+                <Input
+                    value={model}
+                    onChange={(e) => setModel(e.target.value)}
+                    placeholder="whisper-1"
+                    className="focus:ring-1 focus:ring-blue-500 focus:border-blue-500"
+                />
+            </div>
+
+            <div>
+                <Label className="block text-sm font-medium text-gray-700 mb-1">API Key (optional for self-hosted servers)</Label>
+                <div className="relative">
+                    <Input
+                        type={showApiKey ? 'text' : 'password'}
+                        value={apiKey}
+                        onChange={(e) => setApiKey(e.target.value)}
+                        placeholder="Enter your API key"
+                        className="pr-10 focus:ring-1 focus:ring-blue-500 focus:border-blue-500"
+                    />
+                    <div className="absolute inset-y-0 right-0 pr-1 flex items-center">
+                        <Button type="button" variant="ghost" size="icon" onClick={() => setShowApiKey(!showApiKey)}>
+                            {showApiKey ? <EyeOff className="h-4 w-4" /> : <Eye className="h-4 w-4" />}
+                        </Button>
+                    </div>
+                </div>
+            </div>
+
+            <div>
+                <Label className="block text-sm font-medium text-gray-700 mb-1">Retries before using the local model</Label>
+                <Input
+                    type="number"
+                    min={0}
+                    max={MAX_RETRIES_LIMIT}
+                    value={maxRetries}
+                    onChange={(e) => {
+                        const value = parseInt(e.target.value, 10);
+                        setMaxRetries(Number.isNaN(value) ? 0 : Math.min(Math.max(value, 0), MAX_RETRIES_LIMIT));
+                    }}
+                    className="w-24 focus:ring-1 focus:ring-blue-500 focus:border-blue-500"
+                />
+            </div>
+
+            <div className="flex justify-end">
+                <Button onClick={handleSave} disabled={isSaving || !isValidEndpoint || !model.trim()}>
+                    {isSaving ? 'Saving…' : 'Save'}
+                </Button>
+            </div>
+        </div>
+    );
+}
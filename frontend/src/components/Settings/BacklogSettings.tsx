@@ -0,0 +1,124 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Switch } from '@/components/ui/switch';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Hourglass } from 'lucide-react';
+import { toast } from 'sonner';
+import { BacklogSettings as BacklogSettingsType } from '@/types';
+
+const defaultSettings: BacklogSettingsType = {
+  lag_threshold_secs: 20,
+  recover_below_secs: 5,
+  degrade: false,
+  reduce_beam: true,
+  switch_model: true,
+  defer_chunks: true,
+  fallback_whisper_model: null,
+};
+
+const ACTIONS: { key: 'reduce_beam' | 'switch_model' | 'defer_chunks'; label: string; description: string }[] = [
+  { key: 'reduce_beam', label: '1. Narrower beam', description: 'Whisper decodes with a beam of 1 and skips re-decoding unsure segments' },
+  { key: 'switch_model', label: '2. Faster model', description: 'Switch to the quantized or next smaller installed model' },
+  { key: 'defer_chunks', label: '3. Defer new audio', description: 'Park new chunks and transcribe them once caught up or after the meeting' },
+];
+
+export function BacklogSettings() {
+  const [settings, setSettings] = useState<BacklogSettingsType>(defaultSettings);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<BacklogSettingsType>('get_backlog_settings')
+      .then(setSettings)
+      .catch((error) => console.error('Failed to load backlog settings:', error));
+  }, []);
+
+  const saveSettings = useCallback(async (newSettings: BacklogSettingsType) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_backlog_settings', { settings: newSettings });
+      setSettings(newSettings);
+    } catch (error) {
+      console.error('Failed to save backlog settings:', error);
+      toast.error('Failed to save backlog settings', { description: String(error) });
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  return (
+    <div className="space-y-4 pt-6 mt-6 border-t">
+      <div className="flex items-center space-x-3">
+        <Hourglass className="w-5 h-5 text-blue-500" />
+        <div>
+          <h4 className="font-medium text-gray-900">Falling behind</h4>
+          <p className="text-sm text-gray-500">
+            Warn when live transcripts arrive late, and optionally trade quality for speed until transcription catches up.
+          </p>
+        </div>
+      </div>
+
+      <div className="flex items-center gap-3">
+        <Label htmlFor="lag-threshold" className="text-sm text-gray-700 whitespace-nowrap">Warn after</Label>
+        <Input
+          id="lag-threshold"
+          type="number"
+          min={10}
+          max={300}
+          value={settings.lag_threshold_secs}
+          onChange={(e) => setSettings({ ...settings, lag_threshold_secs: Number(e.target.value) })}
+          onBlur={() => saveSettings(settings)}
+          disabled={isSaving}
+          className="w-24"
+        />
+        <span className="text-sm text-gray-600">seconds of delay</span>
+      </div>
+
+      <div className="flex items-center justify-between">
+        <div>
+          <Label htmlFor="backlog-degrade" className="font-medium">Degrade automatically</Label>
+          <p className="text-sm text-gray-500">
+            Steps are applied one at a time while the delay persists and undone once the backlog clears.
+          </p>
+        </div>
+        <Switch
+          id="backlog-degrade"
+          checked={settings.degrade}
+          onCheckedChange={() => saveSettings({ ...settings, degrade: !settings.degrade })}
+          disabled={isSaving}
+        />
+      </div>
+
+      {ACTIONS.map((action) => (
+        <div key={action.key} className="flex items-center justify-between pl-4">
+          <div>
+            <Label htmlFor={`backlog-${action.key}`} className="font-medium">{action.label}</Label>
+            <p className="text-sm text-gray-500">{action.description}</p>
+          </div>
+          <Switch
+            id={`backlog-${action.key}`}
+            checked={settings[action.key]}
+            onCheckedChange={() => saveSettings({ ...settings, [action.key]: !settings[action.key] })}
+            disabled={isSaving || !settings.degrade}
+          />
+        </div>
+      ))}
+
+      {settings.degrade && settings.switch_model && (
+        <div className="flex items-center gap-3 pl-4">
+          <Label htmlFor="fallback-model" className="text-sm text-gray-700 whitespace-nowrap">Fallback Whisper model</Label>
+          <Input
+            id="fallback-model"
+            value={settings.fallback_whisper_model ?? ''}
+            onChange={(e) => setSettings({ ...settings, fallback_whisper_model: e.target.value || null })}
+            onBlur={() => saveSettings(settings)}
+            placeholder="Automatic (e.g. small-q5_0)"
+            disabled={isSaving}
+          />
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,86 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Switch } from '@/components/ui/switch';
+import { Label } from '@/components/ui/label';
+import { Gauge } from 'lucide-react';
+import { toast } from 'sonner';
+import { ConfidenceSettings as ConfidenceSettingsType } from '@/types';
+
+const defaultSettings: ConfidenceSettingsType = {
+  low_confidence_threshold: 0.6,
+  redecode_low_confidence: true,
+};
+
+export function ConfidenceSettings() {
+  const [settings, setSettings] = useState<ConfidenceSettingsType>(defaultSettings);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<ConfidenceSettingsType>('get_confidence_settings')
+      .then(setSettings)
+      .catch((error) => console.error('Failed to load confidence settings:', error));
+  }, []);
+
+  const saveSettings = useCallback(async (newSettings: ConfidenceSettingsType) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_confidence_settings', { settings: newSettings });
+      setSettings(newSettings);
+    } catch (error) {
+      console.error('Failed to save confidence settings:', error);
+      toast.error('Failed to save confidence settings', { description: String(error) });
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  return (
+    <div className="space-y-4 pt-6 mt-6 border-t">
+      <div className="flex items-center space-x-3">
+        <Gauge className="w-5 h-5 text-blue-500" />
+        <div>
+          <h4 className="font-medium text-gray-900">Low-confidence passages</h4>
+          <p className="text-sm text-gray-500">
+            Segments below this confidence are listed for review in the meeting&apos;s transcript.
+          </p>
+        </div>
+      </div>
+
+      <div className="flex items-center gap-3">
+        <Label className="text-sm text-gray-700 whitespace-nowrap">Threshold</Label>
+        <input
+          type="range"
+          min={0.1}
+          max={0.95}
+          step={0.05}
+          value={settings.low_confidence_threshold}
+          onChange={(e) => setSettings({ ...settings, low_confidence_threshold: parseFloat(e.target.value) })}
+          onMouseUp={() => saveSettings(settings)}
+          onKeyUp={() => saveSettings(settings)}
+          disabled={isSaving}
+          className="flex-1"
+        />
+        <span className="text-sm text-gray-600 w-10 text-right">
+          {Math.round(settings.low_confidence_threshold * 100)}%
+        </span>
+      </div>
+
+      <div className="flex items-center justify-between">
+        <div>
+          <Label htmlFor="redecode-low-confidence" className="font-medium">Re-decode unsure segments</Label>
+          <p className="text-sm text-gray-500">
+            Local Whisper decodes low-confidence chunks again with a wider beam. Uses more CPU/GPU while recording.
+          </p>
+        </div>
+        <Switch
+          id="redecode-low-confidence"
+          checked={settings.redecode_low_confidence}
+          onCheckedChange={() => saveSettings({ ...settings, redecode_low_confidence: !settings.redecode_low_confidence })}
+          disabled={isSaving}
+        />
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,314 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Switch } from '@/components/ui/switch';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Button } from '@/components/ui/button';
+import { BookOpen, Wand2, X, ArrowRight } from 'lucide-react';
+import { toast } from 'sonner';
+
+interface GlossaryReplacement {
+  from: string;
+  to: string;
+}
+
+interface GlossarySettings {
+  enabled: boolean;
+  global_terms: string[];
+  template_terms: Record<string, string[]>;
+  active_template: string | null;
+  replacements: GlossaryReplacement[];
+  fuzzy_matching: boolean;
+}
+
+interface TemplateInfo {
+  id: string;
+  name: string;
+  description: string;
+}
+
+const defaultSettings: GlossarySettings = {
+  enabled: true,
+  global_terms: [],
+  template_terms: {},
+  active_template: null,
+  replacements: [],
+  fuzzy_matching: true,
+};
+
+// '' = global list
+const GLOBAL_LIST = '';
+
+export function GlossarySettings() {
+  const [settings, setSettings] = useState<GlossarySettings>(defaultSettings);
+  const [templates, setTemplates] = useState<TemplateInfo[]>([]);
+  const [editingList, setEditingList] = useState<string>(GLOBAL_LIST);
+  const [newTerm, setNewTerm] = useState('');
+  const [replacementFrom, setReplacementFrom] = useState('');
+  const [replacementTo, setReplacementTo] = useState('');
+  const [previewText, setPreviewText] = useState('');
+  const [previewResult, setPreviewResult] = useState<string | null>(null);
+  const [isLoading, setIsLoading] = useState(true);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    if (typeof window === 'undefined') return;
+
+    const load = async () => {
+      try {
+        const [glossary, templateList] = await Promise.all([
+          invoke<GlossarySettings>('get_glossary'),
+          invoke<TemplateInfo[]>('api_list_templates'),
+        ]);
+        setSettings(glossary);
+        setTemplates(templateList);
+      } catch (error) {
+        console.error('Failed to load glossary:', error);
+      } finally {
+        setIsLoading(false);
+      }
+    };
+
+    load();
+  }, []);
+
+  // Run a glossary command that returns the updated settings
+  const runUpdate = useCallback(async (command: string, args: Record<string, unknown>) => {
+    setIsSaving(true);
+    try {
+      const updated = await invoke<GlossarySettings>(command, args);
+      setSettings(updated);
+      return true;
+    } catch (error) {
+      console.error(`Glossary update failed (${command}):`, error);
+      toast.error('Failed to update glossary', { description: String(error) });
+      return false;
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  const saveSettings = useCallback(async (newSettings: GlossarySettings) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_glossary', { settings: newSettings });
+      setSettings(newSettings);
+    } catch (error) {
+      console.error('Failed to save glossary:', error);
+      toast.error('Failed to save glossary', { description: String(error) });
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  const templateId = editingList === GLOBAL_LIST ? null : editingList;
+  const visibleTerms = templateId ? settings.template_terms[templateId] || [] : settings.global_terms;
+
+  const handleAddTerm = async () => {
+    if (!newTerm.trim()) return;
+    if (await runUpdate('add_glossary_term', { term: newTerm, templateId })) {
+      setNewTerm('');
+    }
+  };
+
+  const handleAddReplacement = async () => {
+    if (!replacementFrom.trim()) return;
+    if (await runUpdate('add_glossary_replacement', { from: replacementFrom, to: replacementTo })) {
+      setReplacementFrom('');
+      setReplacementTo('');
+    }
+  };
+
+  const handlePreview = async () => {
+    try {
+      setPreviewResult(await invoke<string>('preview_glossary', { text: previewText }));
+    } catch (error) {
+      console.error('Glossary preview failed:', error);
+    }
+  };
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center justify-center p-8">
+        <div className="animate-spin rounded-full h-6 w-6 border-b-2 border-gray-900"></div>
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-6">
+      {/* Header */}
+      <div className="flex items-center justify-between">
+        <div>
+          <h3 className="text-lg font-semibold">Custom Vocabulary</h3>
+          <p className="text-sm text-gray-500">
+            Teach transcription your product names, customer names and acronyms
+          </p>
+        </div>
+        <div className="flex items-center space-x-2">
+          <Switch
+            id="glossary-enabled"
+            checked={settings.enabled}
+            onCheckedChange={() => saveSettings({ ...settings, enabled: !settings.enabled })}
+            disabled={isSaving}
+          />
+          <Label htmlFor="glossary-enabled" className="font-medium">
+            {settings.enabled ? 'Enabled' : 'Disabled'}
+          </Label>
+        </div>
+      </div>
+
+      {/* Terms */}
+      <div className="space-y-4 pt-4 border-t">
+        <div className="flex items-center justify-between">
+          <div className="flex items-center space-x-3">
+            <BookOpen className="w-5 h-5 text-blue-500" />
+            <div>
+              <h4 className="font-medium text-gray-900">Terms</h4>
+              <p className="text-sm text-gray-500">
+                Correct spellings. Whisper is primed with them, and near-misses in any engine's output are fixed.
+              </p>
+            </div>
+          </div>
+          <select
+            value={editingList}
+            onChange={(e) => setEditingList(e.target.value)}
+            className="px-2 py-1 text-sm border border-gray-300 rounded-md bg-white"
+          >
+            <option value={GLOBAL_LIST}>All meetings</option>
+            {templates.map((t) => (
+              <option key={t.id} value={t.id}>Template: {t.name}</option>
+            ))}
+          </select>
+        </div>
+
+        <div className="flex flex-wrap gap-2">
+          {visibleTerms.length === 0 && (
+            <p className="text-sm text-gray-400">No terms yet</p>
+          )}
+          {visibleTerms.map((term) => (
+            <span key={term} className="flex items-center gap-1 px-2 py-1 text-sm bg-blue-50 border border-blue-200 rounded-md text-blue-800">
+              {term}
+              <button
+                onClick={() => runUpdate('remove_glossary_term', { term, templateId })}
+                disabled={isSaving}
+                className="text-blue-400 hover:text-blue-700"
+                aria-label={`Remove ${term}`}
+              >
+                <X className="w-3 h-3" />
+              </button>
+            </span>
+          ))}
+        </div>
+
+        <div className="flex gap-2">
+          <Input
+            value={newTerm}
+            onChange={(e) => setNewTerm(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handleAddTerm()}
+            placeholder="e.g. Meetily, Kubernetes, OKR"
+            disabled={isSaving}
+          />
+          <Button onClick={handleAddTerm} disabled={isSaving || !newTerm.trim()}>Add</Button>
+        </div>
+
+        <div className="flex items-center justify-between">
+          <div>
+            <Label htmlFor="glossary-active-template" className="font-medium">Template terms for recordings</Label>
+            <p className="text-sm text-gray-500">Which template's terms are used in addition to the global list</p>
+          </div>
+          <select
+            id="glossary-active-template"
+            value={settings.active_template ?? ''}
+            onChange={(e) => saveSettings({ ...settings, active_template: e.target.value || null })}
+            disabled={isSaving}
+            className="px-2 py-1 text-sm border border-gray-300 rounded-md bg-white"
+          >
+            <option value="">None</option>
+            {templates.map((t) => (
+              <option key={t.id} value={t.id}>{t.name}</option>
+            ))}
+          </select>
+        </div>
+
+        <div className="flex items-center justify-between">
+          <div>
+            <Label htmlFor="glossary-fuzzy" className="font-medium">Fuzzy &amp; phonetic matching</Label>
+            <p className="text-sm text-gray-500">Fix misspellings that sound like a term (e.g. &quot;Meetly&quot; → &quot;Meetily&quot;)</p>
+          </div>
+          <Switch
+            id="glossary-fuzzy"
+            checked={settings.fuzzy_matching}
+            onCheckedChange={() => saveSettings({ ...settings, fuzzy_matching: !settings.fuzzy_matching })}
+            disabled={isSaving || !settings.enabled}
+          />
+        </div>
+      </div>
+
+      {/* Replacements */}
+      <div className="space-y-4 pt-4 border-t">
+        <div className="flex items-center space-x-3">
+          <Wand2 className="w-5 h-5 text-purple-500" />
+          <div>
+            <h4 className="font-medium text-gray-900">Replacements</h4>
+            <p className="text-sm text-gray-500">Always replace a phrase as it is usually misheard</p>
+          </div>
+        </div>
+
+        <div className="space-y-2">
+          {settings.replacements.map((r) => (
+            <div key={r.from} className="flex items-center gap-2 text-sm">
+              <span className="px-2 py-1 bg-gray-100 rounded">{r.from}</span>
+              <ArrowRight className="w-4 h-4 text-gray-400" />
+              <span className="px-2 py-1 bg-green-50 text-green-800 rounded">{r.to || '(remove)'}</span>
+              <button
+                onClick={() => runUpdate('remove_glossary_replacement', { from: r.from })}
+                disabled={isSaving}
+                className="ml-auto text-gray-400 hover:text-gray-700"
+                aria-label={`Remove replacement for ${r.from}`}
+              >
+                <X className="w-4 h-4" />
+              </button>
+            </div>
+          ))}
+        </div>
+
+        <div className="flex gap-2">
+          <Input
+            value={replacementFrom}
+            onChange={(e) => setReplacementFrom(e.target.value)}
+            placeholder="Heard as (e.g. cooper netties)"
+            disabled={isSaving}
+          />
+          <Input
+            value={replacementTo}
+            onChange={(e) => setReplacementTo(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handleAddReplacement()}
+            placeholder="Replace with (e.g. Kubernetes)"
+            disabled={isSaving}
+          />
+          <Button onClick={handleAddReplacement} disabled={isSaving || !replacementFrom.trim()}>Add</Button>
+        </div>
+      </div>
+
+      {/* Preview */}
+      <div className="space-y-2 pt-4 border-t">
+        <h4 className="font-medium text-gray-900">Try it</h4>
+        <div className="flex gap-2">
+          <Input
+            value={previewText}
+            onChange={(e) => setPreviewText(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handlePreview()}
+            placeholder="Type a sentence as it was transcribed"
+          />
+          <Button variant="outline" onClick={handlePreview} disabled={!previewText.trim()}>Preview</Button>
+        </div>
+        {previewResult !== null && (
+          <p className="text-sm text-gray-700 p-2 bg-gray-50 border border-gray-200 rounded">{previewResult}</p>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,211 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Switch } from '@/components/ui/switch';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Button } from '@/components/ui/button';
+import { Ghost, X } from 'lucide-react';
+import { toast } from 'sonner';
+import { DroppedSegment, HallucinationFilterSettings as HallucinationFilterSettingsType } from '@/types';
+
+const defaultSettings: HallucinationFilterSettingsType = {
+  enabled: true,
+  repetition_loops: true,
+  min_loop_repeats: 4,
+  known_phrases: true,
+  extra_phrases: {},
+  extra_patterns: [],
+  silent_segments: true,
+  silence_rms: 0.002,
+  near_duplicates: true,
+  duplicate_similarity: 0.9,
+};
+
+const RULES: { key: 'known_phrases' | 'repetition_loops' | 'silent_segments' | 'near_duplicates'; label: string; description: string }[] = [
+  { key: 'known_phrases', label: 'Known phantom phrases', description: '"Thank you for watching", subtitle credits and similar, per language' },
+  { key: 'repetition_loops', label: 'Repetition loops', description: 'A phrase repeated over and over is collapsed, or dropped if that is all there is' },
+  { key: 'silent_segments', label: 'Text from silence', description: 'Drop text decoded from chunks with no speech energy' },
+  { key: 'near_duplicates', label: 'Repeats of the previous segment', description: 'Drop near-duplicates and trim overlap with the segment before' },
+];
+
+export function HallucinationFilterSettings() {
+  const [settings, setSettings] = useState<HallucinationFilterSettingsType>(defaultSettings);
+  const [drops, setDrops] = useState<DroppedSegment[]>([]);
+  const [phrase, setPhrase] = useState('');
+  const [phraseLanguage, setPhraseLanguage] = useState('*');
+  const [pattern, setPattern] = useState('');
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<HallucinationFilterSettingsType>('get_hallucination_filter_settings')
+      .then(setSettings)
+      .catch((error) => console.error('Failed to load hallucination filter settings:', error));
+    invoke<DroppedSegment[]>('get_dropped_segments')
+      .then((list) => setDrops(list.slice().reverse()))
+      .catch((error) => console.error('Failed to load dropped segments:', error));
+  }, []);
+
+  const saveSettings = useCallback(async (newSettings: HallucinationFilterSettingsType) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_hallucination_filter_settings', { settings: newSettings });
+      setSettings(newSettings);
+      return true;
+    } catch (error) {
+      console.error('Failed to save hallucination filter settings:', error);
+      toast.error('Failed to save hallucination filter settings', { description: String(error) });
+      return false;
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  const handleAddPhrase = async () => {
+    const language = phraseLanguage.trim().toLowerCase() || '*';
+    if (!phrase.trim()) return;
+    const existing = settings.extra_phrases[language] ?? [];
+    const extra_phrases = { ...settings.extra_phrases, [language]: [...existing, phrase.trim()] };
+    if (await saveSettings({ ...settings, extra_phrases })) {
+      setPhrase('');
+    }
+  };
+
+  const handleRemovePhrase = (language: string, removed: string) => {
+    const remaining = (settings.extra_phrases[language] ?? []).filter((p) => p !== removed);
+    const extra_phrases = { ...settings.extra_phrases, [language]: remaining };
+    if (remaining.length === 0) delete extra_phrases[language];
+    saveSettings({ ...settings, extra_phrases });
+  };
+
+  const handleAddPattern = async () => {
+    if (!pattern.trim()) return;
+    if (await saveSettings({ ...settings, extra_patterns: [...settings.extra_patterns, pattern.trim()] })) {
+      setPattern('');
+    }
+  };
+
+  const customPhrases = Object.entries(settings.extra_phrases).flatMap(([language, list]) =>
+    list.map((p) => ({ language, phrase: p }))
+  );
+
+  return (
+    <div className="space-y-4 pt-6 mt-6 border-t">
+      <div className="flex items-center justify-between">
+        <div className="flex items-center space-x-3">
+          <Ghost className="w-5 h-5 text-blue-500" />
+          <div>
+            <h4 className="font-medium text-gray-900">Hallucination filter</h4>
+            <p className="text-sm text-gray-500">
+              Drop text the model invents on silence or music before it reaches the transcript.
+            </p>
+          </div>
+        </div>
+        <Switch
+          id="hallucination-filter-enabled"
+          checked={settings.enabled}
+          onCheckedChange={() => saveSettings({ ...settings, enabled: !settings.enabled })}
+          disabled={isSaving}
+        />
+      </div>
+
+      {RULES.map((rule) => (
+        <div key={rule.key} className="flex items-center justify-between">
+          <div>
+            <Label htmlFor={`hallucination-${rule.key}`} className="font-medium">{rule.label}</Label>
+            <p className="text-sm text-gray-500">{rule.description}</p>
+          </div>
+          <Switch
+            id={`hallucination-${rule.key}`}
+            checked={settings[rule.key]}
+            onCheckedChange={() => saveSettings({ ...settings, [rule.key]: !settings[rule.key] })}
+            disabled={isSaving || !settings.enabled}
+          />
+        </div>
+      ))}
+
+      {/* Extra phrases and patterns */}
+      <div className="space-y-2">
+        <Label className="font-medium">Extra phrases</Label>
+        <p className="text-sm text-gray-500">
+          Whole sentences to drop. Use a language code like &quot;en&quot;, or * for every language.
+        </p>
+        {customPhrases.map(({ language, phrase: p }) => (
+          <div key={`${language}:${p}`} className="flex items-center gap-2 text-sm">
+            <span className="px-2 py-1 bg-gray-100 rounded">{language}</span>
+            <span className="text-gray-800">{p}</span>
+            <button
+              onClick={() => handleRemovePhrase(language, p)}
+              disabled={isSaving}
+              className="ml-auto text-gray-400 hover:text-gray-700"
+              aria-label={`Remove phrase ${p}`}
+            >
+              <X className="w-4 h-4" />
+            </button>
+          </div>
+        ))}
+        <div className="flex gap-2">
+          <Input
+            value={phraseLanguage}
+            onChange={(e) => setPhraseLanguage(e.target.value)}
+            className="w-16"
+            disabled={isSaving}
+          />
+          <Input
+            value={phrase}
+            onChange={(e) => setPhrase(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handleAddPhrase()}
+            placeholder="e.g. Captions by ACME"
+            disabled={isSaving}
+          />
+          <Button onClick={handleAddPhrase} disabled={isSaving || !phrase.trim()}>Add</Button>
+        </div>
+      </div>
+
+      <div className="space-y-2">
+        <Label className="font-medium">Extra patterns</Label>
+        {settings.extra_patterns.map((p) => (
+          <div key={p} className="flex items-center gap-2 text-sm">
+            <code className="px-2 py-1 bg-purple-50 text-purple-800 rounded">{p}</code>
+            <button
+              onClick={() => saveSettings({ ...settings, extra_patterns: settings.extra_patterns.filter((c) => c !== p) })}
+              disabled={isSaving}
+              className="ml-auto text-gray-400 hover:text-gray-700"
+              aria-label={`Remove pattern ${p}`}
+            >
+              <X className="w-4 h-4" />
+            </button>
+          </div>
+        ))}
+        <div className="flex gap-2">
+          <Input
+            value={pattern}
+            onChange={(e) => setPattern(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handleAddPattern()}
+            placeholder="Regular expression, e.g. (?i)^www\.\S+$"
+            disabled={isSaving}
+          />
+          <Button onClick={handleAddPattern} disabled={isSaving || !pattern.trim()}>Add</Button>
+        </div>
+      </div>
+
+      {/* Recently dropped */}
+      {drops.length > 0 && (
+        <div className="space-y-2">
+          <Label className="font-medium">Recently dropped</Label>
+          <div className="max-h-48 overflow-y-auto space-y-1">
+            {drops.map((drop) => (
+              <div key={`${drop.dropped_at}-${drop.audio_start_time}`} className="text-sm p-2 border border-gray-200 rounded-md">
+                <p className="text-gray-800">&ldquo;{drop.text}&rdquo;</p>
+                <p className="text-xs text-gray-500">
+                  {drop.reason} · {drop.audio_start_time.toFixed(1)}s
+                </p>
+              </div>
+            ))}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -4,7 +4,7 @@ import { useEffect, useState, useCallback } from 'react';
 import { invoke, listen } from '@/lib/tauri';
 import { Switch } from '@/components/ui/switch';
 import { Label } from '@/components/ui/label';
-import { Video, Monitor, Users, Bell, Play, Square } from 'lucide-react';
+import { Video, Monitor, Users, Bell, Play, Square, History } from 'lucide-react';
 
 interface MeetingDetectionSettings {
   enabled: boolean;
@@ -15,6 +15,8 @@ interface MeetingDetectionSettings {
   detect_google_meet: boolean;
   notify_on_detection: boolean;
   poll_interval_secs: number;
+  pre_roll_enabled: boolean;
+  pre_roll_seconds: number;
 }
 
 interface DetectedMeeting {
@@ -29,6 +31,7 @@ interface MeetingDetectionStatus {
   current_meeting: DetectedMeeting | null;
   settings: MeetingDetectionSettings;
   auto_recording_active: boolean;
+  pre_roll_active: boolean;
 }
 
 const defaultSettings: MeetingDetectionSettings = {
@@ -40,8 +43,12 @@ const defaultSettings: MeetingDetectionSettings = {
   detect_google_meet: true,
   notify_on_detection: true,
   poll_interval_secs: 5,
+  pre_roll_enabled: false,
+  pre_roll_seconds: 60,
 };
 
+const preRollOptions = [15, 30, 60, 120, 300];
+
 export function MeetingDetectionSettings() {
   const [settings, setSettings] = useState<MeetingDetectionSettings>(defaultSettings);
   const [status, setStatus] = useState<MeetingDetectionStatus | null>(null);
@@ -179,7 +186,9 @@ export function MeetingDetectionSettings() {
                   <p className="text-sm text-green-600">
                     {status.auto_recording_active
                       ? 'Recording in progress...'
-                      : 'Click to start recording'}
+                      : status.pre_roll_active
+                        ? `Buffering the last ${settings.pre_roll_seconds}s - click to start recording`
+                        : 'Click to start recording'}
                   </p>
                 </div>
               </>
@@ -325,6 +334,44 @@ export function MeetingDetectionSettings() {
               disabled={isSaving || !settings.enabled}
             />
           </div>
+
+          <div className="flex items-center justify-between">
+            <div className="flex items-center space-x-3">
+              <History className="w-5 h-5 text-indigo-500" />
+              <div>
+                <Label htmlFor="pre-roll" className="font-medium">
+                  Pre-roll Buffer
+                </Label>
+                <p className="text-sm text-gray-500">
+                  Keep recent audio in memory while a meeting is detected and add it to the
+                  start of the recording
+                </p>
+              </div>
+            </div>
+            <div className="flex items-center space-x-2">
+              <select
+                aria-label="Pre-roll length"
+                className="text-sm border rounded px-2 py-1"
+                value={settings.pre_roll_seconds}
+                onChange={(e) =>
+                  updateSettings({ ...settings, pre_roll_seconds: Number(e.target.value) })
+                }
+                disabled={isSaving || !settings.enabled || !settings.pre_roll_enabled}
+              >
+                {preRollOptions.map((seconds) => (
+                  <option key={seconds} value={seconds}>
+                    {seconds < 60 ? `${seconds}s` : `${seconds / 60} min`}
+                  </option>
+                ))}
+              </select>
+              <Switch
+                id="pre-roll"
+                checked={settings.pre_roll_enabled}
+                onCheckedChange={() => handleToggle('pre_roll_enabled')}
+                disabled={isSaving || !settings.enabled}
+              />
+            </div>
+          </div>
         </div>
       </div>
 
@@ -333,7 +380,9 @@ export function MeetingDetectionSettings() {
         <p className="text-sm text-blue-800">
           <strong>Privacy Note:</strong> Meeting detection only monitors running process names
           to identify video conferencing applications. No meeting content, audio, or video is
-          accessed until you explicitly start recording.
+          accessed until you explicitly start recording, unless the pre-roll buffer is enabled:
+          it then keeps the most recent audio in memory only, and discards it when the meeting
+          ends without a recording.
         </p>
       </div>
     </div>
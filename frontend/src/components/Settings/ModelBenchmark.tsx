@@ -0,0 +1,195 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { invoke, listen } from '@/lib/tauri';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Button } from '@/components/ui/button';
+import { Timer } from 'lucide-react';
+import { toast } from 'sonner';
+import { BenchmarkProgress, BenchmarkReport, ModelBenchmark, ModelRecommendation } from '@/types';
+
+const DEFAULT_TARGET_RTF = 0.5;
+
+const formatWer = (wer?: number) => (wer === undefined || wer === null ? '–' : `${(wer * 100).toFixed(1)}%`);
+
+export function ModelBenchmarkSettings() {
+  const [results, setResults] = useState<ModelBenchmark[]>([]);
+  const [recommendation, setRecommendation] = useState<ModelRecommendation | null>(null);
+  const [targetRtf, setTargetRtf] = useState(DEFAULT_TARGET_RTF);
+  const [samplePath, setSamplePath] = useState('');
+  const [referenceText, setReferenceText] = useState('');
+  const [progress, setProgress] = useState<BenchmarkProgress | null>(null);
+  const [isRunning, setIsRunning] = useState(false);
+
+  useEffect(() => {
+    invoke<ModelBenchmark[]>('get_model_benchmarks')
+      .then(setResults)
+      .catch((error) => console.error('Failed to load benchmark results:', error));
+  }, []);
+
+  useEffect(() => {
+    if (results.length === 0) return;
+    invoke<ModelRecommendation | null>('get_model_recommendation', { targetRtf, apply: false })
+      .then(setRecommendation)
+      .catch((error) => console.error('Failed to load model recommendation:', error));
+  }, [results, targetRtf]);
+
+  useEffect(() => {
+    if (typeof window === 'undefined') return;
+    let unlisten: (() => void) | undefined;
+    listen<BenchmarkProgress>('model-benchmark-progress', (event) => setProgress(event.payload))
+      .then((fn) => { unlisten = fn; });
+    return () => unlisten?.();
+  }, []);
+
+  const handleRun = async (apply: boolean) => {
+    setIsRunning(true);
+    setProgress(null);
+    try {
+      const report = await invoke<BenchmarkReport>('run_model_benchmark', {
+        samplePath: samplePath.trim() || null,
+        referenceText: referenceText.trim() || null,
+        targetRtf,
+        apply,
+      });
+      setResults(report.results);
+      setRecommendation(report.recommendation ?? null);
+      report.failures.forEach((failure) => toast.error('Model benchmark failed', { description: failure }));
+      if (report.applied && report.recommendation) {
+        toast.success(`Switched transcription to ${report.recommendation.model_name}`);
+      }
+    } catch (error) {
+      console.error('Model benchmark failed:', error);
+      toast.error('Model benchmark failed', { description: String(error) });
+    } finally {
+      setIsRunning(false);
+      setProgress(null);
+    }
+  };
+
+  // A model that misses the target is only applied from the "Use it anyway" button, next to the warning
+  const handleApply = async (confirmBelowTarget: boolean) => {
+    try {
+      const applied = await invoke<ModelRecommendation | null>('get_model_recommendation', {
+        targetRtf,
+        apply: true,
+        confirmBelowTarget,
+      });
+      if (applied) toast.success(`Switched transcription to ${applied.model_name}`);
+    } catch (error) {
+      toast.error('Failed to switch model', { description: String(error) });
+    }
+  };
+
+  return (
+    <div className="space-y-4 pt-6 mt-6 border-t">
+      <div className="flex items-center space-x-3">
+        <Timer className="w-5 h-5 text-blue-500" />
+        <div>
+          <h4 className="font-medium text-gray-900">Model benchmark</h4>
+          <p className="text-sm text-gray-500">
+            Run every installed model over a speech sample to see which keeps up with live audio on this machine.
+          </p>
+        </div>
+      </div>
+
+      <div className="flex items-center gap-3">
+        <Label className="text-sm text-gray-700 whitespace-nowrap">Target real-time factor</Label>
+        <input
+          type="range"
+          min={0.2}
+          max={1}
+          step={0.05}
+          value={targetRtf}
+          onChange={(e) => setTargetRtf(parseFloat(e.target.value))}
+          disabled={isRunning}
+          className="flex-1"
+        />
+        <span className="text-sm text-gray-600 w-10 text-right">{targetRtf.toFixed(2)}</span>
+      </div>
+
+      <div className="space-y-2">
+        <Input
+          value={samplePath}
+          onChange={(e) => setSamplePath(e.target.value)}
+          placeholder="Own sample (path to an audio file) – leave empty for the bundled sample"
+          disabled={isRunning}
+        />
+        {samplePath.trim() && (
+          <textarea
+            value={referenceText}
+            onChange={(e) => setReferenceText(e.target.value)}
+            placeholder="What is said in the sample (optional, needed for accuracy)"
+            rows={3}
+            disabled={isRunning}
+            className="w-full text-sm p-2 border border-gray-200 rounded-md"
+          />
+        )}
+      </div>
+
+      <div className="flex gap-2">
+        <Button onClick={() => handleRun(false)} disabled={isRunning}>
+          {isRunning ? 'Running…' : 'Run benchmark'}
+        </Button>
+        <Button variant="outline" onClick={() => handleRun(true)} disabled={isRunning}>
+          Run and switch to the best model
+        </Button>
+        {isRunning && (
+          <Button variant="ghost" onClick={() => invoke('cancel_model_benchmark')}>Cancel</Button>
+        )}
+      </div>
+
+      {progress && (
+        <p className="text-sm text-gray-600">
+          {progress.stage === 'loading' ? 'Loading' : 'Testing'} {progress.model_name} ({progress.index + 1} of {progress.total})
+        </p>
+      )}
+
+      {results.length > 0 && (
+        <table className="w-full text-sm">
+          <thead>
+            <tr className="text-left text-gray-500">
+              <th className="font-medium py-1">Model</th>
+              <th className="font-medium py-1">Real-time factor</th>
+              <th className="font-medium py-1">Word error rate</th>
+              <th className="font-medium py-1">Memory</th>
+              <th className="font-medium py-1">Load time</th>
+            </tr>
+          </thead>
+          <tbody>
+            {results.map((result) => (
+              <tr key={`${result.engine}:${result.model_name}`} className="border-t border-gray-100">
+                <td className="py-1 text-gray-800">{result.model_name}</td>
+                <td className={`py-1 ${result.real_time_factor <= targetRtf ? 'text-green-700' : 'text-red-600'}`}>
+                  {result.real_time_factor.toFixed(2)}
+                </td>
+                <td className="py-1 text-gray-700">{formatWer(result.word_error_rate)}</td>
+                <td className="py-1 text-gray-700">{result.peak_memory_mb} MB</td>
+                <td className="py-1 text-gray-700">{(result.load_time_ms / 1000).toFixed(1)}s</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+
+      {recommendation && (
+        <div className="flex items-center justify-between p-3 bg-blue-50 border border-blue-200 rounded-md text-sm">
+          <p className="text-blue-900">
+            {recommendation.meets_target
+              ? `Recommended: ${recommendation.model_name} (real-time factor ${recommendation.real_time_factor.toFixed(2)}, word error rate ${formatWer(recommendation.word_error_rate)})`
+              : `No model stays below ${recommendation.target_rtf.toFixed(2)}; the fastest is ${recommendation.model_name}, which may fall behind live audio`}
+          </p>
+          <Button
+            size="sm"
+            variant="outline"
+            onClick={() => handleApply(!recommendation.meets_target)}
+            disabled={isRunning}
+          >
+            {recommendation.meets_target ? 'Use this model' : 'Use it anyway'}
+          </Button>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,108 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Switch } from '@/components/ui/switch';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Button } from '@/components/ui/button';
+import { Download } from 'lucide-react';
+import { toast } from 'sonner';
+
+interface ModelDownloadSettingsType {
+  mirror_url: string | null;
+  require_checksum: boolean;
+}
+
+const defaultSettings: ModelDownloadSettingsType = {
+  mirror_url: null,
+  require_checksum: false,
+};
+
+export function ModelDownloadSettings() {
+  const [settings, setSettings] = useState<ModelDownloadSettingsType>(defaultSettings);
+  const [mirrorUrl, setMirrorUrl] = useState('');
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<ModelDownloadSettingsType>('get_model_download_settings')
+      .then((loaded) => {
+        setSettings(loaded);
+        setMirrorUrl(loaded.mirror_url || '');
+      })
+      .catch((error) => console.error('Failed to load model download settings:', error));
+  }, []);
+
+  const saveSettings = useCallback(async (newSettings: ModelDownloadSettingsType) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_model_download_settings', { settings: newSettings });
+      setSettings(newSettings);
+      return true;
+    } catch (error) {
+      console.error('Failed to save model download settings:', error);
+      toast.error('Failed to save download settings', { description: String(error) });
+      return false;
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  const handleSaveMirror = async () => {
+    const mirror_url = mirrorUrl.trim() || null;
+    if (await saveSettings({ ...settings, mirror_url })) {
+      toast.success(mirror_url ? 'Models will download from your mirror' : 'Models will download from Hugging Face');
+    }
+  };
+
+  return (
+    <div className="space-y-4 pt-6 mt-6 border-t">
+      <div className="flex items-center space-x-3">
+        <Download className="w-5 h-5 text-blue-500" />
+        <div>
+          <h4 className="font-medium text-gray-900">Model downloads</h4>
+          <p className="text-sm text-gray-500">
+            Interrupted downloads resume where they stopped, and files are checked against their SHA-256 before use.
+          </p>
+        </div>
+      </div>
+
+      <div>
+        <Label className="block text-sm font-medium text-gray-700 mb-1">Mirror URL (optional)</Label>
+        <div className="flex gap-2">
+          <Input
+            value={mirrorUrl}
+            onChange={(e) => setMirrorUrl(e.target.value)}
+            placeholder="https://huggingface.co"
+          />
+          <Button
+            onClick={handleSaveMirror}
+            disabled={isSaving || (mirrorUrl.trim() || null) === settings.mirror_url}
+          >
+            Save
+          </Button>
+        </div>
+        <p className="text-xs text-gray-500 mt-1">
+          Serves files as <code>&lt;mirror&gt;/&lt;repo&gt;/&lt;file&gt;</code>, e.g.{' '}
+          <code>ggerganov/whisper.cpp/ggml-base.bin</code>, with checksums in an optional{' '}
+          <code>manifest.json</code>.
+        </p>
+      </div>
+
+      <div className="flex items-center justify-between">
+        <div>
+          <Label htmlFor="require-checksum" className="font-medium">Require checksums</Label>
+          <p className="text-sm text-gray-500">
+            Reject files whose SHA-256 is not listed in a manifest or reported by the server.
+          </p>
+        </div>
+        <Switch
+          id="require-checksum"
+          checked={settings.require_checksum}
+          onCheckedChange={() => saveSettings({ ...settings, require_checksum: !settings.require_checksum })}
+          disabled={isSaving}
+        />
+      </div>
+    </div>
+  );
+}
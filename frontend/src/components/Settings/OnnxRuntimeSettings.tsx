@@ -0,0 +1,201 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Cpu } from 'lucide-react';
+import { toast } from 'sonner';
+import {
+  OnnxRuntimeSettings as OnnxRuntimeSettingsType,
+  OnnxRuntimeDiagnostics,
+  OnnxOptimizationLevel,
+  OnnxExecutionProvider,
+} from '@/types';
+
+const defaultSettings: OnnxRuntimeSettingsType = {
+  intra_op_threads: null,
+  inter_op_threads: null,
+  optimization_level: null,
+  memory_arena: null,
+  memory_pattern: null,
+  execution_provider: null,
+};
+
+const OPTIMIZATION_LEVELS: { value: OnnxOptimizationLevel; label: string }[] = [
+  { value: 'all', label: 'All' },
+  { value: 'extended', label: 'Extended' },
+  { value: 'basic', label: 'Basic' },
+  { value: 'disabled', label: 'Disabled' },
+];
+
+const PROVIDER_LABELS: Record<OnnxExecutionProvider, string> = {
+  cpu: 'CPU',
+  openvino: 'OpenVINO',
+  xnnpack: 'XNNPACK',
+};
+
+const AUTO = 'auto';
+
+const selectClassName = 'px-2 py-1 text-sm border border-gray-300 rounded-md bg-white';
+
+// Select value for an optional boolean: 'auto' | 'on' | 'off'
+const toToggle = (value: boolean | null) => (value === null ? AUTO : value ? 'on' : 'off');
+const fromToggle = (value: string) => (value === AUTO ? null : value === 'on');
+
+export function OnnxRuntimeSettings() {
+  const [settings, setSettings] = useState<OnnxRuntimeSettingsType>(defaultSettings);
+  const [diagnostics, setDiagnostics] = useState<OnnxRuntimeDiagnostics | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  const loadDiagnostics = useCallback(() => {
+    invoke<OnnxRuntimeDiagnostics>('get_onnx_runtime_diagnostics')
+      .then(setDiagnostics)
+      .catch((error) => console.error('Failed to load ONNX Runtime diagnostics:', error));
+  }, []);
+
+  useEffect(() => {
+    invoke<OnnxRuntimeSettingsType>('get_onnx_runtime_settings')
+      .then(setSettings)
+      .catch((error) => console.error('Failed to load ONNX Runtime settings:', error));
+    loadDiagnostics();
+  }, [loadDiagnostics]);
+
+  const saveSettings = useCallback(async (newSettings: OnnxRuntimeSettingsType) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_onnx_runtime_settings', { settings: newSettings });
+      setSettings(newSettings);
+      loadDiagnostics();
+    } catch (error) {
+      console.error('Failed to save ONNX Runtime settings:', error);
+      toast.error('Failed to save ONNX Runtime settings', { description: String(error) });
+    } finally {
+      setIsSaving(false);
+    }
+  }, [loadDiagnostics]);
+
+  const resolved = diagnostics?.resolved;
+  const threadInput = (key: 'intra_op_threads' | 'inter_op_threads', label: string, auto?: number) => (
+    <div className="flex items-center gap-3">
+      <Label htmlFor={`onnx-${key}`} className="text-sm text-gray-700 w-40">{label}</Label>
+      <Input
+        id={`onnx-${key}`}
+        type="number"
+        min={1}
+        max={64}
+        value={settings[key] ?? ''}
+        onChange={(e) => setSettings({ ...settings, [key]: e.target.value ? Number(e.target.value) : null })}
+        onBlur={() => saveSettings(settings)}
+        placeholder={auto !== undefined ? `Auto (${auto})` : 'Auto'}
+        disabled={isSaving}
+        className="w-32"
+      />
+    </div>
+  );
+
+  return (
+    <div className="space-y-4 pt-6 mt-6 border-t">
+      <div className="flex items-center space-x-3">
+        <Cpu className="w-5 h-5 text-blue-500" />
+        <div>
+          <h4 className="font-medium text-gray-900">ONNX Runtime (Parakeet and ONNX models)</h4>
+          <p className="text-sm text-gray-500">
+            Leave settings on Auto to tune them from this machine&apos;s hardware. Changes apply the next time a model is loaded.
+          </p>
+        </div>
+      </div>
+
+      {threadInput('intra_op_threads', 'Intra-op threads', resolved?.intra_op_threads)}
+      {threadInput('inter_op_threads', 'Inter-op threads', resolved?.inter_op_threads)}
+
+      <div className="flex items-center gap-3">
+        <Label htmlFor="onnx-optimization" className="text-sm text-gray-700 w-40">Graph optimization</Label>
+        <select
+          id="onnx-optimization"
+          value={settings.optimization_level ?? AUTO}
+          onChange={(e) => saveSettings({
+            ...settings,
+            optimization_level: e.target.value === AUTO ? null : e.target.value as OnnxOptimizationLevel,
+          })}
+          disabled={isSaving}
+          className={selectClassName}
+        >
+          <option value={AUTO}>Auto</option>
+          {OPTIMIZATION_LEVELS.map((level) => (
+            <option key={level.value} value={level.value}>{level.label}</option>
+          ))}
+        </select>
+      </div>
+
+      {([
+        ['memory_arena', 'Memory arena'],
+        ['memory_pattern', 'Memory pattern'],
+      ] as const).map(([key, label]) => (
+        <div key={key} className="flex items-center gap-3">
+          <Label htmlFor={`onnx-${key}`} className="text-sm text-gray-700 w-40">{label}</Label>
+          <select
+            id={`onnx-${key}`}
+            value={toToggle(settings[key])}
+            onChange={(e) => saveSettings({ ...settings, [key]: fromToggle(e.target.value) })}
+            disabled={isSaving}
+            className={selectClassName}
+          >
+            <option value={AUTO}>Auto{resolved ? ` (${resolved[key] ? 'on' : 'off'})` : ''}</option>
+            <option value="on">On</option>
+            <option value="off">Off</option>
+          </select>
+        </div>
+      ))}
+
+      <div className="flex items-center gap-3">
+        <Label htmlFor="onnx-provider" className="text-sm text-gray-700 w-40">Execution provider</Label>
+        <select
+          id="onnx-provider"
+          value={settings.execution_provider ?? AUTO}
+          onChange={(e) => saveSettings({
+            ...settings,
+            execution_provider: e.target.value === AUTO ? null : e.target.value as OnnxExecutionProvider,
+          })}
+          disabled={isSaving}
+          className={selectClassName}
+        >
+          <option value={AUTO}>Auto</option>
+          {(Object.keys(PROVIDER_LABELS) as OnnxExecutionProvider[]).map((provider) => (
+            <option
+              key={provider}
+              value={provider}
+              disabled={diagnostics !== null && !diagnostics.available_providers.includes(provider)}
+            >
+              {PROVIDER_LABELS[provider]}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      {diagnostics && (
+        <div className="rounded-md bg-gray-50 border border-gray-200 p-3 text-xs text-gray-600 space-y-1">
+          <p>
+            {diagnostics.cpu_cores} cores, {diagnostics.performance_tier} tier. Available providers:{' '}
+            {diagnostics.available_providers.map((p) => PROVIDER_LABELS[p]).join(', ')}
+          </p>
+          {diagnostics.last_applied ? (
+            <p>
+              Loaded model uses {PROVIDER_LABELS[diagnostics.last_applied.active_provider]},{' '}
+              {diagnostics.last_applied.intra_op_threads} intra-op / {diagnostics.last_applied.inter_op_threads} inter-op threads,{' '}
+              {diagnostics.last_applied.optimization_level} optimization, arena{' '}
+              {diagnostics.last_applied.memory_arena ? 'on' : 'off'}
+            </p>
+          ) : (
+            <p>No ONNX model loaded yet.</p>
+          )}
+          {diagnostics.resolved.active_provider !== diagnostics.resolved.requested_provider && (
+            <p className="text-amber-700">
+              {PROVIDER_LABELS[diagnostics.resolved.requested_provider]} is not available in this build; CPU is used instead.
+            </p>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,253 @@
+'use client';
+
+import { useEffect, useState, useCallback } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Switch } from '@/components/ui/switch';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { Button } from '@/components/ui/button';
+import { ShieldCheck, Regex, X } from 'lucide-react';
+import { toast } from 'sonner';
+import { RedactionMode, RedactionSettings as RedactionSettingsType } from '@/types';
+
+const defaultSettings: RedactionSettingsType = {
+  enabled: false,
+  mode: 'llm_only',
+  emails: true,
+  phone_numbers: true,
+  card_numbers: true,
+  ibans: true,
+  custom_patterns: [],
+  include_local_llm: false,
+};
+
+const MODE_OPTIONS: { value: RedactionMode; label: string; description: string }[] = [
+  {
+    value: 'llm_only',
+    label: 'Only when sending to an LLM',
+    description: 'Transcripts are stored as spoken; summary prompts get placeholders and the summary is restored locally.',
+  },
+  {
+    value: 'storage',
+    label: 'When saving transcripts',
+    description: 'Saved transcripts contain placeholders. The original values cannot be recovered.',
+  },
+  {
+    value: 'both',
+    label: 'Both',
+    description: 'Redact saved transcripts and anything sent to an LLM.',
+  },
+];
+
+const CATEGORIES: { key: 'emails' | 'phone_numbers' | 'card_numbers' | 'ibans'; label: string; example: string }[] = [
+  { key: 'emails', label: 'Email addresses', example: 'jane@example.com → [EMAIL_1]' },
+  { key: 'phone_numbers', label: 'Phone numbers', example: '+44 20 7946 0958 → [PHONE_1]' },
+  { key: 'card_numbers', label: 'Card numbers', example: 'Checked with the Luhn checksum' },
+  { key: 'ibans', label: 'IBANs', example: 'Checked with the IBAN check digits' },
+];
+
+export function RedactionSettings() {
+  const [settings, setSettings] = useState<RedactionSettingsType>(defaultSettings);
+  const [patternName, setPatternName] = useState('');
+  const [pattern, setPattern] = useState('');
+  const [previewText, setPreviewText] = useState('');
+  const [previewResult, setPreviewResult] = useState<string | null>(null);
+  const [isLoading, setIsLoading] = useState(true);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<RedactionSettingsType>('get_redaction_settings')
+      .then(setSettings)
+      .catch((error) => console.error('Failed to load redaction settings:', error))
+      .finally(() => setIsLoading(false));
+  }, []);
+
+  const saveSettings = useCallback(async (newSettings: RedactionSettingsType) => {
+    setIsSaving(true);
+    try {
+      await invoke('set_redaction_settings', { settings: newSettings });
+      setSettings(newSettings);
+      return true;
+    } catch (error) {
+      console.error('Failed to save redaction settings:', error);
+      toast.error('Failed to save redaction settings', { description: String(error) });
+      return false;
+    } finally {
+      setIsSaving(false);
+    }
+  }, []);
+
+  const handleAddPattern = async () => {
+    if (!patternName.trim() || !pattern.trim()) return;
+    const custom_patterns = [
+      ...settings.custom_patterns.filter((p) => p.name !== patternName.trim()),
+      { name: patternName.trim(), pattern: pattern.trim() },
+    ];
+    if (await saveSettings({ ...settings, custom_patterns })) {
+      setPatternName('');
+      setPattern('');
+    }
+  };
+
+  const handlePreview = async () => {
+    try {
+      setPreviewResult(await invoke<string>('preview_redaction', { text: previewText }));
+    } catch (error) {
+      console.error('Redaction preview failed:', error);
+    }
+  };
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center justify-center p-8">
+        <div className="animate-spin rounded-full h-6 w-6 border-b-2 border-gray-900"></div>
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-6">
+      {/* Header */}
+      <div className="flex items-center justify-between">
+        <div>
+          <h3 className="text-lg font-semibold">PII Redaction</h3>
+          <p className="text-sm text-gray-500">
+            Replace personal data in transcripts and summary prompts with placeholders like [EMAIL_1]
+          </p>
+        </div>
+        <div className="flex items-center space-x-2">
+          <Switch
+            id="redaction-enabled"
+            checked={settings.enabled}
+            onCheckedChange={() => saveSettings({ ...settings, enabled: !settings.enabled })}
+            disabled={isSaving}
+          />
+          <Label htmlFor="redaction-enabled" className="font-medium">
+            {settings.enabled ? 'Enabled' : 'Disabled'}
+          </Label>
+        </div>
+      </div>
+
+      {/* Mode */}
+      <div className="space-y-3 pt-4 border-t">
+        <div className="flex items-center space-x-3">
+          <ShieldCheck className="w-5 h-5 text-blue-500" />
+          <h4 className="font-medium text-gray-900">When to redact</h4>
+        </div>
+        {MODE_OPTIONS.map((option) => (
+          <label key={option.value} className="flex items-start gap-3 cursor-pointer">
+            <input
+              type="radio"
+              name="redaction-mode"
+              className="mt-1"
+              checked={settings.mode === option.value}
+              onChange={() => saveSettings({ ...settings, mode: option.value })}
+              disabled={isSaving || !settings.enabled}
+            />
+            <div>
+              <span className="text-sm font-medium text-gray-800">{option.label}</span>
+              <p className="text-sm text-gray-500">{option.description}</p>
+            </div>
+          </label>
+        ))}
+
+        <div className="flex items-center justify-between">
+          <div>
+            <Label htmlFor="redaction-local-llm" className="font-medium">Also redact for Ollama</Label>
+            <p className="text-sm text-gray-500">Ollama usually runs on this machine, so its prompts are left as-is by default</p>
+          </div>
+          <Switch
+            id="redaction-local-llm"
+            checked={settings.include_local_llm}
+            onCheckedChange={() => saveSettings({ ...settings, include_local_llm: !settings.include_local_llm })}
+            disabled={isSaving || !settings.enabled || settings.mode === 'storage'}
+          />
+        </div>
+      </div>
+
+      {/* Categories */}
+      <div className="space-y-3 pt-4 border-t">
+        <h4 className="font-medium text-gray-900">What to redact</h4>
+        {CATEGORIES.map((category) => (
+          <div key={category.key} className="flex items-center justify-between">
+            <div>
+              <Label htmlFor={`redact-${category.key}`} className="font-medium">{category.label}</Label>
+              <p className="text-sm text-gray-500">{category.example}</p>
+            </div>
+            <Switch
+              id={`redact-${category.key}`}
+              checked={settings[category.key]}
+              onCheckedChange={() => saveSettings({ ...settings, [category.key]: !settings[category.key] })}
+              disabled={isSaving || !settings.enabled}
+            />
+          </div>
+        ))}
+      </div>
+
+      {/* Custom patterns */}
+      <div className="space-y-4 pt-4 border-t">
+        <div className="flex items-center space-x-3">
+          <Regex className="w-5 h-5 text-purple-500" />
+          <div>
+            <h4 className="font-medium text-gray-900">Custom patterns</h4>
+            <p className="text-sm text-gray-500">Regular expressions for your own identifiers, e.g. employee or case numbers</p>
+          </div>
+        </div>
+
+        <div className="space-y-2">
+          {settings.custom_patterns.map((p) => (
+            <div key={p.name} className="flex items-center gap-2 text-sm">
+              <span className="px-2 py-1 bg-gray-100 rounded">{p.name}</span>
+              <code className="px-2 py-1 bg-purple-50 text-purple-800 rounded">{p.pattern}</code>
+              <button
+                onClick={() => saveSettings({
+                  ...settings,
+                  custom_patterns: settings.custom_patterns.filter((c) => c.name !== p.name),
+                })}
+                disabled={isSaving}
+                className="ml-auto text-gray-400 hover:text-gray-700"
+                aria-label={`Remove pattern ${p.name}`}
+              >
+                <X className="w-4 h-4" />
+              </button>
+            </div>
+          ))}
+        </div>
+
+        <div className="flex gap-2">
+          <Input
+            value={patternName}
+            onChange={(e) => setPatternName(e.target.value)}
+            placeholder="Name (e.g. employee_id)"
+            disabled={isSaving}
+          />
+          <Input
+            value={pattern}
+            onChange={(e) => setPattern(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handleAddPattern()}
+            placeholder="Pattern (e.g. EMP-\d{6})"
+            disabled={isSaving}
+          />
+          <Button onClick={handleAddPattern} disabled={isSaving || !patternName.trim() || !pattern.trim()}>Add</Button>
+        </div>
+      </div>
+
+      {/* Preview */}
+      <div className="space-y-2 pt-4 border-t">
+        <h4 className="font-medium text-gray-900">Try it</h4>
+        <div className="flex gap-2">
+          <Input
+            value={previewText}
+            onChange={(e) => setPreviewText(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handlePreview()}
+            placeholder="Type a sentence with an email address or phone number"
+          />
+          <Button variant="outline" onClick={handlePreview} disabled={!previewText.trim()}>Preview</Button>
+        </div>
+        {previewResult !== null && (
+          <p className="text-sm text-gray-700 p-2 bg-gray-50 border border-gray-200 rounded">{previewResult}</p>
+        )}
+      </div>
+    </div>
+  );
+}
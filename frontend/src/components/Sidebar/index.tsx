@@ -206,7 +206,9 @@ const Sidebar: React.FC = () => {
       const payload = {
         provider: configToSave.provider,
         model: configToSave.model,
-        apiKey: configToSave.apiKey ?? null
+        apiKey: configToSave.apiKey ?? null,
+        endpoint: configToSave.endpoint ?? null,
+        maxRetries: configToSave.maxRetries ?? null
       };
       console.log('Saving transcript config with payload:', payload);
       
@@ -214,6 +216,8 @@ const Sidebar: React.FC = () => {
         provider: payload.provider,
         model: payload.model,
         apiKey: payload.apiKey,
+        endpoint: payload.endpoint,
+        maxRetries: payload.maxRetries,
       });
 
       
@@ -7,12 +7,16 @@ import { Label } from './ui/label';
 import { Eye, EyeOff, Lock, Unlock } from 'lucide-react';
 import { ModelManager } from './WhisperModelManager';
 import { ParakeetModelManager } from './ParakeetModelManager';
+import { OnnxAsrModelManager } from './OnnxAsrModelManager';
+import { RemoteTranscriptionSettings } from './RemoteTranscriptionSettings';
 
 
 export interface TranscriptModelProps {
-    provider: 'localWhisper' | 'parakeet' | 'deepgram' | 'elevenLabs' | 'groq' | 'openai';
+    provider: 'localWhisper' | 'parakeet' | 'onnxAsr' | 'openaiCompatible' | 'deepgram' | 'elevenLabs' | 'groq' | 'openai';
     model: string;
     apiKey?: string | null;
+    endpoint?: string | null; // Base URL for openaiCompatible
+    maxRetries?: number | null; // Remote request retries before the local fallback
 }
 
 export interface TranscriptSettingsProps {
@@ -28,9 +32,10 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
     const [isLockButtonVibrating, setIsLockButtonVibrating] = useState<boolean>(false);
     const [selectedWhisperModel, setSelectedWhisperModel] = useState<string>(transcriptModelConfig.provider === 'localWhisper' ? transcriptModelConfig.model : 'small');
     const [selectedParakeetModel, setSelectedParakeetModel] = useState<string>(transcriptModelConfig.provider === 'parakeet' ? transcriptModelConfig.model : 'parakeet-tdt-0.6b-v3-int8');
+    const [selectedOnnxAsrModel, setSelectedOnnxAsrModel] = useState<string>(transcriptModelConfig.provider === 'onnxAsr' ? transcriptModelConfig.model : '');
 
     useEffect(() => {
-        if (transcriptModelConfig.provider === 'localWhisper' || transcriptModelConfig.provider === 'parakeet') {
+        if (transcriptModelConfig.provider === 'localWhisper' || transcriptModelConfig.provider === 'parakeet' || transcriptModelConfig.provider === 'onnxAsr' || transcriptModelConfig.provider === 'openaiCompatible') {
             setApiKey(null);
         }
     }, [transcriptModelConfig.provider]);
@@ -49,6 +54,8 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
     const modelOptions = {
         localWhisper: [selectedWhisperModel],
         parakeet: [selectedParakeetModel],
+        onnxAsr: [selectedOnnxAsrModel],
+        openaiCompatible: ['whisper-1'],
         deepgram: ['nova-2-phonecall'],
         elevenLabs: ['eleven_multilingual_v2'],
         groq: ['llama-3.3-70b-versatile'],
@@ -91,6 +98,19 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
         }
     };
 
+    const handleOnnxAsrModelSelect = (modelName: string) => {
+        setSelectedOnnxAsrModel(modelName);
+        if (transcriptModelConfig.provider === 'onnxAsr') {
+            setTranscriptModelConfig({
+                ...transcriptModelConfig,
+                model: modelName
+            });
+            if (onModelSelect) {
+                onModelSelect();
+            }
+        }
+    };
+
     return (
         <div className='max-h-[calc(100vh-200px)]'>
             <div>
@@ -109,7 +129,7 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                                     const provider = value as TranscriptModelProps['provider'];
                                     const newModel = provider === 'localWhisper' ? selectedWhisperModel : modelOptions[provider][0];
                                     setTranscriptModelConfig({ ...transcriptModelConfig, provider, model: newModel });
-                                    if (provider !== 'localWhisper') {
+                                    if (provider !== 'localWhisper' && provider !== 'onnxAsr' && provider !== 'openaiCompatible') {
                                         fetchApiKey(provider);
                                     }
                                 }}
@@ -120,6 +140,8 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                                 <SelectContent>
                                     <SelectItem value="parakeet">⚡ Parakeet (Recommended - Real-time / Accurate)</SelectItem>
                                     <SelectItem value="localWhisper">🏠 Local Whisper (High Accuracy)</SelectItem>
+                                    <SelectItem value="onnxAsr">🧩 Other local ONNX models (Moonshine, SenseVoice, Canary…)</SelectItem>
+                                    <SelectItem value="openaiCompatible">☁️ OpenAI-compatible server</SelectItem>
                                     {/* <SelectItem value="deepgram">☁️ Deepgram (Backup)</SelectItem>
                                     <SelectItem value="elevenLabs">☁️ ElevenLabs</SelectItem>
                                     <SelectItem value="groq">☁️ Groq</SelectItem>
@@ -127,7 +149,7 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                                 </SelectContent>
                             </Select>
 
-                            {transcriptModelConfig.provider !== 'localWhisper' && transcriptModelConfig.provider !== 'parakeet' && (
+                            {transcriptModelConfig.provider !== 'localWhisper' && transcriptModelConfig.provider !== 'parakeet' && transcriptModelConfig.provider !== 'onnxAsr' && transcriptModelConfig.provider !== 'openaiCompatible' && (
                                 <Select
                                     value={transcriptModelConfig.model}
                                     onValueChange={(value) => {
@@ -169,6 +191,29 @@ export function TranscriptSettings({ transcriptModelConfig, setTranscriptModelCo
                         </div>
                     )}
 
+                    {transcriptModelConfig.provider === 'onnxAsr' && (
+                        <div className="mt-6">
+                            <OnnxAsrModelManager
+                                selectedModel={selectedOnnxAsrModel}
+                                onModelSelect={handleOnnxAsrModelSelect}
+                                autoSave={true}
+                            />
+                        </div>
+                    )}
+
+                    {transcriptModelConfig.provider === 'openaiCompatible' && (
+                        <div className="mt-6">
+                            <RemoteTranscriptionSettings
+                                config={transcriptModelConfig}
+                                onSaved={(config) => {
+                                    setTranscriptModelConfig(config);
+                                    if (onModelSelect) {
+                                        onModelSelect();
+                                    }
+                                }}
+                            />
+                        </div>
+                    )}
 
                     {requiresApiKey && (
                         <div>
@@ -9,6 +9,7 @@ import { motion, AnimatePresence } from 'framer-motion';
 
 interface TranscriptViewProps {
   transcripts: Transcript[];
+  partialTranscript?: Transcript | null; // In-progress utterance, replaced until its final result arrives
   isRecording?: boolean;
   isPaused?: boolean; // Is recording paused (affects UI indicators)
   isProcessing?: boolean; // Is processing/finalizing transcription (hides "Listening..." indicator)
@@ -104,7 +105,7 @@ function cleanStopWords(text: string): string {
   return cleanedText;
 }
 
-export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isRecording = false, isPaused = false, isProcessing = false, isStopping = false, enableStreaming = false }) => {
+export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, partialTranscript = null, isRecording = false, isPaused = false, isProcessing = false, isStopping = false, enableStreaming = false }) => {
   const [speechDetected, setSpeechDetected] = useState(false);
 
   // Debug: Log the props to understand what's happening
@@ -328,14 +329,37 @@ export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isR
                     </p>
                   </div>
                 )}
+                {/* Translation track (bilingual meetings) */}
+                {transcript.translation && (
+                  <p className="mt-1 text-sm italic text-gray-500 dark:text-gray-400 leading-relaxed">
+                    {transcript.translation_language && (
+                      <span className="not-italic uppercase text-[10px] font-medium text-gray-400 mr-1">
+                        {transcript.translation_language}
+                      </span>
+                    )}
+                    {transcript.translation}
+                  </p>
+                )}
               </div>
             </div>
           </motion.div>
         );
       })}
 
+      {/* Partial hypothesis of the utterance still being spoken */}
+      {isRecording && partialTranscript && (
+        <div className="mb-3 flex items-start gap-2">
+          <span className="text-xs text-gray-400 dark:text-gray-500 mt-1 flex-shrink-0 min-w-[50px]">
+            {formatRecordingTime(partialTranscript.audio_start_time)}
+          </span>
+          <p className="flex-1 text-base italic text-gray-500 dark:text-gray-400 leading-relaxed">
+            {cleanStopWords(partialTranscript.text)}
+          </p>
+        </div>
+      )}
+
       {/* Show listening indicator when recording and has transcripts */}
-      {!isStopping && isRecording && !isPaused && !isProcessing && transcripts.length > 0 && (
+      {!isStopping && isRecording && !isPaused && !isProcessing && !partialTranscript && transcripts.length > 0 && (
         <motion.div
           initial={{ opacity: 0 }}
           animate={{ opacity: 1 }}
@@ -348,7 +372,7 @@ export const TranscriptView: React.FC<TranscriptViewProps> = ({ transcripts, isR
       )}
 
       {/* Empty state when no transcripts */}
-      {transcripts.length === 0 && (
+      {transcripts.length === 0 && !partialTranscript && (
         <motion.div
           initial={{ opacity: 0 }}
           animate={{ opacity: 1 }}
@@ -0,0 +1,118 @@
+import React, { useState, useEffect } from 'react';
+import { invoke } from '@/lib/tauri';
+import { Languages } from 'lucide-react';
+import { toast } from 'sonner';
+import { TranslationSettings } from '@/types';
+import { LANGUAGES } from '@/components/LanguageSelection';
+
+interface TranslationSelectionProps {
+  disabled?: boolean;
+  provider?: 'localWhisper' | 'parakeet' | 'onnxAsr' | 'openaiCompatible' | 'deepgram' | 'elevenLabs' | 'groq' | 'openai';
+}
+
+interface OllamaModel {
+  name: string;
+}
+
+// Concrete target languages (auto-detect entries make no sense as a translation target)
+const TARGET_LANGUAGES = LANGUAGES.filter(lang => lang.code !== 'auto' && lang.code !== 'auto-translate');
+
+export function TranslationSelection({
+  disabled = false,
+  provider = 'localWhisper'
+}: TranslationSelectionProps) {
+  const [settings, setSettings] = useState<TranslationSettings>({ target_language: null, llm_model: null });
+  const [ollamaModels, setOllamaModels] = useState<string[]>([]);
+  const [saving, setSaving] = useState(false);
+
+  useEffect(() => {
+    invoke<TranslationSettings>('get_translation_settings')
+      .then(setSettings)
+      .catch(error => console.error('Failed to load translation settings:', error));
+  }, []);
+
+  const target = settings.target_language;
+  // Whisper can only translate into English; everything else goes through a local LLM
+  const usesWhisper = target === 'en' && provider === 'localWhisper';
+  const needsLlm = !!target && !usesWhisper;
+
+  useEffect(() => {
+    if (!needsLlm) return;
+    invoke<OllamaModel[]>('get_ollama_models', { endpoint: null })
+      .then(models => setOllamaModels(models.map(m => m.name)))
+      .catch(() => setOllamaModels([]));
+  }, [needsLlm]);
+
+  const saveSettings = async (next: TranslationSettings) => {
+    setSaving(true);
+    try {
+      await invoke('set_translation_settings', { settings: next });
+      setSettings(next);
+    } catch (error) {
+      console.error('Failed to save translation settings:', error);
+      toast.error("Failed to save translation settings", {
+        description: error instanceof Error ? error.message : String(error)
+      });
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center gap-2">
+        <Languages className="h-4 w-4 text-gray-600" />
+        <h4 className="text-sm font-medium text-gray-900">Bilingual Transcript</h4>
+      </div>
+
+      <div className="space-y-2">
+        <select
+          value={target ?? ''}
+          onChange={(e) => saveSettings({ ...settings, target_language: e.target.value || null })}
+          disabled={disabled || saving}
+          className="w-full px-3 py-2 text-sm bg-white border border-gray-300 rounded-md shadow-sm focus:outline-none focus:ring-1 focus:ring-blue-500 focus:border-blue-500 disabled:bg-gray-50 disabled:text-gray-500"
+        >
+          <option value="">Off (original only)</option>
+          {TARGET_LANGUAGES.map((language) => (
+            <option key={language.code} value={language.code}>
+              Translate to {language.name} ({language.code})
+            </option>
+          ))}
+        </select>
+
+        {needsLlm && (
+          <select
+            value={settings.llm_model ?? ''}
+            onChange={(e) => saveSettings({ ...settings, llm_model: e.target.value || null })}
+            disabled={disabled || saving}
+            className="w-full px-3 py-2 text-sm bg-white border border-gray-300 rounded-md shadow-sm focus:outline-none focus:ring-1 focus:ring-blue-500 focus:border-blue-500 disabled:bg-gray-50 disabled:text-gray-500"
+          >
+            <option value="">Use summary model (Ollama)</option>
+            {ollamaModels.map((model) => (
+              <option key={model} value={model}>{model}</option>
+            ))}
+          </select>
+        )}
+
+        <div className="text-xs pt-2">
+          {!target && (
+            <p className="text-gray-600">The transcript is kept in the spoken language only.</p>
+          )}
+          {usesWhisper && (
+            <p className="text-gray-600">
+              Each segment is also translated to English by Whisper from the same audio. The original transcript is kept.
+            </p>
+          )}
+          {needsLlm && (
+            <p className="text-gray-600">
+              Each segment is translated by a local Ollama model and shown below the original. Requires Ollama to be running.
+            </p>
+          )}
+          {target && (
+            <p className="text-gray-500 mt-1">Applies to the next meeting you record.</p>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
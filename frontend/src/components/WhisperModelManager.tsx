@@ -13,6 +13,7 @@ import {
   WhisperAPI
 } from '../lib/whisper';
 import { Accordion, AccordionContent, AccordionItem, AccordionTrigger } from '@/components/ui/accordion';
+import { ImportModelDialog } from '@/components/ImportModelDialog';
 
 interface ModelManagerProps {
   selectedModel?: string;
@@ -386,6 +387,31 @@ export function ModelManager({
     }
   };
 
+  const removeCustomModel = async (modelName: string) => {
+    try {
+      await WhisperAPI.removeCustomModel(modelName);
+      setModels(await WhisperAPI.getAvailableModels());
+
+      toast.success(`${modelName} removed`, {
+        duration: 3000
+      });
+
+      if (selectedModel === modelName && onModelSelect) {
+        onModelSelect('');
+      }
+    } catch (err) {
+      console.error('Failed to remove custom model:', err);
+      toast.error(`Failed to remove ${modelName}`, {
+        description: err instanceof Error ? err.message : String(err),
+        duration: 4000
+      });
+    }
+  };
+
+  const handleImported = async () => {
+    setModels(await WhisperAPI.getAvailableModels());
+  };
+
   const getDisplayName = (modelName: string): string => {
     const modelNameMapping: { [key: string]: string } = {
       "base": "Small",
@@ -397,6 +423,9 @@ export function ModelManager({
     if (basicModelNames.includes(modelName)) {
       return modelNameMapping[modelName] || modelName;
     }
+    if (models.find(m => m.name === modelName)?.custom) {
+      return modelName;
+    }
     return `Whisper ${modelName}`;
   };
 
@@ -424,7 +453,8 @@ export function ModelManager({
   const basicModelNames = ["base", "small", "large-v3-turbo"];
   const basicModels = models.filter(m => basicModelNames.includes(m.name))
     .sort((a, b) => basicModelNames.indexOf(a.name) - basicModelNames.indexOf(b.name));
-  const advancedModels = models.filter(m => !basicModelNames.includes(m.name));
+  const advancedModels = models.filter(m => !basicModelNames.includes(m.name) && !m.custom);
+  const customModels = models.filter(m => m.custom);
 
   return (
     <div className={`space-y-3 ${className}`}>
@@ -486,6 +516,32 @@ export function ModelManager({
         </Accordion>
       )}
 
+      {/* Custom Models */}
+      <div className="space-y-3 pt-2">
+        <div className="flex items-center justify-between">
+          <span className="text-sm font-medium text-gray-700">Custom Models</span>
+          <ImportModelDialog engine="whisper" onImported={handleImported} />
+        </div>
+        {customModels.map((model) => (
+          <ModelCard
+            key={model.name}
+            model={model}
+            isSelected={selectedModel === model.name}
+            isRecommended={false}
+            onSelect={() => {
+              if (model.status === 'Available') {
+                selectModel(model.name);
+              }
+            }}
+            onDownload={() => {}}
+            onCancel={() => {}}
+            onDelete={() => removeCustomModel(model.name)}
+            isDownloading={false}
+            displayName={getDisplayName(model.name)}
+          />
+        ))}
+      </div>
+
       {/* Helper text */}
       {selectedModel && (
         <motion.div
@@ -528,6 +584,7 @@ function ModelCard({
 
   const isAvailable = model.status === 'Available';
   const isMissing = model.status === 'Missing';
+  const isCustom = !!model.custom;
   const isError = typeof model.status === 'object' && 'Error' in model.status;
   const isCorrupted = typeof model.status === 'object' && 'Corrupted' in model.status;
   const downloadProgress =
@@ -593,7 +650,9 @@ function ModelCard({
             </div>
 
             {/* Tagline */}
-            <p className="text-sm text-gray-600 ml-9">{getModelTagline(model.name, model.speed, model.accuracy)}</p>
+            <p className="text-sm text-gray-600 ml-9">
+              {isCustom ? model.description : getModelTagline(model.name, model.speed, model.accuracy)}
+            </p>
 
             {/* Model Specs */}
             <div className="flex items-center space-x-4 text-sm text-gray-600 ml-9 mt-2">
@@ -632,7 +691,7 @@ function ModelCard({
                         onDelete();
                       }}
                       className="text-gray-400 hover:text-red-600 transition-colors p-1"
-                      title="Delete model to free up space"
+                      title={isCustom ? 'Remove imported model' : 'Delete model to free up space'}
                     >
                       <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                         <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16" />
@@ -655,7 +714,19 @@ function ModelCard({
               </button>
             )}
 
-            {downloadProgress === null && isError && (
+            {downloadProgress === null && (isError || isCorrupted) && isCustom && (
+              <button
+                onClick={(e) => {
+                  e.stopPropagation();
+                  onDelete();
+                }}
+                className="bg-red-600 text-white px-3 py-1.5 rounded-md text-sm font-medium hover:bg-red-700 transition-colors"
+              >
+                Remove
+              </button>
+            )}
+
+            {downloadProgress === null && isError && !isCustom && (
               <button
                 onClick={(e) => {
                   e.stopPropagation();
@@ -667,7 +738,7 @@ function ModelCard({
               </button>
             )}
 
-            {isCorrupted && (
+            {isCorrupted && !isCustom && (
               <div className="flex gap-2">
                 <button
                   onClick={(e) => {
@@ -1,4 +1,4 @@
-import { useState, useCallback, useRef } from 'react';
+import { useState, useCallback, useRef, useEffect } from 'react';
 import { Transcript, Summary } from '@/types';
 import { BlockNoteSummaryViewRef } from '@/components/AISummary/BlockNoteSummaryView';
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
@@ -13,7 +13,7 @@ interface UseMeetingDataProps {
 
 export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMeetingDataProps) {
   // State
-  const [transcripts] = useState<Transcript[]>(meeting.transcripts);
+  const [transcripts, setTranscripts] = useState<Transcript[]>(meeting.transcripts);
   const [meetingTitle, setMeetingTitle] = useState(meeting.title || '+ New Call');
   const [isEditingTitle, setIsEditingTitle] = useState(false);
   const [isTitleDirty, setIsTitleDirty] = useState(false);
@@ -22,6 +22,11 @@ export function useMeetingData({ meeting, summaryData, onMeetingUpdated }: UseMe
   const [, setIsSummaryDirty] = useState(false);
   const [, setError] = useState<string>('');
 
+  // Segments are replaced when the meeting is re-transcribed or a version is restored
+  useEffect(() => {
+    setTranscripts(meeting.transcripts);
+  }, [meeting.transcripts]);
+
   // Ref for BlockNoteSummaryView
   const blockNoteSummaryRef = useRef<BlockNoteSummaryViewRef>(null);
 
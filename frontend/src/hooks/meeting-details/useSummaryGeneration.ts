@@ -1,5 +1,5 @@
-import { useState, useCallback } from 'react';
-import { Transcript, Summary } from '@/types';
+import { useState, useCallback, useEffect } from 'react';
+import { Transcript, Summary, SummaryProgressEvent } from '@/types';
 import { ModelConfig } from '@/components/ModelSettingsModal';
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { invoke as invokeTauri } from '@/lib/tauri';
@@ -7,6 +7,7 @@ import { toast } from 'sonner';
 import Analytics from '@/lib/analytics';
 
 type SummaryStatus = 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error';
+export type TranscriptSource = 'original' | 'translation';
 
 interface UseSummaryGenerationProps {
   meeting: any;
@@ -14,6 +15,7 @@ interface UseSummaryGenerationProps {
   modelConfig: ModelConfig;
   isModelConfigLoading: boolean;
   selectedTemplate: string;
+  transcriptSource?: TranscriptSource;
   onMeetingUpdated?: () => Promise<void>;
   updateMeetingTitle: (title: string) => void;
   setAiSummary: (summary: Summary | null) => void;
@@ -25,6 +27,7 @@ export function useSummaryGeneration({
   modelConfig,
   isModelConfigLoading,
   selectedTemplate,
+  transcriptSource = 'original',
   onMeetingUpdated,
   updateMeetingTitle,
   setAiSummary,
@@ -32,8 +35,29 @@ export function useSummaryGeneration({
   const [summaryStatus, setSummaryStatus] = useState<SummaryStatus>('idle');
   const [summaryError, setSummaryError] = useState<string | null>(null);
   const [originalTranscript, setOriginalTranscript] = useState<string>('');
+  const [summaryProgress, setSummaryProgress] = useState<SummaryProgressEvent | null>(null);
+
+  const { startSummaryPolling, stopSummaryPolling } = useSidebar();
+
+  // Streamed partial output of the running summary
+  useEffect(() => {
+    const setupListener = async () => {
+      const { listen } = await import('@tauri-apps/api/event');
+      return await listen<SummaryProgressEvent>('summary-progress', (event) => {
+        if (event.payload.meeting_id === meeting.id) {
+          setSummaryStatus(prev => (prev === 'processing' ? 'summarizing' : prev));
+          setSummaryProgress(event.payload);
+        }
+      });
+    };
+
+    let cleanup: (() => void) | undefined;
+    setupListener().then(fn => cleanup = fn);
 
-  const { startSummaryPolling } = useSidebar();
+    return () => {
+      cleanup?.();
+    };
+  }, [meeting.id]);
 
   // Helper to get status message
   const getSummaryStatusMessage = useCallback((status: SummaryStatus) => {
@@ -41,6 +65,12 @@ export function useSummaryGeneration({
       case 'processing':
         return 'Processing transcript...';
       case 'summarizing':
+        if (summaryProgress?.stage === 'chunk') {
+          return `Summarizing part ${summaryProgress.chunk} of ${summaryProgress.total_chunks}...`;
+        }
+        if (summaryProgress?.stage === 'combine') {
+          return `Combining ${summaryProgress.total_chunks} partial summaries...`;
+        }
         return 'Generating summary...';
       case 'regenerating':
         return 'Regenerating summary...';
@@ -51,7 +81,7 @@ export function useSummaryGeneration({
       default:
         return '';
     }
-  }, []);
+  }, [summaryProgress]);
 
   // Unified summary processing logic
   const processSummary = useCallback(async ({
@@ -65,6 +95,7 @@ export function useSummaryGeneration({
   }) => {
     setSummaryStatus(isRegeneration ? 'regenerating' : 'processing');
     setSummaryError(null);
+    setSummaryProgress(null);
 
     try {
       if (!transcriptText.trim()) {
@@ -112,6 +143,12 @@ export function useSummaryGeneration({
       startSummaryPolling(meeting.id, process_id, async (pollingResult) => {
         console.log('Summary status:', pollingResult);
 
+        if (pollingResult.status === 'cancelled') {
+          setSummaryProgress(null);
+          setSummaryStatus('idle');
+          return;
+        }
+
         // Handle errors
         if (pollingResult.status === 'error' || pollingResult.status === 'failed') {
           console.error('Backend returned error:', pollingResult.error);
@@ -138,6 +175,7 @@ export function useSummaryGeneration({
         // Handle successful completion
         if (pollingResult.status === 'completed' && pollingResult.data) {
           console.log('✅ Summary generation completed:', pollingResult.data);
+          setSummaryProgress(null);
 
           // Update meeting title if available
           const meetingName = pollingResult.data.MeetingName || pollingResult.meetingName;
@@ -307,9 +345,27 @@ export function useSummaryGeneration({
       }
     }
 
-    const fullTranscript = transcripts.map(t => t.text).join('\n');
+    // Bilingual meetings can be summarized from the translation track (original as fallback)
+    const fullTranscript = transcripts
+      .map(t => transcriptSource === 'translation' ? (t.translation || t.text) : t.text)
+      .join('\n');
     await processSummary({ transcriptText: fullTranscript, customPrompt });
-  }, [transcripts, processSummary, modelConfig, isModelConfigLoading, selectedTemplate]);
+  }, [transcripts, transcriptSource, processSummary, modelConfig, isModelConfigLoading, selectedTemplate]);
+
+  // Public API: Abort the running summary (the in-flight LLM request is dropped)
+  const handleCancelSummary = useCallback(async () => {
+    try {
+      await invokeTauri('api_cancel_summary', { meetingId: meeting.id });
+      toast.info('Summary generation cancelled');
+    } catch (error) {
+      console.error('Failed to cancel summary:', error);
+      toast.error('Failed to cancel summary', { description: String(error) });
+    } finally {
+      stopSummaryPolling(meeting.id);
+      setSummaryProgress(null);
+      setSummaryStatus('idle');
+    }
+  }, [meeting.id, stopSummaryPolling]);
 
   // Public API: Regenerate summary from original transcript
   const handleRegenerateSummary = useCallback(async () => {
@@ -327,8 +383,10 @@ export function useSummaryGeneration({
   return {
     summaryStatus,
     summaryError,
+    summaryProgress,
     handleGenerateSummary,
     handleRegenerateSummary,
+    handleCancelSummary,
     getSummaryStatusMessage,
   };
 }
@@ -0,0 +1,47 @@
+// Types for manifest-driven ONNX ASR models (Moonshine, SenseVoice, Canary, ...)
+import { invoke } from '@/lib/tauri';
+
+export type OnnxAsrModelStatus = 'Available' | { Invalid: string };
+
+export interface OnnxAsrModelInfo {
+  name: string;
+  display_name: string;
+  path: string;
+  description: string;
+  languages: string[]; // empty: the model detects the language itself
+  decoder: 'ctc' | 'transducer' | 'attention' | '';
+  quantized: boolean;
+  size_mb: number;
+  status: OnnxAsrModelStatus;
+}
+
+// Tauri command wrappers for the ONNX ASR backend
+export class OnnxAsrAPI {
+  static async init(): Promise<void> {
+    await invoke('onnx_asr_init');
+  }
+
+  static async getAvailableModels(): Promise<OnnxAsrModelInfo[]> {
+    return await invoke('onnx_asr_get_available_models');
+  }
+
+  static async loadModel(modelName: string): Promise<void> {
+    await invoke('onnx_asr_load_model', { modelName });
+  }
+
+  static async getCurrentModel(): Promise<string | null> {
+    return await invoke('onnx_asr_get_current_model');
+  }
+
+  static async getModelsDirectory(): Promise<string> {
+    return await invoke('onnx_asr_get_models_directory');
+  }
+
+  static async importModel(name: string, source: string): Promise<OnnxAsrModelInfo> {
+    return await invoke('onnx_asr_import_model', { name, source });
+  }
+
+  static async removeModel(modelName: string): Promise<void> {
+    await invoke('onnx_asr_remove_model', { modelName });
+  }
+}
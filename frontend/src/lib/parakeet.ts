@@ -7,6 +7,7 @@ export interface ParakeetModelInfo {
   speed: ProcessingSpeed;
   status: ModelStatus;
   description?: string;
+  custom?: boolean;
   quantization: QuantizationType;
 }
 
@@ -203,4 +204,12 @@ export class ParakeetAPI {
   static async openModelsFolder(): Promise<void> {
     await invoke('open_parakeet_models_folder');
   }
+
+  static async importModel(modelName: string, source: string, description?: string): Promise<ParakeetModelInfo> {
+    return await invoke('parakeet_import_model', { modelName, source, description: description || null });
+  }
+
+  static async removeCustomModel(modelName: string): Promise<void> {
+    await invoke('parakeet_remove_custom_model', { modelName });
+  }
 }
@@ -7,6 +7,7 @@ export interface ModelInfo {
   speed: ProcessingSpeed;
   status: ModelStatus;
   description?: string;
+  custom?: boolean;
 }
 
 export type ModelAccuracy = 'High' | 'Good' | 'Decent';
@@ -346,4 +347,12 @@ export class WhisperAPI {
   static async openModelsFolder(): Promise<void> {
     await invoke('open_models_folder');
   }
+
+  static async importModel(modelName: string, source: string, description?: string, sha256?: string): Promise<ModelInfo> {
+    return await invoke('whisper_import_model', { modelName, source, description: description || null, sha256: sha256 || null });
+  }
+
+  static async removeCustomModel(modelName: string): Promise<void> {
+    await invoke('whisper_remove_custom_model', { modelName });
+  }
 }
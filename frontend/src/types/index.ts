@@ -4,6 +4,14 @@ export interface Message {
   timestamp: string;
 }
 
+// Word-level timing (seconds from recording start)
+export interface WordTiming {
+  word: string;
+  start: number;
+  end: number;
+  probability?: number;
+}
+
 export interface Transcript {
   id: string;
   text: string;
@@ -16,6 +24,12 @@ export interface Transcript {
   audio_start_time?: number; // Seconds from recording start (e.g., 125.3)
   audio_end_time?: number;   // Seconds from recording start (e.g., 128.6)
   duration?: number;          // Segment duration in seconds (e.g., 3.3)
+  words?: WordTiming[];       // Word-level timings, when the engine provides them
+  translation?: string;           // Parallel translation track (bilingual meetings)
+  translation_language?: string;  // ISO 639-1 code of the translation
+  speaker?: string;               // Speaker label
+  user_edited?: boolean;          // Corrected by the user; kept on re-transcription
+  utterance_id?: number;          // Live recording: shared by an utterance's partials and its final result
 }
 
 export interface TranscriptUpdate {
@@ -30,6 +44,248 @@ export interface TranscriptUpdate {
   audio_start_time: number; // Seconds from recording start
   audio_end_time: number;   // Seconds from recording start
   duration: number;          // Segment duration in seconds
+  words?: WordTiming[];       // Word-level timings (empty/absent if unsupported)
+  utterance_id?: number;      // Stable per utterance; partials (event 'transcript-partial') share it with the final
+}
+
+// Translation of a transcript segment, emitted after the segment itself
+export interface TranslationUpdate {
+  sequence_id: number;
+  text: string;
+  target_language: string;
+  method: 'whisper' | 'llm';
+}
+
+export interface TranslationSettings {
+  target_language: string | null; // null = monolingual transcript
+  llm_model: string | null;       // Ollama model for non-English targets
+}
+
+// Second-pass transcription of a saved meeting
+export type RetranscriptionEngine = 'whisper' | 'parakeet';
+
+export type RetranscriptionStage =
+  | 'decoding'
+  | 'loading_model'
+  | 'transcribing'
+  | 'paused'
+  | 'saving'
+  | 'completed'
+  | 'cancelled'
+  | 'failed';
+
+export interface RetranscriptionProgress {
+  meeting_id: string;
+  engine: RetranscriptionEngine;
+  model_name: string;
+  stage: RetranscriptionStage;
+  completed_chunks: number;
+  total_chunks: number;
+  message?: string;
+}
+
+// Archived transcript, created whenever a meeting's segments are replaced
+export interface TranscriptVersion {
+  id: string;
+  meeting_id: string;
+  version: number;
+  label: string;
+  segments: string; // JSON array of Transcript
+  created_at: string;
+}
+
+// Low-confidence review (see audio/transcription/confidence.rs)
+export interface ConfidenceSettings {
+  low_confidence_threshold: number; // 0-1
+  redecode_low_confidence: boolean;
+}
+
+export interface LowConfidencePassage {
+  transcript_ids: string[];
+  text: string;
+  audio_start_time?: number;
+  audio_end_time?: number;
+  min_confidence: number;
+  avg_confidence: number;
+}
+
+// Post-decode hallucination filter (see audio/transcription/hallucination.rs)
+export interface HallucinationFilterSettings {
+  enabled: boolean;
+  repetition_loops: boolean;
+  min_loop_repeats: number;
+  known_phrases: boolean;
+  extra_phrases: Record<string, string[]>; // language code -> phrases, '*' for all languages
+  extra_patterns: string[];
+  silent_segments: boolean;
+  silence_rms: number;
+  near_duplicates: boolean;
+  duplicate_similarity: number; // 0.5-1
+}
+
+export interface DroppedSegment {
+  text: string;
+  reason: string;
+  audio_start_time: number;
+  dropped_at: string;
+}
+
+// Local model benchmark (see audio/transcription/benchmark.rs)
+export type BenchmarkEngine = 'whisper' | 'parakeet';
+
+export interface ModelBenchmark {
+  engine: BenchmarkEngine;
+  model_name: string;
+  real_time_factor: number; // processing time / audio duration
+  load_time_ms: number;
+  peak_memory_mb: number;
+  word_error_rate?: number; // 0-1, missing without a reference text
+  audio_duration_secs: number;
+  performance_tier: string;
+  sample: string;
+  created_at: string;
+}
+
+export interface ModelRecommendation {
+  engine: BenchmarkEngine;
+  provider: 'localWhisper' | 'parakeet';
+  model_name: string;
+  real_time_factor: number;
+  word_error_rate?: number;
+  meets_target: boolean;
+  target_rtf: number;
+}
+
+export interface BenchmarkReport {
+  results: ModelBenchmark[];
+  failures: string[];
+  recommendation?: ModelRecommendation;
+  applied: boolean;
+}
+
+export interface BenchmarkProgress {
+  engine: BenchmarkEngine;
+  model_name: string;
+  index: number;
+  total: number;
+  stage: 'loading' | 'transcribing' | 'done' | 'failed';
+}
+
+// Transcription backlog monitoring (see audio/transcription/backlog.rs)
+export type DegradationLevel = 'normal' | 'reduced_beam' | 'faster_model' | 'deferring';
+
+export interface BacklogSettings {
+  lag_threshold_secs: number;
+  recover_below_secs: number;
+  degrade: boolean; // off: only report the lag
+  reduce_beam: boolean;
+  switch_model: boolean;
+  defer_chunks: boolean;
+  fallback_whisper_model?: string | null;
+}
+
+// Payload of the `summary-progress` event (see summary/processor.rs)
+export interface SummaryProgressEvent {
+  meeting_id: string;
+  stage: 'chunk' | 'combine' | 'final';
+  chunk: number; // 1-based; equals total_chunks for the combine and final passes
+  total_chunks: number;
+  partial_markdown: string;
+}
+
+// ONNX Runtime tuning for Parakeet / ONNX ASR models (see onnx_asr/runtime.rs).
+// null fields are auto-tuned from the detected hardware.
+export type OnnxOptimizationLevel = 'disabled' | 'basic' | 'extended' | 'all';
+export type OnnxExecutionProvider = 'cpu' | 'openvino' | 'xnnpack';
+
+export interface OnnxRuntimeSettings {
+  intra_op_threads: number | null;
+  inter_op_threads: number | null;
+  optimization_level: OnnxOptimizationLevel | null;
+  memory_arena: boolean | null;
+  memory_pattern: boolean | null;
+  execution_provider: OnnxExecutionProvider | null;
+}
+
+export interface OnnxResolvedRuntimeConfig {
+  intra_op_threads: number;
+  inter_op_threads: number;
+  parallel_execution: boolean;
+  optimization_level: OnnxOptimizationLevel;
+  memory_arena: boolean;
+  memory_pattern: boolean;
+  requested_provider: OnnxExecutionProvider;
+  active_provider: OnnxExecutionProvider;
+}
+
+export interface OnnxRuntimeDiagnostics {
+  settings: OnnxRuntimeSettings;
+  resolved: OnnxResolvedRuntimeConfig;
+  last_applied: OnnxResolvedRuntimeConfig | null; // config of the last loaded model
+  available_providers: OnnxExecutionProvider[];
+  cpu_cores: number;
+  performance_tier: string;
+}
+
+// Payload of the `transcription-lag` event
+export interface TranscriptionLagEvent {
+  lagging: boolean;
+  lag_seconds: number;
+  queue_depth: number;
+  deferred_chunks: number;
+  threshold_seconds: number;
+  degradation_level: DegradationLevel;
+}
+
+// Chunks an earlier session left untranscribed, completed after restart
+export interface TranscriptionResumeOutcome {
+  meeting_folder: string;
+  meeting_name?: string | null;
+  meeting_id?: string | null;
+  created_meeting: boolean;
+  recovered_segments: number;
+  unrecoverable_chunks: number;
+  error?: string | null;
+}
+
+export type RedactionMode = 'storage' | 'llm_only' | 'both';
+
+export interface RedactionSettings {
+  enabled: boolean;
+  mode: RedactionMode;
+  emails: boolean;
+  phone_numbers: boolean;
+  card_numbers: boolean;
+  ibans: boolean;
+  custom_patterns: { name: string; pattern: string }[];
+  include_local_llm: boolean; // also redact Ollama prompts
+}
+
+// One placeholder in a meeting's redaction report (original values are never stored)
+export interface RedactionReportEntry {
+  meeting_id: string;
+  stage: 'storage' | 'llm';
+  category: string;
+  placeholder: string;
+  occurrences: number;
+  created_at: string;
+}
+
+export type ActionItemStatus = 'open' | 'done' | 'cancelled';
+
+// Action item extracted from a meeting summary (api_list_open_action_items,
+// api_get_meeting_action_items)
+export interface ActionItem {
+  id: string;
+  meeting_id: string;
+  meeting_title: string;
+  text: string;
+  owner: string | null;
+  due_date: string | null;
+  transcript_time: number | null; // Seconds from recording start
+  status: ActionItemStatus;
+  created_at: string;
+  updated_at: string;
 }
 
 export interface Block {
@@ -76,9 +332,18 @@ export interface BlockNoteBlock {
   children?: BlockNoteBlock[];
 }
 
+// Structured summary: meeting title plus one value per template section, keyed by the
+// snake_case section title (strings, string arrays, or arrays of typed field objects)
+export interface StructuredSummary {
+  title: string;
+  [sectionKey: string]: string | string[] | Record<string, string | number | boolean | null>[];
+}
+
 export interface SummaryDataResponse {
   markdown?: string;
   summary_json?: BlockNoteBlock[];
+  // Template-shaped JSON for templates with typed fields (markdown is rendered from it)
+  structured?: StructuredSummary;
   // Legacy format fields
   MeetingName?: string;
   _section_order?: string[];